@@ -2,9 +2,18 @@
 
 #![cfg_attr(not(feature = "std"), no_std, no_main)]
 
+pub use self::assets::{AssetReceiver, AssetVerse, Assets, AssetsRef, ContractError};
+
+/// A typed handle for calling an `Assets` contract at a known address through [`AssetVerse`].
+pub type AssetVerseRef = ink::contract_ref!(AssetVerse, ink::env::DefaultEnvironment);
+
 #[ink::contract]
 mod assets {
     use ink::{
+        env::{
+            call::{build_call, ExecutionInput, Selector},
+            DefaultEnvironment,
+        },
         prelude::string::{String, ToString},
         prelude::vec::Vec,
         storage::Mapping,
@@ -21,6 +30,31 @@ mod assets {
         name: String,
     }
 
+    /// Event emitted when a player changes their profile.
+    #[ink(event)]
+    pub struct PlayerUpdated {
+        /// The account ID of the player.
+        #[ink(topic)]
+        account: AccountId,
+
+        /// The player's new in-game name.
+        name: String,
+
+        /// The player's new avatar URI.
+        avatar_uri: String,
+    }
+
+    /// Event emitted when a player account is removed.
+    #[ink(event)]
+    pub struct PlayerDeregistered {
+        /// The account ID of the removed player.
+        #[ink(topic)]
+        account: AccountId,
+
+        /// Whether remaining holdings were burned with the account.
+        burned: bool,
+    }
+
     /// Event emitted when a new in-game asset is registered on-chain.
     #[ink(event)]
     pub struct AssetCreated {
@@ -33,6 +67,9 @@ mod assets {
 
         /// The asset's price in native token units.
         price: Balance,
+
+        /// Whether the game can freeze and claw back the asset.
+        recoverable: bool,
     }
 
     /// Emitted when a player purchases an asset from the game store.
@@ -48,6 +85,9 @@ mod assets {
         /// The quantity of the asset purchased.
         count: u64,
 
+        /// The average price paid per unit, after any discount.
+        unit_price: Balance,
+
         /// The total price paid for the assets.
         total_price: Balance,
     }
@@ -77,23 +117,26 @@ mod assets {
         #[ink(topic)]
         account: AccountId,
 
+        /// The game of the asset being given away.
+        from_game: String,
+
         /// The asset being given away.
         from_asset: String,
 
+        /// The game of the asset being received.
+        to_game: String,
+
         /// The asset being received.
         to_asset: String,
 
         /// The quantity of the asset given.
-        from_count: i64,
+        from_count: u64,
 
         /// The quantity of the asset received.
-        to_count: i64,
-
-        /// Any balance refunded to the user due to excess value.
-        refund: Balance,
+        to_count: u64,
     }
 
-    /// Emitted when a player's asset count is modified (manually or through gameplay).
+    /// Emitted when a player's asset count is modified, by the player or by their game.
     #[ink(event)]
     pub struct AssetModified {
         /// The account whose asset was modified.
@@ -104,536 +147,9938 @@ mod assets {
         asset: String,
 
         /// The updated quantity after modification.
-        new_count: i64,
+        new_count: u64,
 
         /// Whether the asset count was increased (`true`) or decreased (`false`).
         increased: bool,
     }
 
-    /// Custom errors that can be returned by the contract methods.
-    #[derive(Debug, PartialEq)]
-    #[ink::scale_derive(Encode, Decode, TypeInfo)]
-    pub enum ContractError {
-        /// The specified asset could not be found.
-        AssetNotFound,
+    /// Emitted when the metadata or attributes of an asset are changed by its game owner.
+    #[ink(event)]
+    pub struct MetadataUpdated {
+        /// The game to which the asset belongs.
+        game: String,
 
-        /// The specified game has no registered assets.
-        GameWithoutAssets,
+        /// The asset whose metadata was updated.
+        #[ink(topic)]
+        asset: String,
+    }
 
-        /// The player does not have enough balance to complete the action.
-        InsufficientBalance,
+    /// Emitted when a game mints a unique item to a player.
+    #[ink(event)]
+    pub struct ItemMinted {
+        /// The identifier of the newly minted item.
+        #[ink(topic)]
+        token_id: TokenId,
 
-        /// The specified player could not be found in storage.
-        PlayerNotFound,
+        /// The game that issued the item.
+        game: String,
 
-        /// The player does not have enough units of the asset to proceed.
-        InsufficientAssetCount,
+        /// The catalog asset the item is an instance of.
+        asset: String,
+
+        /// The player receiving the item.
+        #[ink(topic)]
+        owner: AccountId,
     }
 
-    /// The main contract for managing game players and in-game assets.
-    #[derive(Default)]
-    #[ink(storage)]
-    pub struct Assets {
-        /// Mapping from a player's `AccountId` to their `Player` data.
-        ///
-        /// Each player has:
-        /// - A `name`: their in-game identity.
-        /// - A `balance`: their current token balance.
-        /// - A list of `assets`: strings representing assets with quantities (e.g., "firegun_9").
-        players: Mapping<AccountId, Player>,
+    /// Emitted when a unique item changes owner.
+    #[ink(event)]
+    pub struct ItemTransferred {
+        /// The item transferred.
+        #[ink(topic)]
+        token_id: TokenId,
 
-        /// Mapping from a game to its assets
-        assets: Mapping<String, Vec<(String, Balance)>>,
+        /// The previous owner.
+        #[ink(topic)]
+        from: AccountId,
 
-        /// Games available onchain
-        games: Vec<String>,
+        /// The new owner.
+        #[ink(topic)]
+        to: AccountId,
     }
 
-    /// Data structure representing an individual player.
-    #[derive(Default)]
-    #[ink::scale_derive(Encode, Decode, TypeInfo)]
-    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
-    pub struct Player {
-        /// The player’s chosen name across games.
-        name: String,
+    /// Emitted when a state slot of a unique item is written.
+    #[ink(event)]
+    pub struct ItemStateUpdated {
+        /// The item whose state changed.
+        #[ink(topic)]
+        token_id: TokenId,
 
-        /// Player’s available token balance.
-        balance: Balance,
+        /// The state slot written (e.g., "durability").
+        key: String,
 
-        /// List of owned assets with quantity encoded in the string (e.g., "cod_firegun_9").
-        ///
-        /// This flat structure allows flexible handling of asset counts without a custom struct.
-        assets: Vec<String>,
+        /// The new value of the slot.
+        value: String,
+
+        /// The item's state version after the update.
+        version: u32,
+
+        /// Hash chaining every update applied to the item so far.
+        state_hash: Hash,
     }
 
-    impl Assets {
-        /// Constructor that initializes the assetverse contract
-        #[ink(constructor)]
-        pub fn new() -> Self {
-            Self {
-                players: Mapping::default(),
-                assets: Mapping::default(),
-                games: Vec::new(),
-            }
-        }
+    /// Emitted when a game owner proposes, approves or revokes a conversion route.
+    #[ink(event)]
+    pub struct ConversionRuleUpdated {
+        /// The game of the asset given in the conversion.
+        #[ink(topic)]
+        from_game: String,
 
-        /// Register a playing account across the network.
-        /// Players are endowed with 1M units of tokens at registration to buy game assets.
-        #[ink(message, payable)]
-        pub fn register_player(&mut self, name: String) {
-            // Get caller
-            let account_id = self.env().caller();
+        /// The asset given in the conversion.
+        from_asset: String,
 
-            // Create a new player with default values
-            let player = Player {
-                name: name.clone(),
-                balance: 1_000_000,
-                assets: Vec::new(),
-            };
+        /// The game of the asset received in the conversion.
+        #[ink(topic)]
+        to_game: String,
 
-            // Emit event
-            self.env().emit_event(PlayerCreated {
-                account: account_id.clone(),
-                name,
-            });
+        /// The asset received in the conversion.
+        to_asset: String,
 
-            self.players.insert(&account_id, &player);
-        }
+        /// Whether both game owners have approved the route and players can use it.
+        active: bool,
+    }
 
-        /// Returns a player account related to an `AccountId`.
-        #[ink(message, payable)]
-        pub fn auth_player(&mut self) -> Option<Player> {
-            // Get caller
-            let account_id = self.env().caller();
-            self.players.get(&account_id)
-        }
+    /// Emitted when a game owner creates a bundle of assets.
+    #[ink(event)]
+    pub struct BundleCreated {
+        /// The identifier of the new bundle.
+        #[ink(topic)]
+        bundle_id: BundleId,
 
-        /// Returns the registered games
-        #[ink(message, payable)]
-        pub fn games(&self) -> Vec<String> {
-            self.games.clone()
-        }
+        /// The game selling the bundle.
+        game: String,
 
-        /// Register an asset
-        #[ink(message, payable)]
-        pub fn register_asset(&mut self, game: String, name: String, price: Balance) {
-            // Fetch current assets for the game
-            let mut asset_list = self.assets.get(&game).unwrap_or_default();
+        /// The price of one bundle.
+        price: Balance,
+    }
 
-            // Append the new asset
-            asset_list.push((name.clone(), price));
+    /// Emitted when a player buys bundles from a game store.
+    #[ink(event)]
+    pub struct BundlePurchased {
+        /// The account that made the purchase.
+        #[ink(topic)]
+        account: AccountId,
 
-            // Store the updated list back in the mapping
-            self.assets.insert(&game, &asset_list);
+        /// The bundle purchased.
+        #[ink(topic)]
+        bundle_id: BundleId,
 
-            // Store the game entry
-            self.games.push(game.clone());
+        /// The number of bundles purchased.
+        count: u64,
 
-            // Emit event
-            self.env().emit_event(AssetCreated { game, name, price });
-        }
+        /// The total price paid for the bundles.
+        total_price: Balance,
+    }
 
-        /// Get assets belonging to a game
-        #[ink(message, payable)]
-        pub fn assets(&self, game: String) -> Option<Vec<(String, Balance)>> {
-            self.assets.get(&game)
-        }
+    /// Emitted when a game owner schedules a sale on an asset.
+    #[ink(event)]
+    pub struct SaleScheduled {
+        /// The identifier of the sale.
+        #[ink(topic)]
+        sale_id: SaleId,
 
-        /// Buy units of an asset, deducting its price from the player's balance.
-        #[ink(message, payable)]
-        pub fn purchase_asset(
-            &mut self,
-            game: String,
-            asset: String,
-            count: u64,
-        ) -> Result<(), ContractError> {
-            let account_id = self.env().caller();
+        /// The game running the sale.
+        game: String,
 
-            // Validate count
-            if count <= 0 {
-                return Err(ContractError::InsufficientAssetCount);
-            }
+        /// The asset on sale.
+        #[ink(topic)]
+        asset: String,
 
-            // Retrieve asset price from game
-            let asset_price = match self.assets.get(&game) {
-                Some(asset_list) => {
-                    let mut price: Option<Balance> = None;
-                    for (a_name, a_price) in asset_list.iter() {
-                        if *a_name == asset {
-                            price = Some(*a_price);
-                            break;
-                        }
-                    }
-                    match price {
-                        Some(p) => p,
-                        None => return Err(ContractError::AssetNotFound),
-                    }
-                }
-                None => return Err(ContractError::GameWithoutAssets),
-            };
+        /// The discount applied during the sale.
+        discount: Discount,
 
-            // Load player
-            let mut player = match self.players.get(&account_id) {
-                Some(p) => p,
-                None => return Err(ContractError::PlayerNotFound),
-            };
+        /// First block of the sale.
+        start: BlockNumber,
 
-            // Check for sufficient balance
-            if player.balance < (asset_price * count as u128) {
-                return Err(ContractError::InsufficientBalance);
-            }
+        /// Last block of the sale.
+        end: BlockNumber,
+    }
 
-            // Deduct payment
-            player.balance -= asset_price * count as u128;
+    /// Emitted when a game owner issues a coupon for an asset.
+    #[ink(event)]
+    pub struct CouponAdded {
+        /// The hash of the coupon code.
+        #[ink(topic)]
+        code_hash: Hash,
 
-            // Update or add asset string (e.g., firegun_5)
-            let mut found = false;
+        /// The game issuing the coupon.
+        game: String,
 
-            for i in 0..player.assets.len() {
-                if player.assets[i].starts_with(&asset) {
-                    let parts: Vec<&str> = player.assets[i].split('_').collect();
-                    if parts.len() == 2 {
-                        if let Ok(current_count) = parts[1].parse::<u64>() {
-                            let new_count = current_count + count;
-
-                            let mut updated = String::from(asset.as_str());
-                            updated.push('_');
-                            updated.push_str(&new_count.to_string());
-
-                            player.assets[i] = updated;
-                            found = true;
-                            break;
-                        }
-                    }
-                }
-            }
+        /// The asset the coupon applies to.
+        asset: String,
+    }
 
-            // If asset not found, add it
-            if !found {
-                let mut new_asset = String::from(asset.as_str());
-                new_asset.push('_');
-                new_asset.push_str(&count.to_string());
+    /// Emitted when a game owner sets or clears the price curve of an asset.
+    #[ink(event)]
+    pub struct PriceCurveUpdated {
+        /// The game to which the asset belongs.
+        game: String,
 
-                player.assets.push(new_asset);
-            }
+        /// The asset whose pricing changed.
+        #[ink(topic)]
+        asset: String,
 
-            // Store player
-            self.players.insert(&account_id, &player);
+        /// The new curve, or `None` to return to the fixed catalog price.
+        curve: Option<PriceCurve>,
+    }
 
-            // Emit Event
-            self.env().emit_event(AssetPurchased {
-                account: account_id,
-                asset,
-                count,
-                total_price: asset_price * count as u128,
-            });
+    /// Emitted when a player sells units back along an asset's price curve.
+    #[ink(event)]
+    pub struct AssetSoldBack {
+        /// The account that sold the units.
+        #[ink(topic)]
+        account: AccountId,
 
-            Ok(())
-        }
+        /// The game to which the asset belongs.
+        game: String,
 
-        /// Gift an asset to a specific player without charging them.
-        #[ink(message, payable)]
-        pub fn gift_asset(
-            &mut self,
-            receiver: AccountId,
-            asset: String,
-            amount: u64,
-        ) -> Result<(), ContractError> {
-            // Get sender
-            let sender = self.env().caller();
-            let mut sender_player = match self.players.get(&sender) {
-                Some(p) => p,
-                None => return Err(ContractError::PlayerNotFound),
-            };
+        /// The asset sold back.
+        asset: String,
 
-            // Check if sender has enough of the asset
-            let mut found_sender_asset = false;
-
-            for i in 0..sender_player.assets.len() {
-                if sender_player.assets[i].starts_with(&asset) {
-                    let parts: Vec<&str> = sender_player.assets[i].split('_').collect();
-                    if parts.len() == 2 {
-                        if let Ok(current_count) = parts[1].parse::<u64>() {
-                            if current_count < amount {
-                                return Err(ContractError::InsufficientAssetCount);
-                            }
+        /// The quantity sold back.
+        count: u64,
 
-                            let new_count = current_count - amount;
+        /// The balance paid out from the curve reserve.
+        payout: Balance,
+    }
 
-                            if new_count == 0 {
-                                sender_player.assets.remove(i);
-                            } else {
-                                let mut updated = String::from(asset.as_str());
-                                updated.push('_');
-                                updated.push_str(&new_count.to_string());
-                                sender_player.assets[i] = updated;
-                            }
+    /// Emitted when a game owner moves balance into or out of the game's buyback reserve.
+    #[ink(event)]
+    pub struct ReserveUpdated {
+        /// The game whose reserve changed.
+        #[ink(topic)]
+        game: String,
 
-                            found_sender_asset = true;
-                            break;
-                        }
-                    }
-                }
-            }
+        /// The amount moved.
+        amount: Balance,
 
-            if !found_sender_asset {
-                return Err(ContractError::AssetNotFound);
-            }
+        /// Whether the amount was deposited (`true`) or withdrawn (`false`).
+        deposited: bool,
 
-            // Get receiver
-            let mut receiver_player = match self.players.get(&receiver) {
-                Some(p) => p,
-                None => return Err(ContractError::PlayerNotFound),
-            };
+        /// The reserve after the change.
+        reserve: Balance,
+    }
 
-            // Add to receiver's asset count or insert new
-            let mut found_receiver_asset = false;
-
-            for i in 0..receiver_player.assets.len() {
-                if receiver_player.assets[i].starts_with(&asset) {
-                    let parts: Vec<&str> = receiver_player.assets[i].split('_').collect();
-                    if parts.len() == 2 {
-                        if let Ok(current_count) = parts[1].parse::<u64>() {
-                            let new_count = current_count + amount;
-
-                            let mut updated = String::from(asset.as_str());
-                            updated.push('_');
-                            updated.push_str(&new_count.to_string());
-
-                            receiver_player.assets[i] = updated;
-                            found_receiver_asset = true;
-                            break;
-                        }
-                    }
-                }
-            }
+    /// Emitted when a player sells units of an asset back to the game store.
+    #[ink(event)]
+    pub struct AssetSold {
+        /// The account that sold the units.
+        #[ink(topic)]
+        account: AccountId,
 
-            if !found_receiver_asset {
-                let mut new_asset = String::from(asset.as_str());
-                new_asset.push('_');
-                new_asset.push_str(&amount.to_string());
-                receiver_player.assets.push(new_asset);
-            }
+        /// The game buying the units back.
+        game: String,
 
-            // Store updates
-            self.players.insert(&sender, &sender_player);
-            self.players.insert(&receiver, &receiver_player);
+        /// The asset sold.
+        asset: String,
+
+        /// The quantity sold.
+        count: u64,
+
+        /// The balance paid out from the game's reserve.
+        payout: Balance,
+    }
+
+    /// Emitted when a game declares its in-game currency.
+    #[ink(event)]
+    pub struct CurrencyCreated {
+        /// The sub-id of the currency.
+        #[ink(topic)]
+        currency_id: CurrencyId,
+
+        /// The game issuing the currency.
+        game: String,
+
+        /// The ticker of the currency.
+        symbol: String,
+    }
+
+    /// Emitted when units of an in-game currency move, PSP22 style.
+    /// Mints have no `from` and burns have no `to`.
+    #[ink(event)]
+    pub struct CurrencyTransfer {
+        /// The currency transferred.
+        #[ink(topic)]
+        currency_id: CurrencyId,
+
+        /// The account sending the units.
+        #[ink(topic)]
+        from: Option<AccountId>,
+
+        /// The account receiving the units.
+        #[ink(topic)]
+        to: Option<AccountId>,
+
+        /// The amount transferred.
+        value: Balance,
+    }
+
+    /// Emitted when an allowance of an in-game currency is set, PSP22 style.
+    #[ink(event)]
+    pub struct CurrencyApproval {
+        /// The currency approved.
+        #[ink(topic)]
+        currency_id: CurrencyId,
+
+        /// The account granting the allowance.
+        #[ink(topic)]
+        owner: AccountId,
+
+        /// The account allowed to spend.
+        #[ink(topic)]
+        spender: AccountId,
+
+        /// The new allowance.
+        amount: Balance,
+    }
+
+    /// Emitted when a game owner changes the currency an asset is priced in.
+    #[ink(event)]
+    pub struct PriceCurrencyUpdated {
+        /// The game to which the asset belongs.
+        game: String,
+
+        /// The asset whose price currency changed.
+        #[ink(topic)]
+        asset: String,
+
+        /// The new price currency.
+        currency: PriceCurrency,
+    }
+
+    /// Emitted when a player locks units of an asset to earn rewards.
+    #[ink(event)]
+    pub struct AssetStaked {
+        /// The account staking.
+        #[ink(topic)]
+        account: AccountId,
+
+        /// The game to which the asset belongs.
+        game: String,
+
+        /// The asset staked.
+        asset: String,
+
+        /// The quantity staked.
+        count: u64,
+    }
+
+    /// Emitted when a player unlocks staked units of an asset.
+    #[ink(event)]
+    pub struct AssetUnstaked {
+        /// The account unstaking.
+        #[ink(topic)]
+        account: AccountId,
+
+        /// The game to which the asset belongs.
+        game: String,
+
+        /// The asset unstaked.
+        asset: String,
+
+        /// The quantity unstaked.
+        count: u64,
+    }
+
+    /// Emitted when a player claims staking rewards.
+    #[ink(event)]
+    pub struct RewardsClaimed {
+        /// The account claiming.
+        #[ink(topic)]
+        account: AccountId,
+
+        /// The game paying the rewards in its currency.
+        game: String,
+
+        /// The staked asset that earned the rewards.
+        asset: String,
+
+        /// The amount of currency paid.
+        amount: Balance,
+    }
+
+    /// Emitted when a player founds a guild.
+    #[ink(event)]
+    pub struct GuildCreated {
+        /// The identifier of the new guild.
+        #[ink(topic)]
+        guild_id: GuildId,
+
+        /// The guild's name.
+        name: String,
+
+        /// The founding leader.
+        #[ink(topic)]
+        leader: AccountId,
+    }
+
+    /// Emitted when a guild member joins, changes role or leaves.
+    #[ink(event)]
+    pub struct GuildMemberUpdated {
+        /// The guild concerned.
+        #[ink(topic)]
+        guild_id: GuildId,
+
+        /// The member concerned.
+        #[ink(topic)]
+        account: AccountId,
+
+        /// The member's new role, or `None` if they left the guild.
+        role: Option<GuildRole>,
+    }
+
+    /// Emitted when a member deposits into a guild vault.
+    #[ink(event)]
+    pub struct VaultDeposited {
+        /// The guild receiving the deposit.
+        #[ink(topic)]
+        guild_id: GuildId,
+
+        /// The depositing member.
+        #[ink(topic)]
+        account: AccountId,
+
+        /// What was deposited.
+        item: VaultItem,
+    }
+
+    /// Emitted when a member proposes taking something out of a guild vault.
+    #[ink(event)]
+    pub struct WithdrawalProposed {
+        /// The guild concerned.
+        #[ink(topic)]
+        guild_id: GuildId,
+
+        /// The identifier of the proposal within the guild.
+        proposal_id: u32,
+
+        /// The player who would receive the withdrawal.
+        to: AccountId,
+
+        /// What would be withdrawn.
+        item: VaultItem,
+    }
+
+    /// Emitted when a leader or officer approves a vault withdrawal.
+    #[ink(event)]
+    pub struct WithdrawalApproved {
+        /// The guild concerned.
+        #[ink(topic)]
+        guild_id: GuildId,
+
+        /// The identifier of the proposal within the guild.
+        proposal_id: u32,
+
+        /// The approving leader or officer.
+        #[ink(topic)]
+        approver: AccountId,
+    }
+
+    /// Emitted when an approved withdrawal leaves a guild vault.
+    #[ink(event)]
+    pub struct VaultWithdrawn {
+        /// The guild concerned.
+        #[ink(topic)]
+        guild_id: GuildId,
+
+        /// The identifier of the proposal within the guild.
+        proposal_id: u32,
+
+        /// The player receiving the withdrawal.
+        #[ink(topic)]
+        to: AccountId,
+
+        /// What was withdrawn.
+        item: VaultItem,
+    }
+
+    /// Emitted when a game owner changes the catalog price of an asset.
+    #[ink(event)]
+    pub struct AssetPriceUpdated {
+        /// The game to which the asset belongs.
+        game: String,
+
+        /// The asset whose price changed.
+        #[ink(topic)]
+        asset: String,
+
+        /// The new price.
+        price: Balance,
+    }
+
+    /// Emitted when a signer proposes a game owner action.
+    #[ink(event)]
+    pub struct GameActionProposed {
+        /// The identifier of the proposal.
+        #[ink(topic)]
+        proposal_id: u32,
+
+        /// The game the action applies to.
+        #[ink(topic)]
+        game: String,
+
+        /// The proposed action.
+        action: GameAction,
+
+        /// Last block at which the proposal can be executed.
+        expires_at: BlockNumber,
+    }
+
+    /// Emitted when a signer approves a game owner action.
+    #[ink(event)]
+    pub struct GameActionApproved {
+        /// The identifier of the proposal.
+        #[ink(topic)]
+        proposal_id: u32,
+
+        /// The approving signer.
+        #[ink(topic)]
+        signer: AccountId,
+    }
+
+    /// Emitted when an approved game owner action is carried out.
+    #[ink(event)]
+    pub struct GameActionExecuted {
+        /// The identifier of the proposal.
+        #[ink(topic)]
+        proposal_id: u32,
+
+        /// The game the action applied to.
+        #[ink(topic)]
+        game: String,
+    }
+
+    /// Emitted when a game owner sets or clears the key that signs its vouchers.
+    #[ink(event)]
+    pub struct VoucherSignerSet {
+        /// The game whose signer changed.
+        #[ink(topic)]
+        game: String,
+
+        /// The new signing key, if any.
+        signer: Option<SignerKey>,
+    }
+
+    /// Emitted when a signed voucher is redeemed.
+    #[ink(event)]
+    pub struct VoucherRedeemed {
+        /// The game that issued the voucher.
+        #[ink(topic)]
+        game: String,
+
+        /// The player receiving the assets.
+        #[ink(topic)]
+        player: AccountId,
+
+        /// The asset awarded.
+        asset: String,
+
+        /// The number of units awarded.
+        count: u64,
+
+        /// The voucher nonce.
+        nonce: u64,
+    }
+
+    /// Emitted when a relayer submits an action signed by a player.
+    #[ink(event)]
+    pub struct SignedCallExecuted {
+        /// The player who signed the action.
+        #[ink(topic)]
+        player: AccountId,
+
+        /// The account that submitted it.
+        #[ink(topic)]
+        relayer: AccountId,
+
+        /// The player nonce consumed.
+        nonce: u64,
+
+        /// Balance paid by the player to the relayer.
+        fee: Balance,
+    }
+
+    /// Emitted when a game owner escrows assets for a merkle airdrop.
+    #[ink(event)]
+    pub struct AirdropCreated {
+        /// The identifier of the airdrop.
+        #[ink(topic)]
+        airdrop_id: AirdropId,
+
+        /// The game running the airdrop.
+        #[ink(topic)]
+        game: String,
+
+        /// The asset dropped.
+        asset: String,
+
+        /// Root of the tree of `(index, account, count)` leaves.
+        merkle_root: Hash,
+
+        /// Units escrowed for claims.
+        total: u64,
+
+        /// Last block at which claims are accepted.
+        expires_at: BlockNumber,
+    }
+
+    /// Emitted when a player claims their share of an airdrop.
+    #[ink(event)]
+    pub struct AirdropClaimed {
+        /// The identifier of the airdrop.
+        #[ink(topic)]
+        airdrop_id: AirdropId,
+
+        /// The claiming player.
+        #[ink(topic)]
+        account: AccountId,
+
+        /// Units claimed.
+        count: u64,
+    }
+
+    /// Emitted when a game owner takes back the unclaimed units of an expired airdrop.
+    #[ink(event)]
+    pub struct AirdropReclaimed {
+        /// The identifier of the airdrop.
+        #[ink(topic)]
+        airdrop_id: AirdropId,
+
+        /// Units returned to the owner.
+        count: u64,
+    }
+
+    /// Emitted when a game owner lets or stops an account from awarding and consuming its assets.
+    #[ink(event)]
+    pub struct GameOperatorSet {
+        /// The game the operator acts for.
+        #[ink(topic)]
+        game: String,
+
+        /// The operator, usually a game logic contract.
+        #[ink(topic)]
+        operator: AccountId,
+
+        /// Whether the operator is now allowed.
+        allowed: bool,
+    }
+
+    /// Emitted when a player challenges another to a wager match.
+    #[ink(event)]
+    pub struct MatchCreated {
+        /// The identifier of the match.
+        #[ink(topic)]
+        match_id: MatchId,
+
+        /// The challenging player.
+        #[ink(topic)]
+        challenger: AccountId,
+
+        /// The challenged player.
+        #[ink(topic)]
+        opponent: AccountId,
+
+        /// What each player stakes.
+        stake: VaultItem,
+    }
+
+    /// Emitted when the challenged player accepts a match.
+    #[ink(event)]
+    pub struct MatchJoined {
+        /// The identifier of the match.
+        #[ink(topic)]
+        match_id: MatchId,
+
+        /// Last block at which moves can be revealed.
+        reveal_deadline: BlockNumber,
+    }
+
+    /// Emitted when a player reveals their committed move.
+    #[ink(event)]
+    pub struct MoveRevealed {
+        /// The identifier of the match.
+        #[ink(topic)]
+        match_id: MatchId,
+
+        /// The revealing player.
+        #[ink(topic)]
+        player: AccountId,
+
+        /// The revealed move.
+        hand: Hand,
+    }
+
+    /// Emitted when a match is paid out, refunded or forfeited.
+    #[ink(event)]
+    pub struct MatchSettled {
+        /// The identifier of the match.
+        #[ink(topic)]
+        match_id: MatchId,
+
+        /// The player who took the pot, or `None` when the stakes were returned.
+        winner: Option<AccountId>,
+    }
+
+    /// Emitted when a game owner opens a tournament.
+    #[ink(event)]
+    pub struct TournamentCreated {
+        /// The identifier of the tournament.
+        #[ink(topic)]
+        tournament_id: TournamentId,
+
+        /// The game running the tournament.
+        #[ink(topic)]
+        game: String,
+
+        /// The tournament terms.
+        terms: TournamentTerms,
+    }
+
+    /// Emitted when a player pays the entry fee of a tournament.
+    #[ink(event)]
+    pub struct TournamentEntered {
+        /// The identifier of the tournament.
+        #[ink(topic)]
+        tournament_id: TournamentId,
+
+        /// The entrant.
+        #[ink(topic)]
+        account: AccountId,
+    }
+
+    /// Emitted when the results of a tournament are submitted and its prizes paid.
+    #[ink(event)]
+    pub struct TournamentCompleted {
+        /// The identifier of the tournament.
+        #[ink(topic)]
+        tournament_id: TournamentId,
+
+        /// The ranked players, winner first.
+        ranking: Vec<AccountId>,
+    }
+
+    /// Emitted when a tournament is cancelled and its entry fees refunded.
+    #[ink(event)]
+    pub struct TournamentCancelled {
+        /// The identifier of the tournament.
+        #[ink(topic)]
+        tournament_id: TournamentId,
+    }
+
+    /// Emitted when a game defines an achievement.
+    #[ink(event)]
+    pub struct AchievementDefined {
+        /// The game defining the achievement.
+        #[ink(topic)]
+        game: String,
+
+        /// The achievement identifier within the game.
+        achievement: String,
+
+        /// Points added to the gamer score of players who earn it.
+        points: u32,
+    }
+
+    /// Emitted when a player earns an achievement.
+    #[ink(event)]
+    pub struct AchievementGranted {
+        /// The player earning the achievement.
+        #[ink(topic)]
+        account: AccountId,
+
+        /// The game granting the achievement.
+        #[ink(topic)]
+        game: String,
+
+        /// The achievement identifier within the game.
+        achievement: String,
+
+        /// The player's gamer score after the grant.
+        gamer_score: u64,
+    }
+
+    /// Emitted when a game owner flags a player for fraud.
+    #[ink(event)]
+    pub struct PlayerFlagged {
+        /// The flagging game.
+        #[ink(topic)]
+        game: String,
+
+        /// The flagged player.
+        #[ink(topic)]
+        account: AccountId,
+
+        /// Hash of the off-chain reason and evidence.
+        reason_hash: Hash,
+    }
+
+    /// Emitted when a flagged player appeals.
+    #[ink(event)]
+    pub struct FlagAppealed {
+        /// The flagging game.
+        #[ink(topic)]
+        game: String,
+
+        /// The flagged player.
+        #[ink(topic)]
+        account: AccountId,
+
+        /// Hash of the off-chain appeal.
+        appeal_hash: Hash,
+    }
+
+    /// Emitted when a game owner clears a flag.
+    #[ink(event)]
+    pub struct FlagCleared {
+        /// The flagging game.
+        #[ink(topic)]
+        game: String,
+
+        /// The player cleared.
+        #[ink(topic)]
+        account: AccountId,
+    }
+
+    /// Emitted when a game changes a player's reputation.
+    #[ink(event)]
+    pub struct ReputationChanged {
+        /// The game rating the player.
+        #[ink(topic)]
+        game: String,
+
+        /// The player rated.
+        #[ink(topic)]
+        account: AccountId,
+
+        /// The player's reputation with the game.
+        score: i32,
+
+        /// The player's reputation summed across the games counted globally.
+        global_score: i64,
+    }
+
+    /// Emitted when the contract admin counts a game in global reputation or stops counting it.
+    #[ink(event)]
+    pub struct ReputationGameSet {
+        /// The game.
+        #[ink(topic)]
+        game: String,
+
+        /// Whether the game's reputation counts towards global reputation.
+        counted: bool,
+    }
+
+    /// Emitted when a game changes whether it accepts assets from flagged players.
+    #[ink(event)]
+    pub struct FlagPolicySet {
+        /// The game whose policy changed.
+        #[ink(topic)]
+        game: String,
+
+        /// Whether transfers from flagged players are refused.
+        refuse_flagged: bool,
+    }
+
+    /// Emitted when a game starts or stops trusting another game's flags and reputation.
+    #[ink(event)]
+    pub struct FlagTrustSet {
+        /// The trusting game.
+        #[ink(topic)]
+        game: String,
+
+        /// The game being trusted.
+        #[ink(topic)]
+        trusted_game: String,
+
+        /// Whether the game is now trusted.
+        trusted: bool,
+    }
+
+    /// Emitted when a game owner freezes a player's holding of a recoverable asset.
+    #[ink(event)]
+    pub struct Frozen {
+        /// The game owning the asset.
+        game: String,
+
+        /// The player whose holding is frozen.
+        #[ink(topic)]
+        account: AccountId,
+
+        /// The frozen asset.
+        #[ink(topic)]
+        asset: String,
+    }
+
+    /// Emitted when a game owner unfreezes a player's holding.
+    #[ink(event)]
+    pub struct Unfrozen {
+        /// The game owning the asset.
+        game: String,
+
+        /// The player whose holding is unfrozen.
+        #[ink(topic)]
+        account: AccountId,
+
+        /// The unfrozen asset.
+        #[ink(topic)]
+        asset: String,
+    }
+
+    /// Emitted when a game owner burns units of a recoverable asset from a player.
+    #[ink(event)]
+    pub struct ClawedBack {
+        /// The game owning the asset.
+        game: String,
+
+        /// The player the units were taken from.
+        #[ink(topic)]
+        account: AccountId,
+
+        /// The asset recovered.
+        #[ink(topic)]
+        asset: String,
+
+        /// Units burned.
+        count: u64,
+    }
+
+    /// Custom errors that can be returned by the contract methods.
+    #[derive(Debug, PartialEq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    pub enum ContractError {
+        /// The specified asset could not be found.
+        AssetNotFound,
+
+        /// The specified game has no registered assets.
+        GameWithoutAssets,
+
+        /// The player does not have enough balance to complete the action.
+        InsufficientBalance,
+
+        /// The specified player could not be found in storage.
+        PlayerNotFound,
+
+        /// The player does not have enough units of the asset to proceed.
+        InsufficientAssetCount,
+
+        /// The caller is not the owner of the game.
+        NotGameOwner,
+
+        /// The specified item could not be found.
+        ItemNotFound,
+
+        /// No conversion route approved by both game owners exists between the assets.
+        ConversionNotApproved,
+
+        /// The quantity is not a whole multiple of the conversion rate, or the rate is invalid.
+        InvalidConversionAmount,
+
+        /// The conversion would exceed the route's cap for the current period.
+        ConversionCapExceeded,
+
+        /// An arithmetic operation on a balance or count overflowed.
+        Overflow,
+
+        /// The caller already has a player account.
+        PlayerAlreadyExists,
+
+        /// The display name is already reserved by another player.
+        NameTaken,
+
+        /// The player still holds assets and did not ask for them to be burned.
+        HoldingsNotEmpty,
+
+        /// The specified bundle could not be found.
+        BundleNotFound,
+
+        /// The bundle is not on sale at the current block.
+        BundleUnavailable,
+
+        /// The purchase would exceed the per-player limit.
+        PurchaseLimitReached,
+
+        /// The specified sale could not be found.
+        SaleNotFound,
+
+        /// The discount is malformed (e.g., more than 100%).
+        InvalidDiscount,
+
+        /// The coupon code is unknown, expired, used up or for another asset.
+        InvalidCoupon,
+
+        /// The asset is sold at a fixed price and has no price curve.
+        NoPriceCurve,
+
+        /// The price curve cannot change while curve-sold units are outstanding.
+        PriceCurveInUse,
+
+        /// The price curve parameters are invalid.
+        InvalidPriceCurve,
+
+        /// The reserve does not hold enough balance for the payout.
+        InsufficientReserve,
+
+        /// The game does not buy this asset back.
+        NoBuyback,
+
+        /// The buyback rate is above 100% of the list price.
+        InvalidBuybackRate,
+
+        /// The specified currency could not be found.
+        CurrencyNotFound,
+
+        /// The game already has a currency.
+        CurrencyAlreadyExists,
+
+        /// The spender's allowance is too low for the transfer.
+        InsufficientAllowance,
+
+        /// The operation is not available for assets priced outside the game's store unit.
+        UnsupportedCurrency,
+
+        /// The native value sent with the call does not match the price.
+        IncorrectPayment,
+
+        /// Transferring the payment to the game owner failed.
+        PaymentFailed,
+
+        /// The game does not reward staking this asset.
+        StakingDisabled,
+
+        /// The player has not staked enough units of the asset.
+        InsufficientStake,
+
+        /// The specified guild could not be found.
+        GuildNotFound,
+
+        /// The caller is not a member of the guild.
+        NotGuildMember,
+
+        /// Only the guild's leader or officers can do this.
+        NotGuildOfficer,
+
+        /// The account is already a member of the guild.
+        AlreadyGuildMember,
+
+        /// The specified proposal could not be found.
+        ProposalNotFound,
+
+        /// The caller already approved the proposal.
+        AlreadyApproved,
+
+        /// The game has signers, so the action must go through a proposal.
+        MultisigRequired,
+
+        /// The caller is not one of the game's signers.
+        NotSigner,
+
+        /// The threshold is zero or above the number of signers.
+        InvalidThreshold,
+
+        /// The proposal has expired.
+        ProposalExpired,
+
+        /// The proposal does not have enough approvals yet.
+        ThresholdNotReached,
+
+        /// The game has no voucher signing key.
+        NoVoucherSigner,
+
+        /// The signature does not match the signed payload.
+        InvalidSignature,
+
+        /// The voucher has expired.
+        VoucherExpired,
+
+        /// A voucher with this nonce was already redeemed.
+        VoucherAlreadyRedeemed,
+
+        /// The nonce is not the player's next nonce.
+        InvalidNonce,
+
+        /// The airdrop does not exist.
+        AirdropNotFound,
+
+        /// The airdrop no longer accepts claims.
+        AirdropExpired,
+
+        /// The airdrop is still open for claims.
+        AirdropNotExpired,
+
+        /// This leaf of the airdrop was already claimed.
+        AlreadyClaimed,
+
+        /// The merkle proof does not lead to the airdrop's root.
+        InvalidProof,
+
+        /// The receiving contract rejected the assets.
+        TransferRejected,
+
+        /// The match does not exist.
+        MatchNotFound,
+
+        /// The caller is not a player in the match.
+        NotMatchPlayer,
+
+        /// The match is not in the right phase for this action.
+        InvalidMatchPhase,
+
+        /// The revealed move and salt do not match the commitment.
+        InvalidReveal,
+
+        /// The deadline for this action has passed.
+        DeadlinePassed,
+
+        /// The deadline has not passed yet.
+        DeadlineNotReached,
+
+        /// The tournament does not exist.
+        TournamentNotFound,
+
+        /// The tournament is not accepting this action.
+        TournamentClosed,
+
+        /// The tournament has no places left.
+        TournamentFull,
+
+        /// The player already entered the tournament.
+        AlreadyEntered,
+
+        /// The prize shares do not add up to 100%.
+        InvalidPrizeSplit,
+
+        /// The ranking is empty, too long, repeats a player or names a non-entrant.
+        InvalidRanking,
+
+        /// The game already defined this achievement.
+        AchievementExists,
+
+        /// The achievement does not exist.
+        AchievementNotFound,
+
+        /// The player already earned this achievement.
+        AlreadyGranted,
+
+        /// The game already flagged the player.
+        AlreadyFlagged,
+
+        /// The game has not flagged the player.
+        NotFlagged,
+
+        /// A game involved refuses assets from flagged players.
+        SenderFlagged,
+
+        /// The asset was not registered as recoverable.
+        NotRecoverable,
+
+        /// The player's holding of the asset is frozen.
+        HoldingFrozen,
+
+        /// An asset with this name is already registered, by this or another game.
+        AssetExists,
+
+        /// Assets priced on a curve cannot have sales or coupons.
+        CurveNotDiscountable,
+
+        /// The player still has stakes in a match or tournament.
+        EscrowOutstanding,
+
+        /// The game's store already holds reserves, curve supply or sales in the global balance.
+        StorePricingInUse,
+
+        /// The units were not bought from the asset's price curve.
+        NotBoughtOnCurve,
+
+        /// The caller is not the contract admin.
+        NotAdmin,
+
+        /// The caller does not own the item.
+        NotItemOwner,
+
+        /// The player still has stakes, currency balances or guild memberships.
+        AccountInUse,
+
+        /// A match needs a stake and a timeout above zero.
+        InvalidMatchTerms,
+
+        /// The quorum is higher than the number of guild leaders and officers.
+        QuorumTooHigh,
+    }
+
+    /// Rarity tier of an asset, shared across games so they can be compared.
+    #[derive(Debug, Default, Clone, Copy, PartialEq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub enum Rarity {
+        #[default]
+        Common,
+        Uncommon,
+        Rare,
+        Epic,
+        Legendary,
+    }
+
+    /// Descriptive data attached to a registered asset.
+    #[derive(Debug, Default, Clone, PartialEq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct AssetMetadata {
+        /// A human readable description of the asset.
+        description: String,
+
+        /// URI pointing to the asset's image or off-chain metadata.
+        uri: String,
+
+        /// The rarity tier of the asset.
+        rarity: Rarity,
+
+        /// The category of the asset (e.g., "weapon", "skin", "consumable").
+        category: String,
+
+        /// Arbitrary key-value attributes (e.g., ("damage", "40")) readable by other games.
+        attributes: Vec<(String, String)>,
+    }
+
+    /// Identifier of a unique item minted from a catalog asset.
+    pub type TokenId = u64;
+
+    /// A unique instance of a catalog asset with its own mutable state.
+    #[derive(Debug, Clone, PartialEq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct Item {
+        /// The game that issued the item.
+        game: String,
+
+        /// The catalog asset the item is an instance of.
+        asset: String,
+
+        /// The player holding the item.
+        owner: AccountId,
+
+        /// Current state slots of the item (e.g., ("durability", "87"), ("level", "4")).
+        state: Vec<(String, String)>,
+
+        /// Number of state updates applied to the item.
+        version: u32,
+
+        /// Hash chaining every state update, so games can check they see the same history.
+        state_hash: Hash,
+    }
+
+    /// A conversion route as `(from_game, from_asset, to_game, to_asset)`.
+    pub type ConversionRoute = (String, String, String, String);
+
+    /// The terms under which players may convert an asset of one game into an asset of another.
+    /// A rule is only usable once the owners of both games have approved the same terms.
+    #[derive(Debug, Default, Clone, PartialEq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct ConversionRule {
+        /// Units of the source asset given per conversion step.
+        give_units: u64,
+
+        /// Units of the target asset received per conversion step.
+        take_units: u64,
+
+        /// Maximum units of the source asset that can be converted per period.
+        cap_per_period: u64,
+
+        /// Length of a period in blocks.
+        period: BlockNumber,
+
+        /// Whether the owner of the source game approved these terms.
+        from_approved: bool,
+
+        /// Whether the owner of the target game approved these terms.
+        to_approved: bool,
+
+        /// Block at which the current period started.
+        period_start: BlockNumber,
+
+        /// Units of the source asset converted in the current period.
+        converted: u64,
+    }
+
+    /// Identifier of a bundle of assets sold by a game.
+    pub type BundleId = u32;
+
+    /// A pack of assets sold together at a single price (e.g., 1 sword + 5 potions).
+    #[derive(Debug, Clone, PartialEq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct Bundle {
+        /// The game selling the bundle.
+        game: String,
+
+        /// The assets credited per bundle, as `(asset, count)` pairs.
+        contents: Vec<(String, u64)>,
+
+        /// The price of one bundle.
+        price: Balance,
+
+        /// Maximum bundles a single player can buy, `0` meaning unlimited.
+        max_per_player: u64,
+
+        /// First block at which the bundle can be bought.
+        available_from: BlockNumber,
+
+        /// Last block at which the bundle can be bought, if any.
+        available_until: Option<BlockNumber>,
+    }
+
+    /// Identifier of a scheduled sale.
+    pub type SaleId = u32;
+
+    /// A price reduction applied to the catalog price of one unit.
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub enum Discount {
+        /// A percentage (0-100) taken off the unit price.
+        Percent(u8),
+
+        /// A fixed amount taken off the unit price.
+        Absolute(Balance),
+    }
+
+    /// A time-limited discount on an asset.
+    #[derive(Debug, Clone, PartialEq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct Sale {
+        /// The game running the sale.
+        game: String,
+
+        /// The asset on sale.
+        asset: String,
+
+        /// The discount applied during the sale.
+        discount: Discount,
+
+        /// First block of the sale.
+        start: BlockNumber,
+
+        /// Last block of the sale.
+        end: BlockNumber,
+
+        /// Maximum discounted units a single player can buy, `0` meaning unlimited.
+        max_per_player: u64,
+    }
+
+    /// A redeemable coupon, stored under the hash of its code.
+    #[derive(Debug, Clone, PartialEq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct Coupon {
+        /// The game issuing the coupon.
+        game: String,
+
+        /// The asset the coupon applies to.
+        asset: String,
+
+        /// The discount granted by the coupon.
+        discount: Discount,
+
+        /// Last block at which the coupon can be redeemed.
+        expires: BlockNumber,
+
+        /// How many more purchases the coupon can be used for.
+        remaining_uses: u32,
+    }
+
+    /// An asset identified as `(game, asset)`.
+    pub type AssetKey = (String, String);
+
+    /// How the price of the next unit of an asset grows with its supply.
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub enum PriceCurve {
+        /// `base + slope * supply`
+        Linear { base: Balance, slope: Balance },
+
+        /// `base * (1 + growth_bps / 10_000) ^ supply`
+        Exponential { base: Balance, growth_bps: u32 },
+
+        /// `base + increment * (supply / step)`
+        Step {
+            base: Balance,
+            step: u64,
+            increment: Balance,
+        },
+    }
+
+    /// Sub-id of a game's fungible in-game currency.
+    pub type CurrencyId = u32;
+
+    /// A currency allowance as `(currency, owner, spender)`.
+    pub type AllowanceKey = (CurrencyId, AccountId, AccountId);
+
+    /// A game's fungible in-game currency (e.g., gold, gems), exposed through PSP22-style messages.
+    #[derive(Debug, Clone, PartialEq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct Currency {
+        /// The game issuing the currency.
+        game: String,
+
+        /// The name of the currency.
+        name: String,
+
+        /// The ticker of the currency.
+        symbol: String,
+
+        /// Number of decimals used to display amounts.
+        decimals: u8,
+
+        /// Units in circulation.
+        total_supply: Balance,
+
+        /// Whether the game's store prices, reserves and payouts are in this currency
+        /// instead of the global balance.
+        prices_denominated: bool,
+    }
+
+    /// The currency the catalog price of an asset is paid in.
+    #[derive(Debug, Default, Clone, Copy, PartialEq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub enum PriceCurrency {
+        /// The game's store unit: the global balance, or the game's currency if its prices are
+        /// denominated in it.
+        #[default]
+        Store,
+
+        /// The chain's native token, sent with the purchase and forwarded to the game owner.
+        Native,
+
+        /// An external PSP22 token, pulled from the buyer to the game owner with `transfer_from`.
+        Psp22(AccountId),
+    }
+
+    /// Errors returned by a PSP22 token contract.
+    #[derive(Debug, PartialEq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    pub enum Psp22Error {
+        Custom(String),
+        InsufficientBalance,
+        InsufficientAllowance,
+        ZeroRecipientAddress,
+        ZeroSenderAddress,
+        SafeTransferCheckFailed(String),
+    }
+
+    /// A stake as `(staker, game, asset)`.
+    pub type StakeKey = (AccountId, String, String);
+
+    /// Reward accounting for a stakeable asset.
+    #[derive(Debug, Default, Clone, PartialEq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct RewardPool {
+        /// Currency paid per staked unit per block.
+        rate: Balance,
+
+        /// Rewards accumulated by one unit staked since the pool was created.
+        reward_per_unit: Balance,
+
+        /// Block up to which `reward_per_unit` is accounted.
+        last_update: BlockNumber,
+
+        /// Units currently staked by all players.
+        total_staked: u64,
+    }
+
+    /// A player's staked units of an asset.
+    #[derive(Debug, Default, Clone, PartialEq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct Stake {
+        /// Units staked.
+        amount: u64,
+
+        /// The pool's `reward_per_unit` when the stake was last settled.
+        reward_debt: Balance,
+
+        /// Rewards earned and not yet claimed.
+        pending: Balance,
+    }
+
+    /// Identifier of a guild.
+    pub type GuildId = u32;
+
+    /// The role of a guild member.
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub enum GuildRole {
+        /// Manages roles and the quorum, and approves withdrawals.
+        Leader,
+
+        /// Adds members and approves withdrawals.
+        Officer,
+
+        /// Deposits into the vault and proposes withdrawals.
+        Member,
+    }
+
+    /// Something held in a guild vault or escrowed by the contract.
+    #[derive(Debug, Clone, PartialEq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub enum VaultItem {
+        /// An amount of global balance.
+        Balance(Balance),
+
+        /// Units of an asset.
+        Asset(String, u64),
+    }
+
+    /// A clan sharing an inventory.
+    #[derive(Debug, Clone, PartialEq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct Guild {
+        /// The guild's name.
+        name: String,
+
+        /// Members and their roles.
+        members: Vec<(AccountId, GuildRole)>,
+
+        /// Leader or officer approvals needed to withdraw from the vault, at least one.
+        quorum: u32,
+
+        /// Global balance held by the vault.
+        balance: Balance,
+
+        /// Assets held by the vault, as `(asset, count)` pairs.
+        assets: Vec<(String, u64)>,
+
+        /// The identifier assigned to the next withdrawal proposal.
+        next_proposal_id: u32,
+    }
+
+    /// A proposal to take something out of a guild vault.
+    #[derive(Debug, Clone, PartialEq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct Withdrawal {
+        /// The player who would receive the withdrawal.
+        to: AccountId,
+
+        /// What would be withdrawn.
+        item: VaultItem,
+
+        /// Leaders and officers who approved the withdrawal.
+        approvals: Vec<AccountId>,
+    }
+
+    /// The signers of a game whose high-value owner actions need M-of-N approval.
+    #[derive(Debug, Clone, PartialEq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct GameCouncil {
+        /// Accounts allowed to propose and approve actions.
+        signers: Vec<AccountId>,
+
+        /// Approvals needed to execute an action.
+        threshold: u32,
+    }
+
+    /// A high-value game owner action. Once a game has signers these can only be carried out
+    /// through a proposal approved by enough of them.
+    #[derive(Debug, Clone, PartialEq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub enum GameAction {
+        /// Change the catalog price of an asset.
+        SetPrice { asset: String, price: Balance },
+
+        /// Mint a unique item to a player.
+        MintItem { asset: String, owner: AccountId },
+
+        /// Mint units of the game's currency.
+        MintCurrency { to: AccountId, value: Balance },
+
+        /// Withdraw balance from the game's buyback reserve.
+        WithdrawReserve { to: AccountId, amount: Balance },
+
+        /// Replace the signers, or remove them with an empty list to return control to the owner.
+        SetSigners {
+            signers: Vec<AccountId>,
+            threshold: u32,
+        },
+
+        /// Create a bundle of the game's assets.
+        CreateBundle {
+            contents: Vec<(String, u64)>,
+            price: Balance,
+            max_per_player: u64,
+            available_from: BlockNumber,
+            available_until: Option<BlockNumber>,
+        },
+
+        /// Issue a coupon for an asset.
+        AddCoupon {
+            asset: String,
+            code_hash: Hash,
+            discount: Discount,
+            expires: BlockNumber,
+            uses: u32,
+        },
+
+        /// Schedule a sale on an asset.
+        ScheduleSale {
+            asset: String,
+            discount: Discount,
+            start: BlockNumber,
+            end: BlockNumber,
+            max_per_player: u64,
+        },
+
+        /// Set or clear the price curve of an asset.
+        SetPriceCurve {
+            asset: String,
+            curve: Option<PriceCurve>,
+        },
+
+        /// Set the staking reward rate of an asset.
+        SetRewardRate { asset: String, rate: Balance },
+
+        /// Open a tournament.
+        CreateTournament { terms: TournamentTerms },
+
+        /// Set the buyback rate of an asset.
+        SetBuyback { asset: String, percent: u8 },
+
+        /// Set the currency an asset is paid in.
+        SetPriceCurrency {
+            asset: String,
+            currency: PriceCurrency,
+        },
+
+        /// Allow or disallow an operator to award and consume the game's assets.
+        SetOperator { operator: AccountId, allowed: bool },
+
+        /// Set or clear the key that signs the game's vouchers.
+        SetVoucherSigner { signer: Option<SignerKey> },
+
+        /// Grant one of the game's achievements to a player.
+        GrantAchievement {
+            achievement: String,
+            account: AccountId,
+        },
+
+        /// Raise or lower a player's reputation with the game.
+        AdjustReputation { account: AccountId, delta: i32 },
+
+        /// Submit the game's side of a conversion route.
+        ProposeConversion {
+            route: ConversionRoute,
+            give_units: u64,
+            take_units: u64,
+            cap_per_period: u64,
+            period: BlockNumber,
+        },
+
+        /// Declare the game's currency.
+        CreateCurrency {
+            name: String,
+            symbol: String,
+            decimals: u8,
+            denominate_prices: bool,
+        },
+
+        /// Add an asset to the game's catalog.
+        RegisterAsset {
+            name: String,
+            price: Balance,
+            recoverable: bool,
+        },
+    }
+
+    /// A game owner action awaiting approval by the game's signers.
+    #[derive(Debug, Clone, PartialEq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct GameProposal {
+        /// The game the action applies to.
+        game: String,
+
+        /// The proposed action.
+        action: GameAction,
+
+        /// Signers who approved the action.
+        approvals: Vec<AccountId>,
+
+        /// Last block at which the proposal can be executed.
+        expires_at: BlockNumber,
+    }
+
+    /// A public key a game server signs with.
+    #[derive(Debug, Clone, PartialEq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub enum SignerKey {
+        /// An sr25519 public key. Needs the chain to expose the unstable `sr25519_verify`.
+        Sr25519([u8; 32]),
+
+        /// A compressed ECDSA (secp256k1) public key.
+        Ecdsa([u8; 33]),
+    }
+
+    /// An award signed off-chain by a game server and redeemable by anyone.
+    /// The signature covers the Blake2x256 hash of `(contract, voucher)`, SCALE encoded.
+    #[derive(Debug, Clone, PartialEq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    pub struct Voucher {
+        /// The game awarding the assets.
+        pub game: String,
+
+        /// The player receiving the assets.
+        pub player: AccountId,
+
+        /// The asset awarded.
+        pub asset: String,
+
+        /// The number of units awarded.
+        pub count: u64,
+
+        /// Unique per game; each nonce can be redeemed once.
+        pub nonce: u64,
+
+        /// Last block at which the voucher can be redeemed.
+        pub expires_at: BlockNumber,
+    }
+
+    /// A player action a relayer can submit on the player's behalf.
+    #[derive(Debug, Clone, PartialEq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    pub enum SignedCall {
+        /// `gift_asset` from the player.
+        GiftAsset {
+            receiver: AccountId,
+            asset: String,
+            amount: u64,
+        },
+
+        /// `exchange_asset` for the player.
+        ExchangeAsset {
+            from_game: String,
+            asset_give: String,
+            units_give: u64,
+            to_game: String,
+            asset_take: String,
+        },
+    }
+
+    /// Identifier of an airdrop.
+    pub type AirdropId = u32;
+
+    /// Assets escrowed by a game owner and claimable by the accounts in a merkle tree.
+    #[derive(Debug, Clone, PartialEq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct Airdrop {
+        /// The game running the airdrop.
+        game: String,
+
+        /// The asset dropped.
+        asset: String,
+
+        /// Root of the tree whose leaves are the Blake2x256 hashes of `(index, account, count)`.
+        /// Inner nodes hash the sorted pair of their children.
+        merkle_root: Hash,
+
+        /// Escrowed units not yet claimed.
+        remaining: u64,
+
+        /// Last block at which claims are accepted.
+        expires_at: BlockNumber,
+    }
+
+    /// The messages game contracts use to move their assets in and out of player holdings.
+    /// Call them with [`AssetVerseRef`](crate::AssetVerseRef) or `AssetsRef` from a crate
+    /// depending on this one with the `ink-as-dependency` feature.
+    #[ink::trait_definition]
+    pub trait AssetVerse {
+        /// Credit units of an asset registered by `game` to a player. Only the game owner or its operators can do this.
+        #[ink(message)]
+        fn award(
+            &mut self,
+            game: String,
+            player: AccountId,
+            asset: String,
+            count: u64,
+        ) -> Result<(), ContractError>;
+
+        /// Remove units of an asset registered by `game` from a player. Only the game owner or its operators can do this.
+        #[ink(message)]
+        fn consume(
+            &mut self,
+            game: String,
+            player: AccountId,
+            asset: String,
+            count: u64,
+        ) -> Result<(), ContractError>;
+
+        /// Returns a player's holdings of the assets registered by a game, as `(asset, count)` pairs.
+        #[ink(message)]
+        fn holdings_of(&self, account: AccountId, game: String) -> Vec<(String, u64)>;
+
+        /// Returns how many units of an asset a player holds.
+        #[ink(message)]
+        fn balance_of(&self, account: AccountId, asset: String) -> u64;
+
+        /// Returns whether an account can award and consume a game's assets.
+        #[ink(message)]
+        fn is_game_operator(&self, game: String, operator: AccountId) -> bool;
+    }
+
+    /// Implemented by contracts that want to accept or reject assets sent to them.
+    ///
+    /// The hook runs for gifts, item transfers, guild vault withdrawals, awards and vouchers
+    /// redeemed on the contract's behalf. It does not run for match and tournament payouts,
+    /// where one rejection would hold up every other player's payout, nor for assets the
+    /// contract receives through its own calls, such as purchases and airdrop claims.
+    #[ink::trait_definition]
+    pub trait AssetReceiver {
+        /// Called after `count` units of `asset` moved from `from` to this contract at the
+        /// request of `operator`. `from` is the AssetVerse contract itself for assets awarded,
+        /// redeemed or withdrawn from a guild vault. Returning an error reverts the transfer.
+        #[ink(message)]
+        fn on_asset_received(
+            &mut self,
+            operator: AccountId,
+            from: AccountId,
+            asset: String,
+            count: u64,
+            data: Vec<u8>,
+        ) -> Result<(), String>;
+    }
+
+    /// Identifier of a wager match.
+    pub type MatchId = u32;
+
+    /// A rock-paper-scissors move.
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub enum Hand {
+        Rock,
+        Paper,
+        Scissors,
+    }
+
+    /// A commit-reveal match between two players, each staking the same item into escrow.
+    /// Commitments are the Blake2x256 hash of `(hand, salt, player)`, SCALE encoded.
+    #[derive(Debug, Clone, PartialEq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct Match {
+        /// The challenging player.
+        challenger: AccountId,
+
+        /// The challenged player.
+        opponent: AccountId,
+
+        /// What each player stakes.
+        stake: VaultItem,
+
+        /// Blocks each phase stays open.
+        timeout: BlockNumber,
+
+        /// End of the current phase: joining, then revealing.
+        deadline: BlockNumber,
+
+        /// The challenger's committed move.
+        challenger_commit: Hash,
+
+        /// The opponent's committed move, once they joined.
+        opponent_commit: Option<Hash>,
+
+        /// The challenger's revealed move.
+        challenger_hand: Option<Hand>,
+
+        /// The opponent's revealed move.
+        opponent_hand: Option<Hand>,
+    }
+
+    /// Identifier of a tournament.
+    pub type TournamentId = u32;
+
+    /// What a ranked place in a tournament wins.
+    #[derive(Debug, Clone, PartialEq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct Prize {
+        /// Share of the prize pool in basis points.
+        pub share_bps: u16,
+
+        /// Units of a game asset awarded as a trophy.
+        pub trophy: Option<(String, u64)>,
+    }
+
+    /// The terms a tournament is created with.
+    #[derive(Debug, Clone, PartialEq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct TournamentTerms {
+        /// What each entrant pays into the prize pool.
+        pub entry_fee: VaultItem,
+
+        /// Maximum number of entrants.
+        pub max_entrants: u32,
+
+        /// First block at which players can enter.
+        pub opens_at: BlockNumber,
+
+        /// Last block at which players can enter.
+        pub closes_at: BlockNumber,
+
+        /// Prizes by place, winner first. Shares must add up to 10_000; shares of unfilled
+        /// places and rounding dust go to the winner.
+        pub prizes: Vec<Prize>,
+
+        /// An account besides the game owner allowed to submit results.
+        pub oracle: Option<AccountId>,
+    }
+
+    /// The state of a tournament.
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub enum TournamentStatus {
+        Open,
+        Completed,
+        Cancelled,
+    }
+
+    /// A tournament whose entry fees are escrowed until results or cancellation.
+    #[derive(Debug, Clone, PartialEq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct Tournament {
+        /// The game running the tournament.
+        game: String,
+
+        /// The tournament terms.
+        terms: TournamentTerms,
+
+        /// Players who paid the entry fee.
+        entrants: Vec<AccountId>,
+
+        /// The state of the tournament.
+        status: TournamentStatus,
+    }
+
+    /// `(game, achievement)` identifying an achievement.
+    pub type AchievementKey = (String, String);
+
+    /// An achievement a game grants to players. Achievements are soulbound.
+    #[derive(Debug, Clone, PartialEq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct Achievement {
+        /// Display name.
+        name: String,
+
+        /// What the player did to earn it.
+        description: String,
+
+        /// Points added to the gamer score of players who earn it.
+        points: u32,
+    }
+
+    /// A game's fraud flag on a player.
+    #[derive(Debug, Clone, PartialEq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct Flag {
+        /// Hash of the off-chain reason and evidence.
+        reason_hash: Hash,
+
+        /// Block at which the player was flagged.
+        flagged_at: BlockNumber,
+
+        /// Hash of the player's off-chain appeal, if they appealed.
+        appeal_hash: Option<Hash>,
+    }
+
+    /// The assets of a game as `(name, price)` pairs.
+    pub type AssetList = Vec<(String, Balance)>;
+
+    /// The main contract for managing game players and in-game assets.
+    #[derive(Default)]
+    #[ink(storage)]
+    pub struct Assets {
+        /// Mapping from a player's `AccountId` to their `Player` data.
+        ///
+        /// Each player has:
+        /// - A `name`: their in-game identity.
+        /// - A `balance`: their current token balance.
+        /// - A list of `assets`: strings representing assets with quantities (e.g., "firegun_9").
+        players: Mapping<AccountId, Player>,
+
+        /// Mapping from a game to its assets
+        assets: Mapping<String, AssetList>,
+
+        /// Games available onchain
+        games: Vec<String>,
+
+        /// Mapping from a game to the account that registered it
+        game_owners: Mapping<String, AccountId>,
+
+        /// Mapping from a game and asset name to the asset's metadata
+        metadata: Mapping<AssetKey, AssetMetadata>,
+
+        /// Mapping from a token id to a unique item
+        items: Mapping<TokenId, Item>,
+
+        /// The token id assigned to the next minted item
+        next_token_id: TokenId,
+
+        /// Games allowed to update the state of items issued by each game
+        state_editors: Mapping<String, Vec<String>>,
+
+        /// Mapping from a conversion route to the terms agreed by both game owners
+        conversions: Mapping<ConversionRoute, ConversionRule>,
+
+        /// Mapping from a reserved display name to the player holding it
+        names: Mapping<String, AccountId>,
+
+        /// Accounts that already received the registration endowment
+        endowed: Mapping<AccountId, ()>,
+
+        /// Accounts currently holding each asset, by position
+        holders: Mapping<(String, u32), AccountId>,
+
+        /// Number of accounts currently holding each asset
+        holder_counts: Mapping<String, u32>,
+
+        /// Position of each holder in `holders`
+        holder_index: Mapping<(String, AccountId), u32>,
+
+        /// Mapping from a bundle id to its definition
+        bundles: Mapping<BundleId, Bundle>,
+
+        /// The bundle id assigned to the next created bundle
+        next_bundle_id: BundleId,
+
+        /// Number of bundles bought by each player
+        bundle_purchases: Mapping<(BundleId, AccountId), u64>,
+
+        /// Mapping from a sale id to its schedule
+        sales: Mapping<SaleId, Sale>,
+
+        /// Mapping from a game and asset name to the sales scheduled on it
+        asset_sales: Mapping<AssetKey, Vec<SaleId>>,
+
+        /// The sale id assigned to the next scheduled sale
+        next_sale_id: SaleId,
+
+        /// Discounted units bought by each player during a sale
+        sale_purchases: Mapping<(SaleId, AccountId), u64>,
+
+        /// Mapping from the hash of a coupon code to the coupon
+        coupons: Mapping<Hash, Coupon>,
+
+        /// Mapping from an asset to the bonding curve it is priced on, if any
+        curves: Mapping<AssetKey, PriceCurve>,
+
+        /// Units of a curve-priced asset sold by its game store and not yet sold back
+        supply: Mapping<AssetKey, u64>,
+
+        /// Balance paid into a curve-priced asset, used to pay for units sold back
+        curve_reserves: Mapping<AssetKey, Balance>,
+
+        /// Mapping from an asset to the percentage of its list price the game pays to buy it back
+        buyback_rates: Mapping<AssetKey, u8>,
+
+        /// Balance set aside by each game to buy assets back from players
+        game_reserves: Mapping<String, Balance>,
+
+        /// Mapping from a currency sub-id to its definition
+        currencies: Mapping<CurrencyId, Currency>,
+
+        /// Mapping from a game to the currency it issued
+        game_currencies: Mapping<String, CurrencyId>,
+
+        /// The sub-id assigned to the next created currency
+        next_currency_id: CurrencyId,
+
+        /// Currency balances keyed by `(currency, holder)`
+        currency_balances: Mapping<(CurrencyId, AccountId), Balance>,
+
+        /// Currency allowances keyed by `(currency, owner, spender)`
+        currency_allowances: Mapping<AllowanceKey, Balance>,
+
+        /// Mapping from an asset to the currency its price is paid in, when not the store unit
+        price_currencies: Mapping<AssetKey, PriceCurrency>,
+
+        /// Mapping from a stakeable asset to its reward accounting
+        reward_pools: Mapping<AssetKey, RewardPool>,
+
+        /// Mapping from a player and asset to the units they staked
+        stakes: Mapping<StakeKey, Stake>,
+
+        /// Mapping from a guild id to the guild
+        guilds: Mapping<GuildId, Guild>,
+
+        /// The guild id assigned to the next created guild
+        next_guild_id: GuildId,
+
+        /// Pending vault withdrawals keyed by `(guild, proposal)`
+        withdrawals: Mapping<(GuildId, u32), Withdrawal>,
+
+        /// Mapping from a game to the signers approving its high-value actions
+        councils: Mapping<String, GameCouncil>,
+
+        /// Pending game owner actions
+        game_proposals: Mapping<u32, GameProposal>,
+
+        /// The identifier assigned to the next game proposal
+        next_game_proposal_id: u32,
+
+        /// Mapping from a game to the key that signs its vouchers
+        voucher_signers: Mapping<String, SignerKey>,
+
+        /// Voucher nonces already redeemed per game
+        redeemed_vouchers: Mapping<(String, u64), ()>,
+
+        /// Next nonce each player must sign for relayed actions
+        player_nonces: Mapping<AccountId, u64>,
+
+        /// Merkle airdrops by identifier
+        airdrops: Mapping<AirdropId, Airdrop>,
+
+        /// Bitmap of claimed leaves per airdrop, in words of 128 leaves
+        airdrop_claims: Mapping<(AirdropId, u32), u128>,
+
+        /// The identifier assigned to the next airdrop
+        next_airdrop_id: AirdropId,
+
+        /// Accounts allowed to award and consume a game's assets
+        game_operators: Mapping<(String, AccountId), ()>,
+
+        /// Wager matches in progress
+        matches: Mapping<MatchId, Match>,
+
+        /// The identifier assigned to the next match
+        next_match_id: MatchId,
+
+        /// Tournaments by identifier
+        tournaments: Mapping<TournamentId, Tournament>,
+
+        /// The identifier assigned to the next tournament
+        next_tournament_id: TournamentId,
+
+        /// Achievements defined by games
+        achievements: Mapping<AchievementKey, Achievement>,
+
+        /// Achievements earned by each player, across all games
+        earned_achievements: Mapping<AccountId, Vec<AchievementKey>>,
+
+        /// Sum of the points of the achievements earned by each player
+        gamer_scores: Mapping<AccountId, u64>,
+
+        /// The game that registered each asset name
+        asset_games: Mapping<String, String>,
+
+        /// Fraud flags by game and player
+        flags: Mapping<(String, AccountId), Flag>,
+
+        /// Number of games currently flagging each player
+        flag_counts: Mapping<AccountId, u32>,
+
+        /// Games refusing transfers from flagged players
+        refuse_flagged: Mapping<String, ()>,
+
+        /// Reputation of each player with each game
+        reputation: Mapping<(String, AccountId), i32>,
+
+        /// Other games whose flags and reputation each game trusts
+        flag_trust: Mapping<String, Vec<String>>,
+
+        /// Assets their games can freeze and claw back, as declared at registration
+        recoverable: Mapping<AssetKey, ()>,
+
+        /// Frozen holdings by player and asset
+        frozen: Mapping<(AccountId, String), ()>,
+
+        /// Number of matches and tournaments holding each player's stakes
+        open_escrows: Mapping<AccountId, u32>,
+
+        /// Units of each curve-priced asset a player bought from the curve and can sell back
+        curve_holdings: Mapping<(AccountId, String), u64>,
+
+        /// The account that deployed the contract and curates global reputation
+        admin: Option<AccountId>,
+
+        /// Games whose reputation counts towards global reputation
+        reputation_games: Vec<String>,
+
+        /// Number of stakes, currency balances and guild memberships each player holds
+        open_positions: Mapping<AccountId, u32>,
+    }
+
+    /// Data structure representing an individual player.
+    #[derive(Default)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct Player {
+        /// The player’s chosen name across games.
+        name: String,
+
+        /// URI of the player's avatar image.
+        avatar_uri: String,
+
+        /// Player’s available token balance.
+        balance: Balance,
+
+        /// List of owned assets with quantity encoded in the string (e.g., "cod_firegun_9").
+        ///
+        /// This flat structure allows flexible handling of asset counts without a custom struct.
+        assets: Vec<String>,
+    }
+
+    impl Assets {
+        /// Constructor that initializes the assetverse contract
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self {
+                players: Mapping::default(),
+                assets: Mapping::default(),
+                games: Vec::new(),
+                game_owners: Mapping::default(),
+                metadata: Mapping::default(),
+                items: Mapping::default(),
+                next_token_id: 0,
+                state_editors: Mapping::default(),
+                conversions: Mapping::default(),
+                names: Mapping::default(),
+                endowed: Mapping::default(),
+                holders: Mapping::default(),
+                holder_counts: Mapping::default(),
+                holder_index: Mapping::default(),
+                bundles: Mapping::default(),
+                next_bundle_id: 0,
+                bundle_purchases: Mapping::default(),
+                sales: Mapping::default(),
+                asset_sales: Mapping::default(),
+                next_sale_id: 0,
+                sale_purchases: Mapping::default(),
+                coupons: Mapping::default(),
+                curves: Mapping::default(),
+                supply: Mapping::default(),
+                curve_reserves: Mapping::default(),
+                buyback_rates: Mapping::default(),
+                game_reserves: Mapping::default(),
+                currencies: Mapping::default(),
+                game_currencies: Mapping::default(),
+                next_currency_id: 0,
+                currency_balances: Mapping::default(),
+                currency_allowances: Mapping::default(),
+                price_currencies: Mapping::default(),
+                reward_pools: Mapping::default(),
+                stakes: Mapping::default(),
+                guilds: Mapping::default(),
+                next_guild_id: 0,
+                withdrawals: Mapping::default(),
+                councils: Mapping::default(),
+                game_proposals: Mapping::default(),
+                next_game_proposal_id: 0,
+                voucher_signers: Mapping::default(),
+                redeemed_vouchers: Mapping::default(),
+                player_nonces: Mapping::default(),
+                airdrops: Mapping::default(),
+                airdrop_claims: Mapping::default(),
+                next_airdrop_id: 0,
+                game_operators: Mapping::default(),
+                matches: Mapping::default(),
+                next_match_id: 0,
+                tournaments: Mapping::default(),
+                next_tournament_id: 0,
+                achievements: Mapping::default(),
+                earned_achievements: Mapping::default(),
+                gamer_scores: Mapping::default(),
+                asset_games: Mapping::default(),
+                flags: Mapping::default(),
+                flag_counts: Mapping::default(),
+                refuse_flagged: Mapping::default(),
+                reputation: Mapping::default(),
+                flag_trust: Mapping::default(),
+                recoverable: Mapping::default(),
+                frozen: Mapping::default(),
+                open_escrows: Mapping::default(),
+                curve_holdings: Mapping::default(),
+                admin: Some(Self::env().caller()),
+                reputation_games: Vec::new(),
+                open_positions: Mapping::default(),
+            }
+        }
+
+        /// Register a playing account across the network.
+        /// Players are endowed with 1M units of tokens at their first registration to buy game assets.
+        #[ink(message)]
+        pub fn register_player(&mut self, name: String) -> Result<(), ContractError> {
+            // Get caller
+            let account_id = self.env().caller();
+
+            if self.players.contains(account_id) {
+                return Err(ContractError::PlayerAlreadyExists);
+            }
+
+            // Reserve the display name
+            if self.names.contains(&name) {
+                return Err(ContractError::NameTaken);
+            }
+            self.names.insert(&name, &account_id);
+
+            // Only endow an account once, so re-registering is not a balance refill
+            let balance = if self.endowed.contains(account_id) {
+                0
+            } else {
+                self.endowed.insert(account_id, &());
+                1_000_000
+            };
+
+            // Create a new player with default values
+            let player = Player {
+                name: name.clone(),
+                avatar_uri: String::new(),
+                balance,
+                assets: Vec::new(),
+            };
+
+            // Emit event
+            self.env().emit_event(PlayerCreated {
+                account: account_id,
+                name,
+            });
+
+            self.players.insert(account_id, &player);
+
+            Ok(())
+        }
+
+        /// Change the caller's display name and avatar.
+        #[ink(message)]
+        pub fn update_profile(
+            &mut self,
+            name: String,
+            avatar_uri: String,
+        ) -> Result<(), ContractError> {
+            let account_id = self.env().caller();
+            let mut player = self
+                .players
+                .get(account_id)
+                .ok_or(ContractError::PlayerNotFound)?;
+
+            // Move the name reservation if the name changes
+            if name != player.name {
+                if self.names.contains(&name) {
+                    return Err(ContractError::NameTaken);
+                }
+                self.names.remove(&player.name);
+                self.names.insert(&name, &account_id);
+            }
+
+            player.name = name.clone();
+            player.avatar_uri = avatar_uri.clone();
+            self.players.insert(account_id, &player);
+
+            // Emit event
+            self.env().emit_event(PlayerUpdated {
+                account: account_id,
+                name,
+                avatar_uri,
+            });
+
+            Ok(())
+        }
+
+        /// Remove the caller's player account and release their display name.
+        /// Fails while the player holds assets unless `burn` is set, in which case they are destroyed.
+        /// Stakes, currency balances, guild memberships, frozen holdings and open matches or
+        /// tournaments must be settled first.
+        #[ink(message)]
+        pub fn deregister_player(&mut self, burn: bool) -> Result<(), ContractError> {
+            let account_id = self.env().caller();
+            let player = self
+                .players
+                .get(account_id)
+                .ok_or(ContractError::PlayerNotFound)?;
+
+            if !player.assets.is_empty() && !burn {
+                return Err(ContractError::HoldingsNotEmpty);
+            }
+
+            // Matches and tournaments pay out to the player, so they must finish first
+            if self.open_escrows.contains(account_id) {
+                return Err(ContractError::EscrowOutstanding);
+            }
+
+            // Stakes, currency and guild seats would be stranded without a player behind them
+            if self.open_positions.contains(account_id) {
+                return Err(ContractError::AccountInUse);
+            }
+
+            let holdings: Vec<(String, u64)> = player
+                .assets
+                .iter()
+                .filter_map(|holding| Self::parse_asset(holding))
+                .collect();
+
+            // Games may still recover frozen holdings
+            for (asset, _) in holdings.iter() {
+                self.ensure_not_frozen(account_id, asset)?;
+            }
+
+            // Burn whatever is left. Burned curve-bought units leave the supply along with the
+            // reserve backing them, so the curve price comes back down.
+            for (asset, _) in holdings {
+                self.remove_holder(account_id, &asset);
+                let Some(bought) = self.curve_holdings.take(&(account_id, asset.clone())) else {
+                    continue;
+                };
+                let Some(game) = self.asset_games.get(&asset) else {
+                    continue;
+                };
+                let key = (game, asset);
+                if let Some(curve) = self.curves.get(&key) {
+                    let supply = self.supply.get(&key).unwrap_or_default();
+                    let burned = bought.min(supply);
+                    let backing = Self::curve_cost(&curve, supply - burned, burned)?;
+                    let reserve = self.curve_reserves.get(&key).unwrap_or_default();
+                    self.supply.insert(&key, &(supply - burned));
+                    self.curve_reserves
+                        .insert(&key, &reserve.saturating_sub(backing));
+                }
+            }
+
+            self.names.remove(&player.name);
+            self.players.remove(account_id);
+            self.earned_achievements.remove(account_id);
+            self.gamer_scores.remove(account_id);
+
+            // Emit event
+            self.env().emit_event(PlayerDeregistered {
+                account: account_id,
+                burned: !player.assets.is_empty(),
+            });
+
+            Ok(())
+        }
+
+        /// Returns the account that reserved a display name
+        #[ink(message)]
+        pub fn account_of(&self, name: String) -> Option<AccountId> {
+            self.names.get(&name)
+        }
+
+        /// Returns the caller's player account.
+        #[ink(message)]
+        pub fn auth_player(&self) -> Option<Player> {
+            // Get caller
+            let account_id = self.env().caller();
+            self.players.get(account_id)
+        }
+
+        /// Returns the player account related to an `AccountId`.
+        #[ink(message)]
+        pub fn player(&self, account: AccountId) -> Option<Player> {
+            self.players.get(account)
+        }
+
+        /// Returns up to `limit` accounts holding an asset, starting at index `start`.
+        /// Positions are reused when holders leave, so the order is not stable.
+        #[ink(message)]
+        pub fn holders_of(&self, asset: String, start: u32, limit: u32) -> Vec<AccountId> {
+            let end = start
+                .saturating_add(limit)
+                .min(self.holder_counts.get(&asset).unwrap_or_default());
+            (start..end)
+                .filter_map(|index| self.holders.get((&asset, index)))
+                .collect()
+        }
+
+        /// Returns how many accounts hold an asset.
+        #[ink(message)]
+        pub fn holder_count(&self, asset: String) -> u32 {
+            self.holder_counts.get(&asset).unwrap_or_default()
+        }
+
+        /// Returns the registered games
+        #[ink(message)]
+        pub fn games(&self) -> Vec<String> {
+            self.games.clone()
+        }
+
+        /// Register an asset.
+        /// The first account to register an asset for a game becomes the owner of that game.
+        /// Asset names are unique across all games, since holdings are kept by name.
+        /// Only assets registered as `recoverable` can later be frozen and clawed back.
+        /// Since a catalog entry sets a price, games with signers add assets through a
+        /// `RegisterAsset` proposal.
+        #[ink(message, payable)]
+        pub fn register_asset(
+            &mut self,
+            game: String,
+            name: String,
+            price: Balance,
+            recoverable: bool,
+        ) -> Result<(), ContractError> {
+            let caller = self.env().caller();
+
+            // An existing game is only extended by its owner, or through its signers
+            let new_game = !self.game_owners.contains(&game);
+            if !new_game {
+                self.ensure_direct_control(&game)?;
+            }
+
+            self.add_asset(game.clone(), name, price, recoverable)?;
+
+            // Claim a new game for the caller
+            if new_game {
+                self.game_owners.insert(&game, &caller);
+
+                // Store the game entry
+                self.games.push(game);
+            }
+
+            Ok(())
+        }
+
+        /// Returns the owner of a game
+        #[ink(message)]
+        pub fn game_owner(&self, game: String) -> Option<AccountId> {
+            self.game_owners.get(&game)
+        }
+
+        /// Get assets belonging to a game
+        #[ink(message)]
+        pub fn assets(&self, game: String) -> Option<AssetList> {
+            self.assets.get(&game)
+        }
+
+        /// Set the descriptive metadata of an asset. Only the game owner can do this.
+        #[ink(message)]
+        pub fn set_metadata(
+            &mut self,
+            game: String,
+            asset: String,
+            description: String,
+            uri: String,
+            rarity: Rarity,
+            category: String,
+        ) -> Result<(), ContractError> {
+            self.ensure_game_owner(&game)?;
+            self.asset_price(&game, &asset)?;
+
+            let key = (game, asset);
+            let mut metadata = self.metadata.get(&key).unwrap_or_default();
+            metadata.description = description;
+            metadata.uri = uri;
+            metadata.rarity = rarity;
+            metadata.category = category;
+            self.metadata.insert(&key, &metadata);
+
+            // Emit event
+            let (game, asset) = key;
+            self.env().emit_event(MetadataUpdated { game, asset });
+
+            Ok(())
+        }
+
+        /// Set (or overwrite) a single attribute of an asset. Only the game owner can do this.
+        #[ink(message)]
+        pub fn set_attribute(
+            &mut self,
+            game: String,
+            asset: String,
+            attribute: String,
+            value: String,
+        ) -> Result<(), ContractError> {
+            self.ensure_game_owner(&game)?;
+            self.asset_price(&game, &asset)?;
+
+            let key = (game, asset);
+            let mut metadata = self.metadata.get(&key).unwrap_or_default();
+            match metadata
+                .attributes
+                .iter_mut()
+                .find(|(name, _)| *name == attribute)
+            {
+                Some(entry) => entry.1 = value,
+                None => metadata.attributes.push((attribute, value)),
+            }
+            self.metadata.insert(&key, &metadata);
+
+            // Emit event
+            let (game, asset) = key;
+            self.env().emit_event(MetadataUpdated { game, asset });
+
+            Ok(())
+        }
+
+        /// Remove an attribute from an asset. Only the game owner can do this.
+        #[ink(message)]
+        pub fn remove_attribute(
+            &mut self,
+            game: String,
+            asset: String,
+            attribute: String,
+        ) -> Result<(), ContractError> {
+            self.ensure_game_owner(&game)?;
+
+            let key = (game, asset);
+            let mut metadata = self
+                .metadata
+                .get(&key)
+                .ok_or(ContractError::AssetNotFound)?;
+            metadata.attributes.retain(|(name, _)| *name != attribute);
+            self.metadata.insert(&key, &metadata);
+
+            // Emit event
+            let (game, asset) = key;
+            self.env().emit_event(MetadataUpdated { game, asset });
+
+            Ok(())
+        }
+
+        /// Returns the metadata of an asset
+        #[ink(message)]
+        pub fn metadata(&self, game: String, asset: String) -> Option<AssetMetadata> {
+            self.metadata.get(&(game, asset))
+        }
+
+        /// Mint a unique item of a catalog asset to a player. Only the game owner can do this
+        /// directly; games with signers use a `MintItem` proposal.
+        #[ink(message)]
+        pub fn mint_item(
+            &mut self,
+            game: String,
+            asset: String,
+            owner: AccountId,
+        ) -> Result<TokenId, ContractError> {
+            self.ensure_direct_control(&game)?;
+            self.mint_item_to(game, asset, owner)
+        }
+
+        /// Returns a unique item and its current state
+        #[ink(message)]
+        pub fn item(&self, token_id: TokenId) -> Option<Item> {
+            self.items.get(token_id)
+        }
+
+        /// Transfer a unique item owned by the caller to another player.
+        #[ink(message)]
+        pub fn transfer_item(
+            &mut self,
+            token_id: TokenId,
+            to: AccountId,
+        ) -> Result<(), ContractError> {
+            let caller = self.env().caller();
+            let mut item = self
+                .items
+                .get(token_id)
+                .ok_or(ContractError::ItemNotFound)?;
+
+            if item.owner != caller {
+                return Err(ContractError::NotItemOwner);
+            }
+            if !self.players.contains(to) {
+                return Err(ContractError::PlayerNotFound);
+            }
+            self.ensure_not_refused(caller, &[item.game.clone()])?;
+
+            item.owner = to;
+            self.items.insert(token_id, &item);
+
+            // Let a receiving contract accept or reject the item
+            self.notify_receiver(caller, to, &item.asset, 1)?;
+
+            // Emit event
+            self.env().emit_event(ItemTransferred {
+                token_id,
+                from: caller,
+                to,
+            });
+
+            Ok(())
+        }
+
+        /// Allow the owner and operators of `editor` to update the state of items issued by
+        /// `game`.
+        #[ink(message)]
+        pub fn approve_state_editor(
+            &mut self,
+            game: String,
+            editor: String,
+        ) -> Result<(), ContractError> {
+            self.ensure_game_owner(&game)?;
+
+            let mut editors = self.state_editors.get(&game).unwrap_or_default();
+            if !editors.contains(&editor) {
+                editors.push(editor);
+                self.state_editors.insert(&game, &editors);
+            }
+            Ok(())
+        }
+
+        /// Revoke a game's permission to update the state of items issued by `game`.
+        #[ink(message)]
+        pub fn revoke_state_editor(
+            &mut self,
+            game: String,
+            editor: String,
+        ) -> Result<(), ContractError> {
+            self.ensure_game_owner(&game)?;
+
+            let mut editors = self.state_editors.get(&game).unwrap_or_default();
+            editors.retain(|other| *other != editor);
+            self.state_editors.insert(&game, &editors);
+            Ok(())
+        }
+
+        /// Write a state slot of a unique item. Only the owner or an operator of the issuing
+        /// game, or of a game approved by it, can do this.
+        #[ink(message)]
+        pub fn update_item_state(
+            &mut self,
+            token_id: TokenId,
+            key: String,
+            value: String,
+        ) -> Result<(), ContractError> {
+            let caller = self.env().caller();
+            let mut item = self
+                .items
+                .get(token_id)
+                .ok_or(ContractError::ItemNotFound)?;
+
+            // Check that the caller acts for the issuing game or an approved one
+            let editors = self.state_editors.get(&item.game).unwrap_or_default();
+            if !core::iter::once(&item.game)
+                .chain(editors.iter())
+                .any(|game| self.acts_for_game(game, caller))
+            {
+                return Err(ContractError::NotGameOwner);
+            }
+
+            match item.state.iter_mut().find(|(slot, _)| *slot == key) {
+                Some(entry) => entry.1 = value.clone(),
+                None => item.state.push((key.clone(), value.clone())),
+            }
+
+            // Chain the update onto the item's history
+            item.version = item.version.checked_add(1).ok_or(ContractError::Overflow)?;
+            item.state_hash = self
+                .env()
+                .hash_encoded::<ink::env::hash::Blake2x256, _>(&(item.state_hash, &key, &value))
+                .into();
+            self.items.insert(token_id, &item);
+
+            // Emit event
+            self.env().emit_event(ItemStateUpdated {
+                token_id,
+                key,
+                value,
+                version: item.version,
+                state_hash: item.state_hash,
+            });
+
+            Ok(())
+        }
+
+        /// Buy units of an asset, deducting its price from the player's balance.
+        /// The best of any running sale and the optional coupon code is applied.
+        #[ink(message, payable)]
+        pub fn purchase_asset(
+            &mut self,
+            game: String,
+            asset: String,
+            count: u64,
+            coupon: Option<String>,
+        ) -> Result<(), ContractError> {
+            let account_id = self.env().caller();
+
+            // Validate count
+            if count == 0 {
+                return Err(ContractError::InsufficientAssetCount);
+            }
+
+            // Retrieve the list price from the game's catalog or the asset's price curve
+            let key = (game.clone(), asset.clone());
+            let supply = self.supply.get(&key).unwrap_or_default();
+            let list_price = self.list_price(&key, supply, count)?;
+            let now = self.env().block_number();
+            let mut total_price = list_price;
+
+            // Curve prices are never discounted, so the curve reserve covers every sell-back
+            let on_curve = self.curves.contains(&key);
+
+            // Find the best running sale the player is still allowed to use
+            let mut applied_sale: Option<(SaleId, u64)> = None;
+            let sale_ids = if on_curve {
+                Vec::new()
+            } else {
+                self.asset_sales.get(&key).unwrap_or_default()
+            };
+            for sale_id in sale_ids {
+                let Some(sale) = self.sales.get(sale_id) else {
+                    continue;
+                };
+                if now < sale.start || now > sale.end {
+                    continue;
+                }
+
+                let bought = self
+                    .sale_purchases
+                    .get((sale_id, account_id))
+                    .unwrap_or_default()
+                    .checked_add(count)
+                    .ok_or(ContractError::Overflow)?;
+                if sale.max_per_player != 0 && bought > sale.max_per_player {
+                    continue;
+                }
+
+                let price = Self::apply_discount(list_price, count, &sale.discount)?;
+                if price < total_price {
+                    total_price = price;
+                    applied_sale = Some((sale_id, bought));
+                }
+            }
+
+            // Check the coupon, if any
+            let mut applied_coupon: Option<(Hash, Coupon)> = None;
+            if let Some(code) = coupon {
+                if on_curve {
+                    return Err(ContractError::CurveNotDiscountable);
+                }
+                let code_hash = Hash::from(
+                    self.env()
+                        .hash_bytes::<ink::env::hash::Blake2x256>(code.as_bytes()),
+                );
+                let coupon = self
+                    .coupons
+                    .get(code_hash)
+                    .filter(|coupon| {
+                        coupon.game == game
+                            && coupon.asset == asset
+                            && coupon.remaining_uses > 0
+                            && now <= coupon.expires
+                    })
+                    .ok_or(ContractError::InvalidCoupon)?;
+
+                let price = Self::apply_discount(list_price, count, &coupon.discount)?;
+                if price < total_price {
+                    total_price = price;
+                    applied_sale = None;
+                    applied_coupon = Some((code_hash, coupon));
+                }
+            }
+
+            let unit_price = total_price / Balance::from(count);
+
+            // Load player
+            let mut player = match self.players.get(account_id) {
+                Some(p) => p,
+                None => return Err(ContractError::PlayerNotFound),
+            };
+
+            // Deduct payment
+            self.collect_payment(&key, account_id, &mut player, total_price)?;
+
+            // Update or add asset string (e.g., firegun_5)
+            self.credit_asset(account_id, &mut player, &asset, count)?;
+
+            // Store player
+            self.players.insert(account_id, &player);
+
+            // Move the curve along and fund its reserve
+            if self.curves.contains(&key) {
+                let supply = supply.checked_add(count).ok_or(ContractError::Overflow)?;
+                let reserve = self
+                    .curve_reserves
+                    .get(&key)
+                    .unwrap_or_default()
+                    .checked_add(total_price)
+                    .ok_or(ContractError::Overflow)?;
+                self.supply.insert(&key, &supply);
+                self.curve_reserves.insert(&key, &reserve);
+
+                // Only units bought from the curve can be sold back to it
+                let bought = self
+                    .curve_holdings
+                    .get((account_id, asset.clone()))
+                    .unwrap_or_default()
+                    .checked_add(count)
+                    .ok_or(ContractError::Overflow)?;
+                self.curve_holdings
+                    .insert((account_id, asset.clone()), &bought);
+            }
+
+            // Record use of the discount
+            if let Some((sale_id, bought)) = applied_sale {
+                self.sale_purchases.insert((sale_id, account_id), &bought);
+            }
+            if let Some((code_hash, mut coupon)) = applied_coupon {
+                coupon.remaining_uses -= 1;
+                self.coupons.insert(code_hash, &coupon);
+            }
+
+            // Emit Event
+            self.env().emit_event(AssetPurchased {
+                account: account_id,
+                asset,
+                count,
+                unit_price,
+                total_price,
+            });
+
+            Ok(())
+        }
+
+        /// Gift an asset to a specific player without charging them.
+        /// A receiving contract can reject the gift through its `on_asset_received` hook.
+        #[ink(message, payable)]
+        pub fn gift_asset(
+            &mut self,
+            receiver: AccountId,
+            asset: String,
+            amount: u64,
+        ) -> Result<(), ContractError> {
+            let sender = self.env().caller();
+            self.gift_asset_from(sender, receiver, asset, amount)
+        }
+
+        /// Propose or approve a conversion route between an asset of `from_game` and an asset
+        /// of `to_game`. Either game owner can call this; the route becomes active once both
+        /// owners have submitted identical terms. Submitting different terms resets approvals.
+        /// Games with signers submit their side through a `ProposeConversion` proposal.
+        #[ink(message)]
+        pub fn propose_conversion(
+            &mut self,
+            route: ConversionRoute,
+            give_units: u64,
+            take_units: u64,
+            cap_per_period: u64,
+            period: BlockNumber,
+        ) -> Result<(), ContractError> {
+            let caller = self.env().caller();
+            let owns_from = self.game_owners.get(&route.0) == Some(caller);
+            let owns_to = self.game_owners.get(&route.2) == Some(caller);
+            if !owns_from && !owns_to {
+                return Err(ContractError::NotGameOwner);
+            }
+
+            // Games with signers approve routes through a proposal
+            if owns_from {
+                self.ensure_direct_control(&route.0)?;
+            }
+            if owns_to {
+                self.ensure_direct_control(&route.2)?;
+            }
+
+            self.approve_conversion(
+                route,
+                give_units,
+                take_units,
+                cap_per_period,
+                period,
+                (owns_from, owns_to),
+            )
+        }
+
+        /// Remove a conversion route. Either game owner can do this.
+        #[ink(message)]
+        pub fn revoke_conversion(&mut self, route: ConversionRoute) -> Result<(), ContractError> {
+            let caller = self.env().caller();
+            let (from_game, _, to_game, _) = &route;
+
+            if self.game_owners.get(from_game) != Some(caller)
+                && self.game_owners.get(to_game) != Some(caller)
+            {
+                return Err(ContractError::NotGameOwner);
+            }
+
+            self.conversions.remove(&route);
+
+            // Emit event
+            let (from_game, from_asset, to_game, to_asset) = route;
+            self.env().emit_event(ConversionRuleUpdated {
+                from_game,
+                from_asset,
+                to_game,
+                to_asset,
+                active: false,
+            });
+
+            Ok(())
+        }
+
+        /// Returns the conversion terms of a route
+        #[ink(message)]
+        pub fn conversion_rule(&self, route: ConversionRoute) -> Option<ConversionRule> {
+            self.conversions.get(&route)
+        }
+
+        /// Convert units of an asset into another asset along a route approved by both games.
+        #[ink(message)]
+        pub fn exchange_asset(
+            &mut self,
+            from_game: String,
+            asset_give: String,
+            units_give: u64,
+            to_game: String,
+            asset_take: String,
+        ) -> Result<(), ContractError> {
+            let account_id = self.env().caller();
+            self.exchange_asset_for(
+                account_id, from_game, asset_give, units_give, to_game, asset_take,
+            )
+        }
+
+        /// Modify assets owned by the caller. Only the owner or an operator of the asset's game
+        /// can increase their own count; any player can burn units they hold.
+        #[ink(message)]
+        pub fn modify_asset(
+            &mut self,
+            asset: String,
+            count: u64,
+            increase: bool,
+        ) -> Result<(), ContractError> {
+            // Validate count
+            if count == 0 {
+                return Err(ContractError::InsufficientAssetCount);
+            }
+
+            // Get contract caller
+            let caller = self.env().caller();
+            let mut player = self
+                .players
+                .get(caller)
+                .ok_or(ContractError::PlayerNotFound)?;
+
+            let new_count = if increase {
+                // Minting is the game's call, as with `award`
+                let game = self
+                    .asset_games
+                    .get(&asset)
+                    .ok_or(ContractError::AssetNotFound)?;
+                self.ensure_game_operator(&game)?;
+                self.credit_asset(caller, &mut player, &asset, count)?
+            } else {
+                self.ensure_not_frozen(caller, &asset)?;
+                self.debit_asset(caller, &mut player, &asset, count)?
+            };
+
+            self.players.insert(caller, &player);
+
+            // Emit Event
+            self.env().emit_event(AssetModified {
+                account: caller,
+                asset,
+                new_count,
+                increased: increase,
+            });
+
+            Ok(())
+        }
+
+        /// Create a bundle of assets sold together at a single price. Every asset must belong to
+        /// the game. Only the game owner can do this directly; games with signers use a
+        /// `CreateBundle` proposal.
+        #[ink(message)]
+        pub fn create_bundle(
+            &mut self,
+            game: String,
+            contents: Vec<(String, u64)>,
+            price: Balance,
+            max_per_player: u64,
+            available_from: BlockNumber,
+            available_until: Option<BlockNumber>,
+        ) -> Result<BundleId, ContractError> {
+            self.ensure_direct_control(&game)?;
+            self.add_bundle(
+                game,
+                contents,
+                price,
+                max_per_player,
+                available_from,
+                available_until,
+            )
+        }
+
+        /// Take a bundle off sale. Only the game owner can do this.
+        #[ink(message)]
+        pub fn remove_bundle(&mut self, bundle_id: BundleId) -> Result<(), ContractError> {
+            let bundle = self
+                .bundles
+                .get(bundle_id)
+                .ok_or(ContractError::BundleNotFound)?;
+            self.ensure_game_owner(&bundle.game)?;
+
+            self.bundles.remove(bundle_id);
+            Ok(())
+        }
+
+        /// Returns a bundle definition
+        #[ink(message)]
+        pub fn bundle(&self, bundle_id: BundleId) -> Option<Bundle> {
+            self.bundles.get(bundle_id)
+        }
+
+        /// Buy bundles, crediting all of their contents at once.
+        #[ink(message, payable)]
+        pub fn purchase_bundle(
+            &mut self,
+            bundle_id: BundleId,
+            count: u64,
+        ) -> Result<(), ContractError> {
+            let account_id = self.env().caller();
+
+            // Validate count
+            if count == 0 {
+                return Err(ContractError::InsufficientAssetCount);
+            }
+
+            let bundle = self
+                .bundles
+                .get(bundle_id)
+                .ok_or(ContractError::BundleNotFound)?;
+
+            // Check the availability window
+            let now = self.env().block_number();
+            if now < bundle.available_from || bundle.available_until.is_some_and(|end| now > end) {
+                return Err(ContractError::BundleUnavailable);
+            }
+
+            // Check the per-player limit
+            let purchased = self
+                .bundle_purchases
+                .get((bundle_id, account_id))
+                .unwrap_or_default()
+                .checked_add(count)
+                .ok_or(ContractError::Overflow)?;
+            if bundle.max_per_player != 0 && purchased > bundle.max_per_player {
+                return Err(ContractError::PurchaseLimitReached);
+            }
+
+            let total_price = bundle
+                .price
+                .checked_mul(Balance::from(count))
+                .ok_or(ContractError::Overflow)?;
+
+            // Load player
+            let mut player = self
+                .players
+                .get(account_id)
+                .ok_or(ContractError::PlayerNotFound)?;
+
+            // Bundles are paid from the store balance only
+            if self.env().transferred_value() != 0 {
+                return Err(ContractError::IncorrectPayment);
+            }
+
+            // Deduct payment
+            self.charge(&bundle.game, account_id, &mut player, total_price)?;
+
+            // Credit the contents
+            for (asset, units) in bundle.contents.iter() {
+                let units = units.checked_mul(count).ok_or(ContractError::Overflow)?;
+                self.credit_asset(account_id, &mut player, asset, units)?;
+            }
+
+            self.players.insert(account_id, &player);
+            self.bundle_purchases
+                .insert((bundle_id, account_id), &purchased);
+
+            // Emit event
+            self.env().emit_event(BundlePurchased {
+                account: account_id,
+                bundle_id,
+                count,
+                total_price,
+            });
+
+            Ok(())
+        }
+
+        /// Schedule a discount on an asset between two blocks. Only the game owner can do this
+        /// directly; games with signers use a `ScheduleSale` proposal.
+        #[ink(message)]
+        pub fn schedule_sale(
+            &mut self,
+            game: String,
+            asset: String,
+            discount: Discount,
+            start: BlockNumber,
+            end: BlockNumber,
+            max_per_player: u64,
+        ) -> Result<SaleId, ContractError> {
+            self.ensure_direct_control(&game)?;
+            self.add_sale(game, asset, discount, start, end, max_per_player)
+        }
+
+        /// Cancel a scheduled sale. Only the game owner can do this.
+        #[ink(message)]
+        pub fn cancel_sale(&mut self, sale_id: SaleId) -> Result<(), ContractError> {
+            let sale = self.sales.get(sale_id).ok_or(ContractError::SaleNotFound)?;
+            self.ensure_game_owner(&sale.game)?;
+
+            let key = (sale.game, sale.asset);
+            let mut sale_ids = self.asset_sales.get(&key).unwrap_or_default();
+            sale_ids.retain(|id| *id != sale_id);
+            self.asset_sales.insert(&key, &sale_ids);
+            self.sales.remove(sale_id);
+
+            Ok(())
+        }
+
+        /// Returns the sales scheduled on an asset
+        #[ink(message)]
+        pub fn sales_of(&self, game: String, asset: String) -> Vec<(SaleId, Sale)> {
+            self.asset_sales
+                .get(&(game, asset))
+                .unwrap_or_default()
+                .into_iter()
+                .filter_map(|sale_id| self.sales.get(sale_id).map(|sale| (sale_id, sale)))
+                .collect()
+        }
+
+        /// Issue a coupon for an asset. Only the hash of the code is stored on-chain.
+        /// Only the game owner can do this directly; games with signers use an `AddCoupon` proposal.
+        #[ink(message)]
+        pub fn add_coupon(
+            &mut self,
+            game: String,
+            asset: String,
+            code_hash: Hash,
+            discount: Discount,
+            expires: BlockNumber,
+            uses: u32,
+        ) -> Result<(), ContractError> {
+            self.ensure_direct_control(&game)?;
+            self.store_coupon(game, asset, code_hash, discount, expires, uses)
+        }
+
+        /// Revoke a coupon. Only the owner of the issuing game can do this.
+        #[ink(message)]
+        pub fn revoke_coupon(&mut self, code_hash: Hash) -> Result<(), ContractError> {
+            let coupon = self
+                .coupons
+                .get(code_hash)
+                .ok_or(ContractError::InvalidCoupon)?;
+            self.ensure_game_owner(&coupon.game)?;
+
+            self.coupons.remove(code_hash);
+            Ok(())
+        }
+
+        /// Price an asset on a bonding curve, or return it to its fixed catalog price with `None`.
+        /// Only possible while no curve-sold units are outstanding and no sales are scheduled on
+        /// the asset, since curve prices are never discounted. Only the game owner can do
+        /// this directly; games with signers use a `SetPriceCurve` proposal.
+        #[ink(message)]
+        pub fn set_price_curve(
+            &mut self,
+            game: String,
+            asset: String,
+            curve: Option<PriceCurve>,
+        ) -> Result<(), ContractError> {
+            self.ensure_direct_control(&game)?;
+            self.update_price_curve(game, asset, curve)
+        }
+
+        /// Returns the price curve of an asset, if it has one
+        #[ink(message)]
+        pub fn price_curve(&self, game: String, asset: String) -> Option<PriceCurve> {
+            self.curves.get(&(game, asset))
+        }
+
+        /// Returns the units of a curve-priced asset currently sold by its game store
+        #[ink(message)]
+        pub fn total_supply(&self, game: String, asset: String) -> u64 {
+            self.supply.get(&(game, asset)).unwrap_or_default()
+        }
+
+        /// Returns the units of a curve-priced asset a player can sell back to its curve
+        #[ink(message)]
+        pub fn curve_holding(&self, account: AccountId, asset: String) -> u64 {
+            self.curve_holdings
+                .get((account, asset))
+                .unwrap_or_default()
+        }
+
+        /// Returns the undiscounted price of buying `count` units of an asset now
+        #[ink(message)]
+        pub fn quote(
+            &self,
+            game: String,
+            asset: String,
+            count: u64,
+        ) -> Result<Balance, ContractError> {
+            let key = (game, asset);
+            let supply = self.supply.get(&key).unwrap_or_default();
+            self.list_price(&key, supply, count)
+        }
+
+        /// Sell units of a curve-priced asset back to its game store, at the curve price of the
+        /// units being returned. Only units the caller bought from the curve can be sold back;
+        /// they stop counting once they leave the caller's holdings.
+        #[ink(message)]
+        pub fn sell_back_asset(
+            &mut self,
+            game: String,
+            asset: String,
+            count: u64,
+        ) -> Result<(), ContractError> {
+            let account_id = self.env().caller();
+
+            // Validate count
+            if count == 0 {
+                return Err(ContractError::InsufficientAssetCount);
+            }
+
+            let key = (game, asset);
+            let curve = self.curves.get(&key).ok_or(ContractError::NoPriceCurve)?;
+            let supply = self
+                .supply
+                .get(&key)
+                .unwrap_or_default()
+                .checked_sub(count)
+                .ok_or(ContractError::InsufficientAssetCount)?;
+
+            // Walk the curve back down
+            let payout = Self::curve_cost(&curve, supply, count)?;
+            let reserve = self
+                .curve_reserves
+                .get(&key)
+                .unwrap_or_default()
+                .checked_sub(payout)
+                .ok_or(ContractError::InsufficientReserve)?;
+
+            // Only units the caller bought from the curve are paid from its reserve
+            let holding_key = (account_id, key.1.clone());
+            let bought = self
+                .curve_holdings
+                .get(&holding_key)
+                .unwrap_or_default()
+                .checked_sub(count)
+                .ok_or(ContractError::NotBoughtOnCurve)?;
+
+            let mut player = self
+                .players
+                .get(account_id)
+                .ok_or(ContractError::PlayerNotFound)?;
+            self.ensure_not_frozen(account_id, &key.1)?;
+            self.debit_asset(account_id, &mut player, &key.1, count)?;
+            self.pay_out(&key.0, account_id, &mut player, payout)?;
+
+            self.players.insert(account_id, &player);
+            self.supply.insert(&key, &supply);
+            self.curve_reserves.insert(&key, &reserve);
+            if bought == 0 {
+                self.curve_holdings.remove(&holding_key);
+            } else {
+                self.curve_holdings.insert(&holding_key, &bought);
+            }
+
+            // Emit event
+            let (game, asset) = key;
+            self.env().emit_event(AssetSoldBack {
+                account: account_id,
+                game,
+                asset,
+                count,
+                payout,
+            });
+
+            Ok(())
+        }
+
+        /// Set the percentage of the list price paid to players selling an asset back, or `0` to
+        /// stop buying it back. Only the game owner can do this directly; games with signers use
+        /// a `SetBuyback` proposal.
+        #[ink(message)]
+        pub fn set_buyback(
+            &mut self,
+            game: String,
+            asset: String,
+            percent: u8,
+        ) -> Result<(), ContractError> {
+            self.ensure_direct_control(&game)?;
+            self.update_buyback(game, asset, percent)
+        }
+
+        /// Returns the percentage of the list price a game pays to buy an asset back
+        #[ink(message)]
+        pub fn buyback_rate(&self, game: String, asset: String) -> Option<u8> {
+            self.buyback_rates.get(&(game, asset))
+        }
+
+        /// Returns the balance a game holds to buy assets back
+        #[ink(message)]
+        pub fn reserve_of(&self, game: String) -> Balance {
+            self.game_reserves.get(&game).unwrap_or_default()
+        }
+
+        /// Move balance from the caller into the game's buyback reserve.
+        /// Only the game owner can do this.
+        #[ink(message)]
+        pub fn fund_reserve(&mut self, game: String, amount: Balance) -> Result<(), ContractError> {
+            self.ensure_game_owner(&game)?;
+
+            let caller = self.env().caller();
+            let mut player = self
+                .players
+                .get(caller)
+                .ok_or(ContractError::PlayerNotFound)?;
+            self.charge(&game, caller, &mut player, amount)?;
+
+            let reserve = self
+                .reserve_of(game.clone())
+                .checked_add(amount)
+                .ok_or(ContractError::Overflow)?;
+
+            self.players.insert(caller, &player);
+            self.game_reserves.insert(&game, &reserve);
+
+            // Emit event
+            self.env().emit_event(ReserveUpdated {
+                game,
+                amount,
+                deposited: true,
+                reserve,
+            });
+
+            Ok(())
+        }
+
+        /// Move balance from the game's buyback reserve back to the caller. Only the game owner
+        /// can do this directly; games with signers use a `WithdrawReserve` proposal.
+        #[ink(message)]
+        pub fn withdraw_reserve(
+            &mut self,
+            game: String,
+            amount: Balance,
+        ) -> Result<(), ContractError> {
+            self.ensure_direct_control(&game)?;
+
+            let caller = self.env().caller();
+            self.withdraw_reserve_to(game, caller, amount)
+        }
+
+        /// Sell units of an asset back to its game store at the game's buyback rate,
+        /// burning them and crediting the payout from the game's reserve.
+        #[ink(message)]
+        pub fn sell_asset(
+            &mut self,
+            game: String,
+            asset: String,
+            count: u64,
+        ) -> Result<(), ContractError> {
+            let account_id = self.env().caller();
+
+            // Validate count
+            if count == 0 {
+                return Err(ContractError::InsufficientAssetCount);
+            }
+
+            let key = (game, asset);
+            let percent = self
+                .buyback_rates
+                .get(&key)
+                .ok_or(ContractError::NoBuyback)?;
+
+            // Pay a share of the catalog price
+            let payout = self
+                .asset_price(&key.0, &key.1)?
+                .checked_mul(Balance::from(count))
+                .and_then(|value| value.checked_mul(Balance::from(percent)))
+                .ok_or(ContractError::Overflow)?
+                / 100;
+
+            let reserve = self
+                .reserve_of(key.0.clone())
+                .checked_sub(payout)
+                .ok_or(ContractError::InsufficientReserve)?;
+
+            let mut player = self
+                .players
+                .get(account_id)
+                .ok_or(ContractError::PlayerNotFound)?;
+            self.ensure_not_frozen(account_id, &key.1)?;
+            self.debit_asset(account_id, &mut player, &key.1, count)?;
+            self.pay_out(&key.0, account_id, &mut player, payout)?;
+
+            self.players.insert(account_id, &player);
+            self.game_reserves.insert(&key.0, &reserve);
+
+            // Emit event
+            let (game, asset) = key;
+            self.env().emit_event(AssetSold {
+                account: account_id,
+                game,
+                asset,
+                count,
+                payout,
+            });
+
+            Ok(())
+        }
+
+        /// Declare the game's in-game currency. When `denominate_prices` is set, the game's store
+        /// charges, reserves and pays out in this currency instead of the global balance.
+        /// Prices can only be denominated in the currency while the store holds no reserves,
+        /// curve supply or sales in the global balance.
+        /// Only the game owner can do this directly, once per game; games with signers use a
+        /// `CreateCurrency` proposal.
+        #[ink(message)]
+        pub fn create_currency(
+            &mut self,
+            game: String,
+            name: String,
+            symbol: String,
+            decimals: u8,
+            denominate_prices: bool,
+        ) -> Result<CurrencyId, ContractError> {
+            self.ensure_direct_control(&game)?;
+            self.issue_currency(game, name, symbol, decimals, denominate_prices)
+        }
+
+        /// Returns the currency issued by a game
+        #[ink(message)]
+        pub fn currency_of(&self, game: String) -> Option<CurrencyId> {
+            self.game_currencies.get(&game)
+        }
+
+        /// Returns a currency definition
+        #[ink(message)]
+        pub fn currency(&self, currency_id: CurrencyId) -> Option<Currency> {
+            self.currencies.get(currency_id)
+        }
+
+        /// Mint units of a game's currency to an account. Only the game owner can do this
+        /// directly; games with signers use a `MintCurrency` proposal.
+        #[ink(message)]
+        pub fn mint_currency(
+            &mut self,
+            currency_id: CurrencyId,
+            to: AccountId,
+            value: Balance,
+        ) -> Result<(), ContractError> {
+            let currency = self
+                .currencies
+                .get(currency_id)
+                .ok_or(ContractError::CurrencyNotFound)?;
+            self.ensure_direct_control(&currency.game)?;
+
+            self.mint_currency_to(currency_id, to, value)
+        }
+
+        /// PSP22 `token_name` for a currency
+        #[ink(message)]
+        pub fn currency_name(&self, currency_id: CurrencyId) -> Option<String> {
+            self.currencies
+                .get(currency_id)
+                .map(|currency| currency.name)
+        }
+
+        /// PSP22 `token_symbol` for a currency
+        #[ink(message)]
+        pub fn currency_symbol(&self, currency_id: CurrencyId) -> Option<String> {
+            self.currencies
+                .get(currency_id)
+                .map(|currency| currency.symbol)
+        }
+
+        /// PSP22 `token_decimals` for a currency
+        #[ink(message)]
+        pub fn currency_decimals(&self, currency_id: CurrencyId) -> u8 {
+            self.currencies
+                .get(currency_id)
+                .map(|currency| currency.decimals)
+                .unwrap_or_default()
+        }
+
+        /// PSP22 `total_supply` for a currency
+        #[ink(message)]
+        pub fn currency_total_supply(&self, currency_id: CurrencyId) -> Balance {
+            self.currencies
+                .get(currency_id)
+                .map(|currency| currency.total_supply)
+                .unwrap_or_default()
+        }
+
+        /// PSP22 `balance_of` for a currency
+        #[ink(message)]
+        pub fn currency_balance_of(&self, currency_id: CurrencyId, owner: AccountId) -> Balance {
+            self.currency_balances
+                .get((currency_id, owner))
+                .unwrap_or_default()
+        }
+
+        /// PSP22 `allowance` for a currency
+        #[ink(message)]
+        pub fn currency_allowance(
+            &self,
+            currency_id: CurrencyId,
+            owner: AccountId,
+            spender: AccountId,
+        ) -> Balance {
+            self.currency_allowances
+                .get((currency_id, owner, spender))
+                .unwrap_or_default()
+        }
+
+        /// PSP22 `transfer` for a currency
+        #[ink(message)]
+        pub fn currency_transfer(
+            &mut self,
+            currency_id: CurrencyId,
+            to: AccountId,
+            value: Balance,
+            _data: Vec<u8>,
+        ) -> Result<(), ContractError> {
+            let from = self.env().caller();
+            self.transfer_currency(currency_id, from, to, value)
+        }
+
+        /// PSP22 `transfer_from` for a currency
+        #[ink(message)]
+        pub fn currency_transfer_from(
+            &mut self,
+            currency_id: CurrencyId,
+            from: AccountId,
+            to: AccountId,
+            value: Balance,
+            _data: Vec<u8>,
+        ) -> Result<(), ContractError> {
+            let spender = self.env().caller();
+            let allowance = self
+                .currency_allowance(currency_id, from, spender)
+                .checked_sub(value)
+                .ok_or(ContractError::InsufficientAllowance)?;
+
+            self.transfer_currency(currency_id, from, to, value)?;
+            self.currency_allowances
+                .insert((currency_id, from, spender), &allowance);
+
+            // Emit event
+            self.env().emit_event(CurrencyApproval {
+                currency_id,
+                owner: from,
+                spender,
+                amount: allowance,
+            });
+
+            Ok(())
+        }
+
+        /// PSP22 `approve` for a currency
+        #[ink(message)]
+        pub fn currency_approve(
+            &mut self,
+            currency_id: CurrencyId,
+            spender: AccountId,
+            value: Balance,
+        ) -> Result<(), ContractError> {
+            if !self.currencies.contains(currency_id) {
+                return Err(ContractError::CurrencyNotFound);
+            }
+
+            let owner = self.env().caller();
+            self.currency_allowances
+                .insert((currency_id, owner, spender), &value);
+
+            // Emit event
+            self.env().emit_event(CurrencyApproval {
+                currency_id,
+                owner,
+                spender,
+                amount: value,
+            });
+
+            Ok(())
+        }
+
+        /// Set the currency an asset's catalog price is paid in. Assets paid in the native token or
+        /// an external PSP22 token cannot have a price curve or a buyback rate, since the payment
+        /// goes straight to the game owner. Only the game owner can do this directly; games with
+        /// signers use a `SetPriceCurrency` proposal.
+        #[ink(message)]
+        pub fn set_price_currency(
+            &mut self,
+            game: String,
+            asset: String,
+            currency: PriceCurrency,
+        ) -> Result<(), ContractError> {
+            self.ensure_direct_control(&game)?;
+            self.update_price_currency(game, asset, currency)
+        }
+
+        /// Returns the currency an asset's catalog price is paid in
+        #[ink(message)]
+        pub fn price_currency(&self, game: String, asset: String) -> PriceCurrency {
+            self.price_currencies
+                .get(&(game, asset))
+                .unwrap_or_default()
+        }
+
+        /// Set how much of the game's currency each staked unit of an asset earns per block.
+        /// Rewards earned under the previous rate are kept. Only the game owner can do this
+        /// directly; games with signers use a `SetRewardRate` proposal.
+        #[ink(message)]
+        pub fn set_reward_rate(
+            &mut self,
+            game: String,
+            asset: String,
+            rate: Balance,
+        ) -> Result<(), ContractError> {
+            self.ensure_direct_control(&game)?;
+            self.update_reward_rate(game, asset, rate)
+        }
+
+        /// Returns the reward accounting of a stakeable asset
+        #[ink(message)]
+        pub fn reward_pool(&self, game: String, asset: String) -> Option<RewardPool> {
+            self.reward_pools.get(&(game, asset))
+        }
+
+        /// Returns the units of an asset a player has staked
+        #[ink(message)]
+        pub fn stake_of(&self, account: AccountId, game: String, asset: String) -> Option<Stake> {
+            self.stakes.get(&(account, game, asset))
+        }
+
+        /// Returns the rewards a player could claim for a staked asset right now
+        #[ink(message)]
+        pub fn pending_rewards(
+            &self,
+            account: AccountId,
+            game: String,
+            asset: String,
+        ) -> Result<Balance, ContractError> {
+            let key = (game, asset);
+            let mut pool = self
+                .reward_pools
+                .get(&key)
+                .ok_or(ContractError::StakingDisabled)?;
+            let (game, asset) = key;
+            let mut stake = self.stakes.get(&(account, game, asset)).unwrap_or_default();
+
+            self.update_pool(&mut pool)?;
+            Self::settle_stake(&pool, &mut stake)?;
+
+            Ok(stake.pending)
+        }
+
+        /// Lock units of an asset to earn rewards. Staked units cannot be gifted, exchanged or sold.
+        #[ink(message)]
+        pub fn stake_asset(
+            &mut self,
+            game: String,
+            asset: String,
+            count: u64,
+        ) -> Result<(), ContractError> {
+            let account_id = self.env().caller();
+
+            // Validate count
+            if count == 0 {
+                return Err(ContractError::InsufficientAssetCount);
+            }
+
+            let key = (game, asset);
+            let mut pool = self
+                .reward_pools
+                .get(&key)
+                .ok_or(ContractError::StakingDisabled)?;
+            let stake_key = (account_id, key.0.clone(), key.1.clone());
+            let mut stake = self.stakes.get(&stake_key).unwrap_or_default();
+
+            // Settle rewards earned so far before the stake changes
+            self.update_pool(&mut pool)?;
+            Self::settle_stake(&pool, &mut stake)?;
+
+            // Move the units out of the player's holdings
+            let mut player = self
+                .players
+                .get(account_id)
+                .ok_or(ContractError::PlayerNotFound)?;
+            self.ensure_not_frozen(account_id, &stake_key.2)?;
+            self.debit_asset(account_id, &mut player, &stake_key.2, count)?;
+
+            stake.amount = stake
+                .amount
+                .checked_add(count)
+                .ok_or(ContractError::Overflow)?;
+            pool.total_staked = pool
+                .total_staked
+                .checked_add(count)
+                .ok_or(ContractError::Overflow)?;
+
+            self.players.insert(account_id, &player);
+            self.save_stake(&stake_key, &stake);
+            self.reward_pools.insert(&key, &pool);
+
+            // Emit event
+            let (game, asset) = key;
+            self.env().emit_event(AssetStaked {
+                account: account_id,
+                game,
+                asset,
+                count,
+            });
+
+            Ok(())
+        }
+
+        /// Unlock staked units of an asset, returning them to the player's holdings.
+        /// Earned rewards stay claimable.
+        #[ink(message)]
+        pub fn unstake_asset(
+            &mut self,
+            game: String,
+            asset: String,
+            count: u64,
+        ) -> Result<(), ContractError> {
+            let account_id = self.env().caller();
+
+            // Validate count
+            if count == 0 {
+                return Err(ContractError::InsufficientAssetCount);
+            }
+
+            let key = (game, asset);
+            let mut pool = self
+                .reward_pools
+                .get(&key)
+                .ok_or(ContractError::StakingDisabled)?;
+            let stake_key = (account_id, key.0.clone(), key.1.clone());
+            let mut stake = self
+                .stakes
+                .get(&stake_key)
+                .ok_or(ContractError::InsufficientStake)?;
+
+            // Settle rewards earned so far before the stake changes
+            self.update_pool(&mut pool)?;
+            Self::settle_stake(&pool, &mut stake)?;
+
+            stake.amount = stake
+                .amount
+                .checked_sub(count)
+                .ok_or(ContractError::InsufficientStake)?;
+            pool.total_staked = pool.total_staked.saturating_sub(count);
+
+            // Move the units back into the player's holdings
+            let mut player = self
+                .players
+                .get(account_id)
+                .ok_or(ContractError::PlayerNotFound)?;
+            self.credit_asset(account_id, &mut player, &stake_key.2, count)?;
+
+            self.players.insert(account_id, &player);
+            self.save_stake(&stake_key, &stake);
+            self.reward_pools.insert(&key, &pool);
+
+            // Emit event
+            let (game, asset) = key;
+            self.env().emit_event(AssetUnstaked {
+                account: account_id,
+                game,
+                asset,
+                count,
+            });
+
+            Ok(())
+        }
+
+        /// Claim the rewards earned by a staked asset, paid in the game's currency.
+        #[ink(message)]
+        pub fn claim_rewards(
+            &mut self,
+            game: String,
+            asset: String,
+        ) -> Result<Balance, ContractError> {
+            let account_id = self.env().caller();
+
+            let key = (game, asset);
+            let mut pool = self
+                .reward_pools
+                .get(&key)
+                .ok_or(ContractError::StakingDisabled)?;
+            let currency_id = self
+                .game_currencies
+                .get(&key.0)
+                .ok_or(ContractError::CurrencyNotFound)?;
+            let stake_key = (account_id, key.0.clone(), key.1.clone());
+            let mut stake = self
+                .stakes
+                .get(&stake_key)
+                .ok_or(ContractError::InsufficientStake)?;
+
+            self.update_pool(&mut pool)?;
+            Self::settle_stake(&pool, &mut stake)?;
+
+            let amount = stake.pending;
+            stake.pending = 0;
+            self.mint_currency_to(currency_id, account_id, amount)?;
+
+            self.save_stake(&stake_key, &stake);
+            self.reward_pools.insert(&key, &pool);
+
+            // Emit event
+            let (game, asset) = key;
+            self.env().emit_event(RewardsClaimed {
+                account: account_id,
+                game,
+                asset,
+                amount,
+            });
+
+            Ok(amount)
+        }
+
+        /// Found a guild led by the caller.
+        #[ink(message)]
+        pub fn create_guild(&mut self, name: String) -> Result<GuildId, ContractError> {
+            let caller = self.env().caller();
+            if !self.players.contains(caller) {
+                return Err(ContractError::PlayerNotFound);
+            }
+
+            let guild_id = self.next_guild_id;
+            self.next_guild_id = guild_id.checked_add(1).ok_or(ContractError::Overflow)?;
+
+            let guild = Guild {
+                name: name.clone(),
+                members: ink::prelude::vec![(caller, GuildRole::Leader)],
+                quorum: 1,
+                balance: 0,
+                assets: Vec::new(),
+                next_proposal_id: 0,
+            };
+            self.guilds.insert(guild_id, &guild);
+            self.track_position(caller, false, true);
+
+            // Emit event
+            self.env().emit_event(GuildCreated {
+                guild_id,
+                name,
+                leader: caller,
+            });
+
+            Ok(guild_id)
+        }
+
+        /// Returns a guild, its members and its vault
+        #[ink(message)]
+        pub fn guild(&self, guild_id: GuildId) -> Option<Guild> {
+            self.guilds.get(guild_id)
+        }
+
+        /// Add a player to a guild or change their role. Officers can add plain members;
+        /// only the leader can appoint officers or hand over leadership.
+        #[ink(message)]
+        pub fn set_guild_member(
+            &mut self,
+            guild_id: GuildId,
+            account: AccountId,
+            role: GuildRole,
+        ) -> Result<(), ContractError> {
+            let caller = self.env().caller();
+            let mut guild = self
+                .guilds
+                .get(guild_id)
+                .ok_or(ContractError::GuildNotFound)?;
+
+            match (Self::guild_role(&guild, caller), role) {
+                (Some(GuildRole::Leader), _) => {}
+                (Some(GuildRole::Officer), GuildRole::Member) => {
+                    if Self::guild_role(&guild, account).is_some() {
+                        return Err(ContractError::AlreadyGuildMember);
+                    }
+                }
+                _ => return Err(ContractError::NotGuildOfficer),
+            }
+
+            if !self.players.contains(account) {
+                return Err(ContractError::PlayerNotFound);
+            }
+
+            // Handing over leadership demotes the current leader to officer
+            if role == GuildRole::Leader {
+                for member in guild.members.iter_mut() {
+                    if member.1 == GuildRole::Leader {
+                        member.1 = GuildRole::Officer;
+                    }
+                }
+            }
+
+            match guild
+                .members
+                .iter_mut()
+                .find(|(member, _)| *member == account)
+            {
+                Some(member) => member.1 = role,
+                None => {
+                    guild.members.push((account, role));
+                    self.track_position(account, false, true);
+                }
+            }
+
+            // Demoting an officer must leave enough approvers for withdrawals
+            if guild.quorum as usize > Self::guild_approvers(&guild) {
+                return Err(ContractError::QuorumTooHigh);
+            }
+            self.guilds.insert(guild_id, &guild);
+
+            // Emit event
+            self.env().emit_event(GuildMemberUpdated {
+                guild_id,
+                account,
+                role: Some(role),
+            });
+
+            Ok(())
+        }
+
+        /// Remove a member from a guild. Members can remove themselves, officers can remove plain
+        /// members and the leader can remove anyone but themselves.
+        #[ink(message)]
+        pub fn remove_guild_member(
+            &mut self,
+            guild_id: GuildId,
+            account: AccountId,
+        ) -> Result<(), ContractError> {
+            let caller = self.env().caller();
+            let mut guild = self
+                .guilds
+                .get(guild_id)
+                .ok_or(ContractError::GuildNotFound)?;
+
+            let role = Self::guild_role(&guild, account).ok_or(ContractError::NotGuildMember)?;
+            let allowed = match (Self::guild_role(&guild, caller), role) {
+                (_, GuildRole::Leader) => false,
+                _ if caller == account => true,
+                (Some(GuildRole::Leader), _) => true,
+                (Some(GuildRole::Officer), GuildRole::Member) => true,
+                _ => false,
+            };
+            if !allowed {
+                return Err(ContractError::NotGuildOfficer);
+            }
+
+            guild.members.retain(|(member, _)| *member != account);
+            if guild.quorum as usize > Self::guild_approvers(&guild) {
+                return Err(ContractError::QuorumTooHigh);
+            }
+            self.guilds.insert(guild_id, &guild);
+            self.track_position(account, true, false);
+
+            // Emit event
+            self.env().emit_event(GuildMemberUpdated {
+                guild_id,
+                account,
+                role: None,
+            });
+
+            Ok(())
+        }
+
+        /// Set how many leader or officer approvals a vault withdrawal needs, at most the number
+        /// of leaders and officers. Only the leader can do this.
+        #[ink(message)]
+        pub fn set_guild_quorum(
+            &mut self,
+            guild_id: GuildId,
+            quorum: u32,
+        ) -> Result<(), ContractError> {
+            let caller = self.env().caller();
+            let mut guild = self
+                .guilds
+                .get(guild_id)
+                .ok_or(ContractError::GuildNotFound)?;
+
+            if Self::guild_role(&guild, caller) != Some(GuildRole::Leader) {
+                return Err(ContractError::NotGuildOfficer);
+            }
+
+            // Withdrawals could never pass with more approvals than approvers
+            let quorum = quorum.max(1);
+            if quorum as usize > Self::guild_approvers(&guild) {
+                return Err(ContractError::QuorumTooHigh);
+            }
+            guild.quorum = quorum;
+            self.guilds.insert(guild_id, &guild);
+
+            Ok(())
+        }
+
+        /// Move balance or assets from the caller into a guild vault. Any member can do this.
+        #[ink(message)]
+        pub fn deposit_to_guild(
+            &mut self,
+            guild_id: GuildId,
+            item: VaultItem,
+        ) -> Result<(), ContractError> {
+            let caller = self.env().caller();
+            let mut guild = self
+                .guilds
+                .get(guild_id)
+                .ok_or(ContractError::GuildNotFound)?;
+            if Self::guild_role(&guild, caller).is_none() {
+                return Err(ContractError::NotGuildMember);
+            }
+
+            let mut player = self
+                .players
+                .get(caller)
+                .ok_or(ContractError::PlayerNotFound)?;
+
+            match &item {
+                VaultItem::Balance(amount) => {
+                    player.balance = player
+                        .balance
+                        .checked_sub(*amount)
+                        .ok_or(ContractError::InsufficientBalance)?;
+                    guild.balance = guild
+                        .balance
+                        .checked_add(*amount)
+                        .ok_or(ContractError::Overflow)?;
+                }
+                VaultItem::Asset(asset, count) => {
+                    if *count == 0 {
+                        return Err(ContractError::InsufficientAssetCount);
+                    }
+                    self.ensure_not_frozen(caller, asset)?;
+                    self.debit_asset(caller, &mut player, asset, *count)?;
+                    match guild.assets.iter_mut().find(|(name, _)| name == asset) {
+                        Some(holding) => {
+                            holding.1 = holding
+                                .1
+                                .checked_add(*count)
+                                .ok_or(ContractError::Overflow)?
+                        }
+                        None => guild.assets.push((asset.clone(), *count)),
+                    }
+                }
+            }
+
+            self.players.insert(caller, &player);
+            self.guilds.insert(guild_id, &guild);
+
+            // Emit event
+            self.env().emit_event(VaultDeposited {
+                guild_id,
+                account: caller,
+                item,
+            });
+
+            Ok(())
+        }
+
+        /// Propose sending balance or assets from a guild vault to a player.
+        /// Any member can propose; a leader or officer proposing also approves it.
+        #[ink(message)]
+        pub fn propose_withdrawal(
+            &mut self,
+            guild_id: GuildId,
+            to: AccountId,
+            item: VaultItem,
+        ) -> Result<u32, ContractError> {
+            let caller = self.env().caller();
+            let mut guild = self
+                .guilds
+                .get(guild_id)
+                .ok_or(ContractError::GuildNotFound)?;
+            if Self::guild_role(&guild, caller).is_none() {
+                return Err(ContractError::NotGuildMember);
+            }
+
+            let proposal_id = guild.next_proposal_id;
+            guild.next_proposal_id = proposal_id.checked_add(1).ok_or(ContractError::Overflow)?;
+            self.guilds.insert(guild_id, &guild);
+
+            let withdrawal = Withdrawal {
+                to,
+                item: item.clone(),
+                approvals: Vec::new(),
+            };
+            self.withdrawals
+                .insert((guild_id, proposal_id), &withdrawal);
+
+            // Emit event
+            self.env().emit_event(WithdrawalProposed {
+                guild_id,
+                proposal_id,
+                to,
+                item,
+            });
+
+            if matches!(
+                Self::guild_role(&guild, caller),
+                Some(GuildRole::Leader | GuildRole::Officer)
+            ) {
+                self.approve_withdrawal(guild_id, proposal_id)?;
+            }
+
+            Ok(proposal_id)
+        }
+
+        /// Returns a pending vault withdrawal
+        #[ink(message)]
+        pub fn withdrawal(&self, guild_id: GuildId, proposal_id: u32) -> Option<Withdrawal> {
+            self.withdrawals.get((guild_id, proposal_id))
+        }
+
+        /// Approve a pending vault withdrawal as a leader or officer.
+        /// The withdrawal is carried out once it reaches the guild's quorum.
+        #[ink(message)]
+        pub fn approve_withdrawal(
+            &mut self,
+            guild_id: GuildId,
+            proposal_id: u32,
+        ) -> Result<(), ContractError> {
+            let caller = self.env().caller();
+            let mut guild = self
+                .guilds
+                .get(guild_id)
+                .ok_or(ContractError::GuildNotFound)?;
+            if !matches!(
+                Self::guild_role(&guild, caller),
+                Some(GuildRole::Leader | GuildRole::Officer)
+            ) {
+                return Err(ContractError::NotGuildOfficer);
+            }
+
+            let mut withdrawal = self
+                .withdrawals
+                .get((guild_id, proposal_id))
+                .ok_or(ContractError::ProposalNotFound)?;
+            if withdrawal.approvals.contains(&caller) {
+                return Err(ContractError::AlreadyApproved);
+            }
+            withdrawal.approvals.push(caller);
+
+            // Emit event
+            self.env().emit_event(WithdrawalApproved {
+                guild_id,
+                proposal_id,
+                approver: caller,
+            });
+
+            // Only approvals from current leaders and officers count
+            let approvals = withdrawal
+                .approvals
+                .iter()
+                .filter(|approver| {
+                    matches!(
+                        Self::guild_role(&guild, **approver),
+                        Some(GuildRole::Leader | GuildRole::Officer)
+                    )
+                })
+                .count();
+            if approvals < guild.quorum as usize {
+                self.withdrawals
+                    .insert((guild_id, proposal_id), &withdrawal);
+                return Ok(());
+            }
+
+            // Carry out the withdrawal
+            let mut player = self
+                .players
+                .get(withdrawal.to)
+                .ok_or(ContractError::PlayerNotFound)?;
+
+            match &withdrawal.item {
+                VaultItem::Balance(amount) => {
+                    guild.balance = guild
+                        .balance
+                        .checked_sub(*amount)
+                        .ok_or(ContractError::InsufficientBalance)?;
+                    player.balance = player
+                        .balance
+                        .checked_add(*amount)
+                        .ok_or(ContractError::Overflow)?;
+                }
+                VaultItem::Asset(asset, count) => {
+                    let index = guild
+                        .assets
+                        .iter()
+                        .position(|(name, _)| name == asset)
+                        .ok_or(ContractError::AssetNotFound)?;
+                    let remaining = guild.assets[index]
+                        .1
+                        .checked_sub(*count)
+                        .ok_or(ContractError::InsufficientAssetCount)?;
+                    if remaining == 0 {
+                        guild.assets.remove(index);
+                    } else {
+                        guild.assets[index].1 = remaining;
+                    }
+                    self.credit_asset(withdrawal.to, &mut player, asset, *count)?;
+                }
+            }
+
+            self.players.insert(withdrawal.to, &player);
+            self.guilds.insert(guild_id, &guild);
+            self.withdrawals.remove((guild_id, proposal_id));
+
+            // Let a receiving contract accept or reject the assets
+            if let VaultItem::Asset(asset, count) = &withdrawal.item {
+                self.notify_receiver(self.env().account_id(), withdrawal.to, asset, *count)?;
+            }
+
+            // Emit event
+            self.env().emit_event(VaultWithdrawn {
+                guild_id,
+                proposal_id,
+                to: withdrawal.to,
+                item: withdrawal.item,
+            });
+
+            Ok(())
+        }
+
+        /// Change the catalog price of an asset. Only the game owner can do this directly;
+        /// games with signers use a `SetPrice` proposal.
+        #[ink(message)]
+        pub fn set_asset_price(
+            &mut self,
+            game: String,
+            asset: String,
+            price: Balance,
+        ) -> Result<(), ContractError> {
+            self.ensure_direct_control(&game)?;
+            self.update_asset_price(game, asset, price)
+        }
+
+        /// Require M-of-N approval from `signers` for the game's high-value actions.
+        /// Only the game owner can do this, and only while the game has no signers yet;
+        /// afterwards signers change through a `SetSigners` proposal.
+        #[ink(message)]
+        pub fn set_game_signers(
+            &mut self,
+            game: String,
+            signers: Vec<AccountId>,
+            threshold: u32,
+        ) -> Result<(), ContractError> {
+            self.ensure_direct_control(&game)?;
+            self.update_signers(game, signers, threshold)
+        }
+
+        /// Returns the signers of a game
+        #[ink(message)]
+        pub fn game_signers(&self, game: String) -> Option<GameCouncil> {
+            self.councils.get(&game)
+        }
+
+        /// Propose a high-value action on a game. Only its signers can do this, and the
+        /// proposer's approval is counted.
+        #[ink(message)]
+        pub fn propose_game_action(
+            &mut self,
+            game: String,
+            action: GameAction,
+            expires_at: BlockNumber,
+        ) -> Result<u32, ContractError> {
+            let caller = self.env().caller();
+            let council = self.councils.get(&game).ok_or(ContractError::NotSigner)?;
+            if !council.signers.contains(&caller) {
+                return Err(ContractError::NotSigner);
+            }
+            if expires_at < self.env().block_number() {
+                return Err(ContractError::ProposalExpired);
+            }
+
+            let proposal_id = self.next_game_proposal_id;
+            self.next_game_proposal_id =
+                proposal_id.checked_add(1).ok_or(ContractError::Overflow)?;
+
+            let proposal = GameProposal {
+                game: game.clone(),
+                action: action.clone(),
+                approvals: ink::prelude::vec![caller],
+                expires_at,
+            };
+            self.game_proposals.insert(proposal_id, &proposal);
+
+            // Emit events
+            self.env().emit_event(GameActionProposed {
+                proposal_id,
+                game,
+                action,
+                expires_at,
+            });
+            self.env().emit_event(GameActionApproved {
+                proposal_id,
+                signer: caller,
+            });
+
+            Ok(proposal_id)
+        }
+
+        /// Returns a pending game owner action
+        #[ink(message)]
+        pub fn game_proposal(&self, proposal_id: u32) -> Option<GameProposal> {
+            self.game_proposals.get(proposal_id)
+        }
+
+        /// Approve a pending game owner action as one of the game's signers.
+        #[ink(message)]
+        pub fn approve_game_action(&mut self, proposal_id: u32) -> Result<(), ContractError> {
+            let caller = self.env().caller();
+            let mut proposal = self
+                .game_proposals
+                .get(proposal_id)
+                .ok_or(ContractError::ProposalNotFound)?;
+            let council = self
+                .councils
+                .get(&proposal.game)
+                .ok_or(ContractError::NotSigner)?;
+
+            if !council.signers.contains(&caller) {
+                return Err(ContractError::NotSigner);
+            }
+            if self.env().block_number() > proposal.expires_at {
+                return Err(ContractError::ProposalExpired);
+            }
+            if proposal.approvals.contains(&caller) {
+                return Err(ContractError::AlreadyApproved);
+            }
+
+            proposal.approvals.push(caller);
+            self.game_proposals.insert(proposal_id, &proposal);
+
+            // Emit event
+            self.env().emit_event(GameActionApproved {
+                proposal_id,
+                signer: caller,
+            });
+
+            Ok(())
+        }
+
+        /// Carry out a game owner action once enough current signers approved it.
+        /// Any signer can do this before the proposal expires.
+        #[ink(message)]
+        pub fn execute_game_action(&mut self, proposal_id: u32) -> Result<(), ContractError> {
+            let caller = self.env().caller();
+            let proposal = self
+                .game_proposals
+                .get(proposal_id)
+                .ok_or(ContractError::ProposalNotFound)?;
+            let council = self
+                .councils
+                .get(&proposal.game)
+                .ok_or(ContractError::NotSigner)?;
+
+            if !council.signers.contains(&caller) {
+                return Err(ContractError::NotSigner);
+            }
+            if self.env().block_number() > proposal.expires_at {
+                return Err(ContractError::ProposalExpired);
+            }
+
+            // Only approvals from current signers count
+            let approvals = proposal
+                .approvals
+                .iter()
+                .filter(|signer| council.signers.contains(signer))
+                .count();
+            if approvals < council.threshold as usize {
+                return Err(ContractError::ThresholdNotReached);
+            }
+
+            self.game_proposals.remove(proposal_id);
+
+            let game = proposal.game;
+            match proposal.action {
+                GameAction::SetPrice { asset, price } => {
+                    self.update_asset_price(game.clone(), asset, price)?
+                }
+                GameAction::MintItem { asset, owner } => {
+                    self.mint_item_to(game.clone(), asset, owner)?;
+                }
+                GameAction::MintCurrency { to, value } => {
+                    let currency_id = self
+                        .game_currencies
+                        .get(&game)
+                        .ok_or(ContractError::CurrencyNotFound)?;
+                    self.mint_currency_to(currency_id, to, value)?
+                }
+                GameAction::WithdrawReserve { to, amount } => {
+                    self.withdraw_reserve_to(game.clone(), to, amount)?
+                }
+                GameAction::SetSigners { signers, threshold } => {
+                    self.update_signers(game.clone(), signers, threshold)?
+                }
+                GameAction::CreateBundle {
+                    contents,
+                    price,
+                    max_per_player,
+                    available_from,
+                    available_until,
+                } => {
+                    self.add_bundle(
+                        game.clone(),
+                        contents,
+                        price,
+                        max_per_player,
+                        available_from,
+                        available_until,
+                    )?;
+                }
+                GameAction::AddCoupon {
+                    asset,
+                    code_hash,
+                    discount,
+                    expires,
+                    uses,
+                } => self.store_coupon(game.clone(), asset, code_hash, discount, expires, uses)?,
+                GameAction::ScheduleSale {
+                    asset,
+                    discount,
+                    start,
+                    end,
+                    max_per_player,
+                } => {
+                    self.add_sale(game.clone(), asset, discount, start, end, max_per_player)?;
+                }
+                GameAction::SetPriceCurve { asset, curve } => {
+                    self.update_price_curve(game.clone(), asset, curve)?
+                }
+                GameAction::SetRewardRate { asset, rate } => {
+                    self.update_reward_rate(game.clone(), asset, rate)?
+                }
+                GameAction::CreateTournament { terms } => {
+                    self.open_tournament(game.clone(), terms)?;
+                }
+                GameAction::SetBuyback { asset, percent } => {
+                    self.update_buyback(game.clone(), asset, percent)?
+                }
+                GameAction::SetPriceCurrency { asset, currency } => {
+                    self.update_price_currency(game.clone(), asset, currency)?
+                }
+                GameAction::SetOperator { operator, allowed } => {
+                    self.update_operator(game.clone(), operator, allowed)?
+                }
+                GameAction::SetVoucherSigner { signer } => {
+                    self.update_voucher_signer(game.clone(), signer)?
+                }
+                GameAction::GrantAchievement {
+                    achievement,
+                    account,
+                } => self.award_achievement(game.clone(), achievement, account)?,
+                GameAction::AdjustReputation { account, delta } => {
+                    self.change_reputation(game.clone(), account, delta)?
+                }
+                GameAction::ProposeConversion {
+                    route,
+                    give_units,
+                    take_units,
+                    cap_per_period,
+                    period,
+                } => {
+                    let owns_from = route.0 == game;
+                    let owns_to = route.2 == game;
+                    if !owns_from && !owns_to {
+                        return Err(ContractError::NotGameOwner);
+                    }
+                    self.approve_conversion(
+                        route,
+                        give_units,
+                        take_units,
+                        cap_per_period,
+                        period,
+                        (owns_from, owns_to),
+                    )?
+                }
+                GameAction::CreateCurrency {
+                    name,
+                    symbol,
+                    decimals,
+                    denominate_prices,
+                } => {
+                    self.issue_currency(game.clone(), name, symbol, decimals, denominate_prices)?;
+                }
+                GameAction::RegisterAsset {
+                    name,
+                    price,
+                    recoverable,
+                } => self.add_asset(game.clone(), name, price, recoverable)?,
+            }
+
+            // Emit event
+            self.env()
+                .emit_event(GameActionExecuted { proposal_id, game });
+
+            Ok(())
+        }
+
+        /// Set or clear the key that signs a game's vouchers. Only the game owner can do this
+        /// directly; games with signers use a `SetVoucherSigner` proposal.
+        #[ink(message)]
+        pub fn set_voucher_signer(
+            &mut self,
+            game: String,
+            signer: Option<SignerKey>,
+        ) -> Result<(), ContractError> {
+            self.ensure_direct_control(&game)?;
+            self.update_voucher_signer(game, signer)
+        }
+
+        /// Returns the key that signs a game's vouchers
+        #[ink(message)]
+        pub fn voucher_signer(&self, game: String) -> Option<SignerKey> {
+            self.voucher_signers.get(&game)
+        }
+
+        /// Returns whether a game's voucher nonce was already redeemed
+        #[ink(message)]
+        pub fn is_voucher_redeemed(&self, game: String, nonce: u64) -> bool {
+            self.redeemed_vouchers.contains((game, nonce))
+        }
+
+        /// Redeem a voucher signed by the game's signing key, crediting its assets to the player.
+        /// Anyone can submit the voucher, so the player does not have to pay for the transaction.
+        #[ink(message)]
+        pub fn redeem_voucher(
+            &mut self,
+            voucher: Voucher,
+            signature: Vec<u8>,
+        ) -> Result<(), ContractError> {
+            // Validate count
+            if voucher.count == 0 {
+                return Err(ContractError::InsufficientAssetCount);
+            }
+            if self.env().block_number() > voucher.expires_at {
+                return Err(ContractError::VoucherExpired);
+            }
+            self.asset_price(&voucher.game, &voucher.asset)?;
+
+            let nonce_key = (voucher.game.clone(), voucher.nonce);
+            if self.redeemed_vouchers.contains(&nonce_key) {
+                return Err(ContractError::VoucherAlreadyRedeemed);
+            }
+
+            // Check the server's signature
+            let signer = self
+                .voucher_signers
+                .get(&voucher.game)
+                .ok_or(ContractError::NoVoucherSigner)?;
+            let message = self.env().hash_encoded::<ink::env::hash::Blake2x256, _>(&(
+                self.env().account_id(),
+                &voucher,
+            ));
+            self.verify_signature(&signer, &message, &signature)?;
+
+            let mut player = self
+                .players
+                .get(voucher.player)
+                .ok_or(ContractError::PlayerNotFound)?;
+            self.credit_asset(voucher.player, &mut player, &voucher.asset, voucher.count)?;
+            self.players.insert(voucher.player, &player);
+            self.redeemed_vouchers.insert(&nonce_key, &());
+
+            // Let a receiving contract accept or reject the assets
+            self.notify_receiver(
+                self.env().account_id(),
+                voucher.player,
+                &voucher.asset,
+                voucher.count,
+            )?;
+
+            // Emit event
+            self.env().emit_event(VoucherRedeemed {
+                game: voucher.game,
+                player: voucher.player,
+                asset: voucher.asset,
+                count: voucher.count,
+                nonce: voucher.nonce,
+            });
+
+            Ok(())
+        }
+
+        /// Returns the next nonce a player must sign for a relayed action
+        #[ink(message)]
+        pub fn nonce_of(&self, player: AccountId) -> u64 {
+            self.player_nonces.get(player).unwrap_or_default()
+        }
+
+        /// Execute an action signed by `player`, so a relayer can pay the transaction fee.
+        /// The signature covers the Blake2x256 hash of `(contract, player, nonce, fee, call)`,
+        /// SCALE encoded, made with the player's sr25519 or ECDSA account key.
+        /// `fee` is moved from the player's balance to the relayer's.
+        #[ink(message)]
+        pub fn execute_signed(
+            &mut self,
+            call: SignedCall,
+            player: AccountId,
+            nonce: u64,
+            fee: Balance,
+            signature: Vec<u8>,
+        ) -> Result<(), ContractError> {
+            let relayer = self.env().caller();
+
+            // Check replay protection
+            let expected = self.player_nonces.get(player).unwrap_or_default();
+            if nonce != expected {
+                return Err(ContractError::InvalidNonce);
+            }
+
+            // Check the player's signature
+            let message = self.env().hash_encoded::<ink::env::hash::Blake2x256, _>(&(
+                self.env().account_id(),
+                player,
+                nonce,
+                fee,
+                &call,
+            ));
+            self.verify_account_signature(player, &message, &signature)?;
+
+            self.player_nonces.insert(
+                player,
+                &expected.checked_add(1).ok_or(ContractError::Overflow)?,
+            );
+
+            match call {
+                SignedCall::GiftAsset {
+                    receiver,
+                    asset,
+                    amount,
+                } => self.gift_asset_from(player, receiver, asset, amount)?,
+                SignedCall::ExchangeAsset {
+                    from_game,
+                    asset_give,
+                    units_give,
+                    to_game,
+                    asset_take,
+                } => self.exchange_asset_for(
+                    player, from_game, asset_give, units_give, to_game, asset_take,
+                )?,
+            }
+
+            // Pay the relayer
+            if fee > 0 {
+                let mut payer = self
+                    .players
+                    .get(player)
+                    .ok_or(ContractError::PlayerNotFound)?;
+                payer.balance = payer
+                    .balance
+                    .checked_sub(fee)
+                    .ok_or(ContractError::InsufficientBalance)?;
+                self.players.insert(player, &payer);
+
+                let mut payee = self
+                    .players
+                    .get(relayer)
+                    .ok_or(ContractError::PlayerNotFound)?;
+                payee.balance = payee
+                    .balance
+                    .checked_add(fee)
+                    .ok_or(ContractError::Overflow)?;
+                self.players.insert(relayer, &payee);
+            }
+
+            // Emit event
+            self.env().emit_event(SignedCallExecuted {
+                player,
+                relayer,
+                nonce,
+                fee,
+            });
+
+            Ok(())
+        }
+
+        /// Escrow `total` units of an asset from the caller's holdings for accounts to claim with
+        /// a merkle proof until `expires_at`. Only the game owner can do this.
+        #[ink(message)]
+        pub fn create_airdrop(
+            &mut self,
+            game: String,
+            asset: String,
+            merkle_root: Hash,
+            total: u64,
+            expires_at: BlockNumber,
+        ) -> Result<AirdropId, ContractError> {
+            self.ensure_game_owner(&game)?;
+            self.asset_price(&game, &asset)?;
+
+            // Validate total
+            if total == 0 {
+                return Err(ContractError::InsufficientAssetCount);
+            }
+            if expires_at < self.env().block_number() {
+                return Err(ContractError::AirdropExpired);
+            }
+
+            // Escrow the units from the owner
+            let caller = self.env().caller();
+            let mut player = self
+                .players
+                .get(caller)
+                .ok_or(ContractError::PlayerNotFound)?;
+            self.debit_asset(caller, &mut player, &asset, total)?;
+            self.players.insert(caller, &player);
+
+            let airdrop_id = self.next_airdrop_id;
+            self.next_airdrop_id = airdrop_id.checked_add(1).ok_or(ContractError::Overflow)?;
+
+            let airdrop = Airdrop {
+                game: game.clone(),
+                asset: asset.clone(),
+                merkle_root,
+                remaining: total,
+                expires_at,
+            };
+            self.airdrops.insert(airdrop_id, &airdrop);
+
+            // Emit event
+            self.env().emit_event(AirdropCreated {
+                airdrop_id,
+                game,
+                asset,
+                merkle_root,
+                total,
+                expires_at,
+            });
+
+            Ok(airdrop_id)
+        }
+
+        /// Returns an airdrop
+        #[ink(message)]
+        pub fn airdrop(&self, airdrop_id: AirdropId) -> Option<Airdrop> {
+            self.airdrops.get(airdrop_id)
+        }
+
+        /// Returns whether a leaf of an airdrop has been claimed
+        #[ink(message)]
+        pub fn is_airdrop_claimed(&self, airdrop_id: AirdropId, index: u32) -> bool {
+            let word = self
+                .airdrop_claims
+                .get((airdrop_id, index / 128))
+                .unwrap_or_default();
+            word & (1 << (index % 128)) != 0
+        }
+
+        /// Claim the caller's leaf `index` of an airdrop, proving `(index, caller, count)` is in
+        /// its merkle tree.
+        #[ink(message)]
+        pub fn claim_airdrop(
+            &mut self,
+            airdrop_id: AirdropId,
+            index: u32,
+            count: u64,
+            proof: Vec<Hash>,
+        ) -> Result<(), ContractError> {
+            let caller = self.env().caller();
+            let mut airdrop = self
+                .airdrops
+                .get(airdrop_id)
+                .ok_or(ContractError::AirdropNotFound)?;
+
+            if self.env().block_number() > airdrop.expires_at {
+                return Err(ContractError::AirdropExpired);
+            }
+
+            // Check the leaf has not been claimed
+            let word_key = (airdrop_id, index / 128);
+            let bit = 1u128 << (index % 128);
+            let word = self.airdrop_claims.get(word_key).unwrap_or_default();
+            if word & bit != 0 {
+                return Err(ContractError::AlreadyClaimed);
+            }
+
+            // Walk the proof up to the root
+            let mut node: Hash = self
+                .env()
+                .hash_encoded::<ink::env::hash::Blake2x256, _>(&(index, caller, count))
+                .into();
+            for sibling in proof {
+                let pair = if node <= sibling {
+                    (node, sibling)
+                } else {
+                    (sibling, node)
+                };
+                node = self
+                    .env()
+                    .hash_encoded::<ink::env::hash::Blake2x256, _>(&pair)
+                    .into();
+            }
+            if node != airdrop.merkle_root {
+                return Err(ContractError::InvalidProof);
+            }
+
+            airdrop.remaining = airdrop
+                .remaining
+                .checked_sub(count)
+                .ok_or(ContractError::InsufficientAssetCount)?;
+
+            let mut player = self
+                .players
+                .get(caller)
+                .ok_or(ContractError::PlayerNotFound)?;
+            self.credit_asset(caller, &mut player, &airdrop.asset, count)?;
+
+            self.players.insert(caller, &player);
+            self.airdrops.insert(airdrop_id, &airdrop);
+            self.airdrop_claims.insert(word_key, &(word | bit));
+
+            // Emit event
+            self.env().emit_event(AirdropClaimed {
+                airdrop_id,
+                account: caller,
+                count,
+            });
+
+            Ok(())
+        }
+
+        /// Return the unclaimed units of an expired airdrop to the caller.
+        /// Only the game owner can do this.
+        #[ink(message)]
+        pub fn reclaim_airdrop(&mut self, airdrop_id: AirdropId) -> Result<(), ContractError> {
+            let mut airdrop = self
+                .airdrops
+                .get(airdrop_id)
+                .ok_or(ContractError::AirdropNotFound)?;
+            self.ensure_game_owner(&airdrop.game)?;
+
+            if self.env().block_number() <= airdrop.expires_at {
+                return Err(ContractError::AirdropNotExpired);
+            }
+            if airdrop.remaining == 0 {
+                return Err(ContractError::InsufficientAssetCount);
+            }
+
+            let caller = self.env().caller();
+            let mut player = self
+                .players
+                .get(caller)
+                .ok_or(ContractError::PlayerNotFound)?;
+            let count = airdrop.remaining;
+            self.credit_asset(caller, &mut player, &airdrop.asset, count)?;
+            self.players.insert(caller, &player);
+
+            airdrop.remaining = 0;
+            self.airdrops.insert(airdrop_id, &airdrop);
+
+            // Emit event
+            self.env()
+                .emit_event(AirdropReclaimed { airdrop_id, count });
+
+            Ok(())
+        }
+
+        /// Let `operator`, usually a game logic contract, award and consume the game's assets.
+        /// Only the game owner can do this directly; games with signers use a `SetOperator`
+        /// proposal.
+        #[ink(message)]
+        pub fn set_game_operator(
+            &mut self,
+            game: String,
+            operator: AccountId,
+            allowed: bool,
+        ) -> Result<(), ContractError> {
+            self.ensure_direct_control(&game)?;
+            self.update_operator(game, operator, allowed)
+        }
+
+        /// Challenge `opponent` to a match, escrowing the caller's stake and committing their move.
+        /// The opponent has `timeout` blocks to join, then both have `timeout` blocks to reveal.
+        #[ink(message)]
+        pub fn create_match(
+            &mut self,
+            opponent: AccountId,
+            stake: VaultItem,
+            commitment: Hash,
+            timeout: BlockNumber,
+        ) -> Result<MatchId, ContractError> {
+            let caller = self.env().caller();
+            if opponent == caller {
+                return Err(ContractError::NotMatchPlayer);
+            }
+            if !self.players.contains(opponent) {
+                return Err(ContractError::PlayerNotFound);
+            }
+
+            // A match without a stake or a phase to play it in cannot be won
+            let staked = match stake {
+                VaultItem::Balance(amount) => amount > 0,
+                VaultItem::Asset(_, count) => count > 0,
+            };
+            if !staked || timeout == 0 {
+                return Err(ContractError::InvalidMatchTerms);
+            }
+
+            self.escrow_item(caller, &stake)?;
+
+            let match_id = self.next_match_id;
+            self.next_match_id = match_id.checked_add(1).ok_or(ContractError::Overflow)?;
+
+            let deadline = self
+                .env()
+                .block_number()
+                .checked_add(timeout)
+                .ok_or(ContractError::Overflow)?;
+            let wager = Match {
+                challenger: caller,
+                opponent,
+                stake: stake.clone(),
+                timeout,
+                deadline,
+                challenger_commit: commitment,
+                opponent_commit: None,
+                challenger_hand: None,
+                opponent_hand: None,
+            };
+            self.matches.insert(match_id, &wager);
+
+            // Emit event
+            self.env().emit_event(MatchCreated {
+                match_id,
+                challenger: caller,
+                opponent,
+                stake,
+            });
+
+            Ok(match_id)
+        }
+
+        /// Returns a match in progress
+        #[ink(message)]
+        pub fn wager_match(&self, match_id: MatchId) -> Option<Match> {
+            self.matches.get(match_id)
+        }
+
+        /// Accept a challenge, escrowing the same stake and committing a move.
+        #[ink(message)]
+        pub fn join_match(
+            &mut self,
+            match_id: MatchId,
+            commitment: Hash,
+        ) -> Result<(), ContractError> {
+            let caller = self.env().caller();
+            let mut wager = self
+                .matches
+                .get(match_id)
+                .ok_or(ContractError::MatchNotFound)?;
+
+            if caller != wager.opponent {
+                return Err(ContractError::NotMatchPlayer);
+            }
+            if wager.opponent_commit.is_some() {
+                return Err(ContractError::InvalidMatchPhase);
+            }
+            let now = self.env().block_number();
+            if now > wager.deadline {
+                return Err(ContractError::DeadlinePassed);
+            }
+
+            self.escrow_item(caller, &wager.stake)?;
+
+            wager.opponent_commit = Some(commitment);
+            wager.deadline = now
+                .checked_add(wager.timeout)
+                .ok_or(ContractError::Overflow)?;
+            self.matches.insert(match_id, &wager);
+
+            // Emit event
+            self.env().emit_event(MatchJoined {
+                match_id,
+                reveal_deadline: wager.deadline,
+            });
+
+            Ok(())
+        }
+
+        /// Reveal the caller's committed move. Once both moves are revealed the winner
+        /// takes both stakes, and a draw returns them.
+        #[ink(message)]
+        pub fn reveal_move(
+            &mut self,
+            match_id: MatchId,
+            hand: Hand,
+            salt: Hash,
+        ) -> Result<(), ContractError> {
+            let caller = self.env().caller();
+            let mut wager = self
+                .matches
+                .get(match_id)
+                .ok_or(ContractError::MatchNotFound)?;
+
+            let opponent_commit = wager
+                .opponent_commit
+                .ok_or(ContractError::InvalidMatchPhase)?;
+            if self.env().block_number() > wager.deadline {
+                return Err(ContractError::DeadlinePassed);
+            }
+
+            let (commitment, revealed) = if caller == wager.challenger {
+                (wager.challenger_commit, &mut wager.challenger_hand)
+            } else if caller == wager.opponent {
+                (opponent_commit, &mut wager.opponent_hand)
+            } else {
+                return Err(ContractError::NotMatchPlayer);
+            };
+            if revealed.is_some() {
+                return Err(ContractError::InvalidMatchPhase);
+            }
+
+            // Check the move against the commitment
+            let hash: Hash = self
+                .env()
+                .hash_encoded::<ink::env::hash::Blake2x256, _>(&(hand, salt, caller))
+                .into();
+            if hash != commitment {
+                return Err(ContractError::InvalidReveal);
+            }
+            *revealed = Some(hand);
+
+            // Emit event
+            self.env().emit_event(MoveRevealed {
+                match_id,
+                player: caller,
+                hand,
+            });
+
+            match (wager.challenger_hand, wager.opponent_hand) {
+                (Some(challenger_hand), Some(opponent_hand)) => {
+                    let winner = match (challenger_hand, opponent_hand) {
+                        (Hand::Rock, Hand::Scissors)
+                        | (Hand::Paper, Hand::Rock)
+                        | (Hand::Scissors, Hand::Paper) => Some(wager.challenger),
+                        (a, b) if a == b => None,
+                        _ => Some(wager.opponent),
+                    };
+                    self.settle_match(match_id, &wager, winner)
+                }
+                _ => {
+                    self.matches.insert(match_id, &wager);
+                    Ok(())
+                }
+            }
+        }
+
+        /// Settle a match whose deadline passed. An unjoined match is refunded to the challenger;
+        /// in the reveal phase a player who revealed wins the pot, and if neither did both
+        /// stakes are returned. Either player can do this.
+        #[ink(message)]
+        pub fn claim_match_timeout(&mut self, match_id: MatchId) -> Result<(), ContractError> {
+            let caller = self.env().caller();
+            let wager = self
+                .matches
+                .get(match_id)
+                .ok_or(ContractError::MatchNotFound)?;
+
+            if caller != wager.challenger && caller != wager.opponent {
+                return Err(ContractError::NotMatchPlayer);
+            }
+            if self.env().block_number() <= wager.deadline {
+                return Err(ContractError::DeadlineNotReached);
+            }
+
+            if wager.opponent_commit.is_none() {
+                self.release_item(wager.challenger, &wager.stake, 1)?;
+                self.close_escrow(wager.challenger);
+                self.matches.remove(match_id);
+
+                // Emit event
+                self.env().emit_event(MatchSettled {
+                    match_id,
+                    winner: None,
+                });
+                return Ok(());
+            }
+
+            // A player who did not reveal forfeits
+            let winner = match (wager.challenger_hand, wager.opponent_hand) {
+                (Some(_), None) => Some(wager.challenger),
+                (None, Some(_)) => Some(wager.opponent),
+                _ => None,
+            };
+            self.settle_match(match_id, &wager, winner)
+        }
+
+        /// Open a tournament for a game. Only the game owner can do this directly; games with
+        /// signers use a `CreateTournament` proposal.
+        #[ink(message)]
+        pub fn create_tournament(
+            &mut self,
+            game: String,
+            terms: TournamentTerms,
+        ) -> Result<TournamentId, ContractError> {
+            self.ensure_direct_control(&game)?;
+            self.open_tournament(game, terms)
+        }
+
+        /// Returns a tournament
+        #[ink(message)]
+        pub fn tournament(&self, tournament_id: TournamentId) -> Option<Tournament> {
+            self.tournaments.get(tournament_id)
+        }
+
+        /// Enter a tournament during its registration window, escrowing the entry fee.
+        #[ink(message)]
+        pub fn enter_tournament(
+            &mut self,
+            tournament_id: TournamentId,
+        ) -> Result<(), ContractError> {
+            let caller = self.env().caller();
+            let mut tournament = self
+                .tournaments
+                .get(tournament_id)
+                .ok_or(ContractError::TournamentNotFound)?;
+
+            let now = self.env().block_number();
+            if tournament.status != TournamentStatus::Open
+                || now < tournament.terms.opens_at
+                || now > tournament.terms.closes_at
+            {
+                return Err(ContractError::TournamentClosed);
+            }
+            if tournament.entrants.len() >= tournament.terms.max_entrants as usize {
+                return Err(ContractError::TournamentFull);
+            }
+            if tournament.entrants.contains(&caller) {
+                return Err(ContractError::AlreadyEntered);
+            }
+
+            self.escrow_item(caller, &tournament.terms.entry_fee)?;
+            tournament.entrants.push(caller);
+            self.tournaments.insert(tournament_id, &tournament);
+
+            // Emit event
+            self.env().emit_event(TournamentEntered {
+                tournament_id,
+                account: caller,
+            });
+
+            Ok(())
+        }
+
+        /// Submit the final ranking once registration has closed, paying the prize pool and
+        /// trophies in one step. Only the game owner or the tournament oracle can do this.
+        #[ink(message)]
+        pub fn submit_results(
+            &mut self,
+            tournament_id: TournamentId,
+            ranking: Vec<AccountId>,
+        ) -> Result<(), ContractError> {
+            let mut tournament = self
+                .tournaments
+                .get(tournament_id)
+                .ok_or(ContractError::TournamentNotFound)?;
+            if tournament.terms.oracle != Some(self.env().caller()) {
+                self.ensure_game_owner(&tournament.game)?;
+            }
+
+            if tournament.status != TournamentStatus::Open {
+                return Err(ContractError::TournamentClosed);
+            }
+            if self.env().block_number() <= tournament.terms.closes_at {
+                return Err(ContractError::DeadlineNotReached);
+            }
+
+            // Validate ranking
+            if ranking.is_empty() || ranking.len() > tournament.terms.prizes.len() {
+                return Err(ContractError::InvalidRanking);
+            }
+            for (i, account) in ranking.iter().enumerate() {
+                if !tournament.entrants.contains(account) || ranking[..i].contains(account) {
+                    return Err(ContractError::InvalidRanking);
+                }
+            }
+
+            // Split the pool, leaving the remainder to the winner
+            let entrants = tournament.entrants.len() as u128;
+            let pool = match &tournament.terms.entry_fee {
+                VaultItem::Balance(amount) => amount.checked_mul(entrants),
+                VaultItem::Asset(_, count) => (*count as u128).checked_mul(entrants),
+            }
+            .ok_or(ContractError::Overflow)?;
+            let mut payouts = Vec::new();
+            let mut paid = 0u128;
+            for prize in tournament.terms.prizes.iter().take(ranking.len()).skip(1) {
+                let payout = pool
+                    .checked_mul(prize.share_bps as u128)
+                    .ok_or(ContractError::Overflow)?
+                    / 10_000;
+                paid = paid.checked_add(payout).ok_or(ContractError::Overflow)?;
+                payouts.push(payout);
+            }
+            payouts.insert(0, pool.checked_sub(paid).ok_or(ContractError::Overflow)?);
+
+            for (i, account) in ranking.iter().enumerate() {
+                let prize = match &tournament.terms.entry_fee {
+                    VaultItem::Balance(_) => VaultItem::Balance(payouts[i]),
+                    VaultItem::Asset(asset, _) => VaultItem::Asset(
+                        asset.clone(),
+                        u64::try_from(payouts[i]).map_err(|_| ContractError::Overflow)?,
+                    ),
+                };
+                self.release_item(*account, &prize, 1)?;
+
+                if let Some((asset, count)) = &tournament.terms.prizes[i].trophy {
+                    self.release_item(*account, &VaultItem::Asset(asset.clone(), *count), 1)?;
+                }
+            }
+
+            for account in tournament.entrants.iter() {
+                self.close_escrow(*account);
+            }
+
+            tournament.status = TournamentStatus::Completed;
+            self.tournaments.insert(tournament_id, &tournament);
+
+            // Emit event
+            self.env().emit_event(TournamentCompleted {
+                tournament_id,
+                ranking,
+            });
+
+            Ok(())
+        }
+
+        /// Cancel a tournament that has no results yet, refunding every entrant.
+        /// Only the game owner can do this.
+        #[ink(message)]
+        pub fn cancel_tournament(
+            &mut self,
+            tournament_id: TournamentId,
+        ) -> Result<(), ContractError> {
+            let mut tournament = self
+                .tournaments
+                .get(tournament_id)
+                .ok_or(ContractError::TournamentNotFound)?;
+            self.ensure_game_owner(&tournament.game)?;
+
+            if tournament.status != TournamentStatus::Open {
+                return Err(ContractError::TournamentClosed);
+            }
+
+            for account in tournament.entrants.iter() {
+                self.release_item(*account, &tournament.terms.entry_fee, 1)?;
+                self.close_escrow(*account);
+            }
+
+            tournament.status = TournamentStatus::Cancelled;
+            self.tournaments.insert(tournament_id, &tournament);
+
+            // Emit event
+            self.env().emit_event(TournamentCancelled { tournament_id });
+
+            Ok(())
+        }
+
+        /// Define an achievement players of the game can earn. Only the game owner can do this.
+        #[ink(message)]
+        pub fn define_achievement(
+            &mut self,
+            game: String,
+            achievement: String,
+            name: String,
+            description: String,
+            points: u32,
+        ) -> Result<(), ContractError> {
+            self.ensure_game_owner(&game)?;
+
+            let key = (game, achievement);
+            if self.achievements.contains(&key) {
+                return Err(ContractError::AchievementExists);
+            }
+            self.achievements.insert(
+                &key,
+                &Achievement {
+                    name,
+                    description,
+                    points,
+                },
+            );
+
+            // Emit event
+            let (game, achievement) = key;
+            self.env().emit_event(AchievementDefined {
+                game,
+                achievement,
+                points,
+            });
+
+            Ok(())
+        }
+
+        /// Returns an achievement defined by a game
+        #[ink(message)]
+        pub fn achievement(&self, game: String, achievement: String) -> Option<Achievement> {
+            self.achievements.get(&(game, achievement))
+        }
+
+        /// Grant an achievement to a player. Only the game's operators or, directly, its owner can
+        /// do this; games with signers use a `GrantAchievement` proposal in place of the owner.
+        #[ink(message)]
+        pub fn grant_achievement(
+            &mut self,
+            game: String,
+            achievement: String,
+            account: AccountId,
+        ) -> Result<(), ContractError> {
+            self.ensure_game_operator(&game)?;
+            self.award_achievement(game, achievement, account)
+        }
+
+        /// Returns the achievements a player earned across all games, as `(game, achievement)` pairs
+        #[ink(message)]
+        pub fn achievements_of(&self, account: AccountId) -> Vec<AchievementKey> {
+            self.earned_achievements.get(account).unwrap_or_default()
+        }
+
+        /// Returns a player's gamer score
+        #[ink(message)]
+        pub fn gamer_score(&self, account: AccountId) -> u64 {
+            self.gamer_scores.get(account).unwrap_or_default()
+        }
+
+        /// Flag a player for fraud. Only the game owner can do this.
+        #[ink(message)]
+        pub fn flag_player(
+            &mut self,
+            game: String,
+            account: AccountId,
+            reason_hash: Hash,
+        ) -> Result<(), ContractError> {
+            self.ensure_game_owner(&game)?;
+
+            let key = (game, account);
+            if self.flags.contains(&key) {
+                return Err(ContractError::AlreadyFlagged);
+            }
+            let count = self
+                .flag_counts
+                .get(account)
+                .unwrap_or_default()
+                .checked_add(1)
+                .ok_or(ContractError::Overflow)?;
+
+            self.flags.insert(
+                &key,
+                &Flag {
+                    reason_hash,
+                    flagged_at: self.env().block_number(),
+                    appeal_hash: None,
+                },
+            );
+            self.flag_counts.insert(account, &count);
+
+            // Emit event
+            self.env().emit_event(PlayerFlagged {
+                game: key.0,
+                account,
+                reason_hash,
+            });
+
+            Ok(())
+        }
+
+        /// Appeal a game's flag on the caller.
+        #[ink(message)]
+        pub fn appeal_flag(
+            &mut self,
+            game: String,
+            appeal_hash: Hash,
+        ) -> Result<(), ContractError> {
+            let caller = self.env().caller();
+            let key = (game, caller);
+            let mut flag = self.flags.get(&key).ok_or(ContractError::NotFlagged)?;
+
+            flag.appeal_hash = Some(appeal_hash);
+            self.flags.insert(&key, &flag);
+
+            // Emit event
+            self.env().emit_event(FlagAppealed {
+                game: key.0,
+                account: caller,
+                appeal_hash,
+            });
+
+            Ok(())
+        }
+
+        /// Clear a game's flag on a player. Only the game owner can do this.
+        #[ink(message)]
+        pub fn clear_flag(
+            &mut self,
+            game: String,
+            account: AccountId,
+        ) -> Result<(), ContractError> {
+            self.ensure_game_owner(&game)?;
+
+            let key = (game, account);
+            if self.flags.take(&key).is_none() {
+                return Err(ContractError::NotFlagged);
+            }
+            let count = self.flag_counts.get(account).unwrap_or_default();
+            if count > 1 {
+                self.flag_counts.insert(account, &(count - 1));
+            } else {
+                self.flag_counts.remove(account);
+            }
+
+            // Emit event
+            self.env().emit_event(FlagCleared {
+                game: key.0,
+                account,
+            });
+
+            Ok(())
+        }
+
+        /// Returns a game's flag on a player
+        #[ink(message)]
+        pub fn flag(&self, game: String, account: AccountId) -> Option<Flag> {
+            self.flags.get(&(game, account))
+        }
+
+        /// Returns whether any game flags a player
+        #[ink(message)]
+        pub fn is_flagged(&self, account: AccountId) -> bool {
+            self.flag_counts.contains(account)
+        }
+
+        /// Refuse or accept gifts and exchanges of the game's assets from players flagged
+        /// by the game or a game it trusts. Only the game owner can do this.
+        #[ink(message)]
+        pub fn set_flag_policy(
+            &mut self,
+            game: String,
+            refuse_flagged: bool,
+        ) -> Result<(), ContractError> {
+            self.ensure_game_owner(&game)?;
+
+            if refuse_flagged {
+                self.refuse_flagged.insert(&game, &());
+            } else {
+                self.refuse_flagged.remove(&game);
+            }
+
+            // Emit event
+            self.env().emit_event(FlagPolicySet {
+                game,
+                refuse_flagged,
+            });
+
+            Ok(())
+        }
+
+        /// Returns whether a game refuses transfers from flagged players
+        #[ink(message)]
+        pub fn refuses_flagged(&self, game: String) -> bool {
+            self.refuse_flagged.contains(&game)
+        }
+
+        /// Trust or stop trusting another game's flags and reputation.
+        /// Only the game owner can do this.
+        #[ink(message)]
+        pub fn set_flag_trust(
+            &mut self,
+            game: String,
+            trusted_game: String,
+            trusted: bool,
+        ) -> Result<(), ContractError> {
+            self.ensure_game_owner(&game)?;
+            if !self.game_owners.contains(&trusted_game) {
+                return Err(ContractError::GameWithoutAssets);
+            }
+
+            let mut trust = self.flag_trust.get(&game).unwrap_or_default();
+            trust.retain(|other| *other != trusted_game);
+            if trusted && trusted_game != game {
+                trust.push(trusted_game.clone());
+            }
+            self.flag_trust.insert(&game, &trust);
+
+            // Emit event
+            self.env().emit_event(FlagTrustSet {
+                game,
+                trusted_game,
+                trusted,
+            });
+
+            Ok(())
+        }
+
+        /// Returns the games whose flags and reputation a game trusts
+        #[ink(message)]
+        pub fn trusted_games(&self, game: String) -> Vec<String> {
+            self.flag_trust.get(&game).unwrap_or_default()
+        }
+
+        /// Raise or lower a player's reputation with a game. Only the game's operators or,
+        /// directly, its owner can do this; games with signers use an `AdjustReputation` proposal
+        /// in place of the owner.
+        #[ink(message)]
+        pub fn adjust_reputation(
+            &mut self,
+            game: String,
+            account: AccountId,
+            delta: i32,
+        ) -> Result<(), ContractError> {
+            self.ensure_game_operator(&game)?;
+            self.change_reputation(game, account, delta)
+        }
+
+        /// Returns a player's reputation with a game
+        #[ink(message)]
+        pub fn reputation(&self, game: String, account: AccountId) -> i32 {
+            self.reputation.get(&(game, account)).unwrap_or_default()
+        }
+
+        /// Count a game's reputation towards global reputation, or stop counting it.
+        /// Only the contract admin can do this, so throwaway games cannot sway global scores.
+        #[ink(message)]
+        pub fn set_reputation_game(
+            &mut self,
+            game: String,
+            counted: bool,
+        ) -> Result<(), ContractError> {
+            if self.admin != Some(self.env().caller()) {
+                return Err(ContractError::NotAdmin);
+            }
+            if !self.game_owners.contains(&game) {
+                return Err(ContractError::GameWithoutAssets);
+            }
+
+            self.reputation_games.retain(|other| *other != game);
+            if counted {
+                self.reputation_games.push(game.clone());
+            }
+
+            // Emit event
+            self.env().emit_event(ReputationGameSet { game, counted });
+
+            Ok(())
+        }
+
+        /// Returns the games whose reputation counts towards global reputation
+        #[ink(message)]
+        pub fn reputation_games(&self) -> Vec<String> {
+            self.reputation_games.clone()
+        }
+
+        /// Returns a player's reputation summed across the games counted globally
+        #[ink(message)]
+        pub fn global_reputation(&self, account: AccountId) -> i64 {
+            self.reputation_games
+                .iter()
+                .map(|game| {
+                    self.reputation
+                        .get(&(game.clone(), account))
+                        .unwrap_or_default() as i64
+                })
+                .sum()
+        }
+
+        /// Returns a player's reputation with a game plus the games it trusts
+        #[ink(message)]
+        pub fn trusted_reputation(&self, game: String, account: AccountId) -> i64 {
+            let trusted = self.flag_trust.get(&game).unwrap_or_default();
+            core::iter::once(game)
+                .chain(trusted)
+                .map(|game| self.reputation.get(&(game, account)).unwrap_or_default() as i64)
+                .sum()
+        }
+
+        /// Returns whether the game can freeze and claw back an asset
+        #[ink(message)]
+        pub fn is_recoverable(&self, game: String, asset: String) -> bool {
+            self.recoverable.contains(&(game, asset))
+        }
+
+        /// Returns whether a player's holding of an asset is frozen
+        #[ink(message)]
+        pub fn is_frozen(&self, account: AccountId, asset: String) -> bool {
+            self.frozen.contains(&(account, asset))
+        }
+
+        /// Stop a player from moving a recoverable asset out of their holdings.
+        /// Only the game owner can do this.
+        #[ink(message)]
+        pub fn freeze_holding(
+            &mut self,
+            game: String,
+            account: AccountId,
+            asset: String,
+        ) -> Result<(), ContractError> {
+            self.ensure_recoverable(&game, &asset)?;
+
+            // Only a holding can be frozen, and the freeze ends with it
+            if self.balance_of(account, asset.clone()) == 0 {
+                return Err(ContractError::AssetNotFound);
+            }
+            self.frozen.insert(&(account, asset.clone()), &());
+
+            // Emit event
+            self.env().emit_event(Frozen {
+                game,
+                account,
+                asset,
+            });
+
+            Ok(())
+        }
+
+        /// Lift a freeze on a player's holding. Only the game owner can do this.
+        #[ink(message)]
+        pub fn unfreeze_holding(
+            &mut self,
+            game: String,
+            account: AccountId,
+            asset: String,
+        ) -> Result<(), ContractError> {
+            self.ensure_recoverable(&game, &asset)?;
+
+            self.frozen.remove(&(account, asset.clone()));
+
+            // Emit event
+            self.env().emit_event(Unfrozen {
+                game,
+                account,
+                asset,
+            });
+
+            Ok(())
+        }
+
+        /// Burn units of a recoverable asset from a player's holdings, frozen or not.
+        /// Only the game owner can do this.
+        #[ink(message)]
+        pub fn clawback(
+            &mut self,
+            game: String,
+            account: AccountId,
+            asset: String,
+            count: u64,
+        ) -> Result<(), ContractError> {
+            self.ensure_recoverable(&game, &asset)?;
+
+            // Validate count
+            if count == 0 {
+                return Err(ContractError::InsufficientAssetCount);
+            }
+
+            let mut player = self
+                .players
+                .get(account)
+                .ok_or(ContractError::PlayerNotFound)?;
+            self.debit_asset(account, &mut player, &asset, count)?;
+            self.players.insert(account, &player);
+
+            // Emit event
+            self.env().emit_event(ClawedBack {
+                game,
+                account,
+                asset,
+                count,
+            });
+
+            Ok(())
+        }
+
+        /// Make sure the caller owns `game` and the game has no signers that must approve instead
+        fn ensure_direct_control(&self, game: &String) -> Result<(), ContractError> {
+            self.ensure_game_owner(game)?;
+            if self.councils.contains(game) {
+                return Err(ContractError::MultisigRequired);
+            }
+            Ok(())
+        }
+
+        /// Make sure `asset` was registered by `game`
+        fn ensure_game_asset(&self, game: &String, asset: &str) -> Result<(), ContractError> {
+            match self.asset_games.get(asset) {
+                Some(owner) if owner == *game => Ok(()),
+                _ => Err(ContractError::AssetNotFound),
+            }
+        }
+
+        /// Make sure the caller owns `game`
+        fn ensure_game_owner(&self, game: &String) -> Result<(), ContractError> {
+            match self.game_owners.get(game) {
+                Some(owner) if owner == self.env().caller() => Ok(()),
+                Some(_) => Err(ContractError::NotGameOwner),
+                None => Err(ContractError::GameWithoutAssets),
+            }
+        }
+
+        /// Look up the catalog price of an asset in a game
+        fn asset_price(&self, game: &String, asset: &String) -> Result<Balance, ContractError> {
+            let asset_list = self
+                .assets
+                .get(game)
+                .ok_or(ContractError::GameWithoutAssets)?;
+
+            asset_list
+                .iter()
+                .find(|(name, _)| name == asset)
+                .map(|(_, price)| *price)
+                .ok_or(ContractError::AssetNotFound)
+        }
+
+        /// Add units of an asset to a player's holdings, returning the new count
+        fn credit_asset(
+            &mut self,
+            account: AccountId,
+            player: &mut Player,
+            asset: &str,
+            count: u64,
+        ) -> Result<u64, ContractError> {
+            for holding in player.assets.iter_mut() {
+                if let Some((name, qty)) = Self::parse_asset(holding) {
+                    if name == asset {
+                        let new_count = qty.checked_add(count).ok_or(ContractError::Overflow)?;
+                        *holding = Self::format_asset(asset, new_count);
+                        return Ok(new_count);
+                    }
+                }
+            }
+
+            player.assets.push(Self::format_asset(asset, count));
+
+            // Record the player as a new holder
+            let index = self.holder_counts.get(asset).unwrap_or_default();
+            let count_after = index.checked_add(1).ok_or(ContractError::Overflow)?;
+            self.holders.insert((asset, index), &account);
+            self.holder_index.insert((asset, account), &index);
+            self.holder_counts.insert(asset, &count_after);
+
+            Ok(count)
+        }
+
+        /// Remove units of an asset from a player's holdings, returning the remaining count
+        fn debit_asset(
+            &mut self,
+            account: AccountId,
+            player: &mut Player,
+            asset: &str,
+            count: u64,
+        ) -> Result<u64, ContractError> {
+            for i in 0..player.assets.len() {
+                if let Some((name, qty)) = Self::parse_asset(&player.assets[i]) {
+                    if name == asset {
+                        let new_count = qty
+                            .checked_sub(count)
+                            .ok_or(ContractError::InsufficientAssetCount)?;
+
+                        // Drop the entry once it is used up
+                        if new_count == 0 {
+                            player.assets.remove(i);
+                            self.remove_holder(account, asset);
+                            self.frozen.remove(&(account, String::from(asset)));
+                        } else {
+                            player.assets[i] = Self::format_asset(asset, new_count);
+                        }
+
+                        // Curve-bought units that leave the holding can no longer be sold back
+                        let holding_key = (account, String::from(asset));
+                        if let Some(bought) = self.curve_holdings.get(&holding_key) {
+                            if new_count == 0 {
+                                self.curve_holdings.remove(&holding_key);
+                            } else if bought > new_count {
+                                self.curve_holdings.insert(&holding_key, &new_count);
+                            }
+                        }
+
+                        return Ok(new_count);
+                    }
+                }
+            }
+
+            Err(ContractError::AssetNotFound)
+        }
+
+        /// Remove an account from the holders of an asset, moving the last holder into its place
+        fn remove_holder(&mut self, account: AccountId, asset: &str) {
+            let Some(index) = self.holder_index.take((asset, account)) else {
+                return;
+            };
+            let last = self
+                .holder_counts
+                .get(asset)
+                .unwrap_or_default()
+                .saturating_sub(1);
+
+            if index != last {
+                if let Some(moved) = self.holders.get((asset, last)) {
+                    self.holders.insert((asset, index), &moved);
+                    self.holder_index.insert((asset, moved), &index);
+                }
+            }
+            self.holders.remove((asset, last));
+            self.holder_counts.insert(asset, &last);
+        }
+
+        /// Encode an asset and its quantity (e.g., "firegun_9")
+        fn format_asset(asset: &str, count: u64) -> String {
+            let mut encoded = String::from(asset);
+            encoded.push('_');
+            encoded.push_str(&count.to_string());
+            encoded
+        }
+
+        /// Make sure a discount is well formed
+        fn validate_discount(discount: &Discount) -> Result<(), ContractError> {
+            match discount {
+                Discount::Percent(percent) if *percent > 100 => Err(ContractError::InvalidDiscount),
+                _ => Ok(()),
+            }
+        }
+
+        /// Apply a discount to the price of `count` units
+        fn apply_discount(
+            price: Balance,
+            count: u64,
+            discount: &Discount,
+        ) -> Result<Balance, ContractError> {
+            match discount {
+                Discount::Percent(percent) => {
+                    let off = price
+                        .checked_mul(Balance::from(*percent))
+                        .ok_or(ContractError::Overflow)?
+                        / 100;
+                    Ok(price.saturating_sub(off))
+                }
+                Discount::Absolute(amount) => {
+                    let off = amount
+                        .checked_mul(Balance::from(count))
+                        .ok_or(ContractError::Overflow)?;
+                    Ok(price.saturating_sub(off))
+                }
+            }
+        }
+
+        /// Price of buying `count` units of an asset when `supply` units are outstanding,
+        /// following its price curve if it has one
+        fn list_price(
+            &self,
+            key: &AssetKey,
+            supply: u64,
+            count: u64,
+        ) -> Result<Balance, ContractError> {
+            match self.curves.get(key) {
+                Some(curve) => Self::curve_cost(&curve, supply, count),
+                None => self
+                    .asset_price(&key.0, &key.1)?
+                    .checked_mul(Balance::from(count))
+                    .ok_or(ContractError::Overflow),
+            }
+        }
+
+        /// Sum of the curve prices of the units from `supply` to `supply + count - 1`
+        fn curve_cost(
+            curve: &PriceCurve,
+            supply: u64,
+            count: u64,
+        ) -> Result<Balance, ContractError> {
+            let mut total: Balance = 0;
+            for unit in 0..count {
+                let position = supply.checked_add(unit).ok_or(ContractError::Overflow)?;
+                total = total
+                    .checked_add(Self::curve_price(curve, position)?)
+                    .ok_or(ContractError::Overflow)?;
+            }
+            Ok(total)
+        }
+
+        /// Price of a single unit on a curve when `supply` units are outstanding
+        fn curve_price(curve: &PriceCurve, supply: u64) -> Result<Balance, ContractError> {
+            match *curve {
+                PriceCurve::Linear { base, slope } => slope
+                    .checked_mul(Balance::from(supply))
+                    .and_then(|growth| growth.checked_add(base))
+                    .ok_or(ContractError::Overflow),
+                PriceCurve::Exponential { base, growth_bps } => {
+                    // Fixed-point exponentiation by squaring, scaled by 10_000
+                    const SCALE: Balance = 10_000;
+                    let mut factor = SCALE + Balance::from(growth_bps);
+                    let mut multiplier = SCALE;
+                    let mut exponent = supply;
+                    while exponent > 0 {
+                        if exponent & 1 == 1 {
+                            multiplier = multiplier
+                                .checked_mul(factor)
+                                .ok_or(ContractError::Overflow)?
+                                / SCALE;
+                        }
+                        exponent >>= 1;
+                        if exponent > 0 {
+                            factor =
+                                factor.checked_mul(factor).ok_or(ContractError::Overflow)? / SCALE;
+                        }
+                    }
+                    base.checked_mul(multiplier)
+                        .map(|price| price / SCALE)
+                        .ok_or(ContractError::Overflow)
+                }
+                PriceCurve::Step {
+                    base,
+                    step,
+                    increment,
+                } => increment
+                    .checked_mul(Balance::from(supply / step.max(1)))
+                    .and_then(|growth| growth.checked_add(base))
+                    .ok_or(ContractError::Overflow),
+            }
+        }
+
+        /// Returns the currency a game's store is denominated in, if not the global balance
+        fn pricing_currency(&self, game: &String) -> Option<CurrencyId> {
+            let currency_id = self.game_currencies.get(game)?;
+            self.currencies
+                .get(currency_id)
+                .filter(|currency| currency.prices_denominated)
+                .map(|_| currency_id)
+        }
+
+        /// Take an amount from a player in the unit the game's store is denominated in
+        fn charge(
+            &mut self,
+            game: &String,
+            account: AccountId,
+            player: &mut Player,
+            amount: Balance,
+        ) -> Result<(), ContractError> {
+            match self.pricing_currency(game) {
+                Some(currency_id) => self.burn_currency_from(currency_id, account, amount),
+                None => {
+                    player.balance = player
+                        .balance
+                        .checked_sub(amount)
+                        .ok_or(ContractError::InsufficientBalance)?;
+                    Ok(())
+                }
+            }
+        }
+
+        /// Pay an amount to a player in the unit the game's store is denominated in
+        fn pay_out(
+            &mut self,
+            game: &String,
+            account: AccountId,
+            player: &mut Player,
+            amount: Balance,
+        ) -> Result<(), ContractError> {
+            match self.pricing_currency(game) {
+                Some(currency_id) => self.mint_currency_to(currency_id, account, amount),
+                None => {
+                    player.balance = player
+                        .balance
+                        .checked_add(amount)
+                        .ok_or(ContractError::Overflow)?;
+                    Ok(())
+                }
+            }
+        }
+
+        /// Move currency units between two accounts
+        fn transfer_currency(
+            &mut self,
+            currency_id: CurrencyId,
+            from: AccountId,
+            to: AccountId,
+            value: Balance,
+        ) -> Result<(), ContractError> {
+            if !self.currencies.contains(currency_id) {
+                return Err(ContractError::CurrencyNotFound);
+            }
+
+            let from_balance = self
+                .currency_balance_of(currency_id, from)
+                .checked_sub(value)
+                .ok_or(ContractError::InsufficientBalance)?;
+            self.set_currency_balance(currency_id, from, from_balance);
+
+            let to_balance = self
+                .currency_balance_of(currency_id, to)
+                .checked_add(value)
+                .ok_or(ContractError::Overflow)?;
+            self.set_currency_balance(currency_id, to, to_balance);
+
+            // Emit event
+            self.env().emit_event(CurrencyTransfer {
+                currency_id,
+                from: Some(from),
+                to: Some(to),
+                value,
+            });
+
+            Ok(())
+        }
+
+        /// Create currency units on an account
+        fn mint_currency_to(
+            &mut self,
+            currency_id: CurrencyId,
+            to: AccountId,
+            value: Balance,
+        ) -> Result<(), ContractError> {
+            let mut currency = self
+                .currencies
+                .get(currency_id)
+                .ok_or(ContractError::CurrencyNotFound)?;
+            currency.total_supply = currency
+                .total_supply
+                .checked_add(value)
+                .ok_or(ContractError::Overflow)?;
+
+            let balance = self
+                .currency_balance_of(currency_id, to)
+                .checked_add(value)
+                .ok_or(ContractError::Overflow)?;
+
+            self.currencies.insert(currency_id, &currency);
+            self.set_currency_balance(currency_id, to, balance);
+
+            // Emit event
+            self.env().emit_event(CurrencyTransfer {
+                currency_id,
+                from: None,
+                to: Some(to),
+                value,
+            });
+
+            Ok(())
+        }
+
+        /// Destroy currency units held by an account
+        fn burn_currency_from(
+            &mut self,
+            currency_id: CurrencyId,
+            from: AccountId,
+            value: Balance,
+        ) -> Result<(), ContractError> {
+            let mut currency = self
+                .currencies
+                .get(currency_id)
+                .ok_or(ContractError::CurrencyNotFound)?;
+            let balance = self
+                .currency_balance_of(currency_id, from)
+                .checked_sub(value)
+                .ok_or(ContractError::InsufficientBalance)?;
+            currency.total_supply = currency.total_supply.saturating_sub(value);
+
+            self.currencies.insert(currency_id, &currency);
+            self.set_currency_balance(currency_id, from, balance);
+
+            // Emit event
+            self.env().emit_event(CurrencyTransfer {
+                currency_id,
+                from: Some(from),
+                to: None,
+                value,
+            });
+
+            Ok(())
+        }
+
+        /// Take payment for an asset in the currency it is priced in
+        fn collect_payment(
+            &mut self,
+            key: &AssetKey,
+            account: AccountId,
+            player: &mut Player,
+            amount: Balance,
+        ) -> Result<(), ContractError> {
+            let currency = self.price_currencies.get(key).unwrap_or_default();
+
+            // Only native prices are paid with the transferred value
+            if currency != PriceCurrency::Native && self.env().transferred_value() != 0 {
+                return Err(ContractError::IncorrectPayment);
+            }
+
+            match currency {
+                PriceCurrency::Store => self.charge(&key.0, account, player, amount),
+                PriceCurrency::Native => {
+                    if self.env().transferred_value() != amount {
+                        return Err(ContractError::IncorrectPayment);
+                    }
+
+                    let owner = self
+                        .game_owners
+                        .get(&key.0)
+                        .ok_or(ContractError::GameWithoutAssets)?;
+                    self.env()
+                        .transfer(owner, amount)
+                        .map_err(|_| ContractError::PaymentFailed)
+                }
+                PriceCurrency::Psp22(token) => {
+                    let owner = self
+                        .game_owners
+                        .get(&key.0)
+                        .ok_or(ContractError::GameWithoutAssets)?;
+
+                    // Pull the payment with the buyer's allowance to this contract
+                    build_call::<DefaultEnvironment>()
+                        .call(token)
+                        .exec_input(
+                            ExecutionInput::new(Selector::new(ink::selector_bytes!(
+                                "PSP22::transfer_from"
+                            )))
+                            .push_arg(account)
+                            .push_arg(owner)
+                            .push_arg(amount)
+                            .push_arg(Vec::<u8>::new()),
+                        )
+                        .returns::<Result<(), Psp22Error>>()
+                        .try_invoke()
+                        .ok()
+                        .and_then(|result| result.ok())
+                        .and_then(|result| result.ok())
+                        .ok_or(ContractError::PaymentFailed)
+                }
+            }
+        }
+
+        /// Accumulate the rewards earned per staked unit up to the current block
+        fn update_pool(&self, pool: &mut RewardPool) -> Result<(), ContractError> {
+            let now = self.env().block_number();
+            let elapsed = now.saturating_sub(pool.last_update);
+
+            let earned = pool
+                .rate
+                .checked_mul(Balance::from(elapsed))
+                .ok_or(ContractError::Overflow)?;
+            pool.reward_per_unit = pool
+                .reward_per_unit
+                .checked_add(earned)
+                .ok_or(ContractError::Overflow)?;
+            pool.last_update = now;
+
+            Ok(())
+        }
+
+        /// Move the rewards a stake earned since it was last settled into its pending rewards
+        fn settle_stake(pool: &RewardPool, stake: &mut Stake) -> Result<(), ContractError> {
+            let earned = pool
+                .reward_per_unit
+                .saturating_sub(stake.reward_debt)
+                .checked_mul(Balance::from(stake.amount))
+                .ok_or(ContractError::Overflow)?;
+            stake.pending = stake
+                .pending
+                .checked_add(earned)
+                .ok_or(ContractError::Overflow)?;
+            stake.reward_debt = pool.reward_per_unit;
+
+            Ok(())
+        }
+
+        /// Returns the role of an account in a guild
+        fn guild_role(guild: &Guild, account: AccountId) -> Option<GuildRole> {
+            guild
+                .members
+                .iter()
+                .find(|(member, _)| *member == account)
+                .map(|(_, role)| *role)
+        }
+
+        /// Mint a unique item of a catalog asset to a player
+        fn mint_item_to(
+            &mut self,
+            game: String,
+            asset: String,
+            owner: AccountId,
+        ) -> Result<TokenId, ContractError> {
+            self.asset_price(&game, &asset)?;
+
+            if !self.players.contains(owner) {
+                return Err(ContractError::PlayerNotFound);
+            }
+
+            let token_id = self.next_token_id;
+            self.next_token_id = token_id.checked_add(1).ok_or(ContractError::Overflow)?;
+
+            let item = Item {
+                game: game.clone(),
+                asset: asset.clone(),
+                owner,
+                state: Vec::new(),
+                version: 0,
+                state_hash: Hash::default(),
+            };
+            self.items.insert(token_id, &item);
+
+            // Emit event
+            self.env().emit_event(ItemMinted {
+                token_id,
+                game,
+                asset,
+                owner,
+            });
+
+            Ok(token_id)
+        }
+
+        /// Move balance from a game's buyback reserve to a player
+        fn withdraw_reserve_to(
+            &mut self,
+            game: String,
+            to: AccountId,
+            amount: Balance,
+        ) -> Result<(), ContractError> {
+            let mut player = self.players.get(to).ok_or(ContractError::PlayerNotFound)?;
+
+            let reserve = self
+                .reserve_of(game.clone())
+                .checked_sub(amount)
+                .ok_or(ContractError::InsufficientReserve)?;
+            self.pay_out(&game, to, &mut player, amount)?;
+
+            self.players.insert(to, &player);
+            self.game_reserves.insert(&game, &reserve);
+
+            // Emit event
+            self.env().emit_event(ReserveUpdated {
+                game,
+                amount,
+                deposited: false,
+                reserve,
+            });
+
+            Ok(())
+        }
+
+        /// Change the catalog price of an asset
+        fn update_asset_price(
+            &mut self,
+            game: String,
+            asset: String,
+            price: Balance,
+        ) -> Result<(), ContractError> {
+            let mut asset_list = self
+                .assets
+                .get(&game)
+                .ok_or(ContractError::GameWithoutAssets)?;
+            let entry = asset_list
+                .iter_mut()
+                .find(|(name, _)| *name == asset)
+                .ok_or(ContractError::AssetNotFound)?;
+            entry.1 = price;
+            self.assets.insert(&game, &asset_list);
+
+            // Emit event
+            self.env()
+                .emit_event(AssetPriceUpdated { game, asset, price });
+
+            Ok(())
+        }
+
+        /// Replace the signers of a game, or remove them when `signers` is empty
+        fn update_signers(
+            &mut self,
+            game: String,
+            mut signers: Vec<AccountId>,
+            threshold: u32,
+        ) -> Result<(), ContractError> {
+            signers.sort();
+            signers.dedup();
+
+            if signers.is_empty() {
+                self.councils.remove(&game);
+                return Ok(());
+            }
+            if threshold == 0 || threshold as usize > signers.len() {
+                return Err(ContractError::InvalidThreshold);
+            }
+
+            self.councils
+                .insert(&game, &GameCouncil { signers, threshold });
+            Ok(())
+        }
+
+        /// Check `signature` over a 32-byte message hash against `key`
+        fn verify_signature(
+            &self,
+            key: &SignerKey,
+            message: &[u8; 32],
+            signature: &[u8],
+        ) -> Result<(), ContractError> {
+            match key {
+                SignerKey::Sr25519(public) => {
+                    let signature: &[u8; 64] = signature
+                        .try_into()
+                        .map_err(|_| ContractError::InvalidSignature)?;
+                    self.env()
+                        .sr25519_verify(signature, message, public)
+                        .map_err(|_| ContractError::InvalidSignature)
+                }
+                SignerKey::Ecdsa(public) => {
+                    let signature: &[u8; 65] = signature
+                        .try_into()
+                        .map_err(|_| ContractError::InvalidSignature)?;
+                    let recovered = self
+                        .env()
+                        .ecdsa_recover(signature, message)
+                        .map_err(|_| ContractError::InvalidSignature)?;
+                    if recovered != *public {
+                        return Err(ContractError::InvalidSignature);
+                    }
+                    Ok(())
+                }
+            }
+        }
+
+        /// Move units of an asset from `sender` to another player
+        fn gift_asset_from(
+            &mut self,
+            sender: AccountId,
+            receiver: AccountId,
+            asset: String,
+            amount: u64,
+        ) -> Result<(), ContractError> {
+            // Validate amount
+            if amount == 0 {
+                return Err(ContractError::InsufficientAssetCount);
+            }
+
+            // Get sender
+            let mut sender_player = match self.players.get(sender) {
+                Some(p) => p,
+                None => return Err(ContractError::PlayerNotFound),
+            };
+
+            // Check the game owning the asset accepts it from the sender
+            if let Some(game) = self.asset_games.get(&asset) {
+                self.ensure_not_refused(sender, &[game])?;
+            }
+
+            // Check if sender has enough of the asset
+            self.ensure_not_frozen(sender, &asset)?;
+            self.debit_asset(sender, &mut sender_player, &asset, amount)?;
+
+            // Get receiver
+            let mut receiver_player = match self.players.get(receiver) {
+                Some(p) => p,
+                None => return Err(ContractError::PlayerNotFound),
+            };
+
+            // Add to receiver's asset count or insert new
+            self.credit_asset(receiver, &mut receiver_player, &asset, amount)?;
+
+            // Store updates
+            self.players.insert(sender, &sender_player);
+            self.players.insert(receiver, &receiver_player);
+
+            // Let a receiving contract accept or reject the assets
+            self.notify_receiver(sender, receiver, &asset, amount)?;
 
             // Emit Event
             self.env().emit_event(AssetGifted {
                 from: sender,
                 to: receiver,
                 asset,
-                count: amount,
+                count: amount,
+            });
+
+            Ok(())
+        }
+
+        /// Convert units of an asset held by `account_id` along an approved route
+        fn exchange_asset_for(
+            &mut self,
+            account_id: AccountId,
+            from_game: String,
+            asset_give: String,
+            units_give: u64,
+            to_game: String,
+            asset_take: String,
+        ) -> Result<(), ContractError> {
+            // Validate units
+            if units_give == 0 {
+                return Err(ContractError::InsufficientAssetCount);
+            }
+
+            // Look up the route agreed by both game owners
+            let route = (from_game, asset_give, to_game, asset_take);
+            let mut rule = self
+                .conversions
+                .get(&route)
+                .filter(|rule| rule.from_approved && rule.to_approved)
+                .ok_or(ContractError::ConversionNotApproved)?;
+            let (from_game, asset_give, to_game, asset_take) = route;
+            self.ensure_not_refused(account_id, &[from_game.clone(), to_game.clone()])?;
+
+            if !units_give.is_multiple_of(rule.give_units) {
+                return Err(ContractError::InvalidConversionAmount);
+            }
+            let units_take = (units_give / rule.give_units)
+                .checked_mul(rule.take_units)
+                .ok_or(ContractError::Overflow)?;
+
+            // Start a new period if the current one has elapsed
+            let now = self.env().block_number();
+            if now >= rule.period_start.saturating_add(rule.period) {
+                rule.period_start = now;
+                rule.converted = 0;
+            }
+
+            rule.converted = rule
+                .converted
+                .checked_add(units_give)
+                .ok_or(ContractError::Overflow)?;
+            if rule.converted > rule.cap_per_period {
+                return Err(ContractError::ConversionCapExceeded);
+            }
+
+            let mut player = self
+                .players
+                .get(account_id)
+                .ok_or(ContractError::PlayerNotFound)?;
+
+            // Remove the asset_give and add the asset_take
+            self.ensure_not_frozen(account_id, &asset_give)?;
+            self.debit_asset(account_id, &mut player, &asset_give, units_give)?;
+            self.credit_asset(account_id, &mut player, &asset_take, units_take)?;
+
+            self.players.insert(account_id, &player);
+            self.conversions.insert(
+                &(
+                    from_game.clone(),
+                    asset_give.clone(),
+                    to_game.clone(),
+                    asset_take.clone(),
+                ),
+                &rule,
+            );
+
+            // Emit Event
+            self.env().emit_event(AssetExchanged {
+                account: account_id,
+                from_game,
+                from_asset: asset_give,
+                to_game,
+                to_asset: asset_take,
+                from_count: units_give,
+                to_count: units_take,
+            });
+            Ok(())
+        }
+
+        /// Check a signature made with the key behind `account`. A 65-byte signature is ECDSA,
+        /// whose account is the Blake2x256 hash of the compressed public key; anything else
+        /// is checked as sr25519, whose account is the public key itself.
+        fn verify_account_signature(
+            &self,
+            account: AccountId,
+            message: &[u8; 32],
+            signature: &[u8],
+        ) -> Result<(), ContractError> {
+            let account: &[u8; 32] = account.as_ref();
+            if signature.len() == 65 {
+                let signature: &[u8; 65] = signature
+                    .try_into()
+                    .map_err(|_| ContractError::InvalidSignature)?;
+                let public = self
+                    .env()
+                    .ecdsa_recover(signature, message)
+                    .map_err(|_| ContractError::InvalidSignature)?;
+                if self.env().hash_bytes::<ink::env::hash::Blake2x256>(&public) != *account {
+                    return Err(ContractError::InvalidSignature);
+                }
+                return Ok(());
+            }
+
+            self.verify_signature(&SignerKey::Sr25519(*account), message, signature)
+        }
+
+        /// Give a receiving contract the chance to reject incoming assets. Contracts implement
+        /// `AssetReceiver::on_asset_received(operator, from, asset, count, data) -> Result<(), String>`;
+        /// contracts without the message accept everything.
+        fn notify_receiver(
+            &self,
+            from: AccountId,
+            to: AccountId,
+            asset: &String,
+            count: u64,
+        ) -> Result<(), ContractError> {
+            // A contract moving assets to itself already knows about them
+            if !self.env().is_contract(&to) || to == self.env().caller() {
+                return Ok(());
+            }
+
+            let result = build_call::<DefaultEnvironment>()
+                .call(to)
+                .exec_input(
+                    ExecutionInput::new(Selector::new(ink::selector_bytes!(
+                        "AssetReceiver::on_asset_received"
+                    )))
+                    .push_arg(self.env().caller())
+                    .push_arg(from)
+                    .push_arg(asset)
+                    .push_arg(count)
+                    .push_arg(Vec::<u8>::new()),
+                )
+                .returns::<Result<(), String>>()
+                .try_invoke();
+
+            Self::receiver_verdict(result)
+        }
+
+        /// Make sure the caller is an operator of `game`, or its owner while it has no signers
+        fn ensure_game_operator(&self, game: &String) -> Result<(), ContractError> {
+            if self.game_operators.contains((game, self.env().caller())) {
+                return Ok(());
+            }
+            self.ensure_direct_control(game)
+        }
+
+        /// Move an item from a player's holdings into escrow for a match or tournament
+        fn escrow_item(
+            &mut self,
+            account: AccountId,
+            item: &VaultItem,
+        ) -> Result<(), ContractError> {
+            let mut player = self
+                .players
+                .get(account)
+                .ok_or(ContractError::PlayerNotFound)?;
+
+            match item {
+                VaultItem::Balance(amount) => {
+                    player.balance = player
+                        .balance
+                        .checked_sub(*amount)
+                        .ok_or(ContractError::InsufficientBalance)?;
+                }
+                VaultItem::Asset(asset, count) => {
+                    if *count == 0 {
+                        return Err(ContractError::InsufficientAssetCount);
+                    }
+                    self.ensure_not_frozen(account, asset)?;
+                    self.debit_asset(account, &mut player, asset, *count)?;
+                }
+            }
+
+            self.players.insert(account, &player);
+
+            // Keep the player registered until the escrow is closed
+            let open = self
+                .open_escrows
+                .get(account)
+                .unwrap_or_default()
+                .checked_add(1)
+                .ok_or(ContractError::Overflow)?;
+            self.open_escrows.insert(account, &open);
+
+            Ok(())
+        }
+
+        /// Pay `times` an escrowed item out to a player
+        fn release_item(
+            &mut self,
+            account: AccountId,
+            item: &VaultItem,
+            times: u64,
+        ) -> Result<(), ContractError> {
+            let mut player = self
+                .players
+                .get(account)
+                .ok_or(ContractError::PlayerNotFound)?;
+
+            match item {
+                VaultItem::Balance(amount) => {
+                    let amount = amount
+                        .checked_mul(times as Balance)
+                        .ok_or(ContractError::Overflow)?;
+                    player.balance = player
+                        .balance
+                        .checked_add(amount)
+                        .ok_or(ContractError::Overflow)?;
+                }
+                VaultItem::Asset(asset, count) => {
+                    let count = count.checked_mul(times).ok_or(ContractError::Overflow)?;
+                    if count > 0 {
+                        self.credit_asset(account, &mut player, asset, count)?;
+                    }
+                }
+            }
+
+            self.players.insert(account, &player);
+            Ok(())
+        }
+
+        /// Record that a match or tournament holding one of a player's stakes has ended
+        fn close_escrow(&mut self, account: AccountId) {
+            let open = self.open_escrows.get(account).unwrap_or_default();
+            if open > 1 {
+                self.open_escrows.insert(account, &(open - 1));
+            } else {
+                self.open_escrows.remove(account);
+            }
+        }
+
+        /// Pay both stakes of a match to `winner`, or return them when there is none
+        fn settle_match(
+            &mut self,
+            match_id: MatchId,
+            wager: &Match,
+            winner: Option<AccountId>,
+        ) -> Result<(), ContractError> {
+            match winner {
+                Some(winner) => self.release_item(winner, &wager.stake, 2)?,
+                None => {
+                    self.release_item(wager.challenger, &wager.stake, 1)?;
+                    self.release_item(wager.opponent, &wager.stake, 1)?;
+                }
+            }
+            self.close_escrow(wager.challenger);
+            self.close_escrow(wager.opponent);
+            self.matches.remove(match_id);
+
+            // Emit event
+            self.env().emit_event(MatchSettled { match_id, winner });
+
+            Ok(())
+        }
+
+        /// Make sure none of `games` refuses transfers from `account` because it,
+        /// or a game it trusts, flagged the account
+        fn ensure_not_refused(
+            &self,
+            account: AccountId,
+            games: &[String],
+        ) -> Result<(), ContractError> {
+            if !self.flag_counts.contains(account) {
+                return Ok(());
+            }
+            for game in games.iter() {
+                if !self.refuse_flagged.contains(game) {
+                    continue;
+                }
+                if self.flags.contains(&(game.clone(), account)) {
+                    return Err(ContractError::SenderFlagged);
+                }
+                let trust = self.flag_trust.get(game).unwrap_or_default();
+                if trust
+                    .into_iter()
+                    .any(|trusted| self.flags.contains(&(trusted, account)))
+                {
+                    return Err(ContractError::SenderFlagged);
+                }
+            }
+            Ok(())
+        }
+
+        /// Make sure the caller owns `game`, and `game` registered `asset` as recoverable
+        fn ensure_recoverable(&self, game: &String, asset: &str) -> Result<(), ContractError> {
+            self.ensure_game_owner(game)?;
+            self.ensure_game_asset(game, asset)?;
+            if !self
+                .recoverable
+                .contains(&(game.clone(), String::from(asset)))
+            {
+                return Err(ContractError::NotRecoverable);
+            }
+            Ok(())
+        }
+
+        /// Make sure a player's holding of an asset is not frozen
+        fn ensure_not_frozen(&self, account: AccountId, asset: &str) -> Result<(), ContractError> {
+            if self.frozen.contains(&(account, String::from(asset))) {
+                return Err(ContractError::HoldingFrozen);
+            }
+            Ok(())
+        }
+
+        /// Store a new bundle of the game's assets
+        fn add_bundle(
+            &mut self,
+            game: String,
+            contents: Vec<(String, u64)>,
+            price: Balance,
+            max_per_player: u64,
+            available_from: BlockNumber,
+            available_until: Option<BlockNumber>,
+        ) -> Result<BundleId, ContractError> {
+            if contents.is_empty() || contents.iter().any(|(_, count)| *count == 0) {
+                return Err(ContractError::InsufficientAssetCount);
+            }
+            for (asset, _) in contents.iter() {
+                self.asset_price(&game, asset)?;
+            }
+
+            let bundle_id = self.next_bundle_id;
+            self.next_bundle_id = bundle_id.checked_add(1).ok_or(ContractError::Overflow)?;
+
+            let bundle = Bundle {
+                game: game.clone(),
+                contents,
+                price,
+                max_per_player,
+                available_from,
+                available_until,
+            };
+            self.bundles.insert(bundle_id, &bundle);
+
+            // Emit event
+            self.env().emit_event(BundleCreated {
+                bundle_id,
+                game,
+                price,
+            });
+
+            Ok(bundle_id)
+        }
+
+        /// Store a coupon for one of the game's assets
+        fn store_coupon(
+            &mut self,
+            game: String,
+            asset: String,
+            code_hash: Hash,
+            discount: Discount,
+            expires: BlockNumber,
+            uses: u32,
+        ) -> Result<(), ContractError> {
+            self.asset_price(&game, &asset)?;
+            Self::validate_discount(&discount)?;
+            if self.curves.contains(&(game.clone(), asset.clone())) {
+                return Err(ContractError::CurveNotDiscountable);
+            }
+
+            let coupon = Coupon {
+                game: game.clone(),
+                asset: asset.clone(),
+                discount,
+                expires,
+                remaining_uses: uses,
+            };
+            self.coupons.insert(code_hash, &coupon);
+
+            // Emit event
+            self.env().emit_event(CouponAdded {
+                code_hash,
+                game,
+                asset,
+            });
+
+            Ok(())
+        }
+
+        /// Store a sale on one of the game's assets
+        fn add_sale(
+            &mut self,
+            game: String,
+            asset: String,
+            discount: Discount,
+            start: BlockNumber,
+            end: BlockNumber,
+            max_per_player: u64,
+        ) -> Result<SaleId, ContractError> {
+            self.asset_price(&game, &asset)?;
+            Self::validate_discount(&discount)?;
+            if self.curves.contains(&(game.clone(), asset.clone())) {
+                return Err(ContractError::CurveNotDiscountable);
+            }
+
+            if end < start {
+                return Err(ContractError::InvalidDiscount);
+            }
+
+            let sale_id = self.next_sale_id;
+            self.next_sale_id = sale_id.checked_add(1).ok_or(ContractError::Overflow)?;
+
+            let sale = Sale {
+                game: game.clone(),
+                asset: asset.clone(),
+                discount,
+                start,
+                end,
+                max_per_player,
+            };
+            self.sales.insert(sale_id, &sale);
+
+            let key = (game, asset);
+            let mut sale_ids = self.asset_sales.get(&key).unwrap_or_default();
+            sale_ids.push(sale_id);
+            self.asset_sales.insert(&key, &sale_ids);
+
+            // Emit event
+            let (game, asset) = key;
+            self.env().emit_event(SaleScheduled {
+                sale_id,
+                game,
+                asset,
+                discount,
+                start,
+                end,
+            });
+
+            Ok(sale_id)
+        }
+
+        /// Set or clear the price curve of one of the game's assets
+        fn update_price_curve(
+            &mut self,
+            game: String,
+            asset: String,
+            curve: Option<PriceCurve>,
+        ) -> Result<(), ContractError> {
+            self.asset_price(&game, &asset)?;
+
+            let key = (game, asset);
+            if self.supply.get(&key).unwrap_or_default() != 0 {
+                return Err(ContractError::PriceCurveInUse);
+            }
+            if self.price_currencies.contains(&key) {
+                return Err(ContractError::UnsupportedCurrency);
+            }
+            if curve.is_some() && !self.asset_sales.get(&key).unwrap_or_default().is_empty() {
+                return Err(ContractError::CurveNotDiscountable);
+            }
+
+            match curve {
+                Some(PriceCurve::Step { step: 0, .. }) => {
+                    return Err(ContractError::InvalidPriceCurve)
+                }
+                Some(curve) => {
+                    self.curves.insert(&key, &curve);
+                }
+                None => self.curves.remove(&key),
+            }
+
+            // Emit event
+            let (game, asset) = key;
+            self.env()
+                .emit_event(PriceCurveUpdated { game, asset, curve });
+
+            Ok(())
+        }
+
+        /// Set the staking reward rate of one of the game's assets
+        fn update_reward_rate(
+            &mut self,
+            game: String,
+            asset: String,
+            rate: Balance,
+        ) -> Result<(), ContractError> {
+            self.asset_price(&game, &asset)?;
+
+            if !self.game_currencies.contains(&game) {
+                return Err(ContractError::CurrencyNotFound);
+            }
+
+            let key = (game, asset);
+            let mut pool = self.reward_pools.get(&key).unwrap_or_else(|| RewardPool {
+                last_update: self.env().block_number(),
+                ..Default::default()
+            });
+
+            // Account for the blocks elapsed under the old rate
+            self.update_pool(&mut pool)?;
+            pool.rate = rate;
+            self.reward_pools.insert(&key, &pool);
+
+            Ok(())
+        }
+
+        /// Store a new tournament for the game
+        fn open_tournament(
+            &mut self,
+            game: String,
+            terms: TournamentTerms,
+        ) -> Result<TournamentId, ContractError> {
+            // Validate terms
+            if terms.max_entrants == 0 {
+                return Err(ContractError::TournamentFull);
+            }
+            if terms.closes_at < terms.opens_at {
+                return Err(ContractError::TournamentClosed);
+            }
+            let total_bps = terms
+                .prizes
+                .iter()
+                .map(|prize| prize.share_bps as u32)
+                .sum::<u32>();
+            if total_bps != 10_000 {
+                return Err(ContractError::InvalidPrizeSplit);
+            }
+            for prize in terms.prizes.iter() {
+                if let Some((asset, _)) = &prize.trophy {
+                    self.asset_price(&game, asset)?;
+                }
+            }
+
+            let tournament_id = self.next_tournament_id;
+            self.next_tournament_id = tournament_id
+                .checked_add(1)
+                .ok_or(ContractError::Overflow)?;
+
+            let tournament = Tournament {
+                game: game.clone(),
+                terms: terms.clone(),
+                entrants: Vec::new(),
+                status: TournamentStatus::Open,
+            };
+            self.tournaments.insert(tournament_id, &tournament);
+
+            // Emit event
+            self.env().emit_event(TournamentCreated {
+                tournament_id,
+                game,
+                terms,
+            });
+
+            Ok(tournament_id)
+        }
+
+        /// Set the buyback rate of one of the game's assets
+        fn update_buyback(
+            &mut self,
+            game: String,
+            asset: String,
+            percent: u8,
+        ) -> Result<(), ContractError> {
+            self.asset_price(&game, &asset)?;
+
+            if percent > 100 {
+                return Err(ContractError::InvalidBuybackRate);
+            }
+
+            let key = (game, asset);
+            if self.price_currencies.contains(&key) {
+                return Err(ContractError::UnsupportedCurrency);
+            }
+            if percent == 0 {
+                self.buyback_rates.remove(&key);
+            } else {
+                self.buyback_rates.insert(&key, &percent);
+            }
+
+            Ok(())
+        }
+
+        /// Set the currency one of the game's assets is paid in
+        fn update_price_currency(
+            &mut self,
+            game: String,
+            asset: String,
+            currency: PriceCurrency,
+        ) -> Result<(), ContractError> {
+            self.asset_price(&game, &asset)?;
+
+            let key = (game, asset);
+            if currency != PriceCurrency::Store
+                && (self.curves.contains(&key) || self.buyback_rates.contains(&key))
+            {
+                return Err(ContractError::UnsupportedCurrency);
+            }
+
+            if currency == PriceCurrency::Store {
+                self.price_currencies.remove(&key);
+            } else {
+                self.price_currencies.insert(&key, &currency);
+            }
+
+            // Emit event
+            let (game, asset) = key;
+            self.env().emit_event(PriceCurrencyUpdated {
+                game,
+                asset,
+                currency,
+            });
+
+            Ok(())
+        }
+
+        /// Allow or disallow an operator for the game
+        fn update_operator(
+            &mut self,
+            game: String,
+            operator: AccountId,
+            allowed: bool,
+        ) -> Result<(), ContractError> {
+            if allowed {
+                self.game_operators.insert((&game, operator), &());
+            } else {
+                self.game_operators.remove((&game, operator));
+            }
+
+            // Emit event
+            self.env().emit_event(GameOperatorSet {
+                game,
+                operator,
+                allowed,
+            });
+
+            Ok(())
+        }
+
+        /// Set or clear the key that signs the game's vouchers
+        fn update_voucher_signer(
+            &mut self,
+            game: String,
+            signer: Option<SignerKey>,
+        ) -> Result<(), ContractError> {
+            match &signer {
+                Some(key) => {
+                    self.voucher_signers.insert(&game, key);
+                }
+                None => self.voucher_signers.remove(&game),
+            }
+
+            // Emit event
+            self.env().emit_event(VoucherSignerSet { game, signer });
+
+            Ok(())
+        }
+
+        /// Record an achievement of the game as earned by a player
+        fn award_achievement(
+            &mut self,
+            game: String,
+            achievement: String,
+            account: AccountId,
+        ) -> Result<(), ContractError> {
+            let key = (game, achievement);
+            let definition = self
+                .achievements
+                .get(&key)
+                .ok_or(ContractError::AchievementNotFound)?;
+            if !self.players.contains(account) {
+                return Err(ContractError::PlayerNotFound);
+            }
+
+            let mut earned = self.earned_achievements.get(account).unwrap_or_default();
+            if earned.contains(&key) {
+                return Err(ContractError::AlreadyGranted);
+            }
+            let gamer_score = self
+                .gamer_scores
+                .get(account)
+                .unwrap_or_default()
+                .checked_add(definition.points as u64)
+                .ok_or(ContractError::Overflow)?;
+
+            earned.push(key.clone());
+            self.earned_achievements.insert(account, &earned);
+            self.gamer_scores.insert(account, &gamer_score);
+
+            // Emit event
+            let (game, achievement) = key;
+            self.env().emit_event(AchievementGranted {
+                account,
+                game,
+                achievement,
+                gamer_score,
+            });
+
+            Ok(())
+        }
+
+        /// Add `delta` to a player's reputation with the game
+        fn change_reputation(
+            &mut self,
+            game: String,
+            account: AccountId,
+            delta: i32,
+        ) -> Result<(), ContractError> {
+            let key = (game, account);
+            let score = self
+                .reputation
+                .get(&key)
+                .unwrap_or_default()
+                .checked_add(delta)
+                .ok_or(ContractError::Overflow)?;
+
+            self.reputation.insert(&key, &score);
+
+            // Emit event
+            let global_score = self.global_reputation(account);
+            self.env().emit_event(ReputationChanged {
+                game: key.0,
+                account,
+                score,
+                global_score,
+            });
+
+            Ok(())
+        }
+
+        /// Add an asset to the game's catalog
+        fn add_asset(
+            &mut self,
+            game: String,
+            name: String,
+            price: Balance,
+            recoverable: bool,
+        ) -> Result<(), ContractError> {
+            // Make sure no game already uses the name
+            if self.asset_games.contains(&name) {
+                return Err(ContractError::AssetExists);
+            }
+
+            // Fetch current assets for the game
+            let mut asset_list = self.assets.get(&game).unwrap_or_default();
+
+            // Append the new asset
+            asset_list.push((name.clone(), price));
+
+            // Store the updated list back in the mapping
+            self.assets.insert(&game, &asset_list);
+
+            // Record the game that owns the name
+            self.asset_games.insert(&name, &game);
+
+            // Declare whether the asset can be frozen and clawed back
+            if recoverable {
+                self.recoverable.insert(&(game.clone(), name.clone()), &());
+            }
+
+            // Emit event
+            self.env().emit_event(AssetCreated {
+                game,
+                name,
+                price,
+                recoverable,
+            });
+
+            Ok(())
+        }
+
+        /// Declare the game's currency
+        fn issue_currency(
+            &mut self,
+            game: String,
+            name: String,
+            symbol: String,
+            decimals: u8,
+            denominate_prices: bool,
+        ) -> Result<CurrencyId, ContractError> {
+            if self.game_currencies.contains(&game) {
+                return Err(ContractError::CurrencyAlreadyExists);
+            }
+
+            // Balance already held for the store would be paid out in the new unit
+            if denominate_prices && self.store_pricing_in_use(&game) {
+                return Err(ContractError::StorePricingInUse);
+            }
+
+            let currency_id = self.next_currency_id;
+            self.next_currency_id = currency_id.checked_add(1).ok_or(ContractError::Overflow)?;
+
+            let currency = Currency {
+                game: game.clone(),
+                name,
+                symbol: symbol.clone(),
+                decimals,
+                total_supply: 0,
+                prices_denominated: denominate_prices,
+            };
+            self.currencies.insert(currency_id, &currency);
+            self.game_currencies.insert(&game, &currency_id);
+
+            // Emit event
+            self.env().emit_event(CurrencyCreated {
+                currency_id,
+                game,
+                symbol,
+            });
+
+            Ok(currency_id)
+        }
+
+        /// Submit conversion terms on behalf of the source game, the target game, or both,
+        /// as flagged by `(owns_from, owns_to)`
+        fn approve_conversion(
+            &mut self,
+            route: ConversionRoute,
+            give_units: u64,
+            take_units: u64,
+            cap_per_period: u64,
+            period: BlockNumber,
+            (owns_from, owns_to): (bool, bool),
+        ) -> Result<(), ContractError> {
+            let (from_game, from_asset, to_game, to_asset) = &route;
+
+            // Both assets must exist
+            self.asset_price(from_game, from_asset)?;
+            self.asset_price(to_game, to_asset)?;
+
+            if give_units == 0 || take_units == 0 || period == 0 {
+                return Err(ContractError::InvalidConversionAmount);
+            }
+
+            let mut rule = self.conversions.get(&route).unwrap_or_default();
+
+            // New terms invalidate any previous approval
+            if rule.give_units != give_units
+                || rule.take_units != take_units
+                || rule.cap_per_period != cap_per_period
+                || rule.period != period
+            {
+                rule = ConversionRule {
+                    give_units,
+                    take_units,
+                    cap_per_period,
+                    period,
+                    period_start: self.env().block_number(),
+                    ..Default::default()
+                };
+            }
+
+            rule.from_approved |= owns_from;
+            rule.to_approved |= owns_to;
+            self.conversions.insert(&route, &rule);
+
+            // Emit event
+            let (from_game, from_asset, to_game, to_asset) = route;
+            self.env().emit_event(ConversionRuleUpdated {
+                from_game,
+                from_asset,
+                to_game,
+                to_asset,
+                active: rule.from_approved && rule.to_approved,
+            });
+
+            Ok(())
+        }
+
+        /// Returns whether the game holds reserves, curve supply or sales priced in the global
+        /// balance
+        fn store_pricing_in_use(&self, game: &String) -> bool {
+            if self.game_reserves.get(game).unwrap_or_default() != 0 {
+                return true;
+            }
+            self.assets
+                .get(game)
+                .unwrap_or_default()
+                .into_iter()
+                .any(|(asset, _)| {
+                    let key = (game.clone(), asset);
+                    self.supply.get(&key).unwrap_or_default() != 0
+                        || self.curve_reserves.get(&key).unwrap_or_default() != 0
+                        || !self.asset_sales.get(&key).unwrap_or_default().is_empty()
+                })
+        }
+
+        /// Returns whether `account` owns `game` or is one of its operators
+        fn acts_for_game(&self, game: &String, account: AccountId) -> bool {
+            self.game_owners.get(game) == Some(account)
+                || self.game_operators.contains((game, account))
+        }
+
+        /// Count a stake, currency balance or guild membership opening or closing for an account
+        fn track_position(&mut self, account: AccountId, was_open: bool, is_open: bool) {
+            let count = self.open_positions.get(account).unwrap_or_default();
+            let count = match (was_open, is_open) {
+                (false, true) => count.saturating_add(1),
+                (true, false) => count.saturating_sub(1),
+                _ => return,
+            };
+            if count == 0 {
+                self.open_positions.remove(account);
+            } else {
+                self.open_positions.insert(account, &count);
+            }
+        }
+
+        /// Store a stake, dropping it once nothing is staked or owed on it
+        fn save_stake(&mut self, stake_key: &StakeKey, stake: &Stake) {
+            let was_open = self.stakes.contains(stake_key);
+            let is_open = stake.amount > 0 || stake.pending > 0;
+            if is_open {
+                self.stakes.insert(stake_key, stake);
+            } else {
+                self.stakes.remove(stake_key);
+            }
+            self.track_position(stake_key.0, was_open, is_open);
+        }
+
+        /// Store an account's currency balance, dropping it at zero
+        fn set_currency_balance(
+            &mut self,
+            currency_id: CurrencyId,
+            account: AccountId,
+            balance: Balance,
+        ) {
+            let was_open = self.currency_balances.contains((currency_id, account));
+            if balance == 0 {
+                self.currency_balances.remove((currency_id, account));
+            } else {
+                self.currency_balances
+                    .insert((currency_id, account), &balance);
+            }
+            self.track_position(account, was_open, balance > 0);
+        }
+
+        /// Returns how many members can approve a guild's vault withdrawals
+        fn guild_approvers(guild: &Guild) -> usize {
+            guild
+                .members
+                .iter()
+                .filter(|(_, role)| matches!(role, GuildRole::Leader | GuildRole::Officer))
+                .count()
+        }
+
+        /// Map the outcome of an `on_asset_received` call to whether the transfer goes ahead
+        fn receiver_verdict(
+            result: ink::env::Result<ink::MessageResult<Result<(), String>>>,
+        ) -> Result<(), ContractError> {
+            match result {
+                Ok(Ok(Ok(()))) => Ok(()),
+                // The contract has no receiver hook
+                Ok(Err(ink::LangError::CouldNotReadInput)) => Ok(()),
+                _ => Err(ContractError::TransferRejected),
+            }
+        }
+
+        /// Helper function
+        fn parse_asset(asset: &str) -> Option<(String, u64)> {
+            let (name_str, qty_str) = asset.rsplit_once('_')?;
+            let qty = qty_str.parse::<u64>().ok()?;
+            Some((name_str.to_string(), qty))
+        }
+    }
+
+    impl AssetVerse for Assets {
+        #[ink(message)]
+        fn award(
+            &mut self,
+            game: String,
+            player: AccountId,
+            asset: String,
+            count: u64,
+        ) -> Result<(), ContractError> {
+            self.ensure_game_operator(&game)?;
+            self.ensure_game_asset(&game, &asset)?;
+
+            // Validate count
+            if count == 0 {
+                return Err(ContractError::InsufficientAssetCount);
+            }
+
+            let mut holder = self
+                .players
+                .get(player)
+                .ok_or(ContractError::PlayerNotFound)?;
+            let new_count = self.credit_asset(player, &mut holder, &asset, count)?;
+            self.players.insert(player, &holder);
+
+            // Let a receiving contract accept or reject the assets
+            self.notify_receiver(self.env().account_id(), player, &asset, count)?;
+
+            // Emit event
+            self.env().emit_event(AssetModified {
+                account: player,
+                asset,
+                new_count,
+                increased: true,
+            });
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn consume(
+            &mut self,
+            game: String,
+            player: AccountId,
+            asset: String,
+            count: u64,
+        ) -> Result<(), ContractError> {
+            self.ensure_game_operator(&game)?;
+            self.ensure_game_asset(&game, &asset)?;
+
+            // Validate count
+            if count == 0 {
+                return Err(ContractError::InsufficientAssetCount);
+            }
+
+            let mut holder = self
+                .players
+                .get(player)
+                .ok_or(ContractError::PlayerNotFound)?;
+            let new_count = self.debit_asset(player, &mut holder, &asset, count)?;
+            self.players.insert(player, &holder);
+
+            // Emit event
+            self.env().emit_event(AssetModified {
+                account: player,
+                asset,
+                new_count,
+                increased: false,
             });
 
-            Ok(())
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn holdings_of(&self, account: AccountId, game: String) -> Vec<(String, u64)> {
+            let (Some(player), Some(asset_list)) =
+                (self.players.get(account), self.assets.get(&game))
+            else {
+                return Vec::new();
+            };
+
+            player
+                .assets
+                .iter()
+                .filter_map(|holding| Self::parse_asset(holding))
+                .filter(|(name, _)| asset_list.iter().any(|(asset, _)| asset == name))
+                .collect()
+        }
+
+        #[ink(message)]
+        fn balance_of(&self, account: AccountId, asset: String) -> u64 {
+            self.players
+                .get(account)
+                .and_then(|player| {
+                    player
+                        .assets
+                        .iter()
+                        .filter_map(|holding| Self::parse_asset(holding))
+                        .find(|(name, _)| *name == asset)
+                })
+                .map(|(_, count)| count)
+                .unwrap_or_default()
+        }
+
+        #[ink(message)]
+        fn is_game_operator(&self, game: String, operator: AccountId) -> bool {
+            self.game_operators.contains((game, operator))
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        fn accounts() -> ink::env::test::DefaultAccounts<DefaultEnvironment> {
+            ink::env::test::default_accounts::<DefaultEnvironment>()
+        }
+
+        fn set_caller(account: AccountId) {
+            ink::env::test::set_caller::<DefaultEnvironment>(account);
+        }
+
+        #[ink::test]
+        fn asset_names_are_unique_across_games() {
+            let accounts = accounts();
+            let mut contract = Assets::new();
+
+            set_caller(accounts.alice);
+            assert_eq!(
+                contract.register_asset("cod".to_string(), "firegun".to_string(), 100, false),
+                Ok(())
+            );
+            assert_eq!(
+                contract.register_asset("cod".to_string(), "firegun".to_string(), 50, false),
+                Err(ContractError::AssetExists)
+            );
+
+            // Another game cannot take over the name
+            set_caller(accounts.bob);
+            assert_eq!(
+                contract.register_asset("evil".to_string(), "firegun".to_string(), 1, true),
+                Err(ContractError::AssetExists)
+            );
+            assert_eq!(contract.game_owner("evil".to_string()), None);
+        }
+
+        #[ink::test]
+        fn award_and_consume_only_touch_the_games_own_assets() {
+            let accounts = accounts();
+            let mut contract = Assets::new();
+
+            set_caller(accounts.charlie);
+            contract.register_player("charlie".to_string()).unwrap();
+
+            set_caller(accounts.alice);
+            contract
+                .register_asset("cod".to_string(), "firegun".to_string(), 100, false)
+                .unwrap();
+            contract
+                .set_game_operator("cod".to_string(), accounts.django, true)
+                .unwrap();
+
+            set_caller(accounts.bob);
+            contract
+                .register_asset("evil".to_string(), "dagger".to_string(), 1, false)
+                .unwrap();
+
+            // The operator awards and consumes the game's asset
+            set_caller(accounts.django);
+            assert_eq!(
+                contract.award(
+                    "cod".to_string(),
+                    accounts.charlie,
+                    "firegun".to_string(),
+                    3
+                ),
+                Ok(())
+            );
+            assert_eq!(
+                contract.consume(
+                    "cod".to_string(),
+                    accounts.charlie,
+                    "firegun".to_string(),
+                    1
+                ),
+                Ok(())
+            );
+            assert_eq!(
+                contract.balance_of(accounts.charlie, "firegun".to_string()),
+                2
+            );
+
+            // Nobody else can, whatever game they name
+            set_caller(accounts.bob);
+            assert_eq!(
+                contract.award(
+                    "evil".to_string(),
+                    accounts.charlie,
+                    "firegun".to_string(),
+                    5
+                ),
+                Err(ContractError::AssetNotFound)
+            );
+            assert_eq!(
+                contract.consume(
+                    "cod".to_string(),
+                    accounts.charlie,
+                    "firegun".to_string(),
+                    2
+                ),
+                Err(ContractError::NotGameOwner)
+            );
+            assert_eq!(
+                contract.balance_of(accounts.charlie, "firegun".to_string()),
+                2
+            );
+        }
+
+        #[ink::test]
+        fn freeze_and_clawback_need_the_games_own_recoverable_asset() {
+            let accounts = accounts();
+            let mut contract = Assets::new();
+
+            set_caller(accounts.charlie);
+            contract.register_player("charlie".to_string()).unwrap();
+            set_caller(accounts.django);
+            contract.register_player("django".to_string()).unwrap();
+
+            set_caller(accounts.alice);
+            contract
+                .register_asset("cod".to_string(), "firegun".to_string(), 100, true)
+                .unwrap();
+            contract
+                .register_asset("cod".to_string(), "medal".to_string(), 100, false)
+                .unwrap();
+            contract
+                .award(
+                    "cod".to_string(),
+                    accounts.charlie,
+                    "firegun".to_string(),
+                    5,
+                )
+                .unwrap();
+
+            // Another game's owner cannot reach cod's holdings
+            set_caller(accounts.bob);
+            contract
+                .register_asset("evil".to_string(), "dagger".to_string(), 1, true)
+                .unwrap();
+            assert_eq!(
+                contract.freeze_holding(
+                    "evil".to_string(),
+                    accounts.charlie,
+                    "firegun".to_string()
+                ),
+                Err(ContractError::AssetNotFound)
+            );
+            assert_eq!(
+                contract.clawback(
+                    "evil".to_string(),
+                    accounts.charlie,
+                    "firegun".to_string(),
+                    5
+                ),
+                Err(ContractError::AssetNotFound)
+            );
+
+            // Assets not declared recoverable stay out of reach
+            set_caller(accounts.alice);
+            assert_eq!(
+                contract.freeze_holding("cod".to_string(), accounts.charlie, "medal".to_string()),
+                Err(ContractError::NotRecoverable)
+            );
+
+            // A frozen holding cannot be gifted, but can be clawed back
+            contract
+                .freeze_holding("cod".to_string(), accounts.charlie, "firegun".to_string())
+                .unwrap();
+            set_caller(accounts.charlie);
+            assert_eq!(
+                contract.gift_asset(accounts.django, "firegun".to_string(), 1),
+                Err(ContractError::HoldingFrozen)
+            );
+
+            set_caller(accounts.alice);
+            assert_eq!(
+                contract.clawback(
+                    "cod".to_string(),
+                    accounts.charlie,
+                    "firegun".to_string(),
+                    5
+                ),
+                Ok(())
+            );
+            assert_eq!(
+                contract.balance_of(accounts.charlie, "firegun".to_string()),
+                0
+            );
+            assert!(!contract.is_frozen(accounts.charlie, "firegun".to_string()));
+        }
+
+        #[ink::test]
+        fn holders_are_paged_and_removed_in_place() {
+            let accounts = accounts();
+            let mut contract = Assets::new();
+
+            for (account, name) in [
+                (accounts.bob, "bob"),
+                (accounts.charlie, "charlie"),
+                (accounts.django, "django"),
+            ] {
+                set_caller(account);
+                contract.register_player(name.to_string()).unwrap();
+            }
+
+            set_caller(accounts.alice);
+            contract
+                .register_asset("cod".to_string(), "firegun".to_string(), 100, false)
+                .unwrap();
+            for account in [accounts.bob, accounts.charlie, accounts.django] {
+                contract
+                    .award("cod".to_string(), account, "firegun".to_string(), 1)
+                    .unwrap();
+            }
+            assert_eq!(contract.holder_count("firegun".to_string()), 3);
+            assert_eq!(
+                contract.holders_of("firegun".to_string(), 1, 5),
+                vec![accounts.charlie, accounts.django]
+            );
+
+            // The last holder takes the place of the one leaving
+            contract
+                .consume("cod".to_string(), accounts.bob, "firegun".to_string(), 1)
+                .unwrap();
+            assert_eq!(contract.holder_count("firegun".to_string()), 2);
+            assert_eq!(
+                contract.holders_of("firegun".to_string(), 0, 5),
+                vec![accounts.django, accounts.charlie]
+            );
+
+            // Once everyone leaves the list is empty
+            contract
+                .consume("cod".to_string(), accounts.django, "firegun".to_string(), 1)
+                .unwrap();
+            contract
+                .consume(
+                    "cod".to_string(),
+                    accounts.charlie,
+                    "firegun".to_string(),
+                    1,
+                )
+                .unwrap();
+            assert_eq!(contract.holder_count("firegun".to_string()), 0);
+            assert!(contract.holders_of("firegun".to_string(), 0, 5).is_empty());
+        }
+
+        #[ink::test]
+        fn entrants_cannot_deregister_before_a_tournament_ends() {
+            let accounts = accounts();
+            let mut contract = Assets::new();
+
+            set_caller(accounts.alice);
+            contract
+                .register_asset("cod".to_string(), "firegun".to_string(), 100, false)
+                .unwrap();
+            let terms = TournamentTerms {
+                entry_fee: VaultItem::Balance(1_000),
+                max_entrants: 4,
+                opens_at: 0,
+                closes_at: 10,
+                prizes: vec![Prize {
+                    share_bps: 10_000,
+                    trophy: None,
+                }],
+                oracle: None,
+            };
+            let tournament_id = contract
+                .create_tournament("cod".to_string(), terms)
+                .unwrap();
+
+            for (account, name) in [(accounts.bob, "bob"), (accounts.charlie, "charlie")] {
+                set_caller(account);
+                contract.register_player(name.to_string()).unwrap();
+                contract.enter_tournament(tournament_id).unwrap();
+            }
+
+            set_caller(accounts.bob);
+            assert_eq!(
+                contract.deregister_player(true),
+                Err(ContractError::EscrowOutstanding)
+            );
+
+            // Cancelling refunds everyone and releases them
+            set_caller(accounts.alice);
+            assert_eq!(contract.cancel_tournament(tournament_id), Ok(()));
+            assert_eq!(
+                contract
+                    .player(accounts.charlie)
+                    .map(|player| player.balance),
+                Some(1_000_000)
+            );
+
+            set_caller(accounts.bob);
+            assert_eq!(contract.deregister_player(true), Ok(()));
+        }
+
+        #[ink::test]
+        fn flag_policy_only_uses_the_games_own_or_trusted_flags() {
+            let accounts = accounts();
+            let mut contract = Assets::new();
+
+            for (account, name) in [(accounts.charlie, "charlie"), (accounts.django, "django")] {
+                set_caller(account);
+                contract.register_player(name.to_string()).unwrap();
+            }
+
+            set_caller(accounts.alice);
+            contract
+                .register_asset("cod".to_string(), "firegun".to_string(), 100, false)
+                .unwrap();
+            contract.set_flag_policy("cod".to_string(), true).unwrap();
+            contract
+                .award(
+                    "cod".to_string(),
+                    accounts.charlie,
+                    "firegun".to_string(),
+                    2,
+                )
+                .unwrap();
+
+            // A throwaway game flags and rates the player
+            set_caller(accounts.bob);
+            contract
+                .register_asset("evil".to_string(), "dagger".to_string(), 1, false)
+                .unwrap();
+            contract
+                .flag_player("evil".to_string(), accounts.charlie, Hash::default())
+                .unwrap();
+            contract
+                .adjust_reputation("evil".to_string(), accounts.charlie, -1_000)
+                .unwrap();
+
+            set_caller(accounts.charlie);
+            assert_eq!(
+                contract.gift_asset(accounts.django, "firegun".to_string(), 1),
+                Ok(())
+            );
+            assert_eq!(
+                contract.trusted_reputation("cod".to_string(), accounts.charlie),
+                0
+            );
+
+            // Once trusted, the other game's flag and reputation count
+            set_caller(accounts.alice);
+            assert_eq!(
+                contract.set_flag_trust("cod".to_string(), "evil".to_string(), true),
+                Ok(())
+            );
+            assert_eq!(
+                contract.trusted_games("cod".to_string()),
+                vec!["evil".to_string()]
+            );
+            assert_eq!(
+                contract.trusted_reputation("cod".to_string(), accounts.charlie),
+                -1_000
+            );
+
+            set_caller(accounts.charlie);
+            assert_eq!(
+                contract.gift_asset(accounts.django, "firegun".to_string(), 1),
+                Err(ContractError::SenderFlagged)
+            );
+        }
+
+        #[ink::test]
+        fn store_priced_purchases_refuse_transferred_value() {
+            let accounts = accounts();
+            let mut contract = Assets::new();
+
+            set_caller(accounts.alice);
+            contract
+                .register_asset("cod".to_string(), "firegun".to_string(), 100, false)
+                .unwrap();
+            let bundle_id = contract
+                .create_bundle(
+                    "cod".to_string(),
+                    vec![("firegun".to_string(), 2)],
+                    150,
+                    0,
+                    0,
+                    None,
+                )
+                .unwrap();
+
+            set_caller(accounts.bob);
+            contract.register_player("bob".to_string()).unwrap();
+
+            ink::env::test::set_value_transferred::<DefaultEnvironment>(100);
+            assert_eq!(
+                contract.purchase_asset("cod".to_string(), "firegun".to_string(), 1, None),
+                Err(ContractError::IncorrectPayment)
+            );
+            assert_eq!(
+                contract.purchase_bundle(bundle_id, 1),
+                Err(ContractError::IncorrectPayment)
+            );
+
+            ink::env::test::set_value_transferred::<DefaultEnvironment>(0);
+            assert_eq!(
+                contract.purchase_asset("cod".to_string(), "firegun".to_string(), 1, None),
+                Ok(())
+            );
+            assert_eq!(contract.purchase_bundle(bundle_id, 1), Ok(()));
+            assert_eq!(contract.balance_of(accounts.bob, "firegun".to_string()), 3);
+        }
+
+        #[ink::test]
+        fn curve_prices_follow_their_formulas() {
+            let linear = PriceCurve::Linear {
+                base: 100,
+                slope: 10,
+            };
+            assert_eq!(Assets::curve_price(&linear, 0), Ok(100));
+            assert_eq!(Assets::curve_price(&linear, 5), Ok(150));
+            assert_eq!(Assets::curve_cost(&linear, 0, 3), Ok(330));
+
+            let exponential = PriceCurve::Exponential {
+                base: 10_000,
+                growth_bps: 1_000,
+            };
+            let prices: Vec<_> = [0, 1, 2, 3, 10]
+                .into_iter()
+                .map(|supply| Assets::curve_price(&exponential, supply).unwrap())
+                .collect();
+            assert_eq!(prices, vec![10_000, 11_000, 12_100, 13_310, 25_936]);
+
+            let step = PriceCurve::Step {
+                base: 100,
+                step: 3,
+                increment: 50,
+            };
+            assert_eq!(Assets::curve_price(&step, 2), Ok(100));
+            assert_eq!(Assets::curve_price(&step, 3), Ok(150));
+            assert_eq!(Assets::curve_price(&step, 7), Ok(200));
+
+            // Prices that do not fit a balance are refused
+            let steep = PriceCurve::Linear {
+                base: 1,
+                slope: Balance::MAX,
+            };
+            assert_eq!(Assets::curve_price(&steep, 2), Err(ContractError::Overflow));
+            assert_eq!(
+                Assets::curve_price(&exponential, u64::MAX),
+                Err(ContractError::Overflow)
+            );
+        }
+
+        #[ink::test]
+        fn curve_assets_sell_at_list_price_and_buy_back_from_the_reserve() {
+            let accounts = accounts();
+            let mut contract = Assets::new();
+
+            set_caller(accounts.alice);
+            contract
+                .register_asset("cod".to_string(), "firegun".to_string(), 100, false)
+                .unwrap();
+            assert_eq!(
+                contract.set_price_curve(
+                    "cod".to_string(),
+                    "firegun".to_string(),
+                    Some(PriceCurve::Linear {
+                        base: 100,
+                        slope: 10
+                    })
+                ),
+                Ok(())
+            );
+
+            // Curve prices cannot be discounted
+            assert_eq!(
+                contract.add_coupon(
+                    "cod".to_string(),
+                    "firegun".to_string(),
+                    Hash::default(),
+                    Discount::Percent(50),
+                    100,
+                    1
+                ),
+                Err(ContractError::CurveNotDiscountable)
+            );
+            assert_eq!(
+                contract.schedule_sale(
+                    "cod".to_string(),
+                    "firegun".to_string(),
+                    Discount::Percent(50),
+                    0,
+                    100,
+                    0
+                ),
+                Err(ContractError::CurveNotDiscountable)
+            );
+
+            set_caller(accounts.bob);
+            contract.register_player("bob".to_string()).unwrap();
+            assert_eq!(
+                contract.purchase_asset(
+                    "cod".to_string(),
+                    "firegun".to_string(),
+                    1,
+                    Some("HALFOFF".to_string())
+                ),
+                Err(ContractError::CurveNotDiscountable)
+            );
+
+            assert_eq!(
+                contract.quote("cod".to_string(), "firegun".to_string(), 3),
+                Ok(330)
+            );
+            assert_eq!(
+                contract.purchase_asset("cod".to_string(), "firegun".to_string(), 3, None),
+                Ok(())
+            );
+            assert_eq!(
+                contract.total_supply("cod".to_string(), "firegun".to_string()),
+                3
+            );
+
+            // Selling back walks the curve down and pays from the reserve
+            assert_eq!(
+                contract.sell_back_asset("cod".to_string(), "firegun".to_string(), 4),
+                Err(ContractError::InsufficientAssetCount)
+            );
+            assert_eq!(
+                contract.sell_back_asset("cod".to_string(), "firegun".to_string(), 1),
+                Ok(())
+            );
+            assert_eq!(
+                contract.player(accounts.bob).map(|player| player.balance),
+                Some(1_000_000 - 330 + 120)
+            );
+            assert_eq!(
+                contract.quote("cod".to_string(), "firegun".to_string(), 1),
+                Ok(120)
+            );
+
+            // The curve stays until every curve-sold unit is returned
+            set_caller(accounts.alice);
+            assert_eq!(
+                contract.set_price_curve("cod".to_string(), "firegun".to_string(), None),
+                Err(ContractError::PriceCurveInUse)
+            );
         }
 
-        #[ink(message)]
-        pub fn exchange_asset(
-            &mut self,
-            asset_give: String,
-            units_give: i64,
-            asset_take: String,
-            units_take: i64,
-        ) -> Result<(), ContractError> {
-            let account_id = self.env().caller();
+        #[ink::test]
+        fn staking_rewards_follow_rate_changes() {
+            let accounts = accounts();
+            let mut contract = Assets::new();
+            let advance = |blocks: u32| {
+                for _ in 0..blocks {
+                    ink::env::test::advance_block::<DefaultEnvironment>();
+                }
+            };
 
-            // Get asset prices from all games
-            let mut price_give: Option<Balance> = None;
-            let mut price_take: Option<Balance> = None;
+            set_caller(accounts.alice);
+            contract
+                .register_asset("cod".to_string(), "firegun".to_string(), 100, false)
+                .unwrap();
 
-            for game in &self.games {
-                if let Some(list) = self.assets.get(game) {
-                    for (name, price) in list {
-                        if name == asset_give {
-                            price_give = Some(price);
-                        }
-                        if name == asset_take {
-                            price_take = Some(price);
-                        }
-                    }
-                }
+            // Rewards are paid in the game's currency
+            assert_eq!(
+                contract.set_reward_rate("cod".to_string(), "firegun".to_string(), 10),
+                Err(ContractError::CurrencyNotFound)
+            );
+            let currency_id = contract
+                .create_currency(
+                    "cod".to_string(),
+                    "Gold".to_string(),
+                    "GLD".to_string(),
+                    0,
+                    false,
+                )
+                .unwrap();
+
+            for (account, name) in [(accounts.bob, "bob"), (accounts.charlie, "charlie")] {
+                set_caller(account);
+                contract.register_player(name.to_string()).unwrap();
+                assert_eq!(
+                    contract.stake_asset("cod".to_string(), "firegun".to_string(), 1),
+                    Err(ContractError::StakingDisabled)
+                );
+                set_caller(accounts.alice);
+                contract
+                    .award("cod".to_string(), account, "firegun".to_string(), 5)
+                    .unwrap();
             }
 
-            let price_give = price_give.ok_or(ContractError::AssetNotFound)?;
-            let price_take = price_take.ok_or(ContractError::AssetNotFound)?;
+            set_caller(accounts.alice);
+            contract
+                .set_reward_rate("cod".to_string(), "firegun".to_string(), 10)
+                .unwrap();
+            set_caller(accounts.bob);
+            contract
+                .stake_asset("cod".to_string(), "firegun".to_string(), 2)
+                .unwrap();
+
+            // Blocks under the old rate are kept when the rate changes
+            advance(3);
+            assert_eq!(
+                contract.pending_rewards(accounts.bob, "cod".to_string(), "firegun".to_string()),
+                Ok(60)
+            );
+            set_caller(accounts.alice);
+            contract
+                .set_reward_rate("cod".to_string(), "firegun".to_string(), 100)
+                .unwrap();
+            advance(2);
+            assert_eq!(
+                contract.pending_rewards(accounts.bob, "cod".to_string(), "firegun".to_string()),
+                Ok(460)
+            );
 
-            let total_give = price_give * (units_give as u128);
-            let total_take = price_take * (units_take as u128);
+            // A late staker only earns from when they joined
+            set_caller(accounts.charlie);
+            contract
+                .stake_asset("cod".to_string(), "firegun".to_string(), 2)
+                .unwrap();
+            advance(1);
+            assert_eq!(
+                contract.pending_rewards(
+                    accounts.charlie,
+                    "cod".to_string(),
+                    "firegun".to_string()
+                ),
+                Ok(200)
+            );
 
-            if total_give < total_take {
-                return Err(ContractError::InsufficientBalance);
+            set_caller(accounts.bob);
+            assert_eq!(
+                contract.claim_rewards("cod".to_string(), "firegun".to_string()),
+                Ok(660)
+            );
+            assert_eq!(contract.currency_balance_of(currency_id, accounts.bob), 660);
+            assert_eq!(
+                contract.pending_rewards(accounts.bob, "cod".to_string(), "firegun".to_string()),
+                Ok(0)
+            );
+            assert_eq!(
+                contract.unstake_asset("cod".to_string(), "firegun".to_string(), 3),
+                Err(ContractError::InsufficientStake)
+            );
+            assert_eq!(
+                contract.unstake_asset("cod".to_string(), "firegun".to_string(), 2),
+                Ok(())
+            );
+            assert_eq!(contract.balance_of(accounts.bob, "firegun".to_string()), 5);
+        }
+
+        fn blake2<T: scale::Encode>(input: &T) -> Hash {
+            let mut output = [0u8; 32];
+            ink::env::hash_encoded::<ink::env::hash::Blake2x256, _>(input, &mut output);
+            Hash::from(output)
+        }
+
+        fn merkle_node(a: Hash, b: Hash) -> Hash {
+            if a <= b {
+                blake2(&(a, b))
+            } else {
+                blake2(&(b, a))
             }
+        }
 
-            let mut player = self
-                .players
-                .get(&account_id)
-                .ok_or(ContractError::PlayerNotFound)?;
+        #[ink::test]
+        fn airdrop_claims_need_a_valid_proof_once_before_expiry() {
+            let accounts = accounts();
+            let mut contract = Assets::new();
 
-            let mut found_give = false;
+            set_caller(accounts.alice);
+            contract.register_player("alice".to_string()).unwrap();
+            contract
+                .register_asset("cod".to_string(), "firegun".to_string(), 100, false)
+                .unwrap();
+            contract
+                .award("cod".to_string(), accounts.alice, "firegun".to_string(), 10)
+                .unwrap();
 
-            for i in 0..player.assets.len() {
-                if let Some((name, qty)) = Self::parse_asset(&player.assets[i]) {
-                    if name == asset_give {
-                        if qty < units_give {
-                            return Err(ContractError::InsufficientAssetCount);
-                        }
+            // Three leaves: bob and charlie under one node, django beside it
+            let bob = blake2(&(0u32, accounts.bob, 3u64));
+            let charlie = blake2(&(1u32, accounts.charlie, 4u64));
+            let django = blake2(&(2u32, accounts.django, 2u64));
+            let left = merkle_node(bob, charlie);
+            let root = merkle_node(left, django);
 
-                        // Adjust or remove the asset_give
-                        if qty == units_give {
-                            player.assets.remove(i);
-                        } else {
-                            let mut new_str = asset_give.clone();
-                            new_str.push('_');
-                            new_str.push_str(&(qty - units_give).to_string());
-                            player.assets[i] = new_str;
-                        }
+            let airdrop_id = contract
+                .create_airdrop("cod".to_string(), "firegun".to_string(), root, 10, 5)
+                .unwrap();
+            assert_eq!(
+                contract.balance_of(accounts.alice, "firegun".to_string()),
+                0
+            );
 
-                        found_give = true;
-                        break;
-                    }
-                }
+            for (account, name) in [
+                (accounts.bob, "bob"),
+                (accounts.charlie, "charlie"),
+                (accounts.django, "django"),
+            ] {
+                set_caller(account);
+                contract.register_player(name.to_string()).unwrap();
             }
 
-            if !found_give {
-                return Err(ContractError::AssetNotFound);
+            set_caller(accounts.bob);
+            assert_eq!(
+                contract.claim_airdrop(airdrop_id, 0, 3, vec![charlie, django]),
+                Ok(())
+            );
+            assert_eq!(
+                contract.claim_airdrop(airdrop_id, 0, 3, vec![charlie, django]),
+                Err(ContractError::AlreadyClaimed)
+            );
+
+            // Wrong counts, indices or callers do not reach the root
+            set_caller(accounts.charlie);
+            assert_eq!(
+                contract.claim_airdrop(airdrop_id, 1, 5, vec![bob, django]),
+                Err(ContractError::InvalidProof)
+            );
+            assert_eq!(
+                contract.claim_airdrop(airdrop_id, 2, 2, vec![left]),
+                Err(ContractError::InvalidProof)
+            );
+            assert_eq!(
+                contract.claim_airdrop(airdrop_id, 1, 4, vec![bob, django]),
+                Ok(())
+            );
+            assert!(contract.is_airdrop_claimed(airdrop_id, 0));
+            assert!(contract.is_airdrop_claimed(airdrop_id, 1));
+            assert!(!contract.is_airdrop_claimed(airdrop_id, 2));
+
+            set_caller(accounts.alice);
+            assert_eq!(
+                contract.reclaim_airdrop(airdrop_id),
+                Err(ContractError::AirdropNotExpired)
+            );
+
+            for _ in 0..6 {
+                ink::env::test::advance_block::<DefaultEnvironment>();
             }
+            set_caller(accounts.django);
+            assert_eq!(
+                contract.claim_airdrop(airdrop_id, 2, 2, vec![left]),
+                Err(ContractError::AirdropExpired)
+            );
 
-            // Add or increase the asset_take
-            let mut found_take = false;
+            // The owner takes back what was left unclaimed
+            set_caller(accounts.alice);
+            assert_eq!(contract.reclaim_airdrop(airdrop_id), Ok(()));
+            assert_eq!(
+                contract.balance_of(accounts.alice, "firegun".to_string()),
+                3
+            );
+            assert_eq!(contract.balance_of(accounts.bob, "firegun".to_string()), 3);
+            assert_eq!(
+                contract.balance_of(accounts.charlie, "firegun".to_string()),
+                4
+            );
+            assert_eq!(
+                contract.reclaim_airdrop(airdrop_id),
+                Err(ContractError::InsufficientAssetCount)
+            );
+        }
 
-            for i in 0..player.assets.len() {
-                if let Some((name, qty)) = Self::parse_asset(&player.assets[i]) {
-                    if name == asset_take {
-                        let mut new_str = asset_take.clone();
-                        new_str.push('_');
-                        new_str.push_str(&(qty + units_take).to_string());
-                        player.assets[i] = new_str;
-                        found_take = true;
-                        break;
-                    }
+        fn balance(contract: &Assets, account: AccountId) -> Option<Balance> {
+            contract.player(account).map(|player| player.balance)
+        }
+
+        #[ink::test]
+        fn matches_pay_the_winner_and_refund_draws() {
+            let accounts = accounts();
+            let mut contract = Assets::new();
+            let salt = Hash::from([7; 32]);
+
+            for (account, name) in [(accounts.bob, "bob"), (accounts.charlie, "charlie")] {
+                set_caller(account);
+                contract.register_player(name.to_string()).unwrap();
+            }
+
+            set_caller(accounts.bob);
+            let match_id = contract
+                .create_match(
+                    accounts.charlie,
+                    VaultItem::Balance(1_000),
+                    blake2(&(Hand::Rock, salt, accounts.bob)),
+                    10,
+                )
+                .unwrap();
+            assert_eq!(
+                contract.reveal_move(match_id, Hand::Rock, salt),
+                Err(ContractError::InvalidMatchPhase)
+            );
+
+            set_caller(accounts.django);
+            assert_eq!(
+                contract.join_match(match_id, Hash::default()),
+                Err(ContractError::NotMatchPlayer)
+            );
+
+            set_caller(accounts.charlie);
+            contract
+                .join_match(match_id, blake2(&(Hand::Scissors, salt, accounts.charlie)))
+                .unwrap();
+            assert_eq!(
+                contract.deregister_player(true),
+                Err(ContractError::EscrowOutstanding)
+            );
+
+            // A move other than the committed one is refused
+            assert_eq!(
+                contract.reveal_move(match_id, Hand::Rock, salt),
+                Err(ContractError::InvalidReveal)
+            );
+            assert_eq!(contract.reveal_move(match_id, Hand::Scissors, salt), Ok(()));
+            set_caller(accounts.bob);
+            assert_eq!(contract.reveal_move(match_id, Hand::Rock, salt), Ok(()));
+
+            // Rock beats scissors
+            assert_eq!(contract.wager_match(match_id), None);
+            assert_eq!(balance(&contract, accounts.bob), Some(1_001_000));
+            assert_eq!(balance(&contract, accounts.charlie), Some(999_000));
+
+            // A draw returns both stakes
+            let match_id = contract
+                .create_match(
+                    accounts.charlie,
+                    VaultItem::Balance(1_000),
+                    blake2(&(Hand::Paper, salt, accounts.bob)),
+                    10,
+                )
+                .unwrap();
+            set_caller(accounts.charlie);
+            contract
+                .join_match(match_id, blake2(&(Hand::Paper, salt, accounts.charlie)))
+                .unwrap();
+            contract.reveal_move(match_id, Hand::Paper, salt).unwrap();
+            set_caller(accounts.bob);
+            contract.reveal_move(match_id, Hand::Paper, salt).unwrap();
+            assert_eq!(balance(&contract, accounts.bob), Some(1_001_000));
+            assert_eq!(balance(&contract, accounts.charlie), Some(999_000));
+
+            set_caller(accounts.charlie);
+            assert_eq!(contract.deregister_player(true), Ok(()));
+        }
+
+        #[ink::test]
+        fn matches_time_out_to_refunds_and_forfeits() {
+            let accounts = accounts();
+            let mut contract = Assets::new();
+            let salt = Hash::from([7; 32]);
+            let advance = |blocks: u32| {
+                for _ in 0..blocks {
+                    ink::env::test::advance_block::<DefaultEnvironment>();
                 }
+            };
+
+            for (account, name) in [(accounts.bob, "bob"), (accounts.charlie, "charlie")] {
+                set_caller(account);
+                contract.register_player(name.to_string()).unwrap();
+            }
+
+            // Matches need something at stake and time to play
+            set_caller(accounts.bob);
+            let commitment = blake2(&(Hand::Rock, salt, accounts.bob));
+            for (stake, timeout) in [
+                (VaultItem::Balance(0), 2),
+                (VaultItem::Asset("firegun".to_string(), 0), 2),
+                (VaultItem::Balance(1_000), 0),
+            ] {
+                assert_eq!(
+                    contract.create_match(accounts.charlie, stake, commitment, timeout),
+                    Err(ContractError::InvalidMatchTerms)
+                );
+            }
+
+            // An unjoined challenge is refunded once the join window closes
+            let match_id = contract
+                .create_match(
+                    accounts.charlie,
+                    VaultItem::Balance(1_000),
+                    blake2(&(Hand::Rock, salt, accounts.bob)),
+                    2,
+                )
+                .unwrap();
+            assert_eq!(balance(&contract, accounts.bob), Some(999_000));
+            assert_eq!(
+                contract.claim_match_timeout(match_id),
+                Err(ContractError::DeadlineNotReached)
+            );
+            advance(3);
+            set_caller(accounts.charlie);
+            assert_eq!(
+                contract.join_match(match_id, Hash::default()),
+                Err(ContractError::DeadlinePassed)
+            );
+            set_caller(accounts.bob);
+            assert_eq!(contract.claim_match_timeout(match_id), Ok(()));
+            assert_eq!(balance(&contract, accounts.bob), Some(1_000_000));
+
+            // A player who does not reveal in time forfeits
+            let match_id = contract
+                .create_match(
+                    accounts.charlie,
+                    VaultItem::Balance(1_000),
+                    blake2(&(Hand::Rock, salt, accounts.bob)),
+                    2,
+                )
+                .unwrap();
+            set_caller(accounts.charlie);
+            contract
+                .join_match(match_id, blake2(&(Hand::Paper, salt, accounts.charlie)))
+                .unwrap();
+            contract.reveal_move(match_id, Hand::Paper, salt).unwrap();
+            advance(3);
+            set_caller(accounts.bob);
+            assert_eq!(
+                contract.reveal_move(match_id, Hand::Rock, salt),
+                Err(ContractError::DeadlinePassed)
+            );
+            assert_eq!(contract.claim_match_timeout(match_id), Ok(()));
+            assert_eq!(balance(&contract, accounts.bob), Some(999_000));
+            assert_eq!(balance(&contract, accounts.charlie), Some(1_001_000));
+            assert_eq!(
+                contract.claim_match_timeout(match_id),
+                Err(ContractError::MatchNotFound)
+            );
+        }
+
+        #[ink::test]
+        fn game_signers_need_a_threshold_of_approvals() {
+            let accounts = accounts();
+            let mut contract = Assets::new();
+            let cod = || "cod".to_string();
+            let firegun = || "firegun".to_string();
+
+            set_caller(accounts.alice);
+            contract
+                .register_asset(cod(), firegun(), 100, false)
+                .unwrap();
+            let signers = vec![accounts.bob, accounts.charlie, accounts.django];
+            assert_eq!(
+                contract.set_game_signers(cod(), signers.clone(), 4),
+                Err(ContractError::InvalidThreshold)
+            );
+            assert_eq!(
+                contract.set_game_signers(cod(), signers.clone(), 0),
+                Err(ContractError::InvalidThreshold)
+            );
+            assert_eq!(contract.set_game_signers(cod(), signers, 2), Ok(()));
+
+            // The owner alone can no longer act
+            assert_eq!(
+                contract.set_asset_price(cod(), firegun(), 1),
+                Err(ContractError::MultisigRequired)
+            );
+            assert_eq!(
+                contract.propose_game_action(
+                    cod(),
+                    GameAction::SetPrice {
+                        asset: firegun(),
+                        price: 1
+                    },
+                    10
+                ),
+                Err(ContractError::NotSigner)
+            );
+
+            set_caller(accounts.bob);
+            let proposal_id = contract
+                .propose_game_action(
+                    cod(),
+                    GameAction::SetPrice {
+                        asset: firegun(),
+                        price: 250,
+                    },
+                    10,
+                )
+                .unwrap();
+            assert_eq!(
+                contract.approve_game_action(proposal_id),
+                Err(ContractError::AlreadyApproved)
+            );
+            assert_eq!(
+                contract.execute_game_action(proposal_id),
+                Err(ContractError::ThresholdNotReached)
+            );
+
+            set_caller(accounts.charlie);
+            assert_eq!(contract.approve_game_action(proposal_id), Ok(()));
+            assert_eq!(contract.execute_game_action(proposal_id), Ok(()));
+            assert_eq!(contract.asset_price(&cod(), &firegun()), Ok(250));
+            assert_eq!(
+                contract.execute_game_action(proposal_id),
+                Err(ContractError::ProposalNotFound)
+            );
+
+            // Approvals arriving after expiry are refused
+            let removal = GameAction::SetSigners {
+                signers: Vec::new(),
+                threshold: 0,
+            };
+            let now = ink::env::block_number::<DefaultEnvironment>();
+            let proposal_id = contract
+                .propose_game_action(cod(), removal.clone(), now + 1)
+                .unwrap();
+            for _ in 0..2 {
+                ink::env::test::advance_block::<DefaultEnvironment>();
             }
+            set_caller(accounts.django);
+            assert_eq!(
+                contract.approve_game_action(proposal_id),
+                Err(ContractError::ProposalExpired)
+            );
+
+            // Removing every signer returns control to the owner
+            let proposal_id = contract
+                .propose_game_action(cod(), removal, now + 10)
+                .unwrap();
+            set_caller(accounts.bob);
+            contract.approve_game_action(proposal_id).unwrap();
+            assert_eq!(contract.execute_game_action(proposal_id), Ok(()));
+            assert_eq!(contract.game_signers(cod()), None);
+
+            set_caller(accounts.alice);
+            assert_eq!(contract.set_asset_price(cod(), firegun(), 300), Ok(()));
+            assert_eq!(contract.asset_price(&cod(), &firegun()), Ok(300));
+        }
+
+        #[ink::test]
+        fn owner_actions_without_a_message_of_their_own_run_through_proposals() {
+            let accounts = accounts();
+            let mut contract = Assets::new();
+            let cod = || "cod".to_string();
 
-            if !found_take {
-                let mut new_str = asset_take.clone();
-                new_str.push('_');
-                new_str.push_str(&units_take.to_string());
-                player.assets.push(new_str);
+            set_caller(accounts.django);
+            contract.register_player("django".to_string()).unwrap();
+
+            set_caller(accounts.alice);
+            contract
+                .register_asset(cod(), "firegun".to_string(), 100, false)
+                .unwrap();
+            contract
+                .define_achievement(
+                    cod(),
+                    "first-blood".to_string(),
+                    "First blood".to_string(),
+                    "Win a match".to_string(),
+                    10,
+                )
+                .unwrap();
+            contract
+                .set_game_signers(cod(), vec![accounts.bob, accounts.charlie], 2)
+                .unwrap();
+
+            // The owner alone is refused
+            assert_eq!(
+                contract.set_game_operator(cod(), accounts.eve, true),
+                Err(ContractError::MultisigRequired)
+            );
+            assert_eq!(
+                contract.set_voucher_signer(cod(), Some(SignerKey::Sr25519([1; 32]))),
+                Err(ContractError::MultisigRequired)
+            );
+            assert_eq!(
+                contract.grant_achievement(cod(), "first-blood".to_string(), accounts.django),
+                Err(ContractError::MultisigRequired)
+            );
+            assert_eq!(
+                contract.adjust_reputation(cod(), accounts.django, 5),
+                Err(ContractError::MultisigRequired)
+            );
+
+            let actions = vec![
+                GameAction::SetOperator {
+                    operator: accounts.eve,
+                    allowed: true,
+                },
+                GameAction::SetVoucherSigner {
+                    signer: Some(SignerKey::Sr25519([1; 32])),
+                },
+                GameAction::GrantAchievement {
+                    achievement: "first-blood".to_string(),
+                    account: accounts.django,
+                },
+                GameAction::AdjustReputation {
+                    account: accounts.django,
+                    delta: 5,
+                },
+            ];
+            for action in actions {
+                set_caller(accounts.bob);
+                let proposal_id = contract.propose_game_action(cod(), action, 10).unwrap();
+                set_caller(accounts.charlie);
+                contract.approve_game_action(proposal_id).unwrap();
+                assert_eq!(contract.execute_game_action(proposal_id), Ok(()));
             }
 
-            // Refund balance if value_give > value_take
-            if total_give > total_take {
-                player.balance += total_give - total_take;
+            assert!(contract.is_game_operator(cod(), accounts.eve));
+            assert_eq!(
+                contract.voucher_signer(cod()),
+                Some(SignerKey::Sr25519([1; 32]))
+            );
+            assert_eq!(contract.gamer_score(accounts.django), 10);
+            assert_eq!(contract.reputation(cod(), accounts.django), 5);
+
+            // Operators keep acting on their own
+            set_caller(accounts.eve);
+            assert_eq!(
+                contract.adjust_reputation(cod(), accounts.django, -2),
+                Ok(())
+            );
+            assert_eq!(contract.reputation(cod(), accounts.django), 3);
+        }
+
+        #[ink::test]
+        fn conversions_currencies_and_new_assets_need_the_signers() {
+            let accounts = accounts();
+            let mut contract = Assets::new();
+            let cod = || "cod".to_string();
+            let route = || {
+                (
+                    "cod".to_string(),
+                    "firegun".to_string(),
+                    "fifa".to_string(),
+                    "ball".to_string(),
+                )
+            };
+
+            set_caller(accounts.django);
+            contract
+                .register_asset("fifa".to_string(), "ball".to_string(), 10, false)
+                .unwrap();
+
+            set_caller(accounts.alice);
+            contract
+                .register_asset(cod(), "firegun".to_string(), 100, false)
+                .unwrap();
+            contract
+                .set_game_signers(cod(), vec![accounts.bob, accounts.charlie], 2)
+                .unwrap();
+
+            // The owner alone is refused
+            assert_eq!(
+                contract.propose_conversion(route(), 1, 100, 0, 10),
+                Err(ContractError::MultisigRequired)
+            );
+            assert_eq!(
+                contract.create_currency(cod(), "Gold".to_string(), "GLD".to_string(), 0, true),
+                Err(ContractError::MultisigRequired)
+            );
+            assert_eq!(
+                contract.register_asset(cod(), "shield".to_string(), 0, false),
+                Err(ContractError::MultisigRequired)
+            );
+            assert_eq!(
+                contract.register_asset("chess".to_string(), "rook".to_string(), 5, false),
+                Ok(())
+            );
+
+            let actions = vec![
+                GameAction::ProposeConversion {
+                    route: route(),
+                    give_units: 1,
+                    take_units: 100,
+                    cap_per_period: 0,
+                    period: 10,
+                },
+                GameAction::CreateCurrency {
+                    name: "Gold".to_string(),
+                    symbol: "GLD".to_string(),
+                    decimals: 0,
+                    denominate_prices: false,
+                },
+                GameAction::RegisterAsset {
+                    name: "shield".to_string(),
+                    price: 50,
+                    recoverable: false,
+                },
+            ];
+            for action in actions {
+                set_caller(accounts.bob);
+                let proposal_id = contract.propose_game_action(cod(), action, 10).unwrap();
+                set_caller(accounts.charlie);
+                contract.approve_game_action(proposal_id).unwrap();
+                assert_eq!(contract.execute_game_action(proposal_id), Ok(()));
             }
 
-            self.players.insert(account_id, &player);
+            // Only the council's side of the route is approved
+            let rule = contract.conversion_rule(route()).unwrap();
+            assert!(rule.from_approved);
+            assert!(!rule.to_approved);
+            assert_eq!(
+                contract.currency(0).map(|currency| currency.symbol),
+                Some("GLD".to_string())
+            );
+            assert_eq!(
+                contract.assets(cod()),
+                Some(vec![
+                    ("firegun".to_string(), 100),
+                    ("shield".to_string(), 50)
+                ])
+            );
 
-            // Emit Event
-            self.env().emit_event(AssetExchanged {
-                account: account_id,
-                from_asset: asset_give,
-                to_asset: asset_take,
-                from_count: units_give,
-                to_count: units_take,
-                refund: total_give - total_take,
-            });
-            Ok(())
+            // The owner of the other game completes the route on its own
+            set_caller(accounts.django);
+            assert_eq!(contract.propose_conversion(route(), 1, 100, 0, 10), Ok(()));
+            let rule = contract.conversion_rule(route()).unwrap();
+            assert!(rule.from_approved && rule.to_approved);
         }
 
-        /// Modify assets owned by a player
-        #[ink(message)]
-        pub fn modify_asset(
-            &mut self,
-            asset: String,
-            count: i64,
-            increase: bool,
-        ) -> Result<(), ContractError> {
-            // Get contract caller
-            let caller = self.env().caller();
-            let mut player = self
-                .players
-                .get(caller)
-                .ok_or(ContractError::PlayerNotFound)?;
+        #[ink::test]
+        fn prices_cannot_switch_currency_over_existing_reserves_or_sales() {
+            let accounts = accounts();
+            let mut contract = Assets::new();
+            let create = |contract: &mut Assets, game: &str, denominate_prices: bool| {
+                contract.create_currency(
+                    game.to_string(),
+                    "Gold".to_string(),
+                    "GLD".to_string(),
+                    0,
+                    denominate_prices,
+                )
+            };
 
-            let mut found = false;
-            let mut n_count = 0;
+            set_caller(accounts.alice);
+            contract.register_player("alice".to_string()).unwrap();
+            contract
+                .register_asset("cod".to_string(), "firegun".to_string(), 100, false)
+                .unwrap();
+            contract
+                .register_asset("fifa".to_string(), "ball".to_string(), 10, false)
+                .unwrap();
 
-            for i in 0..player.assets.len() {
-                if let Some(pos) = player.assets[i].rfind('_') {
-                    let (name, qty_str) = player.assets[i].split_at(pos);
-                    let qty_str = &qty_str[1..]; // remove underscore
-                    if name == asset {
-                        let existing_count = qty_str
-                            .parse::<i64>()
-                            .map_err(|_| ContractError::InsufficientAssetCount)?;
+            // A funded buyback reserve
+            contract.fund_reserve("cod".to_string(), 500).unwrap();
+            assert_eq!(
+                create(&mut contract, "cod", true),
+                Err(ContractError::StorePricingInUse)
+            );
 
-                        let new_count = if increase {
-                            existing_count + count
-                        } else {
-                            existing_count - count
-                        };
+            // A scheduled sale
+            contract
+                .schedule_sale(
+                    "fifa".to_string(),
+                    "ball".to_string(),
+                    Discount::Percent(10),
+                    0,
+                    100,
+                    0,
+                )
+                .unwrap();
+            assert_eq!(
+                create(&mut contract, "fifa", true),
+                Err(ContractError::StorePricingInUse)
+            );
 
-                        n_count = new_count;
+            // A currency that leaves prices alone is always allowed
+            assert_eq!(create(&mut contract, "cod", false), Ok(0));
+        }
 
-                        if new_count < 0 {
-                            return Err(ContractError::InsufficientAssetCount);
-                        } else if new_count == 0 {
-                            player.assets.remove(i);
-                        } else {
-                            let mut new_string = String::from(name);
-                            new_string.push('_');
-                            new_string.push_str(&new_count.to_string());
-                            player.assets[i] = new_string;
-                        }
+        #[ink::test]
+        fn only_curve_bought_units_can_be_sold_back() {
+            let accounts = accounts();
+            let mut contract = Assets::new();
+            let (cod, firegun) = (|| "cod".to_string(), || "firegun".to_string());
 
-                        found = true;
-                        break;
-                    }
-                }
+            set_caller(accounts.alice);
+            contract
+                .register_asset(cod(), firegun(), 100, false)
+                .unwrap();
+            contract
+                .set_price_curve(
+                    cod(),
+                    firegun(),
+                    Some(PriceCurve::Linear {
+                        base: 100,
+                        slope: 10,
+                    }),
+                )
+                .unwrap();
+
+            for (account, name) in [(accounts.bob, "bob"), (accounts.charlie, "charlie")] {
+                set_caller(account);
+                contract.register_player(name.to_string()).unwrap();
+                contract.purchase_asset(cod(), firegun(), 1, None).unwrap();
             }
 
-            if !found {
-                if increase {
-                    let mut new_string = String::from(asset.clone());
-                    new_string.push('_');
-                    new_string.push_str(&count.to_string());
-                    player.assets.push(new_string);
-                } else {
-                    return Err(ContractError::InsufficientAssetCount);
+            // Awarded units never paid into the reserve
+            set_caller(accounts.alice);
+            contract.award(cod(), accounts.bob, firegun(), 5).unwrap();
+            set_caller(accounts.bob);
+            assert_eq!(contract.curve_holding(accounts.bob, firegun()), 1);
+            assert_eq!(
+                contract.sell_back_asset(cod(), firegun(), 2),
+                Err(ContractError::NotBoughtOnCurve)
+            );
+
+            // Gifted units lose their curve credit
+            set_caller(accounts.charlie);
+            contract.gift_asset(accounts.bob, firegun(), 1).unwrap();
+            assert_eq!(contract.curve_holding(accounts.charlie, firegun()), 0);
+            assert_eq!(contract.curve_holding(accounts.bob, firegun()), 1);
+
+            set_caller(accounts.bob);
+            assert_eq!(contract.sell_back_asset(cod(), firegun(), 1), Ok(()));
+            assert_eq!(contract.curve_holding(accounts.bob, firegun()), 0);
+            assert_eq!(contract.total_supply(cod(), firegun()), 1);
+            assert_eq!(
+                contract.sell_back_asset(cod(), firegun(), 1),
+                Err(ContractError::NotBoughtOnCurve)
+            );
+        }
+
+        #[ink::test]
+        fn global_reputation_only_counts_games_the_admin_lists() {
+            let accounts = accounts();
+            set_caller(accounts.alice);
+            let mut contract = Assets::new();
+
+            contract
+                .register_asset("cod".to_string(), "firegun".to_string(), 100, false)
+                .unwrap();
+            contract
+                .adjust_reputation("cod".to_string(), accounts.charlie, 40)
+                .unwrap();
+
+            set_caller(accounts.bob);
+            contract
+                .register_asset("evil".to_string(), "dagger".to_string(), 1, false)
+                .unwrap();
+            contract
+                .adjust_reputation("evil".to_string(), accounts.charlie, -1_000)
+                .unwrap();
+            assert_eq!(
+                contract.set_reputation_game("evil".to_string(), true),
+                Err(ContractError::NotAdmin)
+            );
+            assert_eq!(contract.global_reputation(accounts.charlie), 0);
+
+            // The deployer curates the games that count
+            set_caller(accounts.alice);
+            assert_eq!(
+                contract.set_reputation_game("cod".to_string(), true),
+                Ok(())
+            );
+            assert_eq!(contract.reputation_games(), vec!["cod".to_string()]);
+            assert_eq!(contract.global_reputation(accounts.charlie), 40);
+            assert_eq!(
+                contract.reputation("evil".to_string(), accounts.charlie),
+                -1_000
+            );
+
+            assert_eq!(
+                contract.set_reputation_game("cod".to_string(), false),
+                Ok(())
+            );
+            assert_eq!(contract.global_reputation(accounts.charlie), 0);
+        }
+
+        #[ink::test]
+        fn items_change_hands_and_only_approved_games_edit_their_state() {
+            let accounts = accounts();
+            let mut contract = Assets::new();
+            let token = |contract: &Assets| contract.item(0).unwrap();
+
+            for (account, name) in [(accounts.bob, "bob"), (accounts.charlie, "charlie")] {
+                set_caller(account);
+                contract.register_player(name.to_string()).unwrap();
+            }
+
+            set_caller(accounts.charlie);
+            contract
+                .register_asset("fifa".to_string(), "ball".to_string(), 10, false)
+                .unwrap();
+
+            set_caller(accounts.alice);
+            contract
+                .register_asset("cod".to_string(), "sword".to_string(), 100, false)
+                .unwrap();
+            contract
+                .set_game_operator("cod".to_string(), accounts.django, true)
+                .unwrap();
+            let token_id = contract
+                .mint_item("cod".to_string(), "sword".to_string(), accounts.bob)
+                .unwrap();
+            assert_eq!(
+                contract.update_item_state(token_id, "durability".to_string(), "90".to_string()),
+                Ok(())
+            );
+
+            // Another game needs the issuer's approval
+            set_caller(accounts.charlie);
+            assert_eq!(
+                contract.update_item_state(token_id, "durability".to_string(), "1".to_string()),
+                Err(ContractError::NotGameOwner)
+            );
+            set_caller(accounts.alice);
+            contract
+                .approve_state_editor("cod".to_string(), "fifa".to_string())
+                .unwrap();
+            set_caller(accounts.charlie);
+            assert_eq!(
+                contract.update_item_state(token_id, "level".to_string(), "2".to_string()),
+                Ok(())
+            );
+
+            // The issuing game's operators can edit too, but not the holder
+            set_caller(accounts.django);
+            assert_eq!(
+                contract.update_item_state(token_id, "durability".to_string(), "85".to_string()),
+                Ok(())
+            );
+            set_caller(accounts.bob);
+            assert_eq!(
+                contract.update_item_state(token_id, "level".to_string(), "99".to_string()),
+                Err(ContractError::NotGameOwner)
+            );
+            assert_eq!(token(&contract).version, 3);
+            assert_eq!(
+                token(&contract).state,
+                vec![
+                    ("durability".to_string(), "85".to_string()),
+                    ("level".to_string(), "2".to_string())
+                ]
+            );
+
+            // Only the holder moves the item, and only to a player
+            set_caller(accounts.charlie);
+            assert_eq!(
+                contract.transfer_item(token_id, accounts.charlie),
+                Err(ContractError::NotItemOwner)
+            );
+            set_caller(accounts.bob);
+            assert_eq!(
+                contract.transfer_item(token_id, accounts.eve),
+                Err(ContractError::PlayerNotFound)
+            );
+            assert_eq!(contract.transfer_item(token_id, accounts.charlie), Ok(()));
+            assert_eq!(token(&contract).owner, accounts.charlie);
+
+            // Revoked games lose access
+            set_caller(accounts.alice);
+            contract
+                .revoke_state_editor("cod".to_string(), "fifa".to_string())
+                .unwrap();
+            set_caller(accounts.charlie);
+            assert_eq!(
+                contract.update_item_state(token_id, "level".to_string(), "3".to_string()),
+                Err(ContractError::NotGameOwner)
+            );
+        }
+
+        #[ink::test]
+        fn deregistering_needs_positions_settled_and_burns_curve_supply() {
+            let accounts = accounts();
+            let mut contract = Assets::new();
+            let (cod, firegun) = (|| "cod".to_string(), || "firegun".to_string());
+
+            set_caller(accounts.alice);
+            contract
+                .register_asset(cod(), firegun(), 100, true)
+                .unwrap();
+            contract
+                .set_price_curve(
+                    cod(),
+                    firegun(),
+                    Some(PriceCurve::Linear {
+                        base: 100,
+                        slope: 10,
+                    }),
+                )
+                .unwrap();
+            let currency_id = contract
+                .create_currency(cod(), "Gold".to_string(), "GLD".to_string(), 0, false)
+                .unwrap();
+
+            set_caller(accounts.charlie);
+            contract.register_player("charlie".to_string()).unwrap();
+            let guild_id = contract.create_guild("clan".to_string()).unwrap();
+            set_caller(accounts.bob);
+            contract.register_player("bob".to_string()).unwrap();
+            contract.purchase_asset(cod(), firegun(), 2, None).unwrap();
+            assert_eq!(contract.total_supply(cod(), firegun()), 2);
+
+            // Guild seats hold the player back
+            set_caller(accounts.charlie);
+            contract
+                .set_guild_member(guild_id, accounts.bob, GuildRole::Member)
+                .unwrap();
+            set_caller(accounts.bob);
+            assert_eq!(
+                contract.deregister_player(true),
+                Err(ContractError::AccountInUse)
+            );
+            contract
+                .remove_guild_member(guild_id, accounts.bob)
+                .unwrap();
+
+            // So do currency balances, until they are spent
+            set_caller(accounts.alice);
+            contract
+                .mint_currency(currency_id, accounts.bob, 50)
+                .unwrap();
+            set_caller(accounts.bob);
+            assert_eq!(
+                contract.deregister_player(true),
+                Err(ContractError::AccountInUse)
+            );
+            contract
+                .currency_transfer(currency_id, accounts.charlie, 50, Vec::new())
+                .unwrap();
+
+            // Only holdings can be frozen, and frozen holdings cannot be burned
+            set_caller(accounts.alice);
+            assert_eq!(
+                contract.freeze_holding(cod(), accounts.django, firegun()),
+                Err(ContractError::AssetNotFound)
+            );
+            contract
+                .freeze_holding(cod(), accounts.bob, firegun())
+                .unwrap();
+            set_caller(accounts.bob);
+            assert_eq!(
+                contract.deregister_player(true),
+                Err(ContractError::HoldingFrozen)
+            );
+            set_caller(accounts.alice);
+            contract
+                .unfreeze_holding(cod(), accounts.bob, firegun())
+                .unwrap();
+
+            // Burning curve-bought units takes them out of the supply
+            set_caller(accounts.bob);
+            assert_eq!(contract.deregister_player(true), Ok(()));
+            assert_eq!(contract.total_supply(cod(), firegun()), 0);
+            assert_eq!(contract.curve_holding(accounts.bob, firegun()), 0);
+            assert_eq!(contract.curve_reserves.get((cod(), firegun())), Some(0));
+        }
+
+        #[ink::test]
+        fn guild_withdrawals_wait_for_a_quorum_of_officers() {
+            let accounts = accounts();
+            let mut contract = Assets::new();
+
+            for (account, name) in [
+                (accounts.bob, "bob"),
+                (accounts.charlie, "charlie"),
+                (accounts.django, "django"),
+            ] {
+                set_caller(account);
+                contract.register_player(name.to_string()).unwrap();
+            }
+
+            set_caller(accounts.bob);
+            let guild_id = contract.create_guild("clan".to_string()).unwrap();
+            contract
+                .set_guild_member(guild_id, accounts.charlie, GuildRole::Officer)
+                .unwrap();
+            contract
+                .set_guild_member(guild_id, accounts.django, GuildRole::Member)
+                .unwrap();
+
+            // The quorum cannot outnumber the leader and officers
+            assert_eq!(
+                contract.set_guild_quorum(guild_id, 3),
+                Err(ContractError::QuorumTooHigh)
+            );
+            assert_eq!(contract.set_guild_quorum(guild_id, 2), Ok(()));
+            assert_eq!(
+                contract.set_guild_member(guild_id, accounts.charlie, GuildRole::Member),
+                Err(ContractError::QuorumTooHigh)
+            );
+            assert_eq!(
+                contract.remove_guild_member(guild_id, accounts.charlie),
+                Err(ContractError::QuorumTooHigh)
+            );
+
+            set_caller(accounts.django);
+            contract
+                .deposit_to_guild(guild_id, VaultItem::Balance(5_000))
+                .unwrap();
+
+            // A plain member can propose but not approve
+            let proposal_id = contract
+                .propose_withdrawal(guild_id, accounts.django, VaultItem::Balance(2_000))
+                .unwrap();
+            assert_eq!(
+                contract.approve_withdrawal(guild_id, proposal_id),
+                Err(ContractError::NotGuildOfficer)
+            );
+
+            set_caller(accounts.bob);
+            contract.approve_withdrawal(guild_id, proposal_id).unwrap();
+            assert_eq!(
+                contract.approve_withdrawal(guild_id, proposal_id),
+                Err(ContractError::AlreadyApproved)
+            );
+            assert_eq!(balance(&contract, accounts.django), Some(995_000));
+
+            // The second approval carries it out
+            set_caller(accounts.charlie);
+            contract.approve_withdrawal(guild_id, proposal_id).unwrap();
+            assert_eq!(balance(&contract, accounts.django), Some(997_000));
+            assert_eq!(
+                contract.guild(guild_id).map(|guild| guild.balance),
+                Some(3_000)
+            );
+            assert_eq!(contract.withdrawal(guild_id, proposal_id), None);
+
+            // Lowering the quorum lets the officer step down
+            set_caller(accounts.bob);
+            contract.set_guild_quorum(guild_id, 1).unwrap();
+            assert_eq!(
+                contract.set_guild_member(guild_id, accounts.charlie, GuildRole::Member),
+                Ok(())
+            );
+        }
+
+        #[ink::test]
+        fn receiving_contracts_can_reject_assets() {
+            let rejected = Err(ContractError::TransferRejected);
+
+            // Accepting, or having no hook at all, lets the transfer through
+            assert_eq!(Assets::receiver_verdict(Ok(Ok(Ok(())))), Ok(()));
+            assert_eq!(
+                Assets::receiver_verdict(Ok(Err(ink::LangError::CouldNotReadInput))),
+                Ok(())
+            );
+
+            // A refusal or a failing hook reverts it
+            assert_eq!(
+                Assets::receiver_verdict(Ok(Ok(Err("no thanks".to_string())))),
+                rejected
+            );
+            assert_eq!(
+                Assets::receiver_verdict(Err(ink::env::Error::ReturnError(
+                    ink::env::ReturnErrorCode::CalleeTrapped
+                ))),
+                rejected
+            );
+            assert_eq!(
+                Assets::receiver_verdict(Err(ink::env::Error::ReturnError(
+                    ink::env::ReturnErrorCode::CalleeReverted
+                ))),
+                rejected
+            );
+
+            // Accounts that are not contracts are never called
+            let accounts = accounts();
+            let mut contract = Assets::new();
+            set_caller(accounts.alice);
+            contract
+                .register_asset("cod".to_string(), "firegun".to_string(), 100, false)
+                .unwrap();
+            set_caller(accounts.bob);
+            contract.register_player("bob".to_string()).unwrap();
+            set_caller(accounts.alice);
+            assert_eq!(
+                contract.award("cod".to_string(), accounts.bob, "firegun".to_string(), 1),
+                Ok(())
+            );
+        }
+
+        #[ink::test]
+        fn players_burn_their_own_units_but_only_games_mint() {
+            let accounts = accounts();
+            let mut contract = Assets::new();
+            let firegun = || "firegun".to_string();
+
+            set_caller(accounts.alice);
+            contract.register_player("alice".to_string()).unwrap();
+            contract
+                .register_asset("cod".to_string(), firegun(), 100, true)
+                .unwrap();
+            set_caller(accounts.bob);
+            contract.register_player("bob".to_string()).unwrap();
+
+            // Players cannot credit themselves
+            assert_eq!(
+                contract.modify_asset(firegun(), 5, true),
+                Err(ContractError::NotGameOwner)
+            );
+            assert_eq!(
+                contract.modify_asset("laser".to_string(), 5, true),
+                Err(ContractError::AssetNotFound)
+            );
+
+            // The game can, and so can its operators
+            set_caller(accounts.alice);
+            assert_eq!(contract.modify_asset(firegun(), 5, true), Ok(()));
+            contract
+                .set_game_operator("cod".to_string(), accounts.bob, true)
+                .unwrap();
+            set_caller(accounts.bob);
+            assert_eq!(contract.modify_asset(firegun(), 3, true), Ok(()));
+            assert_eq!(contract.balance_of(accounts.bob, firegun()), 3);
+
+            // Burning needs the units and an unfrozen holding
+            assert_eq!(
+                contract.modify_asset(firegun(), 4, false),
+                Err(ContractError::InsufficientAssetCount)
+            );
+            set_caller(accounts.alice);
+            contract
+                .freeze_holding("cod".to_string(), accounts.bob, firegun())
+                .unwrap();
+            set_caller(accounts.bob);
+            assert_eq!(
+                contract.modify_asset(firegun(), 1, false),
+                Err(ContractError::HoldingFrozen)
+            );
+            set_caller(accounts.alice);
+            contract
+                .unfreeze_holding("cod".to_string(), accounts.bob, firegun())
+                .unwrap();
+            set_caller(accounts.bob);
+            assert_eq!(contract.modify_asset(firegun(), 3, false), Ok(()));
+            assert_eq!(contract.balance_of(accounts.bob, firegun()), 0);
+        }
+
+        #[ink::test]
+        fn exchanges_follow_approved_routes_within_their_caps() {
+            let accounts = accounts();
+            let mut contract = Assets::new();
+            let route = || {
+                (
+                    "cod".to_string(),
+                    "gold".to_string(),
+                    "fifa".to_string(),
+                    "coins".to_string(),
+                )
+            };
+            let exchange = |contract: &mut Assets, units: u64| {
+                contract.exchange_asset(
+                    "cod".to_string(),
+                    "gold".to_string(),
+                    units,
+                    "fifa".to_string(),
+                    "coins".to_string(),
+                )
+            };
+
+            set_caller(accounts.alice);
+            contract
+                .register_asset("cod".to_string(), "gold".to_string(), 10, false)
+                .unwrap();
+            set_caller(accounts.django);
+            contract
+                .register_asset("fifa".to_string(), "coins".to_string(), 1, false)
+                .unwrap();
+            set_caller(accounts.bob);
+            contract.register_player("bob".to_string()).unwrap();
+            set_caller(accounts.alice);
+            contract
+                .award("cod".to_string(), accounts.bob, "gold".to_string(), 20)
+                .unwrap();
+
+            // Only the two game owners take part, and both must agree
+            set_caller(accounts.charlie);
+            assert_eq!(
+                contract.propose_conversion(route(), 2, 3, 4, 10),
+                Err(ContractError::NotGameOwner)
+            );
+            set_caller(accounts.alice);
+            contract.propose_conversion(route(), 2, 3, 4, 10).unwrap();
+            set_caller(accounts.bob);
+            assert_eq!(
+                exchange(&mut contract, 2),
+                Err(ContractError::ConversionNotApproved)
+            );
+            set_caller(accounts.django);
+            contract.propose_conversion(route(), 2, 3, 4, 10).unwrap();
+
+            // Whole steps of the agreed rate, up to the cap each period
+            set_caller(accounts.bob);
+            assert_eq!(
+                exchange(&mut contract, 3),
+                Err(ContractError::InvalidConversionAmount)
+            );
+            assert_eq!(exchange(&mut contract, 2), Ok(()));
+            assert_eq!(contract.balance_of(accounts.bob, "coins".to_string()), 3);
+            assert_eq!(
+                exchange(&mut contract, 4),
+                Err(ContractError::ConversionCapExceeded)
+            );
+            assert_eq!(exchange(&mut contract, 2), Ok(()));
+            assert_eq!(
+                exchange(&mut contract, 2),
+                Err(ContractError::ConversionCapExceeded)
+            );
+
+            // The cap resets once the period is over
+            for _ in 0..10 {
+                ink::env::test::advance_block::<DefaultEnvironment>();
+            }
+            assert_eq!(exchange(&mut contract, 4), Ok(()));
+            assert_eq!(contract.balance_of(accounts.bob, "gold".to_string()), 12);
+            assert_eq!(contract.balance_of(accounts.bob, "coins".to_string()), 12);
+
+            // Routes only run one way
+            assert_eq!(
+                contract.exchange_asset(
+                    "fifa".to_string(),
+                    "coins".to_string(),
+                    3,
+                    "cod".to_string(),
+                    "gold".to_string(),
+                ),
+                Err(ContractError::ConversionNotApproved)
+            );
+
+            // New terms need both owners again, and either owner can end the route
+            set_caller(accounts.alice);
+            contract.propose_conversion(route(), 1, 1, 4, 10).unwrap();
+            set_caller(accounts.bob);
+            assert_eq!(
+                exchange(&mut contract, 2),
+                Err(ContractError::ConversionNotApproved)
+            );
+            set_caller(accounts.django);
+            contract.propose_conversion(route(), 1, 1, 4, 10).unwrap();
+            contract.revoke_conversion(route()).unwrap();
+            assert_eq!(contract.conversion_rule(route()), None);
+        }
+
+        #[ink::test]
+        fn vouchers_need_the_games_signature_once_and_in_time() {
+            use ink_e2e::subxt_signer::{ecdsa, sr25519};
+
+            let accounts = accounts();
+            let mut contract = Assets::new();
+            let contract_id = ink::env::account_id::<DefaultEnvironment>();
+            let voucher = |nonce: u64, expires_at: BlockNumber| Voucher {
+                game: "cod".to_string(),
+                player: accounts.bob,
+                asset: "firegun".to_string(),
+                count: 2,
+                nonce,
+                expires_at,
+            };
+            // ECDSA signing hashes the payload itself; sr25519 signs the hash
+            let sign_ecdsa = |key: &ecdsa::Keypair, voucher: &Voucher| {
+                key.sign(&scale::Encode::encode(&(contract_id, voucher)))
+                    .0
+                    .to_vec()
+            };
+            let sign_sr25519 = |key: &sr25519::Keypair, voucher: &Voucher| {
+                key.sign(blake2(&(contract_id, voucher)).as_ref())
+                    .0
+                    .to_vec()
+            };
+
+            set_caller(accounts.alice);
+            contract
+                .register_asset("cod".to_string(), "firegun".to_string(), 100, false)
+                .unwrap();
+            set_caller(accounts.bob);
+            contract.register_player("bob".to_string()).unwrap();
+
+            // Anyone can relay a voucher, but only once the game has a signer
+            set_caller(accounts.charlie);
+            let first = voucher(1, 5);
+            assert_eq!(
+                contract.redeem_voucher(first.clone(), sign_ecdsa(&ecdsa::dev::alice(), &first)),
+                Err(ContractError::NoVoucherSigner)
+            );
+            set_caller(accounts.alice);
+            contract
+                .set_voucher_signer(
+                    "cod".to_string(),
+                    Some(SignerKey::Ecdsa(ecdsa::dev::alice().public_key().0)),
+                )
+                .unwrap();
+
+            // Signed by another key, or altered after signing
+            set_caller(accounts.charlie);
+            assert_eq!(
+                contract.redeem_voucher(first.clone(), sign_ecdsa(&ecdsa::dev::bob(), &first)),
+                Err(ContractError::InvalidSignature)
+            );
+            let inflated = Voucher {
+                count: 200,
+                ..first.clone()
+            };
+            assert_eq!(
+                contract.redeem_voucher(inflated, sign_ecdsa(&ecdsa::dev::alice(), &first)),
+                Err(ContractError::InvalidSignature)
+            );
+
+            // Redeemed once
+            let signature = sign_ecdsa(&ecdsa::dev::alice(), &first);
+            assert_eq!(
+                contract.redeem_voucher(first.clone(), signature.clone()),
+                Ok(())
+            );
+            assert_eq!(contract.balance_of(accounts.bob, "firegun".to_string()), 2);
+            assert!(contract.is_voucher_redeemed("cod".to_string(), 1));
+            assert_eq!(
+                contract.redeem_voucher(first, signature),
+                Err(ContractError::VoucherAlreadyRedeemed)
+            );
+
+            // Expired vouchers are refused
+            for _ in 0..6 {
+                ink::env::test::advance_block::<DefaultEnvironment>();
+            }
+            let late = voucher(2, 5);
+            assert_eq!(
+                contract.redeem_voucher(late.clone(), sign_ecdsa(&ecdsa::dev::alice(), &late)),
+                Err(ContractError::VoucherExpired)
+            );
+
+            // Switching to an sr25519 key retires the ECDSA one
+            set_caller(accounts.alice);
+            contract
+                .set_voucher_signer(
+                    "cod".to_string(),
+                    Some(SignerKey::Sr25519(sr25519::dev::alice().public_key().0)),
+                )
+                .unwrap();
+            set_caller(accounts.charlie);
+            let second = voucher(3, 100);
+            assert_eq!(
+                contract.redeem_voucher(second.clone(), sign_ecdsa(&ecdsa::dev::alice(), &second)),
+                Err(ContractError::InvalidSignature)
+            );
+            assert_eq!(
+                contract
+                    .redeem_voucher(second.clone(), sign_sr25519(&sr25519::dev::bob(), &second)),
+                Err(ContractError::InvalidSignature)
+            );
+            assert_eq!(
+                contract.redeem_voucher(
+                    second.clone(),
+                    sign_sr25519(&sr25519::dev::alice(), &second)
+                ),
+                Ok(())
+            );
+            assert_eq!(contract.balance_of(accounts.bob, "firegun".to_string()), 4);
+        }
+
+        #[ink::test]
+        fn relayers_submit_calls_signed_by_the_player_key() {
+            use ink_e2e::subxt_signer::{ecdsa, sr25519};
+
+            let accounts = accounts();
+            let mut contract = Assets::new();
+            let contract_id = ink::env::account_id::<DefaultEnvironment>();
+            let call = SignedCall::GiftAsset {
+                receiver: accounts.bob,
+                asset: "firegun".to_string(),
+                amount: 2,
+            };
+            let fee: Balance = 10;
+
+            // An sr25519 account is its public key, an ECDSA one the hash of it
+            let sr_key = sr25519::dev::ferdie();
+            let sr_player = AccountId::from(sr_key.public_key().0);
+            let ecdsa_key = ecdsa::dev::ferdie();
+            let ecdsa_player = AccountId::from(<[u8; 32]>::from(blake2(&ecdsa_key.public_key().0)));
+
+            set_caller(accounts.alice);
+            contract
+                .register_asset("cod".to_string(), "firegun".to_string(), 100, false)
+                .unwrap();
+            for (account, name) in [
+                (accounts.bob, "bob"),
+                (accounts.charlie, "charlie"),
+                (sr_player, "sr"),
+                (ecdsa_player, "ecdsa"),
+            ] {
+                set_caller(account);
+                contract.register_player(name.to_string()).unwrap();
+            }
+            set_caller(accounts.alice);
+            for player in [sr_player, ecdsa_player] {
+                contract
+                    .award("cod".to_string(), player, "firegun".to_string(), 5)
+                    .unwrap();
+            }
+
+            // Charlie relays for the sr25519 player
+            set_caller(accounts.charlie);
+            let payload = |nonce: u64, fee: Balance| (contract_id, sr_player, nonce, fee, &call);
+            let signature = sr_key.sign(blake2(&payload(0, fee)).as_ref()).0.to_vec();
+            assert_eq!(
+                contract.execute_signed(call.clone(), sr_player, 1, fee, signature.clone()),
+                Err(ContractError::InvalidNonce)
+            );
+            assert_eq!(
+                contract.execute_signed(call.clone(), sr_player, 0, 500, signature.clone()),
+                Err(ContractError::InvalidSignature)
+            );
+            let forged = sr25519::dev::bob()
+                .sign(blake2(&payload(0, fee)).as_ref())
+                .0
+                .to_vec();
+            assert_eq!(
+                contract.execute_signed(call.clone(), sr_player, 0, fee, forged),
+                Err(ContractError::InvalidSignature)
+            );
+
+            assert_eq!(
+                contract.execute_signed(call.clone(), sr_player, 0, fee, signature.clone()),
+                Ok(())
+            );
+            assert_eq!(contract.balance_of(accounts.bob, "firegun".to_string()), 2);
+            assert_eq!(balance(&contract, sr_player), Some(999_990));
+            assert_eq!(balance(&contract, accounts.charlie), Some(1_000_010));
+            assert_eq!(contract.nonce_of(sr_player), 1);
+
+            // The same signature cannot be replayed
+            assert_eq!(
+                contract.execute_signed(call.clone(), sr_player, 0, fee, signature),
+                Err(ContractError::InvalidNonce)
+            );
+
+            // ECDSA signatures are checked against the hash of the recovered key
+            let payload =
+                scale::Encode::encode(&(contract_id, ecdsa_player, 0u64, 0 as Balance, &call));
+            assert_eq!(
+                contract.execute_signed(
+                    call.clone(),
+                    ecdsa_player,
+                    0,
+                    0,
+                    ecdsa::dev::bob().sign(&payload).0.to_vec()
+                ),
+                Err(ContractError::InvalidSignature)
+            );
+            assert_eq!(
+                contract.execute_signed(
+                    call.clone(),
+                    ecdsa_player,
+                    0,
+                    0,
+                    ecdsa_key.sign(&payload).0.to_vec()
+                ),
+                Ok(())
+            );
+            assert_eq!(contract.balance_of(accounts.bob, "firegun".to_string()), 4);
+            assert_eq!(contract.nonce_of(ecdsa_player), 1);
+        }
+
+        #[ink::test]
+        fn quantities_must_be_positive_and_arithmetic_is_checked() {
+            let accounts = accounts();
+            let mut contract = Assets::new();
+            let (cod, firegun) = (|| "cod".to_string(), || "firegun".to_string());
+
+            set_caller(accounts.alice);
+            contract
+                .register_asset(cod(), firegun(), Balance::MAX / 2 + 1, false)
+                .unwrap();
+            contract
+                .register_asset(cod(), "ammo".to_string(), 1, false)
+                .unwrap();
+            set_caller(accounts.bob);
+            contract.register_player("bob".to_string()).unwrap();
+
+            // Zero quantities are refused everywhere
+            assert_eq!(
+                contract.purchase_asset(cod(), "ammo".to_string(), 0, None),
+                Err(ContractError::InsufficientAssetCount)
+            );
+            assert_eq!(
+                contract.gift_asset(accounts.alice, firegun(), 0),
+                Err(ContractError::InsufficientAssetCount)
+            );
+            assert_eq!(
+                contract.modify_asset(firegun(), 0, false),
+                Err(ContractError::InsufficientAssetCount)
+            );
+            assert_eq!(
+                contract.exchange_asset(cod(), firegun(), 0, cod(), "ammo".to_string()),
+                Err(ContractError::InsufficientAssetCount)
+            );
+
+            // Totals that do not fit are errors instead of wrapping
+            assert_eq!(
+                contract.purchase_asset(cod(), firegun(), 2, None),
+                Err(ContractError::Overflow)
+            );
+            set_caller(accounts.alice);
+            contract
+                .award(cod(), accounts.bob, "ammo".to_string(), u64::MAX)
+                .unwrap();
+            assert_eq!(
+                contract.award(cod(), accounts.bob, "ammo".to_string(), 1),
+                Err(ContractError::Overflow)
+            );
+            assert_eq!(
+                contract.balance_of(accounts.bob, "ammo".to_string()),
+                u64::MAX
+            );
+        }
+
+        #[ink::test]
+        fn bundles_credit_their_contents_within_window_and_limit() {
+            let accounts = accounts();
+            let mut contract = Assets::new();
+            let pack = || vec![("sword".to_string(), 1), ("potion".to_string(), 5)];
+            let advance = |blocks: u32| {
+                for _ in 0..blocks {
+                    ink::env::test::advance_block::<DefaultEnvironment>();
                 }
+            };
+
+            set_caller(accounts.alice);
+            for (asset, price) in [("sword", 100), ("potion", 10)] {
+                contract
+                    .register_asset("cod".to_string(), asset.to_string(), price, false)
+                    .unwrap();
             }
+            set_caller(accounts.django);
+            contract
+                .register_asset("fifa".to_string(), "ball".to_string(), 1, false)
+                .unwrap();
 
-            self.players.insert(caller, &player);
+            // Bundles only hold the game's own assets, in positive amounts
+            set_caller(accounts.alice);
+            assert_eq!(
+                contract.create_bundle(
+                    "cod".to_string(),
+                    vec![("ball".to_string(), 1)],
+                    1,
+                    0,
+                    0,
+                    None
+                ),
+                Err(ContractError::AssetNotFound)
+            );
+            assert_eq!(
+                contract.create_bundle(
+                    "cod".to_string(),
+                    vec![("sword".to_string(), 0)],
+                    1,
+                    0,
+                    0,
+                    None
+                ),
+                Err(ContractError::InsufficientAssetCount)
+            );
+            let bundle_id = contract
+                .create_bundle("cod".to_string(), pack(), 120, 2, 2, Some(5))
+                .unwrap();
 
-            // Emit Event
-            //  self.env().emit_event(AssetPurchased {
-            //     account: account_id,
-            //     asset,
-            //     count,
-            //     total_price: asset_price * count as u128,
-            // });
+            set_caller(accounts.bob);
+            contract.register_player("bob".to_string()).unwrap();
+            assert_eq!(
+                contract.purchase_bundle(bundle_id, 1),
+                Err(ContractError::BundleUnavailable)
+            );
 
-            // Emit Event
-            self.env().emit_event(AssetModified {
-                account: caller,
-                asset,
-                new_count: n_count,
-                increased: increase,
-            });
+            // Every item of the pack arrives at once, for the bundle price
+            advance(2);
+            assert_eq!(contract.purchase_bundle(bundle_id, 1), Ok(()));
+            assert_eq!(contract.balance_of(accounts.bob, "sword".to_string()), 1);
+            assert_eq!(contract.balance_of(accounts.bob, "potion".to_string()), 5);
+            assert_eq!(balance(&contract, accounts.bob), Some(999_880));
 
-            Ok(())
+            assert_eq!(
+                contract.purchase_bundle(bundle_id, 2),
+                Err(ContractError::PurchaseLimitReached)
+            );
+            assert_eq!(contract.purchase_bundle(bundle_id, 1), Ok(()));
+            assert_eq!(contract.balance_of(accounts.bob, "potion".to_string()), 10);
+
+            // The window closes, and a removed bundle is gone
+            set_caller(accounts.charlie);
+            contract.register_player("charlie".to_string()).unwrap();
+            advance(4);
+            assert_eq!(
+                contract.purchase_bundle(bundle_id, 1),
+                Err(ContractError::BundleUnavailable)
+            );
+            set_caller(accounts.alice);
+            contract.remove_bundle(bundle_id).unwrap();
+            set_caller(accounts.charlie);
+            assert_eq!(
+                contract.purchase_bundle(bundle_id, 1),
+                Err(ContractError::BundleNotFound)
+            );
         }
 
-        /// Helper function
-        fn parse_asset(asset: &str) -> Option<(String, i64)> {
-            let mut split = asset.rsplitn(2, '_');
-            let qty_str = split.next()?;
-            let name_str = split.next()?;
-            let qty = qty_str.parse::<i64>().ok()?;
-            Some((name_str.to_string(), qty))
+        #[ink::test]
+        fn purchases_take_the_best_running_sale_or_coupon() {
+            let accounts = accounts();
+            let mut contract = Assets::new();
+            let (cod, sword) = (|| "cod".to_string(), || "sword".to_string());
+            let code_hash = |code: &str| {
+                let mut output = [0u8; 32];
+                ink::env::hash_bytes::<ink::env::hash::Blake2x256>(code.as_bytes(), &mut output);
+                Hash::from(output)
+            };
+
+            set_caller(accounts.alice);
+            contract.register_asset(cod(), sword(), 100, false).unwrap();
+            contract
+                .schedule_sale(cod(), sword(), Discount::Percent(20), 1, 3, 2)
+                .unwrap();
+            contract
+                .schedule_sale(cod(), sword(), Discount::Absolute(30), 1, 3, 1)
+                .unwrap();
+            contract
+                .add_coupon(
+                    cod(),
+                    sword(),
+                    code_hash("HALF"),
+                    Discount::Percent(50),
+                    10,
+                    1,
+                )
+                .unwrap();
+
+            // Before the sales start the list price applies
+            set_caller(accounts.bob);
+            contract.register_player("bob".to_string()).unwrap();
+            contract.purchase_asset(cod(), sword(), 1, None).unwrap();
+            assert_eq!(balance(&contract, accounts.bob), Some(999_900));
+
+            // The deepest discount wins until its per-player cap is used up
+            ink::env::test::advance_block::<DefaultEnvironment>();
+            contract.purchase_asset(cod(), sword(), 1, None).unwrap();
+            assert_eq!(balance(&contract, accounts.bob), Some(999_830));
+            contract.purchase_asset(cod(), sword(), 1, None).unwrap();
+            assert_eq!(balance(&contract, accounts.bob), Some(999_750));
+            contract.purchase_asset(cod(), sword(), 2, None).unwrap();
+            assert_eq!(balance(&contract, accounts.bob), Some(999_550));
+
+            // Coupons beat the sales and run out
+            assert_eq!(
+                contract.purchase_asset(cod(), sword(), 1, Some("FREE".to_string())),
+                Err(ContractError::InvalidCoupon)
+            );
+            contract
+                .purchase_asset(cod(), sword(), 1, Some("HALF".to_string()))
+                .unwrap();
+            assert_eq!(balance(&contract, accounts.bob), Some(999_500));
+            assert_eq!(
+                contract.purchase_asset(cod(), sword(), 1, Some("HALF".to_string())),
+                Err(ContractError::InvalidCoupon)
+            );
+
+            // Once the sales end the list price is back
+            for _ in 0..3 {
+                ink::env::test::advance_block::<DefaultEnvironment>();
+            }
+            contract.purchase_asset(cod(), sword(), 1, None).unwrap();
+            assert_eq!(balance(&contract, accounts.bob), Some(999_400));
+            assert_eq!(contract.balance_of(accounts.bob, sword()), 7);
+        }
+
+        #[ink::test]
+        fn achievements_add_up_to_a_gamer_score_across_games() {
+            let accounts = accounts();
+            let mut contract = Assets::new();
+            let define = |contract: &mut Assets, game: &str, achievement: &str, points: u32| {
+                contract.define_achievement(
+                    game.to_string(),
+                    achievement.to_string(),
+                    achievement.to_string(),
+                    String::new(),
+                    points,
+                )
+            };
+
+            set_caller(accounts.alice);
+            contract
+                .register_asset("cod".to_string(), "firegun".to_string(), 100, false)
+                .unwrap();
+            set_caller(accounts.django);
+            contract
+                .register_asset("fifa".to_string(), "ball".to_string(), 1, false)
+                .unwrap();
+            set_caller(accounts.bob);
+            contract.register_player("bob".to_string()).unwrap();
+
+            // Games define their own achievements, once
+            set_caller(accounts.alice);
+            assert_eq!(define(&mut contract, "cod", "first_blood", 10), Ok(()));
+            assert_eq!(
+                define(&mut contract, "cod", "first_blood", 50),
+                Err(ContractError::AchievementExists)
+            );
+            assert_eq!(
+                define(&mut contract, "fifa", "hat_trick", 50),
+                Err(ContractError::NotGameOwner)
+            );
+            set_caller(accounts.django);
+            assert_eq!(define(&mut contract, "fifa", "hat_trick", 25), Ok(()));
+
+            // Only the defining game grants them, once per player
+            assert_eq!(
+                contract.grant_achievement(
+                    "cod".to_string(),
+                    "first_blood".to_string(),
+                    accounts.bob
+                ),
+                Err(ContractError::NotGameOwner)
+            );
+            contract
+                .grant_achievement("fifa".to_string(), "hat_trick".to_string(), accounts.bob)
+                .unwrap();
+            set_caller(accounts.alice);
+            assert_eq!(
+                contract.grant_achievement("cod".to_string(), "missing".to_string(), accounts.bob),
+                Err(ContractError::AchievementNotFound)
+            );
+            contract
+                .grant_achievement("cod".to_string(), "first_blood".to_string(), accounts.bob)
+                .unwrap();
+            assert_eq!(
+                contract.grant_achievement(
+                    "cod".to_string(),
+                    "first_blood".to_string(),
+                    accounts.bob
+                ),
+                Err(ContractError::AlreadyGranted)
+            );
+
+            // Any game sees the player's achievements from every game
+            assert_eq!(
+                contract.achievements_of(accounts.bob),
+                vec![
+                    ("fifa".to_string(), "hat_trick".to_string()),
+                    ("cod".to_string(), "first_blood".to_string()),
+                ]
+            );
+            assert_eq!(contract.gamer_score(accounts.bob), 35);
+
+            // They stay with the account and go when it deregisters
+            set_caller(accounts.bob);
+            contract.deregister_player(false).unwrap();
+            assert!(contract.achievements_of(accounts.bob).is_empty());
+            assert_eq!(contract.gamer_score(accounts.bob), 0);
+        }
+    }
+
+    #[cfg(all(test, feature = "e2e-tests"))]
+    mod e2e_tests {
+        use super::*;
+        use ink_e2e::ContractsBackend;
+        use mock_psp22::{MockPsp22, MockPsp22Ref, PSP22};
+
+        type E2EResult<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+        #[ink_e2e::test]
+        async fn purchase_pulls_psp22_payment<Client: E2EBackend>(
+            mut client: Client,
+        ) -> E2EResult<()> {
+            let alice = ink_e2e::account_id(ink_e2e::AccountKeyring::Alice);
+            let bob = ink_e2e::account_id(ink_e2e::AccountKeyring::Bob);
+
+            // Deploy a mock token and AssetVerse
+            let mut token_constructor = MockPsp22Ref::new(1_000_000);
+            let token = client
+                .instantiate("mock_psp22", &ink_e2e::alice(), &mut token_constructor)
+                .submit()
+                .await
+                .expect("token instantiate failed");
+            let mut token_builder = token.call_builder::<MockPsp22>();
+
+            let mut constructor = AssetsRef::new();
+            let assets = client
+                .instantiate("assets", &ink_e2e::alice(), &mut constructor)
+                .submit()
+                .await
+                .expect("assets instantiate failed");
+            let mut call_builder = assets.call_builder::<Assets>();
+
+            // Alice runs "cod" and prices its firegun in the token
+            let register = call_builder.register_player("alice".to_string());
+            client.call(&ink_e2e::alice(), &register).submit().await?;
+            let register = call_builder.register_player("bob".to_string());
+            client.call(&ink_e2e::bob(), &register).submit().await?;
+
+            let asset =
+                call_builder.register_asset("cod".to_string(), "firegun".to_string(), 100, false);
+            client.call(&ink_e2e::alice(), &asset).submit().await?;
+            let currency = call_builder.set_price_currency(
+                "cod".to_string(),
+                "firegun".to_string(),
+                PriceCurrency::Psp22(token.account_id),
+            );
+            client.call(&ink_e2e::alice(), &currency).submit().await?;
+
+            // Give Bob some tokens
+            let transfer = token_builder.transfer(bob, 1_000, Vec::new());
+            client.call(&ink_e2e::alice(), &transfer).submit().await?;
+
+            // Without an allowance the payment cannot be pulled
+            let purchase =
+                call_builder.purchase_asset("cod".to_string(), "firegun".to_string(), 3, None);
+            let result = client.call(&ink_e2e::bob(), &purchase).dry_run().await?;
+            assert_eq!(result.return_value(), Err(ContractError::PaymentFailed));
+
+            // Bob allows AssetVerse to spend his tokens and buys three fireguns
+            let approve = token_builder.approve(assets.account_id, 300);
+            client.call(&ink_e2e::bob(), &approve).submit().await?;
+            client.call(&ink_e2e::bob(), &purchase).submit().await?;
+
+            // The payment moved from Bob to the game owner
+            let bob_tokens = token_builder.balance_of(bob);
+            let bob_tokens = client.call(&ink_e2e::bob(), &bob_tokens).dry_run().await?;
+            assert_eq!(bob_tokens.return_value(), 700);
+
+            let alice_tokens = token_builder.balance_of(alice);
+            let alice_tokens = client
+                .call(&ink_e2e::bob(), &alice_tokens)
+                .dry_run()
+                .await?;
+            assert_eq!(alice_tokens.return_value(), 999_300);
+
+            // Bob holds the assets and his global balance is untouched
+            let holdings = call_builder.balance_of(bob, "firegun".to_string());
+            let holdings = client.call(&ink_e2e::bob(), &holdings).dry_run().await?;
+            assert_eq!(holdings.return_value(), 3);
+
+            let player = call_builder.player(bob);
+            let player = client.call(&ink_e2e::bob(), &player).dry_run().await?;
+            assert_eq!(player.return_value().map(|p| p.balance), Some(1_000_000));
+
+            Ok(())
         }
     }
 }
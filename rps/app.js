@@ -136,7 +136,7 @@ async function registerAsset(req, res) {
         const data = req.data.split("$$$");
 
         // Call contract to register asset
-        await chain.registerAsset(api, contract, /* user */bob, data[0] + "$", data[1] + "$", BigInt(data[2])).then(() => {
+        await chain.registerAsset(api, contract, /* user */bob, data[0] + "$", data[1] + "$", BigInt(data[2]), false).then(() => {
             // Return the keys to the user for next auth
             return res.send({
                 data: "Asset successfully registered",
@@ -186,7 +186,7 @@ async function buyAsset(req, res) {
         const data = req.data.split("$$$");
 
         // Call contract to purchase asset
-        await chain.buyAsset(api, contract, /* user */bob, data[0].trim() + "$", data[1] + "$", parseInt(data[2])).then(() => {
+        await chain.buyAsset(api, contract, /* user */bob, data[0].trim() + "$", data[1] + "$", parseInt(data[2]), null).then(() => {
             // Return the keys to the user for next auth
             return res.send({
                 data: "Asset successfully bought!",
@@ -233,7 +233,7 @@ async function swapAssets(req, res) {
         const data = req.data.split("$$$");
 
         // Call contract to buy asset
-        await chain.swapAsset(api, contract, /* user */bob, data[1].trim() + "$", parseInt(data[2]), data[4].trim() + "$", parseInt(data[5])).then(() => {
+        await chain.swapAsset(api, contract, /* user */bob, data[0].trim() + "$", data[1].trim() + "$", parseInt(data[2]), data[3].trim() + "$", data[4].trim() + "$").then(() => {
             // Return the keys to the user for next auth
             return res.send({
                 data: "Asset swaped successfully!",
@@ -51,7 +51,7 @@ function registerPlayer(api, contract, account, name) {
                     return [4 /*yield*/, contract.query.registerPlayer(account.address, {
                             gasLimit: gasLimit,
                             storageDepositLimit: null,
-                            value: new util_1.BN('1000000000000000000')
+                            value: 0 // register_player is not payable
                         }, name)
                         // Check for errors
                     ];
@@ -78,7 +78,7 @@ function registerPlayer(api, contract, account, name) {
                             .registerPlayer({
                             gasLimit: estimatedGas,
                             storageDepositLimit: null,
-                            value: new util_1.BN('10000000') // 1 TOKEN or it could be value you want to send to the contract in title
+                            value: 0 // register_player is not payable
                         }, name)
                             .signAndSend(account, function (res) {
                             // Send the transaction, like elsewhere this is a normal extrinsic
@@ -100,8 +100,9 @@ function registerPlayer(api, contract, account, name) {
     });
 }
 exports.registerPlayer = registerPlayer;
-// pub fn register_asset(&mut self, game: String, name: String, price: Balance) {
-function registerAsset(api, contract, account, game, name, price) {
+// pub fn register_asset(&mut self, game: String, name: String, price: Balance, recoverable: bool) {
+function registerAsset(api, contract, account, game, name, price, recoverable) {
+    if (recoverable === void 0) { recoverable = false; }
     return __awaiter(this, void 0, void 0, function () {
         var gasLimit, _a, gasRequired, storageDeposit, result, error, dispatchError, estimatedGas, unsub;
         return __generator(this, function (_b) {
@@ -112,7 +113,7 @@ function registerAsset(api, contract, account, game, name, price) {
                             gasLimit: gasLimit,
                             storageDepositLimit: null,
                             value: new util_1.BN('1000000000000000000')
-                        }, game, name, new util_1.BN(price))
+                        }, game, name, new util_1.BN(price), recoverable)
                         // Check for errors
                     ];
                 case 1:
@@ -139,7 +140,7 @@ function registerAsset(api, contract, account, game, name, price) {
                             gasLimit: estimatedGas,
                             storageDepositLimit: null,
                             value: new util_1.BN('10000000') // 1 TOKEN or it could be value you want to send to the contract in title
-                        }, game, name, new util_1.BN(price))
+                        }, game, name, new util_1.BN(price), recoverable)
                             .signAndSend(account, function (res) {
                             // Send the transaction, like elsewhere this is a normal extrinsic
                             // with the same rules as applied in the API (As with the read example,
@@ -200,7 +201,8 @@ function fetchAssets(api, contract, account, game) {
     });
 }
 exports.fetchAssets = fetchAssets;
-function buyAsset(api, contract, account, game, name, amount) {
+function buyAsset(api, contract, account, game, name, amount, coupon) {
+    if (coupon === void 0) { coupon = null; }
     return __awaiter(this, void 0, void 0, function () {
         var gasLimit, _a, gasRequired, storageDeposit, result, error, dispatchError, estimatedGas, unsub;
         return __generator(this, function (_b) {
@@ -210,8 +212,8 @@ function buyAsset(api, contract, account, game, name, amount) {
                     return [4 /*yield*/, contract.query.purchaseAsset(account.address, {
                             gasLimit: gasLimit,
                             storageDepositLimit: null,
-                            value: new util_1.BN('1000000000000000000')
-                        }, game, name, amount)
+                            value: 0 // Store-priced assets are paid from the player's balance
+                        }, game, name, amount, coupon)
                         // Check for errors
                     ];
                 case 1:
@@ -237,8 +239,8 @@ function buyAsset(api, contract, account, game, name, amount) {
                             .purchaseAsset({
                             gasLimit: estimatedGas,
                             storageDepositLimit: null,
-                            value: new util_1.BN('10000000') // 1 TOKEN or it could be value you want to send to the contract in title
-                        }, game, name, amount)
+                            value: 0 // Store-priced assets are paid from the player's balance
+                        }, game, name, amount, coupon)
                             .signAndSend(account, function (res) {
                             // Send the transaction, like elsewhere this is a normal extrinsic
                             // with the same rules as applied in the API (As with the read example,
@@ -318,7 +320,7 @@ function giftAsset(api, contract, account, reciever, name, amount) {
     });
 }
 exports.giftAsset = giftAsset;
-function swapAsset(api, contract, account, asset, amount, asset_1, amount_1) {
+function swapAsset(api, contract, account, game, asset, amount, game_1, asset_1) {
     return __awaiter(this, void 0, void 0, function () {
         var gasLimit, _a, gasRequired, storageDeposit, result, error, dispatchError, estimatedGas, unsub;
         return __generator(this, function (_b) {
@@ -328,8 +330,8 @@ function swapAsset(api, contract, account, asset, amount, asset_1, amount_1) {
                     return [4 /*yield*/, contract.query.exchangeAsset(account.address, {
                             gasLimit: gasLimit,
                             storageDepositLimit: null,
-                            value: new util_1.BN('1000000000000000000')
-                        }, asset, amount, asset_1, amount_1)
+                            value: 0 // exchange_asset is not payable
+                        }, game, asset, amount, game_1, asset_1)
                         // Check for errors
                     ];
                 case 1:
@@ -355,8 +357,8 @@ function swapAsset(api, contract, account, asset, amount, asset_1, amount_1) {
                             .exchangeAsset({
                             gasLimit: estimatedGas,
                             storageDepositLimit: null,
-                            value: new util_1.BN('1000000000000000000') // 1 TOKEN or it could be value you want to send to the contract in title
-                        }, asset, amount, asset_1, amount_1)
+                            value: 0 // exchange_asset is not payable
+                        }, game, asset, amount, game_1, asset_1)
                             .signAndSend(account, function (res) {
                             // Send the transaction, like elsewhere this is a normal extrinsic
                             // with the same rules as applied in the API (As with the read example,
@@ -20,7 +20,7 @@ export async function registerPlayer(api: any, contract: any, account: any, name
         {
             gasLimit: gasLimit,
             storageDepositLimit: null,
-            value: new BN('1000000000000000000')
+            value: 0 // register_player is not payable
         }, name
     )
 
@@ -53,7 +53,7 @@ export async function registerPlayer(api: any, contract: any, account: any, name
         .registerPlayer({
             gasLimit: estimatedGas,
             storageDepositLimit: null,
-            value: new BN('10000000') // 1 TOKEN or it could be value you want to send to the contract in title
+            value: 0 // register_player is not payable
         }, name)
         .signAndSend(account, (res: any) => {
             // Send the transaction, like elsewhere this is a normal extrinsic
@@ -69,10 +69,10 @@ export async function registerPlayer(api: any, contract: any, account: any, name
         })
 }
 
-// pub fn register_asset(&mut self, game: String, name: String, price: Balance) {
+// pub fn register_asset(&mut self, game: String, name: String, price: Balance, recoverable: bool) {
 
 
-export async function registerAsset(api: any, contract: any, account: any, game: string, name: String, price: any) {
+export async function registerAsset(api: any, contract: any, account: any, game: string, name: String, price: any, recoverable: boolean = false) {
     // Get the initial gas WeightV2 using api.consts.system.blockWeights['maxBlock']
     const gasLimit = api.registry.createType(
         'WeightV2',
@@ -88,7 +88,7 @@ export async function registerAsset(api: any, contract: any, account: any, game:
             gasLimit: gasLimit,
             storageDepositLimit: null,
             value: new BN('1000000000000000000')
-        }, game, name, new BN(price)
+        }, game, name, new BN(price), recoverable
     )
 
     // Check for errors
@@ -121,7 +121,7 @@ export async function registerAsset(api: any, contract: any, account: any, game:
             gasLimit: estimatedGas,
             storageDepositLimit: null,
             value: new BN('10000000') // 1 TOKEN or it could be value you want to send to the contract in title
-        }, game, name, new BN(price))
+        }, game, name, new BN(price), recoverable)
         .signAndSend(account, (res: any) => {
             // Send the transaction, like elsewhere this is a normal extrinsic
             // with the same rules as applied in the API (As with the read example,
@@ -164,7 +164,7 @@ export async function fetchAssets(api: any, contract: any, account: any, game: a
     return result.toHuman();
 }
 
-export async function buyAsset(api: any, contract: any, account: any, game: string, name: String, amount: any) {
+export async function buyAsset(api: any, contract: any, account: any, game: string, name: String, amount: any, coupon: String | null = null) {
     // Get the initial gas WeightV2 using api.consts.system.blockWeights['maxBlock']
     const gasLimit = api.registry.createType(
         'WeightV2',
@@ -179,8 +179,8 @@ export async function buyAsset(api: any, contract: any, account: any, game: stri
         {
             gasLimit: gasLimit,
             storageDepositLimit: null,
-            value: new BN('1000000000000000000')
-        }, game, name, amount
+            value: 0 // Store-priced assets are paid from the player's balance
+        }, game, name, amount, coupon
     )
 
     // Check for errors
@@ -212,8 +212,8 @@ export async function buyAsset(api: any, contract: any, account: any, game: stri
         .purchaseAsset({
             gasLimit: estimatedGas,
             storageDepositLimit: null,
-            value: new BN('10000000') // 1 TOKEN or it could be value you want to send to the contract in title
-        }, game, name, amount)
+            value: 0 // Store-priced assets are paid from the player's balance
+        }, game, name, amount, coupon)
         .signAndSend(account, (res: any) => {
             // Send the transaction, like elsewhere this is a normal extrinsic
             // with the same rules as applied in the API (As with the read example,
@@ -293,7 +293,7 @@ export async function giftAsset(api: any, contract: any, account: any, reciever:
         })
 }
 
-export async function swapAsset(api: any, contract: any, account: any, asset: String, amount: any, asset_1: String, amount_1: any) {
+export async function swapAsset(api: any, contract: any, account: any, game: string, asset: String, amount: any, game_1: string, asset_1: String) {
     // Get the initial gas WeightV2 using api.consts.system.blockWeights['maxBlock']
     const gasLimit = api.registry.createType(
         'WeightV2',
@@ -308,8 +308,8 @@ export async function swapAsset(api: any, contract: any, account: any, asset: St
         {
             gasLimit: gasLimit,
             storageDepositLimit: null,
-            value: new BN('1000000000000000000')
-        }, asset, amount, asset_1, amount_1
+            value: 0 // exchange_asset is not payable
+        }, game, asset, amount, game_1, asset_1
     )
 
     // Check for errors
@@ -341,8 +341,8 @@ export async function swapAsset(api: any, contract: any, account: any, asset: St
         .exchangeAsset({
             gasLimit: estimatedGas,
             storageDepositLimit: null,
-            value: new BN('1000000000000000000') // 1 TOKEN or it could be value you want to send to the contract in title
-        }, asset, amount, asset_1, amount_1)
+            value: 0 // exchange_asset is not payable
+        }, game, asset, amount, game_1, asset_1)
         .signAndSend(account, (res: any) => {
             // Send the transaction, like elsewhere this is a normal extrinsic
             // with the same rules as applied in the API (As with the read example,
@@ -36,7 +36,7 @@ export function metadata() {
                             "ink_primitives",
                             "ConstructorResult"
                         ],
-                        "type": 18
+                        "type": 241
                     },
                     "selector": "0x9bae9d5e"
                 }
@@ -59,19 +59,19 @@ export function metadata() {
                     "displayName": [
                         "BlockNumber"
                     ],
-                    "type": 31
+                    "type": 27
                 },
                 "chainExtension": {
                     "displayName": [
                         "ChainExtension"
                     ],
-                    "type": 32
+                    "type": 317
                 },
                 "hash": {
                     "displayName": [
                         "Hash"
                     ],
-                    "type": 30
+                    "type": 28
                 },
                 "maxEventTopics": 4,
                 "staticBufferSize": 16384,
@@ -79,7 +79,7 @@ export function metadata() {
                     "displayName": [
                         "Timestamp"
                     ],
-                    "type": 25
+                    "type": 30
                 }
             },
             "events": [
@@ -87,7 +87,20 @@ export function metadata() {
                     "args": [
                         {
                             "docs": [
-                                "The account ID of the newly created player."
+                                "The game owning the asset."
+                            ],
+                            "indexed": false,
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The player whose holding is frozen."
                             ],
                             "indexed": true,
                             "label": "account",
@@ -100,10 +113,10 @@ export function metadata() {
                         },
                         {
                             "docs": [
-                                "The in-game name chosen by the player."
+                                "The frozen asset."
                             ],
-                            "indexed": false,
-                            "label": "name",
+                            "indexed": true,
+                            "label": "asset",
                             "type": {
                                 "displayName": [
                                     "String"
@@ -113,17 +126,17 @@ export function metadata() {
                         }
                     ],
                     "docs": [
-                        "Event emitted when a new player account is successfully created."
+                        "Emitted when a game owner freezes a player's holding of a recoverable asset."
                     ],
-                    "label": "PlayerCreated",
+                    "label": "Frozen",
                     "module_path": "assets::assets",
-                    "signature_topic": "0x49643ac39dc6ec0902776735685fe52d8e8a672436cf7490f4e73ca23d5dca95"
+                    "signature_topic": "0xb35489eed1803485eabd1e0dd06df5c65d0dfd8abb5c3c655c112790748c9f43"
                 },
                 {
                     "args": [
                         {
                             "docs": [
-                                "The game to which the asset belongs."
+                                "The game owning the asset."
                             ],
                             "indexed": false,
                             "label": "game",
@@ -136,115 +149,58 @@ export function metadata() {
                         },
                         {
                             "docs": [
-                                "The name of the asset added (e.g., weapon, skin, item)."
+                                "The player whose holding is unfrozen."
                             ],
                             "indexed": true,
-                            "label": "name",
+                            "label": "account",
                             "type": {
                                 "displayName": [
-                                    "String"
+                                    "AccountId"
                                 ],
-                                "type": 0
+                                "type": 4
                             }
                         },
                         {
                             "docs": [
-                                "The asset's price in native token units."
+                                "The unfrozen asset."
                             ],
-                            "indexed": false,
-                            "label": "price",
+                            "indexed": true,
+                            "label": "asset",
                             "type": {
                                 "displayName": [
-                                    "Balance"
+                                    "String"
                                 ],
-                                "type": 1
+                                "type": 0
                             }
                         }
                     ],
                     "docs": [
-                        "Event emitted when a new in-game asset is registered on-chain."
+                        "Emitted when a game owner unfreezes a player's holding."
                     ],
-                    "label": "AssetCreated",
+                    "label": "Unfrozen",
                     "module_path": "assets::assets",
-                    "signature_topic": "0xeb18a5ea7ec2afe85ec27f741b2c8b3591436121e425afa2b28d8c535caecce4"
-                }
-            ],
-            "lang_error": {
-                "displayName": [
-                    "ink",
-                    "LangError"
-                ],
-                "type": 19
-            },
-            "messages": [
+                    "signature_topic": "0x314bc3b9390661b23c12da181feadcf8912aefc22ae25dc65dbbc41db249b7a5"
+                },
                 {
                     "args": [
                         {
-                            "label": "name",
+                            "docs": [
+                                "The account that sold the units."
+                            ],
+                            "indexed": true,
+                            "label": "account",
                             "type": {
                                 "displayName": [
-                                    "String"
+                                    "AccountId"
                                 ],
-                                "type": 0
+                                "type": 4
                             }
-                        }
-                    ],
-                    "default": false,
-                    "docs": [
-                        " Register a playing account across the network.",
-                        " Players are endowed with 1M units of tokens at registration to buy game assets."
-                    ],
-                    "label": "register_player",
-                    "mutates": true,
-                    "payable": true,
-                    "returnType": {
-                        "displayName": [
-                            "ink",
-                            "MessageResult"
-                        ],
-                        "type": 18
-                    },
-                    "selector": "0x44c9d826"
-                },
-                {
-                    "args": [],
-                    "default": false,
-                    "docs": [
-                        " Returns a player account related to an `AccountId`."
-                    ],
-                    "label": "auth_player",
-                    "mutates": true,
-                    "payable": true,
-                    "returnType": {
-                        "displayName": [
-                            "ink",
-                            "MessageResult"
-                        ],
-                        "type": 20
-                    },
-                    "selector": "0x34928eec"
-                },
-                {
-                    "args": [],
-                    "default": false,
-                    "docs": [
-                        " Returns the registered games"
-                    ],
-                    "label": "games",
-                    "mutates": false,
-                    "payable": true,
-                    "returnType": {
-                        "displayName": [
-                            "ink",
-                            "MessageResult"
-                        ],
-                        "type": 22
-                    },
-                    "selector": "0x4c1db0e3"
-                },
-                {
-                    "args": [
+                        },
                         {
+                            "docs": [
+                                "The game buying the units back."
+                            ],
+                            "indexed": false,
                             "label": "game",
                             "type": {
                                 "displayName": [
@@ -254,7 +210,11 @@ export function metadata() {
                             }
                         },
                         {
-                            "label": "name",
+                            "docs": [
+                                "The asset sold."
+                            ],
+                            "indexed": false,
+                            "label": "asset",
                             "type": {
                                 "displayName": [
                                     "String"
@@ -263,7 +223,24 @@ export function metadata() {
                             }
                         },
                         {
-                            "label": "price",
+                            "docs": [
+                                "The quantity sold."
+                            ],
+                            "indexed": false,
+                            "label": "count",
+                            "type": {
+                                "displayName": [
+                                    "u64"
+                                ],
+                                "type": 30
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The balance paid out from the game's reserve."
+                            ],
+                            "indexed": false,
+                            "label": "payout",
                             "type": {
                                 "displayName": [
                                     "Balance"
@@ -272,25 +249,20 @@ export function metadata() {
                             }
                         }
                     ],
-                    "default": false,
                     "docs": [
-                        " Register an asset"
+                        "Emitted when a player sells units of an asset back to the game store."
                     ],
-                    "label": "register_asset",
-                    "mutates": true,
-                    "payable": true,
-                    "returnType": {
-                        "displayName": [
-                            "ink",
-                            "MessageResult"
-                        ],
-                        "type": 18
-                    },
-                    "selector": "0x763156d2"
+                    "label": "AssetSold",
+                    "module_path": "assets::assets",
+                    "signature_topic": "0xcd869b12684e966cfd7d5b78f06b53eece002de89c19dc892774f017628dbb5f"
                 },
                 {
                     "args": [
                         {
+                            "docs": [
+                                "The game owning the asset."
+                            ],
+                            "indexed": false,
                             "label": "game",
                             "type": {
                                 "displayName": [
@@ -298,36 +270,25 @@ export function metadata() {
                                 ],
                                 "type": 0
                             }
-                        }
-                    ],
-                    "default": false,
-                    "docs": [
-                        " Get assets belonging to a game"
-                    ],
-                    "label": "assets",
-                    "mutates": false,
-                    "payable": true,
-                    "returnType": {
-                        "displayName": [
-                            "ink",
-                            "MessageResult"
-                        ],
-                        "type": 23
-                    },
-                    "selector": "0xc47d7428"
-                },
-                {
-                    "args": [
+                        },
                         {
-                            "label": "game",
+                            "docs": [
+                                "The player the units were taken from."
+                            ],
+                            "indexed": true,
+                            "label": "account",
                             "type": {
                                 "displayName": [
-                                    "String"
+                                    "AccountId"
                                 ],
-                                "type": 0
+                                "type": 4
                             }
                         },
                         {
+                            "docs": [
+                                "The asset recovered."
+                            ],
+                            "indexed": true,
                             "label": "asset",
                             "type": {
                                 "displayName": [
@@ -337,43 +298,59 @@ export function metadata() {
                             }
                         },
                         {
+                            "docs": [
+                                "Units burned."
+                            ],
+                            "indexed": false,
                             "label": "count",
                             "type": {
                                 "displayName": [
                                     "u64"
                                 ],
-                                "type": 25
+                                "type": 30
                             }
                         }
                     ],
-                    "default": false,
                     "docs": [
-                        " Buy units of an asset, deducting its price from the player's balance."
+                        "Emitted when a game owner burns units of a recoverable asset from a player."
                     ],
-                    "label": "purchase_asset",
-                    "mutates": true,
-                    "payable": true,
-                    "returnType": {
-                        "displayName": [
-                            "ink",
-                            "MessageResult"
-                        ],
-                        "type": 26
-                    },
-                    "selector": "0x907a5f68"
+                    "label": "ClawedBack",
+                    "module_path": "assets::assets",
+                    "signature_topic": "0xc13f09912ae5e283bebe3bdf8a7af68ee1c6497e2969328d2a2d5b88d7fc6dd8"
                 },
                 {
                     "args": [
                         {
-                            "label": "receiver",
+                            "docs": [
+                                "The identifier of the newly minted item."
+                            ],
+                            "indexed": true,
+                            "label": "token_id",
                             "type": {
                                 "displayName": [
-                                    "AccountId"
+                                    "TokenId"
                                 ],
-                                "type": 4
+                                "type": 30
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The game that issued the item."
+                            ],
+                            "indexed": false,
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
                             }
                         },
                         {
+                            "docs": [
+                                "The catalog asset the item is an instance of."
+                            ],
+                            "indexed": false,
                             "label": "asset",
                             "type": {
                                 "displayName": [
@@ -383,54 +360,342 @@ export function metadata() {
                             }
                         },
                         {
-                            "label": "amount",
+                            "docs": [
+                                "The player receiving the item."
+                            ],
+                            "indexed": true,
+                            "label": "owner",
                             "type": {
                                 "displayName": [
-                                    "u64"
+                                    "AccountId"
                                 ],
-                                "type": 25
+                                "type": 4
                             }
                         }
                     ],
-                    "default": false,
                     "docs": [
-                        " Gift an asset to a specific player without charging them."
+                        "Emitted when a game mints a unique item to a player."
                     ],
-                    "label": "gift_asset",
-                    "mutates": true,
-                    "payable": true,
-                    "returnType": {
-                        "displayName": [
-                            "ink",
-                            "MessageResult"
-                        ],
-                        "type": 26
-                    },
-                    "selector": "0x6b538a1d"
+                    "label": "ItemMinted",
+                    "module_path": "assets::assets",
+                    "signature_topic": "0xfdb35c67bb96057afeebac0b41ab57e17db2e6291cd6ca99ce8945a4329aee27"
                 },
                 {
                     "args": [
                         {
-                            "label": "asset_give",
+                            "docs": [
+                                "The sender's account."
+                            ],
+                            "indexed": true,
+                            "label": "from",
                             "type": {
                                 "displayName": [
-                                    "String"
+                                    "AccountId"
                                 ],
-                                "type": 0
+                                "type": 4
                             }
                         },
                         {
-                            "label": "units_give",
+                            "docs": [
+                                "The receiver's account."
+                            ],
+                            "indexed": true,
+                            "label": "to",
                             "type": {
                                 "displayName": [
-                                    "i64"
+                                    "AccountId"
                                 ],
-                                "type": 29
+                                "type": 4
                             }
                         },
                         {
-                            "label": "asset_take",
-                            "type": {
+                            "docs": [
+                                "The asset being gifted."
+                            ],
+                            "indexed": false,
+                            "label": "asset",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The quantity of the asset gifted."
+                            ],
+                            "indexed": false,
+                            "label": "count",
+                            "type": {
+                                "displayName": [
+                                    "u64"
+                                ],
+                                "type": 30
+                            }
+                        }
+                    ],
+                    "docs": [
+                        "Emitted when a player gifts an asset to another player."
+                    ],
+                    "label": "AssetGifted",
+                    "module_path": "assets::assets",
+                    "signature_topic": "0x3e3c57f302172bdfe60874ce93cfaee9f98c422abe8ca80f27edbe2d9f0d5a18"
+                },
+                {
+                    "args": [
+                        {
+                            "docs": [
+                                "The account staking."
+                            ],
+                            "indexed": true,
+                            "label": "account",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The game to which the asset belongs."
+                            ],
+                            "indexed": false,
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The asset staked."
+                            ],
+                            "indexed": false,
+                            "label": "asset",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The quantity staked."
+                            ],
+                            "indexed": false,
+                            "label": "count",
+                            "type": {
+                                "displayName": [
+                                    "u64"
+                                ],
+                                "type": 30
+                            }
+                        }
+                    ],
+                    "docs": [
+                        "Emitted when a player locks units of an asset to earn rewards."
+                    ],
+                    "label": "AssetStaked",
+                    "module_path": "assets::assets",
+                    "signature_topic": "0xf9d3ec79f8c3ac271a52332ad354cee4306b53864d05257d314cac81ae299b7a"
+                },
+                {
+                    "args": [
+                        {
+                            "docs": [
+                                "The hash of the coupon code."
+                            ],
+                            "indexed": true,
+                            "label": "code_hash",
+                            "type": {
+                                "displayName": [
+                                    "Hash"
+                                ],
+                                "type": 28
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The game issuing the coupon."
+                            ],
+                            "indexed": false,
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The asset the coupon applies to."
+                            ],
+                            "indexed": false,
+                            "label": "asset",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        }
+                    ],
+                    "docs": [
+                        "Emitted when a game owner issues a coupon for an asset."
+                    ],
+                    "label": "CouponAdded",
+                    "module_path": "assets::assets",
+                    "signature_topic": "0xbee8abb536c1b12c5afb953caa98e702219be22512b756dee6b844c661935cee"
+                },
+                {
+                    "args": [
+                        {
+                            "docs": [
+                                "The flagging game."
+                            ],
+                            "indexed": true,
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The player cleared."
+                            ],
+                            "indexed": true,
+                            "label": "account",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        }
+                    ],
+                    "docs": [
+                        "Emitted when a game owner clears a flag."
+                    ],
+                    "label": "FlagCleared",
+                    "module_path": "assets::assets",
+                    "signature_topic": "0xcee8be19f91191fde9d2b049af080e80093b498aa4f91dec304c50a53cafded5"
+                },
+                {
+                    "args": [
+                        {
+                            "docs": [
+                                "The identifier of the match."
+                            ],
+                            "indexed": true,
+                            "label": "match_id",
+                            "type": {
+                                "displayName": [
+                                    "MatchId"
+                                ],
+                                "type": 27
+                            }
+                        },
+                        {
+                            "docs": [
+                                "Last block at which moves can be revealed."
+                            ],
+                            "indexed": false,
+                            "label": "reveal_deadline",
+                            "type": {
+                                "displayName": [
+                                    "BlockNumber"
+                                ],
+                                "type": 27
+                            }
+                        }
+                    ],
+                    "docs": [
+                        "Emitted when the challenged player accepts a match."
+                    ],
+                    "label": "MatchJoined",
+                    "module_path": "assets::assets",
+                    "signature_topic": "0x2d8831b6b53a7e59c21d06a4f8053afe4ce5b959301fcbfba99090f02957c652"
+                },
+                {
+                    "args": [
+                        {
+                            "docs": [
+                                "The game to which the asset belongs."
+                            ],
+                            "indexed": false,
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The name of the asset added (e.g., weapon, skin, item)."
+                            ],
+                            "indexed": true,
+                            "label": "name",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The asset's price in native token units."
+                            ],
+                            "indexed": false,
+                            "label": "price",
+                            "type": {
+                                "displayName": [
+                                    "Balance"
+                                ],
+                                "type": 1
+                            }
+                        },
+                        {
+                            "docs": [
+                                "Whether the game can freeze and claw back the asset."
+                            ],
+                            "indexed": false,
+                            "label": "recoverable",
+                            "type": {
+                                "displayName": [
+                                    "bool"
+                                ],
+                                "type": 37
+                            }
+                        }
+                    ],
+                    "docs": [
+                        "Event emitted when a new in-game asset is registered on-chain."
+                    ],
+                    "label": "AssetCreated",
+                    "module_path": "assets::assets",
+                    "signature_topic": "0x49f7b3ccf5630c7c09908d11c14416cc0a52e8d9107e13c7534678ad070a2510"
+                },
+                {
+                    "args": [
+                        {
+                            "docs": [
+                                "The flagging game."
+                            ],
+                            "indexed": true,
+                            "label": "game",
+                            "type": {
                                 "displayName": [
                                     "String"
                                 ],
@@ -438,423 +703,21079 @@ export function metadata() {
                             }
                         },
                         {
-                            "label": "units_take",
-                            "type": {
-                                "displayName": [
-                                    "i64"
-                                ],
-                                "type": 29
-                            }
+                            "docs": [
+                                "The flagged player."
+                            ],
+                            "indexed": true,
+                            "label": "account",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        },
+                        {
+                            "docs": [
+                                "Hash of the off-chain appeal."
+                            ],
+                            "indexed": false,
+                            "label": "appeal_hash",
+                            "type": {
+                                "displayName": [
+                                    "Hash"
+                                ],
+                                "type": 28
+                            }
+                        }
+                    ],
+                    "docs": [
+                        "Emitted when a flagged player appeals."
+                    ],
+                    "label": "FlagAppealed",
+                    "module_path": "assets::assets",
+                    "signature_topic": "0x74c6390a72c367a56af08ec7da369ce77cb2c8ac4e6f4aa8cc6b5ba0ec7e3b51"
+                },
+                {
+                    "args": [
+                        {
+                            "docs": [
+                                "The trusting game."
+                            ],
+                            "indexed": true,
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The game being trusted."
+                            ],
+                            "indexed": true,
+                            "label": "trusted_game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "docs": [
+                                "Whether the game is now trusted."
+                            ],
+                            "indexed": false,
+                            "label": "trusted",
+                            "type": {
+                                "displayName": [
+                                    "bool"
+                                ],
+                                "type": 37
+                            }
+                        }
+                    ],
+                    "docs": [
+                        "Emitted when a game starts or stops trusting another game's flags and reputation."
+                    ],
+                    "label": "FlagTrustSet",
+                    "module_path": "assets::assets",
+                    "signature_topic": "0x78a540fdbf0274be3d4eb671096c1ffac08ea931c667b12c988242c7317da4e1"
+                },
+                {
+                    "args": [
+                        {
+                            "docs": [
+                                "The identifier of the new guild."
+                            ],
+                            "indexed": true,
+                            "label": "guild_id",
+                            "type": {
+                                "displayName": [
+                                    "GuildId"
+                                ],
+                                "type": 27
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The guild's name."
+                            ],
+                            "indexed": false,
+                            "label": "name",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The founding leader."
+                            ],
+                            "indexed": true,
+                            "label": "leader",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        }
+                    ],
+                    "docs": [
+                        "Emitted when a player founds a guild."
+                    ],
+                    "label": "GuildCreated",
+                    "module_path": "assets::assets",
+                    "signature_topic": "0x3f781e3b2fe99242ccccabc35a92a0b48d183a750a4eaa5899efd45ce2949613"
+                },
+                {
+                    "args": [
+                        {
+                            "docs": [
+                                "The identifier of the match."
+                            ],
+                            "indexed": true,
+                            "label": "match_id",
+                            "type": {
+                                "displayName": [
+                                    "MatchId"
+                                ],
+                                "type": 27
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The challenging player."
+                            ],
+                            "indexed": true,
+                            "label": "challenger",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The challenged player."
+                            ],
+                            "indexed": true,
+                            "label": "opponent",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        },
+                        {
+                            "docs": [
+                                "What each player stakes."
+                            ],
+                            "indexed": false,
+                            "label": "stake",
+                            "type": {
+                                "displayName": [
+                                    "VaultItem"
+                                ],
+                                "type": 141
+                            }
+                        }
+                    ],
+                    "docs": [
+                        "Emitted when a player challenges another to a wager match."
+                    ],
+                    "label": "MatchCreated",
+                    "module_path": "assets::assets",
+                    "signature_topic": "0x6acc49f9527dea232b240317e3e5d036b740f934d0cfedc3beedb0c3570d3b24"
+                },
+                {
+                    "args": [
+                        {
+                            "docs": [
+                                "The identifier of the match."
+                            ],
+                            "indexed": true,
+                            "label": "match_id",
+                            "type": {
+                                "displayName": [
+                                    "MatchId"
+                                ],
+                                "type": 27
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The player who took the pot, or `None` when the stakes were returned."
+                            ],
+                            "indexed": false,
+                            "label": "winner",
+                            "type": {
+                                "displayName": [
+                                    "Option"
+                                ],
+                                "type": 158
+                            }
+                        }
+                    ],
+                    "docs": [
+                        "Emitted when a match is paid out, refunded or forfeited."
+                    ],
+                    "label": "MatchSettled",
+                    "module_path": "assets::assets",
+                    "signature_topic": "0xe8d249ccc4b7e17dacb262ead7725fd6e89c87c3c8f346ef8e1e701d1c9751a8"
+                },
+                {
+                    "args": [
+                        {
+                            "docs": [
+                                "The identifier of the match."
+                            ],
+                            "indexed": true,
+                            "label": "match_id",
+                            "type": {
+                                "displayName": [
+                                    "MatchId"
+                                ],
+                                "type": 27
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The revealing player."
+                            ],
+                            "indexed": true,
+                            "label": "player",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The revealed move."
+                            ],
+                            "indexed": false,
+                            "label": "hand",
+                            "type": {
+                                "displayName": [
+                                    "Hand"
+                                ],
+                                "type": 187
+                            }
+                        }
+                    ],
+                    "docs": [
+                        "Emitted when a player reveals their committed move."
+                    ],
+                    "label": "MoveRevealed",
+                    "module_path": "assets::assets",
+                    "signature_topic": "0x42c9adf0664a2e763b8483f202ff6d592845f0f69804f2b86fd25c9953840a3f"
+                },
+                {
+                    "args": [
+                        {
+                            "docs": [
+                                "The account whose asset was modified."
+                            ],
+                            "indexed": true,
+                            "label": "account",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The asset affected."
+                            ],
+                            "indexed": false,
+                            "label": "asset",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The updated quantity after modification."
+                            ],
+                            "indexed": false,
+                            "label": "new_count",
+                            "type": {
+                                "displayName": [
+                                    "u64"
+                                ],
+                                "type": 30
+                            }
+                        },
+                        {
+                            "docs": [
+                                "Whether the asset count was increased (`true`) or decreased (`false`)."
+                            ],
+                            "indexed": false,
+                            "label": "increased",
+                            "type": {
+                                "displayName": [
+                                    "bool"
+                                ],
+                                "type": 37
+                            }
+                        }
+                    ],
+                    "docs": [
+                        "Emitted when a player's asset count is modified, by the player or by their game."
+                    ],
+                    "label": "AssetModified",
+                    "module_path": "assets::assets",
+                    "signature_topic": "0xd8996674be17312bddb68654ed1fd64b34dc5f898ad6d734ac093233baa44830"
+                },
+                {
+                    "args": [
+                        {
+                            "docs": [
+                                "The account that sold the units."
+                            ],
+                            "indexed": true,
+                            "label": "account",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The game to which the asset belongs."
+                            ],
+                            "indexed": false,
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The asset sold back."
+                            ],
+                            "indexed": false,
+                            "label": "asset",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The quantity sold back."
+                            ],
+                            "indexed": false,
+                            "label": "count",
+                            "type": {
+                                "displayName": [
+                                    "u64"
+                                ],
+                                "type": 30
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The balance paid out from the curve reserve."
+                            ],
+                            "indexed": false,
+                            "label": "payout",
+                            "type": {
+                                "displayName": [
+                                    "Balance"
+                                ],
+                                "type": 1
+                            }
+                        }
+                    ],
+                    "docs": [
+                        "Emitted when a player sells units back along an asset's price curve."
+                    ],
+                    "label": "AssetSoldBack",
+                    "module_path": "assets::assets",
+                    "signature_topic": "0xcb03564fa27f8a6fd837566248899ee9bd9b7ae6c8b62fc5b4af9817d1960885"
+                },
+                {
+                    "args": [
+                        {
+                            "docs": [
+                                "The account unstaking."
+                            ],
+                            "indexed": true,
+                            "label": "account",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The game to which the asset belongs."
+                            ],
+                            "indexed": false,
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The asset unstaked."
+                            ],
+                            "indexed": false,
+                            "label": "asset",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The quantity unstaked."
+                            ],
+                            "indexed": false,
+                            "label": "count",
+                            "type": {
+                                "displayName": [
+                                    "u64"
+                                ],
+                                "type": 30
+                            }
+                        }
+                    ],
+                    "docs": [
+                        "Emitted when a player unlocks staked units of an asset."
+                    ],
+                    "label": "AssetUnstaked",
+                    "module_path": "assets::assets",
+                    "signature_topic": "0xd4e37932f2d76ea991dd992ecfa6f1b807cc1d70f4ffcb1b4a0eac7946f4ad49"
+                },
+                {
+                    "args": [
+                        {
+                            "docs": [
+                                "The identifier of the new bundle."
+                            ],
+                            "indexed": true,
+                            "label": "bundle_id",
+                            "type": {
+                                "displayName": [
+                                    "BundleId"
+                                ],
+                                "type": 27
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The game selling the bundle."
+                            ],
+                            "indexed": false,
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The price of one bundle."
+                            ],
+                            "indexed": false,
+                            "label": "price",
+                            "type": {
+                                "displayName": [
+                                    "Balance"
+                                ],
+                                "type": 1
+                            }
+                        }
+                    ],
+                    "docs": [
+                        "Emitted when a game owner creates a bundle of assets."
+                    ],
+                    "label": "BundleCreated",
+                    "module_path": "assets::assets",
+                    "signature_topic": "0x4a58e0892ba8fef52ef6c51a9da3f74e6600158dff1bee19b6665c824f63ac75"
+                },
+                {
+                    "args": [
+                        {
+                            "docs": [
+                                "The game whose policy changed."
+                            ],
+                            "indexed": true,
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "docs": [
+                                "Whether transfers from flagged players are refused."
+                            ],
+                            "indexed": false,
+                            "label": "refuse_flagged",
+                            "type": {
+                                "displayName": [
+                                    "bool"
+                                ],
+                                "type": 37
+                            }
+                        }
+                    ],
+                    "docs": [
+                        "Emitted when a game changes whether it accepts assets from flagged players."
+                    ],
+                    "label": "FlagPolicySet",
+                    "module_path": "assets::assets",
+                    "signature_topic": "0x685df4f8ce77ac853194119246654f4108508d0cf14a285a5af0f4965f8c8824"
+                },
+                {
+                    "args": [
+                        {
+                            "docs": [
+                                "The account ID of the newly created player."
+                            ],
+                            "indexed": true,
+                            "label": "account",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The in-game name chosen by the player."
+                            ],
+                            "indexed": false,
+                            "label": "name",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        }
+                    ],
+                    "docs": [
+                        "Event emitted when a new player account is successfully created."
+                    ],
+                    "label": "PlayerCreated",
+                    "module_path": "assets::assets",
+                    "signature_topic": "0x49643ac39dc6ec0902776735685fe52d8e8a672436cf7490f4e73ca23d5dca95"
+                },
+                {
+                    "args": [
+                        {
+                            "docs": [
+                                "The flagging game."
+                            ],
+                            "indexed": true,
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The flagged player."
+                            ],
+                            "indexed": true,
+                            "label": "account",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        },
+                        {
+                            "docs": [
+                                "Hash of the off-chain reason and evidence."
+                            ],
+                            "indexed": false,
+                            "label": "reason_hash",
+                            "type": {
+                                "displayName": [
+                                    "Hash"
+                                ],
+                                "type": 28
+                            }
+                        }
+                    ],
+                    "docs": [
+                        "Emitted when a game owner flags a player for fraud."
+                    ],
+                    "label": "PlayerFlagged",
+                    "module_path": "assets::assets",
+                    "signature_topic": "0xbc26eaacd711cee2f2a82a2ff73a5880d7aaa8811f087c619e607d06fd44d2b1"
+                },
+                {
+                    "args": [
+                        {
+                            "docs": [
+                                "The account ID of the player."
+                            ],
+                            "indexed": true,
+                            "label": "account",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The player's new in-game name."
+                            ],
+                            "indexed": false,
+                            "label": "name",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The player's new avatar URI."
+                            ],
+                            "indexed": false,
+                            "label": "avatar_uri",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        }
+                    ],
+                    "docs": [
+                        "Event emitted when a player changes their profile."
+                    ],
+                    "label": "PlayerUpdated",
+                    "module_path": "assets::assets",
+                    "signature_topic": "0xa6870b54efb10016770cff96026a1bc404dec6fbb99aa556348093f2f41e7e3a"
+                },
+                {
+                    "args": [
+                        {
+                            "docs": [
+                                "The identifier of the sale."
+                            ],
+                            "indexed": true,
+                            "label": "sale_id",
+                            "type": {
+                                "displayName": [
+                                    "SaleId"
+                                ],
+                                "type": 27
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The game running the sale."
+                            ],
+                            "indexed": false,
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The asset on sale."
+                            ],
+                            "indexed": true,
+                            "label": "asset",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The discount applied during the sale."
+                            ],
+                            "indexed": false,
+                            "label": "discount",
+                            "type": {
+                                "displayName": [
+                                    "Discount"
+                                ],
+                                "type": 73
+                            }
+                        },
+                        {
+                            "docs": [
+                                "First block of the sale."
+                            ],
+                            "indexed": false,
+                            "label": "start",
+                            "type": {
+                                "displayName": [
+                                    "BlockNumber"
+                                ],
+                                "type": 27
+                            }
+                        },
+                        {
+                            "docs": [
+                                "Last block of the sale."
+                            ],
+                            "indexed": false,
+                            "label": "end",
+                            "type": {
+                                "displayName": [
+                                    "BlockNumber"
+                                ],
+                                "type": 27
+                            }
+                        }
+                    ],
+                    "docs": [
+                        "Emitted when a game owner schedules a sale on an asset."
+                    ],
+                    "label": "SaleScheduled",
+                    "module_path": "assets::assets",
+                    "signature_topic": "0x53d5e9e79a621d461143dc9514868d414c882d8b2d051016e22f402ab2ac40ea"
+                },
+                {
+                    "args": [
+                        {
+                            "docs": [
+                                "The identifier of the airdrop."
+                            ],
+                            "indexed": true,
+                            "label": "airdrop_id",
+                            "type": {
+                                "displayName": [
+                                    "AirdropId"
+                                ],
+                                "type": 27
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The claiming player."
+                            ],
+                            "indexed": true,
+                            "label": "account",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        },
+                        {
+                            "docs": [
+                                "Units claimed."
+                            ],
+                            "indexed": false,
+                            "label": "count",
+                            "type": {
+                                "displayName": [
+                                    "u64"
+                                ],
+                                "type": 30
+                            }
+                        }
+                    ],
+                    "docs": [
+                        "Emitted when a player claims their share of an airdrop."
+                    ],
+                    "label": "AirdropClaimed",
+                    "module_path": "assets::assets",
+                    "signature_topic": "0x876d3a66de69bb89e180cf619c7e39d5528a87e6cfde193cf3544ee8bc34e70b"
+                },
+                {
+                    "args": [
+                        {
+                            "docs": [
+                                "The identifier of the airdrop."
+                            ],
+                            "indexed": true,
+                            "label": "airdrop_id",
+                            "type": {
+                                "displayName": [
+                                    "AirdropId"
+                                ],
+                                "type": 27
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The game running the airdrop."
+                            ],
+                            "indexed": true,
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The asset dropped."
+                            ],
+                            "indexed": false,
+                            "label": "asset",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "docs": [
+                                "Root of the tree of `(index, account, count)` leaves."
+                            ],
+                            "indexed": false,
+                            "label": "merkle_root",
+                            "type": {
+                                "displayName": [
+                                    "Hash"
+                                ],
+                                "type": 28
+                            }
+                        },
+                        {
+                            "docs": [
+                                "Units escrowed for claims."
+                            ],
+                            "indexed": false,
+                            "label": "total",
+                            "type": {
+                                "displayName": [
+                                    "u64"
+                                ],
+                                "type": 30
+                            }
+                        },
+                        {
+                            "docs": [
+                                "Last block at which claims are accepted."
+                            ],
+                            "indexed": false,
+                            "label": "expires_at",
+                            "type": {
+                                "displayName": [
+                                    "BlockNumber"
+                                ],
+                                "type": 27
+                            }
+                        }
+                    ],
+                    "docs": [
+                        "Emitted when a game owner escrows assets for a merkle airdrop."
+                    ],
+                    "label": "AirdropCreated",
+                    "module_path": "assets::assets",
+                    "signature_topic": "0x355c5f06148e82335883dc2e1150edbe0c9ef0c0f17a2bdbbe87aa3facf2b957"
+                },
+                {
+                    "args": [
+                        {
+                            "docs": [
+                                "The account that initiated the exchange."
+                            ],
+                            "indexed": true,
+                            "label": "account",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The game of the asset being given away."
+                            ],
+                            "indexed": false,
+                            "label": "from_game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The asset being given away."
+                            ],
+                            "indexed": false,
+                            "label": "from_asset",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The game of the asset being received."
+                            ],
+                            "indexed": false,
+                            "label": "to_game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The asset being received."
+                            ],
+                            "indexed": false,
+                            "label": "to_asset",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The quantity of the asset given."
+                            ],
+                            "indexed": false,
+                            "label": "from_count",
+                            "type": {
+                                "displayName": [
+                                    "u64"
+                                ],
+                                "type": 30
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The quantity of the asset received."
+                            ],
+                            "indexed": false,
+                            "label": "to_count",
+                            "type": {
+                                "displayName": [
+                                    "u64"
+                                ],
+                                "type": 30
+                            }
+                        }
+                    ],
+                    "docs": [
+                        "Emitted when a player exchanges one asset for another."
+                    ],
+                    "label": "AssetExchanged",
+                    "module_path": "assets::assets",
+                    "signature_topic": "0x5b9f868b7bbd67e42c21d604759ef0284d93b9e25dcea342b3ac9b76c4474e1a"
+                },
+                {
+                    "args": [
+                        {
+                            "docs": [
+                                "The account that made the purchase."
+                            ],
+                            "indexed": true,
+                            "label": "account",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The name of the asset purchased."
+                            ],
+                            "indexed": false,
+                            "label": "asset",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The quantity of the asset purchased."
+                            ],
+                            "indexed": false,
+                            "label": "count",
+                            "type": {
+                                "displayName": [
+                                    "u64"
+                                ],
+                                "type": 30
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The average price paid per unit, after any discount."
+                            ],
+                            "indexed": false,
+                            "label": "unit_price",
+                            "type": {
+                                "displayName": [
+                                    "Balance"
+                                ],
+                                "type": 1
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The total price paid for the assets."
+                            ],
+                            "indexed": false,
+                            "label": "total_price",
+                            "type": {
+                                "displayName": [
+                                    "Balance"
+                                ],
+                                "type": 1
+                            }
+                        }
+                    ],
+                    "docs": [
+                        "Emitted when a player purchases an asset from the game store."
+                    ],
+                    "label": "AssetPurchased",
+                    "module_path": "assets::assets",
+                    "signature_topic": "0xe7fa31d1acc35fa5f60084df6220cae93e857fa14259c42f4ae16c1078d6f779"
+                },
+                {
+                    "args": [
+                        {
+                            "docs": [
+                                "The game whose reserve changed."
+                            ],
+                            "indexed": true,
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The amount moved."
+                            ],
+                            "indexed": false,
+                            "label": "amount",
+                            "type": {
+                                "displayName": [
+                                    "Balance"
+                                ],
+                                "type": 1
+                            }
+                        },
+                        {
+                            "docs": [
+                                "Whether the amount was deposited (`true`) or withdrawn (`false`)."
+                            ],
+                            "indexed": false,
+                            "label": "deposited",
+                            "type": {
+                                "displayName": [
+                                    "bool"
+                                ],
+                                "type": 37
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The reserve after the change."
+                            ],
+                            "indexed": false,
+                            "label": "reserve",
+                            "type": {
+                                "displayName": [
+                                    "Balance"
+                                ],
+                                "type": 1
+                            }
+                        }
+                    ],
+                    "docs": [
+                        "Emitted when a game owner moves balance into or out of the game's buyback reserve."
+                    ],
+                    "label": "ReserveUpdated",
+                    "module_path": "assets::assets",
+                    "signature_topic": "0xfb9972073ca1c774b4ae4734424c79cbef3e18f10b095958a1d39ebbedb425c0"
+                },
+                {
+                    "args": [
+                        {
+                            "docs": [
+                                "The account claiming."
+                            ],
+                            "indexed": true,
+                            "label": "account",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The game paying the rewards in its currency."
+                            ],
+                            "indexed": false,
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The staked asset that earned the rewards."
+                            ],
+                            "indexed": false,
+                            "label": "asset",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The amount of currency paid."
+                            ],
+                            "indexed": false,
+                            "label": "amount",
+                            "type": {
+                                "displayName": [
+                                    "Balance"
+                                ],
+                                "type": 1
+                            }
+                        }
+                    ],
+                    "docs": [
+                        "Emitted when a player claims staking rewards."
+                    ],
+                    "label": "RewardsClaimed",
+                    "module_path": "assets::assets",
+                    "signature_topic": "0x567c96f611f7e8a85fe379a5215f63ecc5652de7d7fd55f531611be15d0bda6a"
+                },
+                {
+                    "args": [
+                        {
+                            "docs": [
+                                "The guild receiving the deposit."
+                            ],
+                            "indexed": true,
+                            "label": "guild_id",
+                            "type": {
+                                "displayName": [
+                                    "GuildId"
+                                ],
+                                "type": 27
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The depositing member."
+                            ],
+                            "indexed": true,
+                            "label": "account",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        },
+                        {
+                            "docs": [
+                                "What was deposited."
+                            ],
+                            "indexed": false,
+                            "label": "item",
+                            "type": {
+                                "displayName": [
+                                    "VaultItem"
+                                ],
+                                "type": 141
+                            }
+                        }
+                    ],
+                    "docs": [
+                        "Emitted when a member deposits into a guild vault."
+                    ],
+                    "label": "VaultDeposited",
+                    "module_path": "assets::assets",
+                    "signature_topic": "0x0d82bc3c6cbf6aef995ace5c73abaa900bd1530bce87a2685fbb453bf3e8205b"
+                },
+                {
+                    "args": [
+                        {
+                            "docs": [
+                                "The guild concerned."
+                            ],
+                            "indexed": true,
+                            "label": "guild_id",
+                            "type": {
+                                "displayName": [
+                                    "GuildId"
+                                ],
+                                "type": 27
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The identifier of the proposal within the guild."
+                            ],
+                            "indexed": false,
+                            "label": "proposal_id",
+                            "type": {
+                                "displayName": [
+                                    "u32"
+                                ],
+                                "type": 27
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The player receiving the withdrawal."
+                            ],
+                            "indexed": true,
+                            "label": "to",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        },
+                        {
+                            "docs": [
+                                "What was withdrawn."
+                            ],
+                            "indexed": false,
+                            "label": "item",
+                            "type": {
+                                "displayName": [
+                                    "VaultItem"
+                                ],
+                                "type": 141
+                            }
+                        }
+                    ],
+                    "docs": [
+                        "Emitted when an approved withdrawal leaves a guild vault."
+                    ],
+                    "label": "VaultWithdrawn",
+                    "module_path": "assets::assets",
+                    "signature_topic": "0x7a5e14fd0b78b55ec5a2d4f3dc29dcd3bff052c741650eb70f7d64c75985d894"
+                },
+                {
+                    "args": [
+                        {
+                            "docs": [
+                                "The account that made the purchase."
+                            ],
+                            "indexed": true,
+                            "label": "account",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The bundle purchased."
+                            ],
+                            "indexed": true,
+                            "label": "bundle_id",
+                            "type": {
+                                "displayName": [
+                                    "BundleId"
+                                ],
+                                "type": 27
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The number of bundles purchased."
+                            ],
+                            "indexed": false,
+                            "label": "count",
+                            "type": {
+                                "displayName": [
+                                    "u64"
+                                ],
+                                "type": 30
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The total price paid for the bundles."
+                            ],
+                            "indexed": false,
+                            "label": "total_price",
+                            "type": {
+                                "displayName": [
+                                    "Balance"
+                                ],
+                                "type": 1
+                            }
+                        }
+                    ],
+                    "docs": [
+                        "Emitted when a player buys bundles from a game store."
+                    ],
+                    "label": "BundlePurchased",
+                    "module_path": "assets::assets",
+                    "signature_topic": "0x9d19b10e51915fb17704e62c12e3f13cc89c1f26cb6deec25421c57e726eae88"
+                },
+                {
+                    "args": [
+                        {
+                            "docs": [
+                                "The sub-id of the currency."
+                            ],
+                            "indexed": true,
+                            "label": "currency_id",
+                            "type": {
+                                "displayName": [
+                                    "CurrencyId"
+                                ],
+                                "type": 27
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The game issuing the currency."
+                            ],
+                            "indexed": false,
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The ticker of the currency."
+                            ],
+                            "indexed": false,
+                            "label": "symbol",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        }
+                    ],
+                    "docs": [
+                        "Emitted when a game declares its in-game currency."
+                    ],
+                    "label": "CurrencyCreated",
+                    "module_path": "assets::assets",
+                    "signature_topic": "0xff14fd4a69d6b8673cb2ad830347b28f87087313da866479bb98b409383f46dc"
+                },
+                {
+                    "args": [
+                        {
+                            "docs": [
+                                "The game the operator acts for."
+                            ],
+                            "indexed": true,
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The operator, usually a game logic contract."
+                            ],
+                            "indexed": true,
+                            "label": "operator",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        },
+                        {
+                            "docs": [
+                                "Whether the operator is now allowed."
+                            ],
+                            "indexed": false,
+                            "label": "allowed",
+                            "type": {
+                                "displayName": [
+                                    "bool"
+                                ],
+                                "type": 37
+                            }
+                        }
+                    ],
+                    "docs": [
+                        "Emitted when a game owner lets or stops an account from awarding and consuming its assets."
+                    ],
+                    "label": "GameOperatorSet",
+                    "module_path": "assets::assets",
+                    "signature_topic": "0x682385a4d2e33427c96de2aef45c12a9f5693c75bee518d9960fc653e5cf743e"
+                },
+                {
+                    "args": [
+                        {
+                            "docs": [
+                                "The item transferred."
+                            ],
+                            "indexed": true,
+                            "label": "token_id",
+                            "type": {
+                                "displayName": [
+                                    "TokenId"
+                                ],
+                                "type": 30
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The previous owner."
+                            ],
+                            "indexed": true,
+                            "label": "from",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The new owner."
+                            ],
+                            "indexed": true,
+                            "label": "to",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        }
+                    ],
+                    "docs": [
+                        "Emitted when a unique item changes owner."
+                    ],
+                    "label": "ItemTransferred",
+                    "module_path": "assets::assets",
+                    "signature_topic": "0x50f029238f4b17d5720ac63f7ceb70b5e234cbeb5af60b098d8999222f7b6e8d"
+                },
+                {
+                    "args": [
+                        {
+                            "docs": [
+                                "The game to which the asset belongs."
+                            ],
+                            "indexed": false,
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The asset whose metadata was updated."
+                            ],
+                            "indexed": true,
+                            "label": "asset",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        }
+                    ],
+                    "docs": [
+                        "Emitted when the metadata or attributes of an asset are changed by its game owner."
+                    ],
+                    "label": "MetadataUpdated",
+                    "module_path": "assets::assets",
+                    "signature_topic": "0x3885232a393058ff4a059d5def0d17f4c48a5d58a04ba585398ce79f2c97166f"
+                },
+                {
+                    "args": [
+                        {
+                            "docs": [
+                                "The game that issued the voucher."
+                            ],
+                            "indexed": true,
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The player receiving the assets."
+                            ],
+                            "indexed": true,
+                            "label": "player",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The asset awarded."
+                            ],
+                            "indexed": false,
+                            "label": "asset",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The number of units awarded."
+                            ],
+                            "indexed": false,
+                            "label": "count",
+                            "type": {
+                                "displayName": [
+                                    "u64"
+                                ],
+                                "type": 30
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The voucher nonce."
+                            ],
+                            "indexed": false,
+                            "label": "nonce",
+                            "type": {
+                                "displayName": [
+                                    "u64"
+                                ],
+                                "type": 30
+                            }
+                        }
+                    ],
+                    "docs": [
+                        "Emitted when a signed voucher is redeemed."
+                    ],
+                    "label": "VoucherRedeemed",
+                    "module_path": "assets::assets",
+                    "signature_topic": "0xea528e81bd4e41920589c8de9e0c5b8e84615843bd6698f1549b29d95243b594"
+                },
+                {
+                    "args": [
+                        {
+                            "docs": [
+                                "The identifier of the airdrop."
+                            ],
+                            "indexed": true,
+                            "label": "airdrop_id",
+                            "type": {
+                                "displayName": [
+                                    "AirdropId"
+                                ],
+                                "type": 27
+                            }
+                        },
+                        {
+                            "docs": [
+                                "Units returned to the owner."
+                            ],
+                            "indexed": false,
+                            "label": "count",
+                            "type": {
+                                "displayName": [
+                                    "u64"
+                                ],
+                                "type": 30
+                            }
+                        }
+                    ],
+                    "docs": [
+                        "Emitted when a game owner takes back the unclaimed units of an expired airdrop."
+                    ],
+                    "label": "AirdropReclaimed",
+                    "module_path": "assets::assets",
+                    "signature_topic": "0x3361e94870af588f7c659ebeaabd5110eddc914e6494bd18ccb70779cc2270aa"
+                },
+                {
+                    "args": [
+                        {
+                            "docs": [
+                                "The currency approved."
+                            ],
+                            "indexed": true,
+                            "label": "currency_id",
+                            "type": {
+                                "displayName": [
+                                    "CurrencyId"
+                                ],
+                                "type": 27
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The account granting the allowance."
+                            ],
+                            "indexed": true,
+                            "label": "owner",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The account allowed to spend."
+                            ],
+                            "indexed": true,
+                            "label": "spender",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The new allowance."
+                            ],
+                            "indexed": false,
+                            "label": "amount",
+                            "type": {
+                                "displayName": [
+                                    "Balance"
+                                ],
+                                "type": 1
+                            }
+                        }
+                    ],
+                    "docs": [
+                        "Emitted when an allowance of an in-game currency is set, PSP22 style."
+                    ],
+                    "label": "CurrencyApproval",
+                    "module_path": "assets::assets",
+                    "signature_topic": "0x377267e2416aef21c2f08df6fc1c9963731c10c879ffaaa98a9c09f5695aed53"
+                },
+                {
+                    "args": [
+                        {
+                            "docs": [
+                                "The currency transferred."
+                            ],
+                            "indexed": true,
+                            "label": "currency_id",
+                            "type": {
+                                "displayName": [
+                                    "CurrencyId"
+                                ],
+                                "type": 27
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The account sending the units."
+                            ],
+                            "indexed": true,
+                            "label": "from",
+                            "type": {
+                                "displayName": [
+                                    "Option"
+                                ],
+                                "type": 158
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The account receiving the units."
+                            ],
+                            "indexed": true,
+                            "label": "to",
+                            "type": {
+                                "displayName": [
+                                    "Option"
+                                ],
+                                "type": 158
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The amount transferred."
+                            ],
+                            "indexed": false,
+                            "label": "value",
+                            "type": {
+                                "displayName": [
+                                    "Balance"
+                                ],
+                                "type": 1
+                            }
+                        }
+                    ],
+                    "docs": [
+                        "Emitted when units of an in-game currency move, PSP22 style.",
+                        "Mints have no `from` and burns have no `to`."
+                    ],
+                    "label": "CurrencyTransfer",
+                    "module_path": "assets::assets",
+                    "signature_topic": "0xb08d4312130391d3c913efa23814d46e63063c6a398f255c841453cef08c1daf"
+                },
+                {
+                    "args": [
+                        {
+                            "docs": [
+                                "The item whose state changed."
+                            ],
+                            "indexed": true,
+                            "label": "token_id",
+                            "type": {
+                                "displayName": [
+                                    "TokenId"
+                                ],
+                                "type": 30
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The state slot written (e.g., \"durability\")."
+                            ],
+                            "indexed": false,
+                            "label": "key",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The new value of the slot."
+                            ],
+                            "indexed": false,
+                            "label": "value",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The item's state version after the update."
+                            ],
+                            "indexed": false,
+                            "label": "version",
+                            "type": {
+                                "displayName": [
+                                    "u32"
+                                ],
+                                "type": 27
+                            }
+                        },
+                        {
+                            "docs": [
+                                "Hash chaining every update applied to the item so far."
+                            ],
+                            "indexed": false,
+                            "label": "state_hash",
+                            "type": {
+                                "displayName": [
+                                    "Hash"
+                                ],
+                                "type": 28
+                            }
+                        }
+                    ],
+                    "docs": [
+                        "Emitted when a state slot of a unique item is written."
+                    ],
+                    "label": "ItemStateUpdated",
+                    "module_path": "assets::assets",
+                    "signature_topic": "0x027e4eae360c18580cdac8095e583fbb599e86d1fb038f314592452b99d32b8d"
+                },
+                {
+                    "args": [
+                        {
+                            "docs": [
+                                "The game whose signer changed."
+                            ],
+                            "indexed": true,
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The new signing key, if any."
+                            ],
+                            "indexed": false,
+                            "label": "signer",
+                            "type": {
+                                "displayName": [
+                                    "Option"
+                                ],
+                                "type": 159
+                            }
+                        }
+                    ],
+                    "docs": [
+                        "Emitted when a game owner sets or clears the key that signs its vouchers."
+                    ],
+                    "label": "VoucherSignerSet",
+                    "module_path": "assets::assets",
+                    "signature_topic": "0x81b91c51ee95f90ff58f7d2a44fde6534af256e33461cf251fe1cba4ff411dd1"
+                },
+                {
+                    "args": [
+                        {
+                            "docs": [
+                                "The game to which the asset belongs."
+                            ],
+                            "indexed": false,
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The asset whose price changed."
+                            ],
+                            "indexed": true,
+                            "label": "asset",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The new price."
+                            ],
+                            "indexed": false,
+                            "label": "price",
+                            "type": {
+                                "displayName": [
+                                    "Balance"
+                                ],
+                                "type": 1
+                            }
+                        }
+                    ],
+                    "docs": [
+                        "Emitted when a game owner changes the catalog price of an asset."
+                    ],
+                    "label": "AssetPriceUpdated",
+                    "module_path": "assets::assets",
+                    "signature_topic": "0xbdae52df3f32246a63649690354cc67a1a87ea4d60971356da3ed4f830466ec7"
+                },
+                {
+                    "args": [
+                        {
+                            "docs": [
+                                "The game to which the asset belongs."
+                            ],
+                            "indexed": false,
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The asset whose pricing changed."
+                            ],
+                            "indexed": true,
+                            "label": "asset",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The new curve, or `None` to return to the fixed catalog price."
+                            ],
+                            "indexed": false,
+                            "label": "curve",
+                            "type": {
+                                "displayName": [
+                                    "Option"
+                                ],
+                                "type": 156
+                            }
+                        }
+                    ],
+                    "docs": [
+                        "Emitted when a game owner sets or clears the price curve of an asset."
+                    ],
+                    "label": "PriceCurveUpdated",
+                    "module_path": "assets::assets",
+                    "signature_topic": "0x4ff3bb583c31f5a75208e83036772e710a5dc271019d052e970fb956031f9360"
+                },
+                {
+                    "args": [
+                        {
+                            "docs": [
+                                "The game rating the player."
+                            ],
+                            "indexed": true,
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The player rated."
+                            ],
+                            "indexed": true,
+                            "label": "account",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The player's reputation with the game."
+                            ],
+                            "indexed": false,
+                            "label": "score",
+                            "type": {
+                                "displayName": [
+                                    "i32"
+                                ],
+                                "type": 152
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The player's reputation summed across the games counted globally."
+                            ],
+                            "indexed": false,
+                            "label": "global_score",
+                            "type": {
+                                "displayName": [
+                                    "i64"
+                                ],
+                                "type": 314
+                            }
+                        }
+                    ],
+                    "docs": [
+                        "Emitted when a game changes a player's reputation."
+                    ],
+                    "label": "ReputationChanged",
+                    "module_path": "assets::assets",
+                    "signature_topic": "0x6e6748575a7aa304c4a0b1bf4e5adab2848444a4abfda67819fc52ff74241085"
+                },
+                {
+                    "args": [
+                        {
+                            "docs": [
+                                "The game."
+                            ],
+                            "indexed": true,
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "docs": [
+                                "Whether the game's reputation counts towards global reputation."
+                            ],
+                            "indexed": false,
+                            "label": "counted",
+                            "type": {
+                                "displayName": [
+                                    "bool"
+                                ],
+                                "type": 37
+                            }
+                        }
+                    ],
+                    "docs": [
+                        "Emitted when the contract admin counts a game in global reputation or stops counting it."
+                    ],
+                    "label": "ReputationGameSet",
+                    "module_path": "assets::assets",
+                    "signature_topic": "0x4f23af897838241f93834dc3469922888e13969e95aa8c464f3a51454200d996"
+                },
+                {
+                    "args": [
+                        {
+                            "docs": [
+                                "The identifier of the tournament."
+                            ],
+                            "indexed": true,
+                            "label": "tournament_id",
+                            "type": {
+                                "displayName": [
+                                    "TournamentId"
+                                ],
+                                "type": 27
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The game running the tournament."
+                            ],
+                            "indexed": true,
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The tournament terms."
+                            ],
+                            "indexed": false,
+                            "label": "terms",
+                            "type": {
+                                "displayName": [
+                                    "TournamentTerms"
+                                ],
+                                "type": 157
+                            }
+                        }
+                    ],
+                    "docs": [
+                        "Emitted when a game owner opens a tournament."
+                    ],
+                    "label": "TournamentCreated",
+                    "module_path": "assets::assets",
+                    "signature_topic": "0x18ac0aa918cd3cab4c02713955c17d964d1c6731c0e8a64655b94a04f7f3cdd7"
+                },
+                {
+                    "args": [
+                        {
+                            "docs": [
+                                "The identifier of the tournament."
+                            ],
+                            "indexed": true,
+                            "label": "tournament_id",
+                            "type": {
+                                "displayName": [
+                                    "TournamentId"
+                                ],
+                                "type": 27
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The entrant."
+                            ],
+                            "indexed": true,
+                            "label": "account",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        }
+                    ],
+                    "docs": [
+                        "Emitted when a player pays the entry fee of a tournament."
+                    ],
+                    "label": "TournamentEntered",
+                    "module_path": "assets::assets",
+                    "signature_topic": "0x1f8f9a7fdd19b70c402f314f6a5525419cc50e441b08f9af2468ebfc3497d894"
+                },
+                {
+                    "args": [
+                        {
+                            "docs": [
+                                "The game defining the achievement."
+                            ],
+                            "indexed": true,
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The achievement identifier within the game."
+                            ],
+                            "indexed": false,
+                            "label": "achievement",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "docs": [
+                                "Points added to the gamer score of players who earn it."
+                            ],
+                            "indexed": false,
+                            "label": "points",
+                            "type": {
+                                "displayName": [
+                                    "u32"
+                                ],
+                                "type": 27
+                            }
+                        }
+                    ],
+                    "docs": [
+                        "Emitted when a game defines an achievement."
+                    ],
+                    "label": "AchievementDefined",
+                    "module_path": "assets::assets",
+                    "signature_topic": "0x0f193b8cd455524f52c2d80113a78ea5ae3bcf5b6dced37581210a69fdab241a"
+                },
+                {
+                    "args": [
+                        {
+                            "docs": [
+                                "The player earning the achievement."
+                            ],
+                            "indexed": true,
+                            "label": "account",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The game granting the achievement."
+                            ],
+                            "indexed": true,
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The achievement identifier within the game."
+                            ],
+                            "indexed": false,
+                            "label": "achievement",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The player's gamer score after the grant."
+                            ],
+                            "indexed": false,
+                            "label": "gamer_score",
+                            "type": {
+                                "displayName": [
+                                    "u64"
+                                ],
+                                "type": 30
+                            }
+                        }
+                    ],
+                    "docs": [
+                        "Emitted when a player earns an achievement."
+                    ],
+                    "label": "AchievementGranted",
+                    "module_path": "assets::assets",
+                    "signature_topic": "0xabb506224da2c36b7069f85757c4cf8eea2ca66a57f958262fa267692f7f6ef4"
+                },
+                {
+                    "args": [
+                        {
+                            "docs": [
+                                "The identifier of the proposal."
+                            ],
+                            "indexed": true,
+                            "label": "proposal_id",
+                            "type": {
+                                "displayName": [
+                                    "u32"
+                                ],
+                                "type": 27
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The approving signer."
+                            ],
+                            "indexed": true,
+                            "label": "signer",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        }
+                    ],
+                    "docs": [
+                        "Emitted when a signer approves a game owner action."
+                    ],
+                    "label": "GameActionApproved",
+                    "module_path": "assets::assets",
+                    "signature_topic": "0x69f3e24916a50f10296cb16232802f3188331e11d3e9af0b2306eb3fdded089f"
+                },
+                {
+                    "args": [
+                        {
+                            "docs": [
+                                "The identifier of the proposal."
+                            ],
+                            "indexed": true,
+                            "label": "proposal_id",
+                            "type": {
+                                "displayName": [
+                                    "u32"
+                                ],
+                                "type": 27
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The game the action applied to."
+                            ],
+                            "indexed": true,
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        }
+                    ],
+                    "docs": [
+                        "Emitted when an approved game owner action is carried out."
+                    ],
+                    "label": "GameActionExecuted",
+                    "module_path": "assets::assets",
+                    "signature_topic": "0x2b24c31983c1a8aa4f0dad7c8fdeaa6cd61791230f58cc3a99122887441a9c5a"
+                },
+                {
+                    "args": [
+                        {
+                            "docs": [
+                                "The identifier of the proposal."
+                            ],
+                            "indexed": true,
+                            "label": "proposal_id",
+                            "type": {
+                                "displayName": [
+                                    "u32"
+                                ],
+                                "type": 27
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The game the action applies to."
+                            ],
+                            "indexed": true,
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The proposed action."
+                            ],
+                            "indexed": false,
+                            "label": "action",
+                            "type": {
+                                "displayName": [
+                                    "GameAction"
+                                ],
+                                "type": 155
+                            }
+                        },
+                        {
+                            "docs": [
+                                "Last block at which the proposal can be executed."
+                            ],
+                            "indexed": false,
+                            "label": "expires_at",
+                            "type": {
+                                "displayName": [
+                                    "BlockNumber"
+                                ],
+                                "type": 27
+                            }
+                        }
+                    ],
+                    "docs": [
+                        "Emitted when a signer proposes a game owner action."
+                    ],
+                    "label": "GameActionProposed",
+                    "module_path": "assets::assets",
+                    "signature_topic": "0x9254c5be4dce3394c4411d97c05b4f871c73b04a098a6b2f2434b3365b8f8808"
+                },
+                {
+                    "args": [
+                        {
+                            "docs": [
+                                "The guild concerned."
+                            ],
+                            "indexed": true,
+                            "label": "guild_id",
+                            "type": {
+                                "displayName": [
+                                    "GuildId"
+                                ],
+                                "type": 27
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The member concerned."
+                            ],
+                            "indexed": true,
+                            "label": "account",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The member's new role, or `None` if they left the guild."
+                            ],
+                            "indexed": false,
+                            "label": "role",
+                            "type": {
+                                "displayName": [
+                                    "Option"
+                                ],
+                                "type": 316
+                            }
+                        }
+                    ],
+                    "docs": [
+                        "Emitted when a guild member joins, changes role or leaves."
+                    ],
+                    "label": "GuildMemberUpdated",
+                    "module_path": "assets::assets",
+                    "signature_topic": "0xd88f0f473ba35ec42a8726485d2b9fe6ca78a8d38c44b7ca2c5c15bc662d363b"
+                },
+                {
+                    "args": [
+                        {
+                            "docs": [
+                                "The account ID of the removed player."
+                            ],
+                            "indexed": true,
+                            "label": "account",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        },
+                        {
+                            "docs": [
+                                "Whether remaining holdings were burned with the account."
+                            ],
+                            "indexed": false,
+                            "label": "burned",
+                            "type": {
+                                "displayName": [
+                                    "bool"
+                                ],
+                                "type": 37
+                            }
+                        }
+                    ],
+                    "docs": [
+                        "Event emitted when a player account is removed."
+                    ],
+                    "label": "PlayerDeregistered",
+                    "module_path": "assets::assets",
+                    "signature_topic": "0xa59a4faaa89368bce8d150619b0f3023a9dc8372c231a4a98e2823e53576e8ab"
+                },
+                {
+                    "args": [
+                        {
+                            "docs": [
+                                "The player who signed the action."
+                            ],
+                            "indexed": true,
+                            "label": "player",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The account that submitted it."
+                            ],
+                            "indexed": true,
+                            "label": "relayer",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The player nonce consumed."
+                            ],
+                            "indexed": false,
+                            "label": "nonce",
+                            "type": {
+                                "displayName": [
+                                    "u64"
+                                ],
+                                "type": 30
+                            }
+                        },
+                        {
+                            "docs": [
+                                "Balance paid by the player to the relayer."
+                            ],
+                            "indexed": false,
+                            "label": "fee",
+                            "type": {
+                                "displayName": [
+                                    "Balance"
+                                ],
+                                "type": 1
+                            }
+                        }
+                    ],
+                    "docs": [
+                        "Emitted when a relayer submits an action signed by a player."
+                    ],
+                    "label": "SignedCallExecuted",
+                    "module_path": "assets::assets",
+                    "signature_topic": "0x195645bc19ac30b18ec9b004074d5b00e335545bcb61326b2be1d425fac9d754"
+                },
+                {
+                    "args": [
+                        {
+                            "docs": [
+                                "The guild concerned."
+                            ],
+                            "indexed": true,
+                            "label": "guild_id",
+                            "type": {
+                                "displayName": [
+                                    "GuildId"
+                                ],
+                                "type": 27
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The identifier of the proposal within the guild."
+                            ],
+                            "indexed": false,
+                            "label": "proposal_id",
+                            "type": {
+                                "displayName": [
+                                    "u32"
+                                ],
+                                "type": 27
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The approving leader or officer."
+                            ],
+                            "indexed": true,
+                            "label": "approver",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        }
+                    ],
+                    "docs": [
+                        "Emitted when a leader or officer approves a vault withdrawal."
+                    ],
+                    "label": "WithdrawalApproved",
+                    "module_path": "assets::assets",
+                    "signature_topic": "0xfc2fcbe0aaad3356d5029b52eaf68a78ad04d19cd053d275c47df0e4820aeb9b"
+                },
+                {
+                    "args": [
+                        {
+                            "docs": [
+                                "The guild concerned."
+                            ],
+                            "indexed": true,
+                            "label": "guild_id",
+                            "type": {
+                                "displayName": [
+                                    "GuildId"
+                                ],
+                                "type": 27
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The identifier of the proposal within the guild."
+                            ],
+                            "indexed": false,
+                            "label": "proposal_id",
+                            "type": {
+                                "displayName": [
+                                    "u32"
+                                ],
+                                "type": 27
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The player who would receive the withdrawal."
+                            ],
+                            "indexed": false,
+                            "label": "to",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        },
+                        {
+                            "docs": [
+                                "What would be withdrawn."
+                            ],
+                            "indexed": false,
+                            "label": "item",
+                            "type": {
+                                "displayName": [
+                                    "VaultItem"
+                                ],
+                                "type": 141
+                            }
+                        }
+                    ],
+                    "docs": [
+                        "Emitted when a member proposes taking something out of a guild vault."
+                    ],
+                    "label": "WithdrawalProposed",
+                    "module_path": "assets::assets",
+                    "signature_topic": "0x5a0c706ad44c71ad2d9b433f9a62d5aad55fbc4d39fe614c8a69ff511ede94e9"
+                },
+                {
+                    "args": [
+                        {
+                            "docs": [
+                                "The identifier of the tournament."
+                            ],
+                            "indexed": true,
+                            "label": "tournament_id",
+                            "type": {
+                                "displayName": [
+                                    "TournamentId"
+                                ],
+                                "type": 27
+                            }
+                        }
+                    ],
+                    "docs": [
+                        "Emitted when a tournament is cancelled and its entry fees refunded."
+                    ],
+                    "label": "TournamentCancelled",
+                    "module_path": "assets::assets",
+                    "signature_topic": "0x2459278a500482c1a0dea16405f8c8dd95858193d7483f3e027b2925633cddd2"
+                },
+                {
+                    "args": [
+                        {
+                            "docs": [
+                                "The identifier of the tournament."
+                            ],
+                            "indexed": true,
+                            "label": "tournament_id",
+                            "type": {
+                                "displayName": [
+                                    "TournamentId"
+                                ],
+                                "type": 27
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The ranked players, winner first."
+                            ],
+                            "indexed": false,
+                            "label": "ranking",
+                            "type": {
+                                "displayName": [
+                                    "Vec"
+                                ],
+                                "type": 137
+                            }
+                        }
+                    ],
+                    "docs": [
+                        "Emitted when the results of a tournament are submitted and its prizes paid."
+                    ],
+                    "label": "TournamentCompleted",
+                    "module_path": "assets::assets",
+                    "signature_topic": "0x55f9093c51907d28585174ba6ab108e64906e347832b728810e82151632088ce"
+                },
+                {
+                    "args": [
+                        {
+                            "docs": [
+                                "The game to which the asset belongs."
+                            ],
+                            "indexed": false,
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The asset whose price currency changed."
+                            ],
+                            "indexed": true,
+                            "label": "asset",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The new price currency."
+                            ],
+                            "indexed": false,
+                            "label": "currency",
+                            "type": {
+                                "displayName": [
+                                    "PriceCurrency"
+                                ],
+                                "type": 118
+                            }
+                        }
+                    ],
+                    "docs": [
+                        "Emitted when a game owner changes the currency an asset is priced in."
+                    ],
+                    "label": "PriceCurrencyUpdated",
+                    "module_path": "assets::assets",
+                    "signature_topic": "0xe01b73ef0eb3e1031d2d4660ef659cab28fd84dd16c7aa789bbafeb7084a0a1e"
+                },
+                {
+                    "args": [
+                        {
+                            "docs": [
+                                "The game of the asset given in the conversion."
+                            ],
+                            "indexed": true,
+                            "label": "from_game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The asset given in the conversion."
+                            ],
+                            "indexed": false,
+                            "label": "from_asset",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The game of the asset received in the conversion."
+                            ],
+                            "indexed": true,
+                            "label": "to_game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "docs": [
+                                "The asset received in the conversion."
+                            ],
+                            "indexed": false,
+                            "label": "to_asset",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "docs": [
+                                "Whether both game owners have approved the route and players can use it."
+                            ],
+                            "indexed": false,
+                            "label": "active",
+                            "type": {
+                                "displayName": [
+                                    "bool"
+                                ],
+                                "type": 37
+                            }
+                        }
+                    ],
+                    "docs": [
+                        "Emitted when a game owner proposes, approves or revokes a conversion route."
+                    ],
+                    "label": "ConversionRuleUpdated",
+                    "module_path": "assets::assets",
+                    "signature_topic": "0x6b2a4c39a0afd41b1845abc25340c7ec31c41d4d4bb6c0cc5fe2e0defef307ea"
+                }
+            ],
+            "lang_error": {
+                "displayName": [
+                    "ink",
+                    "LangError"
+                ],
+                "type": 242
+            },
+            "messages": [
+                {
+                    "args": [
+                        {
+                            "label": "name",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Register a playing account across the network.",
+                        " Players are endowed with 1M units of tokens at their first registration to buy game assets."
+                    ],
+                    "label": "register_player",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 243
+                    },
+                    "selector": "0x44c9d826"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "name",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "avatar_uri",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Change the caller's display name and avatar."
+                    ],
+                    "label": "update_profile",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 243
+                    },
+                    "selector": "0x08974eaf"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "burn",
+                            "type": {
+                                "displayName": [
+                                    "bool"
+                                ],
+                                "type": 37
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Remove the caller's player account and release their display name.",
+                        " Fails while the player holds assets unless `burn` is set, in which case they are destroyed.",
+                        " Stakes, currency balances, guild memberships, frozen holdings and open matches or",
+                        " tournaments must be settled first."
+                    ],
+                    "label": "deregister_player",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 243
+                    },
+                    "selector": "0xe41ad820"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "name",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Returns the account that reserved a display name"
+                    ],
+                    "label": "account_of",
+                    "mutates": false,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 246
+                    },
+                    "selector": "0xa22e107b"
+                },
+                {
+                    "args": [],
+                    "default": false,
+                    "docs": [
+                        " Returns the caller's player account."
+                    ],
+                    "label": "auth_player",
+                    "mutates": false,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 247
+                    },
+                    "selector": "0x34928eec"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "account",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Returns the player account related to an `AccountId`."
+                    ],
+                    "label": "player",
+                    "mutates": false,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 247
+                    },
+                    "selector": "0x404d6adf"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "asset",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "start",
+                            "type": {
+                                "displayName": [
+                                    "u32"
+                                ],
+                                "type": 27
+                            }
+                        },
+                        {
+                            "label": "limit",
+                            "type": {
+                                "displayName": [
+                                    "u32"
+                                ],
+                                "type": 27
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Returns up to `limit` accounts holding an asset, starting at index `start`.",
+                        " Positions are reused when holders leave, so the order is not stable."
+                    ],
+                    "label": "holders_of",
+                    "mutates": false,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 249
+                    },
+                    "selector": "0x92b27288"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "asset",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Returns how many accounts hold an asset."
+                    ],
+                    "label": "holder_count",
+                    "mutates": false,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 250
+                    },
+                    "selector": "0xce83a421"
+                },
+                {
+                    "args": [],
+                    "default": false,
+                    "docs": [
+                        " Returns the registered games"
+                    ],
+                    "label": "games",
+                    "mutates": false,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 251
+                    },
+                    "selector": "0x4c1db0e3"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "name",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "price",
+                            "type": {
+                                "displayName": [
+                                    "Balance"
+                                ],
+                                "type": 1
+                            }
+                        },
+                        {
+                            "label": "recoverable",
+                            "type": {
+                                "displayName": [
+                                    "bool"
+                                ],
+                                "type": 37
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Register an asset.",
+                        " The first account to register an asset for a game becomes the owner of that game.",
+                        " Asset names are unique across all games, since holdings are kept by name.",
+                        " Only assets registered as `recoverable` can later be frozen and clawed back.",
+                        " Since a catalog entry sets a price, games with signers add assets through a",
+                        " `RegisterAsset` proposal."
+                    ],
+                    "label": "register_asset",
+                    "mutates": true,
+                    "payable": true,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 243
+                    },
+                    "selector": "0x763156d2"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Returns the owner of a game"
+                    ],
+                    "label": "game_owner",
+                    "mutates": false,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 246
+                    },
+                    "selector": "0x4f107c27"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Get assets belonging to a game"
+                    ],
+                    "label": "assets",
+                    "mutates": false,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 252
+                    },
+                    "selector": "0xc47d7428"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "asset",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "description",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "uri",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "rarity",
+                            "type": {
+                                "displayName": [
+                                    "Rarity"
+                                ],
+                                "type": 24
+                            }
+                        },
+                        {
+                            "label": "category",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Set the descriptive metadata of an asset. Only the game owner can do this."
+                    ],
+                    "label": "set_metadata",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 243
+                    },
+                    "selector": "0x0b787bb5"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "asset",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "attribute",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "value",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Set (or overwrite) a single attribute of an asset. Only the game owner can do this."
+                    ],
+                    "label": "set_attribute",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 243
+                    },
+                    "selector": "0x12b9f661"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "asset",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "attribute",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Remove an attribute from an asset. Only the game owner can do this."
+                    ],
+                    "label": "remove_attribute",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 243
+                    },
+                    "selector": "0x900319c6"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "asset",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Returns the metadata of an asset"
+                    ],
+                    "label": "metadata",
+                    "mutates": false,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 254
+                    },
+                    "selector": "0x6394e97a"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "asset",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "owner",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Mint a unique item of a catalog asset to a player. Only the game owner can do this",
+                        " directly; games with signers use a `MintItem` proposal."
+                    ],
+                    "label": "mint_item",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 256
+                    },
+                    "selector": "0x4d8ff07e"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "token_id",
+                            "type": {
+                                "displayName": [
+                                    "TokenId"
+                                ],
+                                "type": 30
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Returns a unique item and its current state"
+                    ],
+                    "label": "item",
+                    "mutates": false,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 258
+                    },
+                    "selector": "0x74b83752"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "token_id",
+                            "type": {
+                                "displayName": [
+                                    "TokenId"
+                                ],
+                                "type": 30
+                            }
+                        },
+                        {
+                            "label": "to",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Transfer a unique item owned by the caller to another player."
+                    ],
+                    "label": "transfer_item",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 243
+                    },
+                    "selector": "0x978cfc05"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "editor",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Allow the owner and operators of `editor` to update the state of items issued by",
+                        " `game`."
+                    ],
+                    "label": "approve_state_editor",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 243
+                    },
+                    "selector": "0x36acd959"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "editor",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Revoke a game's permission to update the state of items issued by `game`."
+                    ],
+                    "label": "revoke_state_editor",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 243
+                    },
+                    "selector": "0xaf132c35"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "token_id",
+                            "type": {
+                                "displayName": [
+                                    "TokenId"
+                                ],
+                                "type": 30
+                            }
+                        },
+                        {
+                            "label": "key",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "value",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Write a state slot of a unique item. Only the owner or an operator of the issuing",
+                        " game, or of a game approved by it, can do this."
+                    ],
+                    "label": "update_item_state",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 243
+                    },
+                    "selector": "0x5b59eb1d"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "asset",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "count",
+                            "type": {
+                                "displayName": [
+                                    "u64"
+                                ],
+                                "type": 30
+                            }
+                        },
+                        {
+                            "label": "coupon",
+                            "type": {
+                                "displayName": [
+                                    "Option"
+                                ],
+                                "type": 260
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Buy units of an asset, deducting its price from the player's balance.",
+                        " The best of any running sale and the optional coupon code is applied."
+                    ],
+                    "label": "purchase_asset",
+                    "mutates": true,
+                    "payable": true,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 243
+                    },
+                    "selector": "0x907a5f68"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "receiver",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        },
+                        {
+                            "label": "asset",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "amount",
+                            "type": {
+                                "displayName": [
+                                    "u64"
+                                ],
+                                "type": 30
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Gift an asset to a specific player without charging them.",
+                        " A receiving contract can reject the gift through its `on_asset_received` hook."
+                    ],
+                    "label": "gift_asset",
+                    "mutates": true,
+                    "payable": true,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 243
+                    },
+                    "selector": "0x6b538a1d"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "route",
+                            "type": {
+                                "displayName": [
+                                    "ConversionRoute"
+                                ],
+                                "type": 39
+                            }
+                        },
+                        {
+                            "label": "give_units",
+                            "type": {
+                                "displayName": [
+                                    "u64"
+                                ],
+                                "type": 30
+                            }
+                        },
+                        {
+                            "label": "take_units",
+                            "type": {
+                                "displayName": [
+                                    "u64"
+                                ],
+                                "type": 30
+                            }
+                        },
+                        {
+                            "label": "cap_per_period",
+                            "type": {
+                                "displayName": [
+                                    "u64"
+                                ],
+                                "type": 30
+                            }
+                        },
+                        {
+                            "label": "period",
+                            "type": {
+                                "displayName": [
+                                    "BlockNumber"
+                                ],
+                                "type": 27
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Propose or approve a conversion route between an asset of `from_game` and an asset",
+                        " of `to_game`. Either game owner can call this; the route becomes active once both",
+                        " owners have submitted identical terms. Submitting different terms resets approvals.",
+                        " Games with signers submit their side through a `ProposeConversion` proposal."
+                    ],
+                    "label": "propose_conversion",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 243
+                    },
+                    "selector": "0x661bee75"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "route",
+                            "type": {
+                                "displayName": [
+                                    "ConversionRoute"
+                                ],
+                                "type": 39
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Remove a conversion route. Either game owner can do this."
+                    ],
+                    "label": "revoke_conversion",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 243
+                    },
+                    "selector": "0x6f01320d"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "route",
+                            "type": {
+                                "displayName": [
+                                    "ConversionRoute"
+                                ],
+                                "type": 39
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Returns the conversion terms of a route"
+                    ],
+                    "label": "conversion_rule",
+                    "mutates": false,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 261
+                    },
+                    "selector": "0x384aa5f2"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "from_game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "asset_give",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "units_give",
+                            "type": {
+                                "displayName": [
+                                    "u64"
+                                ],
+                                "type": 30
+                            }
+                        },
+                        {
+                            "label": "to_game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "asset_take",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Convert units of an asset into another asset along a route approved by both games."
+                    ],
+                    "label": "exchange_asset",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 243
+                    },
+                    "selector": "0xe9af9c8a"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "asset",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "count",
+                            "type": {
+                                "displayName": [
+                                    "u64"
+                                ],
+                                "type": 30
+                            }
+                        },
+                        {
+                            "label": "increase",
+                            "type": {
+                                "displayName": [
+                                    "bool"
+                                ],
+                                "type": 37
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Modify assets owned by the caller. Only the owner or an operator of the asset's game",
+                        " can increase their own count; any player can burn units they hold."
+                    ],
+                    "label": "modify_asset",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 243
+                    },
+                    "selector": "0xe55730b9"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "contents",
+                            "type": {
+                                "displayName": [
+                                    "Vec"
+                                ],
+                                "type": 60
+                            }
+                        },
+                        {
+                            "label": "price",
+                            "type": {
+                                "displayName": [
+                                    "Balance"
+                                ],
+                                "type": 1
+                            }
+                        },
+                        {
+                            "label": "max_per_player",
+                            "type": {
+                                "displayName": [
+                                    "u64"
+                                ],
+                                "type": 30
+                            }
+                        },
+                        {
+                            "label": "available_from",
+                            "type": {
+                                "displayName": [
+                                    "BlockNumber"
+                                ],
+                                "type": 27
+                            }
+                        },
+                        {
+                            "label": "available_until",
+                            "type": {
+                                "displayName": [
+                                    "Option"
+                                ],
+                                "type": 64
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Create a bundle of assets sold together at a single price. Every asset must belong to",
+                        " the game. Only the game owner can do this directly; games with signers use a",
+                        " `CreateBundle` proposal."
+                    ],
+                    "label": "create_bundle",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 263
+                    },
+                    "selector": "0xbd9dc26c"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "bundle_id",
+                            "type": {
+                                "displayName": [
+                                    "BundleId"
+                                ],
+                                "type": 27
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Take a bundle off sale. Only the game owner can do this."
+                    ],
+                    "label": "remove_bundle",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 243
+                    },
+                    "selector": "0xb0173b7d"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "bundle_id",
+                            "type": {
+                                "displayName": [
+                                    "BundleId"
+                                ],
+                                "type": 27
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Returns a bundle definition"
+                    ],
+                    "label": "bundle",
+                    "mutates": false,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 265
+                    },
+                    "selector": "0x2d2c706a"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "bundle_id",
+                            "type": {
+                                "displayName": [
+                                    "BundleId"
+                                ],
+                                "type": 27
+                            }
+                        },
+                        {
+                            "label": "count",
+                            "type": {
+                                "displayName": [
+                                    "u64"
+                                ],
+                                "type": 30
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Buy bundles, crediting all of their contents at once."
+                    ],
+                    "label": "purchase_bundle",
+                    "mutates": true,
+                    "payable": true,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 243
+                    },
+                    "selector": "0xb15fd82d"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "asset",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "discount",
+                            "type": {
+                                "displayName": [
+                                    "Discount"
+                                ],
+                                "type": 73
+                            }
+                        },
+                        {
+                            "label": "start",
+                            "type": {
+                                "displayName": [
+                                    "BlockNumber"
+                                ],
+                                "type": 27
+                            }
+                        },
+                        {
+                            "label": "end",
+                            "type": {
+                                "displayName": [
+                                    "BlockNumber"
+                                ],
+                                "type": 27
+                            }
+                        },
+                        {
+                            "label": "max_per_player",
+                            "type": {
+                                "displayName": [
+                                    "u64"
+                                ],
+                                "type": 30
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Schedule a discount on an asset between two blocks. Only the game owner can do this",
+                        " directly; games with signers use a `ScheduleSale` proposal."
+                    ],
+                    "label": "schedule_sale",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 263
+                    },
+                    "selector": "0xe1559af8"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "sale_id",
+                            "type": {
+                                "displayName": [
+                                    "SaleId"
+                                ],
+                                "type": 27
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Cancel a scheduled sale. Only the game owner can do this."
+                    ],
+                    "label": "cancel_sale",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 243
+                    },
+                    "selector": "0x1dc3a188"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "asset",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Returns the sales scheduled on an asset"
+                    ],
+                    "label": "sales_of",
+                    "mutates": false,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 267
+                    },
+                    "selector": "0x81899069"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "asset",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "code_hash",
+                            "type": {
+                                "displayName": [
+                                    "Hash"
+                                ],
+                                "type": 28
+                            }
+                        },
+                        {
+                            "label": "discount",
+                            "type": {
+                                "displayName": [
+                                    "Discount"
+                                ],
+                                "type": 73
+                            }
+                        },
+                        {
+                            "label": "expires",
+                            "type": {
+                                "displayName": [
+                                    "BlockNumber"
+                                ],
+                                "type": 27
+                            }
+                        },
+                        {
+                            "label": "uses",
+                            "type": {
+                                "displayName": [
+                                    "u32"
+                                ],
+                                "type": 27
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Issue a coupon for an asset. Only the hash of the code is stored on-chain.",
+                        " Only the game owner can do this directly; games with signers use an `AddCoupon` proposal."
+                    ],
+                    "label": "add_coupon",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 243
+                    },
+                    "selector": "0x897f3338"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "code_hash",
+                            "type": {
+                                "displayName": [
+                                    "Hash"
+                                ],
+                                "type": 28
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Revoke a coupon. Only the owner of the issuing game can do this."
+                    ],
+                    "label": "revoke_coupon",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 243
+                    },
+                    "selector": "0x6fa751a3"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "asset",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "curve",
+                            "type": {
+                                "displayName": [
+                                    "Option"
+                                ],
+                                "type": 156
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Price an asset on a bonding curve, or return it to its fixed catalog price with `None`.",
+                        " Only possible while no curve-sold units are outstanding and no sales are scheduled on",
+                        " the asset, since curve prices are never discounted. Only the game owner can do",
+                        " this directly; games with signers use a `SetPriceCurve` proposal."
+                    ],
+                    "label": "set_price_curve",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 243
+                    },
+                    "selector": "0xe5ec8292"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "asset",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Returns the price curve of an asset, if it has one"
+                    ],
+                    "label": "price_curve",
+                    "mutates": false,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 270
+                    },
+                    "selector": "0x78eb4938"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "asset",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Returns the units of a curve-priced asset currently sold by its game store"
+                    ],
+                    "label": "total_supply",
+                    "mutates": false,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 271
+                    },
+                    "selector": "0xdb6375a8"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "account",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        },
+                        {
+                            "label": "asset",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Returns the units of a curve-priced asset a player can sell back to its curve"
+                    ],
+                    "label": "curve_holding",
+                    "mutates": false,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 271
+                    },
+                    "selector": "0x771aad49"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "asset",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "count",
+                            "type": {
+                                "displayName": [
+                                    "u64"
+                                ],
+                                "type": 30
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Returns the undiscounted price of buying `count` units of an asset now"
+                    ],
+                    "label": "quote",
+                    "mutates": false,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 272
+                    },
+                    "selector": "0xff7b33a5"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "asset",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "count",
+                            "type": {
+                                "displayName": [
+                                    "u64"
+                                ],
+                                "type": 30
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Sell units of a curve-priced asset back to its game store, at the curve price of the",
+                        " units being returned. Only units the caller bought from the curve can be sold back;",
+                        " they stop counting once they leave the caller's holdings."
+                    ],
+                    "label": "sell_back_asset",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 243
+                    },
+                    "selector": "0x96486ab5"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "asset",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "percent",
+                            "type": {
+                                "displayName": [
+                                    "u8"
+                                ],
+                                "type": 6
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Set the percentage of the list price paid to players selling an asset back, or `0` to",
+                        " stop buying it back. Only the game owner can do this directly; games with signers use",
+                        " a `SetBuyback` proposal."
+                    ],
+                    "label": "set_buyback",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 243
+                    },
+                    "selector": "0x47ae93a7"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "asset",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Returns the percentage of the list price a game pays to buy an asset back"
+                    ],
+                    "label": "buyback_rate",
+                    "mutates": false,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 274
+                    },
+                    "selector": "0xbb98b0c0"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Returns the balance a game holds to buy assets back"
+                    ],
+                    "label": "reserve_of",
+                    "mutates": false,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 276
+                    },
+                    "selector": "0x4ee34e83"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "amount",
+                            "type": {
+                                "displayName": [
+                                    "Balance"
+                                ],
+                                "type": 1
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Move balance from the caller into the game's buyback reserve.",
+                        " Only the game owner can do this."
+                    ],
+                    "label": "fund_reserve",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 243
+                    },
+                    "selector": "0x3a515a58"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "amount",
+                            "type": {
+                                "displayName": [
+                                    "Balance"
+                                ],
+                                "type": 1
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Move balance from the game's buyback reserve back to the caller. Only the game owner",
+                        " can do this directly; games with signers use a `WithdrawReserve` proposal."
+                    ],
+                    "label": "withdraw_reserve",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 243
+                    },
+                    "selector": "0x53f66e0c"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "asset",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "count",
+                            "type": {
+                                "displayName": [
+                                    "u64"
+                                ],
+                                "type": 30
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Sell units of an asset back to its game store at the game's buyback rate,",
+                        " burning them and crediting the payout from the game's reserve."
+                    ],
+                    "label": "sell_asset",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 243
+                    },
+                    "selector": "0xdfe50e07"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "name",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "symbol",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "decimals",
+                            "type": {
+                                "displayName": [
+                                    "u8"
+                                ],
+                                "type": 6
+                            }
+                        },
+                        {
+                            "label": "denominate_prices",
+                            "type": {
+                                "displayName": [
+                                    "bool"
+                                ],
+                                "type": 37
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Declare the game's in-game currency. When `denominate_prices` is set, the game's store",
+                        " charges, reserves and pays out in this currency instead of the global balance.",
+                        " Prices can only be denominated in the currency while the store holds no reserves,",
+                        " curve supply or sales in the global balance.",
+                        " Only the game owner can do this directly, once per game; games with signers use a",
+                        " `CreateCurrency` proposal."
+                    ],
+                    "label": "create_currency",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 263
+                    },
+                    "selector": "0xad26a493"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Returns the currency issued by a game"
+                    ],
+                    "label": "currency_of",
+                    "mutates": false,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 277
+                    },
+                    "selector": "0x31a84146"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "currency_id",
+                            "type": {
+                                "displayName": [
+                                    "CurrencyId"
+                                ],
+                                "type": 27
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Returns a currency definition"
+                    ],
+                    "label": "currency",
+                    "mutates": false,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 278
+                    },
+                    "selector": "0xf9491f07"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "currency_id",
+                            "type": {
+                                "displayName": [
+                                    "CurrencyId"
+                                ],
+                                "type": 27
+                            }
+                        },
+                        {
+                            "label": "to",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        },
+                        {
+                            "label": "value",
+                            "type": {
+                                "displayName": [
+                                    "Balance"
+                                ],
+                                "type": 1
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Mint units of a game's currency to an account. Only the game owner can do this",
+                        " directly; games with signers use a `MintCurrency` proposal."
+                    ],
+                    "label": "mint_currency",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 243
+                    },
+                    "selector": "0xb6728d85"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "currency_id",
+                            "type": {
+                                "displayName": [
+                                    "CurrencyId"
+                                ],
+                                "type": 27
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " PSP22 `token_name` for a currency"
+                    ],
+                    "label": "currency_name",
+                    "mutates": false,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 280
+                    },
+                    "selector": "0xbb8286ff"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "currency_id",
+                            "type": {
+                                "displayName": [
+                                    "CurrencyId"
+                                ],
+                                "type": 27
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " PSP22 `token_symbol` for a currency"
+                    ],
+                    "label": "currency_symbol",
+                    "mutates": false,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 280
+                    },
+                    "selector": "0x4cfc2121"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "currency_id",
+                            "type": {
+                                "displayName": [
+                                    "CurrencyId"
+                                ],
+                                "type": 27
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " PSP22 `token_decimals` for a currency"
+                    ],
+                    "label": "currency_decimals",
+                    "mutates": false,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 281
+                    },
+                    "selector": "0xa3e62c6d"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "currency_id",
+                            "type": {
+                                "displayName": [
+                                    "CurrencyId"
+                                ],
+                                "type": 27
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " PSP22 `total_supply` for a currency"
+                    ],
+                    "label": "currency_total_supply",
+                    "mutates": false,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 276
+                    },
+                    "selector": "0x296ef587"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "currency_id",
+                            "type": {
+                                "displayName": [
+                                    "CurrencyId"
+                                ],
+                                "type": 27
+                            }
+                        },
+                        {
+                            "label": "owner",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " PSP22 `balance_of` for a currency"
+                    ],
+                    "label": "currency_balance_of",
+                    "mutates": false,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 276
+                    },
+                    "selector": "0x20173f03"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "currency_id",
+                            "type": {
+                                "displayName": [
+                                    "CurrencyId"
+                                ],
+                                "type": 27
+                            }
+                        },
+                        {
+                            "label": "owner",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        },
+                        {
+                            "label": "spender",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " PSP22 `allowance` for a currency"
+                    ],
+                    "label": "currency_allowance",
+                    "mutates": false,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 276
+                    },
+                    "selector": "0xd90caec6"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "currency_id",
+                            "type": {
+                                "displayName": [
+                                    "CurrencyId"
+                                ],
+                                "type": 27
+                            }
+                        },
+                        {
+                            "label": "to",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        },
+                        {
+                            "label": "value",
+                            "type": {
+                                "displayName": [
+                                    "Balance"
+                                ],
+                                "type": 1
+                            }
+                        },
+                        {
+                            "label": "_data",
+                            "type": {
+                                "displayName": [
+                                    "Vec"
+                                ],
+                                "type": 282
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " PSP22 `transfer` for a currency"
+                    ],
+                    "label": "currency_transfer",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 243
+                    },
+                    "selector": "0x04d652a3"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "currency_id",
+                            "type": {
+                                "displayName": [
+                                    "CurrencyId"
+                                ],
+                                "type": 27
+                            }
+                        },
+                        {
+                            "label": "from",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        },
+                        {
+                            "label": "to",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        },
+                        {
+                            "label": "value",
+                            "type": {
+                                "displayName": [
+                                    "Balance"
+                                ],
+                                "type": 1
+                            }
+                        },
+                        {
+                            "label": "_data",
+                            "type": {
+                                "displayName": [
+                                    "Vec"
+                                ],
+                                "type": 282
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " PSP22 `transfer_from` for a currency"
+                    ],
+                    "label": "currency_transfer_from",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 243
+                    },
+                    "selector": "0xc2ab444a"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "currency_id",
+                            "type": {
+                                "displayName": [
+                                    "CurrencyId"
+                                ],
+                                "type": 27
+                            }
+                        },
+                        {
+                            "label": "spender",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        },
+                        {
+                            "label": "value",
+                            "type": {
+                                "displayName": [
+                                    "Balance"
+                                ],
+                                "type": 1
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " PSP22 `approve` for a currency"
+                    ],
+                    "label": "currency_approve",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 243
+                    },
+                    "selector": "0x0f2a624f"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "asset",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "currency",
+                            "type": {
+                                "displayName": [
+                                    "PriceCurrency"
+                                ],
+                                "type": 118
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Set the currency an asset's catalog price is paid in. Assets paid in the native token or",
+                        " an external PSP22 token cannot have a price curve or a buyback rate, since the payment",
+                        " goes straight to the game owner. Only the game owner can do this directly; games with",
+                        " signers use a `SetPriceCurrency` proposal."
+                    ],
+                    "label": "set_price_currency",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 243
+                    },
+                    "selector": "0x6a43771f"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "asset",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Returns the currency an asset's catalog price is paid in"
+                    ],
+                    "label": "price_currency",
+                    "mutates": false,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 283
+                    },
+                    "selector": "0x5a8ea917"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "asset",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "rate",
+                            "type": {
+                                "displayName": [
+                                    "Balance"
+                                ],
+                                "type": 1
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Set how much of the game's currency each staked unit of an asset earns per block.",
+                        " Rewards earned under the previous rate are kept. Only the game owner can do this",
+                        " directly; games with signers use a `SetRewardRate` proposal."
+                    ],
+                    "label": "set_reward_rate",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 243
+                    },
+                    "selector": "0x93f1e657"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "asset",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Returns the reward accounting of a stakeable asset"
+                    ],
+                    "label": "reward_pool",
+                    "mutates": false,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 284
+                    },
+                    "selector": "0x644b2fa9"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "account",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        },
+                        {
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "asset",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Returns the units of an asset a player has staked"
+                    ],
+                    "label": "stake_of",
+                    "mutates": false,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 286
+                    },
+                    "selector": "0x0ad0bea9"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "account",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        },
+                        {
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "asset",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Returns the rewards a player could claim for a staked asset right now"
+                    ],
+                    "label": "pending_rewards",
+                    "mutates": false,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 272
+                    },
+                    "selector": "0x46daed48"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "asset",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "count",
+                            "type": {
+                                "displayName": [
+                                    "u64"
+                                ],
+                                "type": 30
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Lock units of an asset to earn rewards. Staked units cannot be gifted, exchanged or sold."
+                    ],
+                    "label": "stake_asset",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 243
+                    },
+                    "selector": "0x8d383e88"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "asset",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "count",
+                            "type": {
+                                "displayName": [
+                                    "u64"
+                                ],
+                                "type": 30
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Unlock staked units of an asset, returning them to the player's holdings.",
+                        " Earned rewards stay claimable."
+                    ],
+                    "label": "unstake_asset",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 243
+                    },
+                    "selector": "0xbcad0aae"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "asset",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Claim the rewards earned by a staked asset, paid in the game's currency."
+                    ],
+                    "label": "claim_rewards",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 272
+                    },
+                    "selector": "0x8027650c"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "name",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Found a guild led by the caller."
+                    ],
+                    "label": "create_guild",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 263
+                    },
+                    "selector": "0xb73fbaeb"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "guild_id",
+                            "type": {
+                                "displayName": [
+                                    "GuildId"
+                                ],
+                                "type": 27
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Returns a guild, its members and its vault"
+                    ],
+                    "label": "guild",
+                    "mutates": false,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 288
+                    },
+                    "selector": "0xd0bf440d"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "guild_id",
+                            "type": {
+                                "displayName": [
+                                    "GuildId"
+                                ],
+                                "type": 27
+                            }
+                        },
+                        {
+                            "label": "account",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        },
+                        {
+                            "label": "role",
+                            "type": {
+                                "displayName": [
+                                    "GuildRole"
+                                ],
+                                "type": 132
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Add a player to a guild or change their role. Officers can add plain members;",
+                        " only the leader can appoint officers or hand over leadership."
+                    ],
+                    "label": "set_guild_member",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 243
+                    },
+                    "selector": "0xe810a904"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "guild_id",
+                            "type": {
+                                "displayName": [
+                                    "GuildId"
+                                ],
+                                "type": 27
+                            }
+                        },
+                        {
+                            "label": "account",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Remove a member from a guild. Members can remove themselves, officers can remove plain",
+                        " members and the leader can remove anyone but themselves."
+                    ],
+                    "label": "remove_guild_member",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 243
+                    },
+                    "selector": "0xdd6bd629"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "guild_id",
+                            "type": {
+                                "displayName": [
+                                    "GuildId"
+                                ],
+                                "type": 27
+                            }
+                        },
+                        {
+                            "label": "quorum",
+                            "type": {
+                                "displayName": [
+                                    "u32"
+                                ],
+                                "type": 27
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Set how many leader or officer approvals a vault withdrawal needs, at most the number",
+                        " of leaders and officers. Only the leader can do this."
+                    ],
+                    "label": "set_guild_quorum",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 243
+                    },
+                    "selector": "0xb43d0b01"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "guild_id",
+                            "type": {
+                                "displayName": [
+                                    "GuildId"
+                                ],
+                                "type": 27
+                            }
+                        },
+                        {
+                            "label": "item",
+                            "type": {
+                                "displayName": [
+                                    "VaultItem"
+                                ],
+                                "type": 141
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Move balance or assets from the caller into a guild vault. Any member can do this."
+                    ],
+                    "label": "deposit_to_guild",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 243
+                    },
+                    "selector": "0x70147c02"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "guild_id",
+                            "type": {
+                                "displayName": [
+                                    "GuildId"
+                                ],
+                                "type": 27
+                            }
+                        },
+                        {
+                            "label": "to",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        },
+                        {
+                            "label": "item",
+                            "type": {
+                                "displayName": [
+                                    "VaultItem"
+                                ],
+                                "type": 141
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Propose sending balance or assets from a guild vault to a player.",
+                        " Any member can propose; a leader or officer proposing also approves it."
+                    ],
+                    "label": "propose_withdrawal",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 263
+                    },
+                    "selector": "0x5d46fdc9"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "guild_id",
+                            "type": {
+                                "displayName": [
+                                    "GuildId"
+                                ],
+                                "type": 27
+                            }
+                        },
+                        {
+                            "label": "proposal_id",
+                            "type": {
+                                "displayName": [
+                                    "u32"
+                                ],
+                                "type": 27
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Returns a pending vault withdrawal"
+                    ],
+                    "label": "withdrawal",
+                    "mutates": false,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 290
+                    },
+                    "selector": "0x70cddc09"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "guild_id",
+                            "type": {
+                                "displayName": [
+                                    "GuildId"
+                                ],
+                                "type": 27
+                            }
+                        },
+                        {
+                            "label": "proposal_id",
+                            "type": {
+                                "displayName": [
+                                    "u32"
+                                ],
+                                "type": 27
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Approve a pending vault withdrawal as a leader or officer.",
+                        " The withdrawal is carried out once it reaches the guild's quorum."
+                    ],
+                    "label": "approve_withdrawal",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 243
+                    },
+                    "selector": "0x029b3378"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "asset",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "price",
+                            "type": {
+                                "displayName": [
+                                    "Balance"
+                                ],
+                                "type": 1
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Change the catalog price of an asset. Only the game owner can do this directly;",
+                        " games with signers use a `SetPrice` proposal."
+                    ],
+                    "label": "set_asset_price",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 243
+                    },
+                    "selector": "0x7febf652"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "signers",
+                            "type": {
+                                "displayName": [
+                                    "Vec"
+                                ],
+                                "type": 137
+                            }
+                        },
+                        {
+                            "label": "threshold",
+                            "type": {
+                                "displayName": [
+                                    "u32"
+                                ],
+                                "type": 27
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Require M-of-N approval from `signers` for the game's high-value actions.",
+                        " Only the game owner can do this, and only while the game has no signers yet;",
+                        " afterwards signers change through a `SetSigners` proposal."
+                    ],
+                    "label": "set_game_signers",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 243
+                    },
+                    "selector": "0x606a5497"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Returns the signers of a game"
+                    ],
+                    "label": "game_signers",
+                    "mutates": false,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 292
+                    },
+                    "selector": "0xdfdd1c74"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "action",
+                            "type": {
+                                "displayName": [
+                                    "GameAction"
+                                ],
+                                "type": 155
+                            }
+                        },
+                        {
+                            "label": "expires_at",
+                            "type": {
+                                "displayName": [
+                                    "BlockNumber"
+                                ],
+                                "type": 27
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Propose a high-value action on a game. Only its signers can do this, and the",
+                        " proposer's approval is counted."
+                    ],
+                    "label": "propose_game_action",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 263
+                    },
+                    "selector": "0x5bbdc4bc"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "proposal_id",
+                            "type": {
+                                "displayName": [
+                                    "u32"
+                                ],
+                                "type": 27
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Returns a pending game owner action"
+                    ],
+                    "label": "game_proposal",
+                    "mutates": false,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 294
+                    },
+                    "selector": "0xceeea0ba"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "proposal_id",
+                            "type": {
+                                "displayName": [
+                                    "u32"
+                                ],
+                                "type": 27
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Approve a pending game owner action as one of the game's signers."
+                    ],
+                    "label": "approve_game_action",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 243
+                    },
+                    "selector": "0x8e752cec"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "proposal_id",
+                            "type": {
+                                "displayName": [
+                                    "u32"
+                                ],
+                                "type": 27
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Carry out a game owner action once enough current signers approved it.",
+                        " Any signer can do this before the proposal expires."
+                    ],
+                    "label": "execute_game_action",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 243
+                    },
+                    "selector": "0x719bd80c"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "signer",
+                            "type": {
+                                "displayName": [
+                                    "Option"
+                                ],
+                                "type": 159
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Set or clear the key that signs a game's vouchers. Only the game owner can do this",
+                        " directly; games with signers use a `SetVoucherSigner` proposal."
+                    ],
+                    "label": "set_voucher_signer",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 243
+                    },
+                    "selector": "0xe73ffa18"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Returns the key that signs a game's vouchers"
+                    ],
+                    "label": "voucher_signer",
+                    "mutates": false,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 296
+                    },
+                    "selector": "0x19bdac80"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "nonce",
+                            "type": {
+                                "displayName": [
+                                    "u64"
+                                ],
+                                "type": 30
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Returns whether a game's voucher nonce was already redeemed"
+                    ],
+                    "label": "is_voucher_redeemed",
+                    "mutates": false,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 297
+                    },
+                    "selector": "0xecb9380f"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "voucher",
+                            "type": {
+                                "displayName": [
+                                    "Voucher"
+                                ],
+                                "type": 298
+                            }
+                        },
+                        {
+                            "label": "signature",
+                            "type": {
+                                "displayName": [
+                                    "Vec"
+                                ],
+                                "type": 282
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Redeem a voucher signed by the game's signing key, crediting its assets to the player.",
+                        " Anyone can submit the voucher, so the player does not have to pay for the transaction."
+                    ],
+                    "label": "redeem_voucher",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 243
+                    },
+                    "selector": "0xb7c8329e"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "player",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Returns the next nonce a player must sign for a relayed action"
+                    ],
+                    "label": "nonce_of",
+                    "mutates": false,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 271
+                    },
+                    "selector": "0x32544995"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "call",
+                            "type": {
+                                "displayName": [
+                                    "SignedCall"
+                                ],
+                                "type": 299
+                            }
+                        },
+                        {
+                            "label": "player",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        },
+                        {
+                            "label": "nonce",
+                            "type": {
+                                "displayName": [
+                                    "u64"
+                                ],
+                                "type": 30
+                            }
+                        },
+                        {
+                            "label": "fee",
+                            "type": {
+                                "displayName": [
+                                    "Balance"
+                                ],
+                                "type": 1
+                            }
+                        },
+                        {
+                            "label": "signature",
+                            "type": {
+                                "displayName": [
+                                    "Vec"
+                                ],
+                                "type": 282
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Execute an action signed by `player`, so a relayer can pay the transaction fee.",
+                        " The signature covers the Blake2x256 hash of `(contract, player, nonce, fee, call)`,",
+                        " SCALE encoded, made with the player's sr25519 or ECDSA account key.",
+                        " `fee` is moved from the player's balance to the relayer's."
+                    ],
+                    "label": "execute_signed",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 243
+                    },
+                    "selector": "0xb463ad38"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "asset",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "merkle_root",
+                            "type": {
+                                "displayName": [
+                                    "Hash"
+                                ],
+                                "type": 28
+                            }
+                        },
+                        {
+                            "label": "total",
+                            "type": {
+                                "displayName": [
+                                    "u64"
+                                ],
+                                "type": 30
+                            }
+                        },
+                        {
+                            "label": "expires_at",
+                            "type": {
+                                "displayName": [
+                                    "BlockNumber"
+                                ],
+                                "type": 27
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Escrow `total` units of an asset from the caller's holdings for accounts to claim with",
+                        " a merkle proof until `expires_at`. Only the game owner can do this."
+                    ],
+                    "label": "create_airdrop",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 263
+                    },
+                    "selector": "0xeec72bde"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "airdrop_id",
+                            "type": {
+                                "displayName": [
+                                    "AirdropId"
+                                ],
+                                "type": 27
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Returns an airdrop"
+                    ],
+                    "label": "airdrop",
+                    "mutates": false,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 300
+                    },
+                    "selector": "0x91b0a242"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "airdrop_id",
+                            "type": {
+                                "displayName": [
+                                    "AirdropId"
+                                ],
+                                "type": 27
+                            }
+                        },
+                        {
+                            "label": "index",
+                            "type": {
+                                "displayName": [
+                                    "u32"
+                                ],
+                                "type": 27
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Returns whether a leaf of an airdrop has been claimed"
+                    ],
+                    "label": "is_airdrop_claimed",
+                    "mutates": false,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 297
+                    },
+                    "selector": "0xe8a941a8"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "airdrop_id",
+                            "type": {
+                                "displayName": [
+                                    "AirdropId"
+                                ],
+                                "type": 27
+                            }
+                        },
+                        {
+                            "label": "index",
+                            "type": {
+                                "displayName": [
+                                    "u32"
+                                ],
+                                "type": 27
+                            }
+                        },
+                        {
+                            "label": "count",
+                            "type": {
+                                "displayName": [
+                                    "u64"
+                                ],
+                                "type": 30
+                            }
+                        },
+                        {
+                            "label": "proof",
+                            "type": {
+                                "displayName": [
+                                    "Vec"
+                                ],
+                                "type": 302
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Claim the caller's leaf `index` of an airdrop, proving `(index, caller, count)` is in",
+                        " its merkle tree."
+                    ],
+                    "label": "claim_airdrop",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 243
+                    },
+                    "selector": "0x0ba35542"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "airdrop_id",
+                            "type": {
+                                "displayName": [
+                                    "AirdropId"
+                                ],
+                                "type": 27
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Return the unclaimed units of an expired airdrop to the caller.",
+                        " Only the game owner can do this."
+                    ],
+                    "label": "reclaim_airdrop",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 243
+                    },
+                    "selector": "0xa275050d"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "operator",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        },
+                        {
+                            "label": "allowed",
+                            "type": {
+                                "displayName": [
+                                    "bool"
+                                ],
+                                "type": 37
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Let `operator`, usually a game logic contract, award and consume the game's assets.",
+                        " Only the game owner can do this directly; games with signers use a `SetOperator`",
+                        " proposal."
+                    ],
+                    "label": "set_game_operator",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 243
+                    },
+                    "selector": "0xa11559e8"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "opponent",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        },
+                        {
+                            "label": "stake",
+                            "type": {
+                                "displayName": [
+                                    "VaultItem"
+                                ],
+                                "type": 141
+                            }
+                        },
+                        {
+                            "label": "commitment",
+                            "type": {
+                                "displayName": [
+                                    "Hash"
+                                ],
+                                "type": 28
+                            }
+                        },
+                        {
+                            "label": "timeout",
+                            "type": {
+                                "displayName": [
+                                    "BlockNumber"
+                                ],
+                                "type": 27
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Challenge `opponent` to a match, escrowing the caller's stake and committing their move.",
+                        " The opponent has `timeout` blocks to join, then both have `timeout` blocks to reveal."
+                    ],
+                    "label": "create_match",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 263
+                    },
+                    "selector": "0xf218e52e"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "match_id",
+                            "type": {
+                                "displayName": [
+                                    "MatchId"
+                                ],
+                                "type": 27
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Returns a match in progress"
+                    ],
+                    "label": "wager_match",
+                    "mutates": false,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 303
+                    },
+                    "selector": "0x5725c760"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "match_id",
+                            "type": {
+                                "displayName": [
+                                    "MatchId"
+                                ],
+                                "type": 27
+                            }
+                        },
+                        {
+                            "label": "commitment",
+                            "type": {
+                                "displayName": [
+                                    "Hash"
+                                ],
+                                "type": 28
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Accept a challenge, escrowing the same stake and committing a move."
+                    ],
+                    "label": "join_match",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 243
+                    },
+                    "selector": "0xe3149c9d"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "match_id",
+                            "type": {
+                                "displayName": [
+                                    "MatchId"
+                                ],
+                                "type": 27
+                            }
+                        },
+                        {
+                            "label": "hand",
+                            "type": {
+                                "displayName": [
+                                    "Hand"
+                                ],
+                                "type": 187
+                            }
+                        },
+                        {
+                            "label": "salt",
+                            "type": {
+                                "displayName": [
+                                    "Hash"
+                                ],
+                                "type": 28
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Reveal the caller's committed move. Once both moves are revealed the winner",
+                        " takes both stakes, and a draw returns them."
+                    ],
+                    "label": "reveal_move",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 243
+                    },
+                    "selector": "0x5da27388"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "match_id",
+                            "type": {
+                                "displayName": [
+                                    "MatchId"
+                                ],
+                                "type": 27
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Settle a match whose deadline passed. An unjoined match is refunded to the challenger;",
+                        " in the reveal phase a player who revealed wins the pot, and if neither did both",
+                        " stakes are returned. Either player can do this."
+                    ],
+                    "label": "claim_match_timeout",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 243
+                    },
+                    "selector": "0x1210ba54"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "terms",
+                            "type": {
+                                "displayName": [
+                                    "TournamentTerms"
+                                ],
+                                "type": 157
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Open a tournament for a game. Only the game owner can do this directly; games with",
+                        " signers use a `CreateTournament` proposal."
+                    ],
+                    "label": "create_tournament",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 263
+                    },
+                    "selector": "0x5afd3726"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "tournament_id",
+                            "type": {
+                                "displayName": [
+                                    "TournamentId"
+                                ],
+                                "type": 27
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Returns a tournament"
+                    ],
+                    "label": "tournament",
+                    "mutates": false,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 305
+                    },
+                    "selector": "0x0fdcc67c"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "tournament_id",
+                            "type": {
+                                "displayName": [
+                                    "TournamentId"
+                                ],
+                                "type": 27
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Enter a tournament during its registration window, escrowing the entry fee."
+                    ],
+                    "label": "enter_tournament",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 243
+                    },
+                    "selector": "0x3ee2f1fb"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "tournament_id",
+                            "type": {
+                                "displayName": [
+                                    "TournamentId"
+                                ],
+                                "type": 27
+                            }
+                        },
+                        {
+                            "label": "ranking",
+                            "type": {
+                                "displayName": [
+                                    "Vec"
+                                ],
+                                "type": 137
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Submit the final ranking once registration has closed, paying the prize pool and",
+                        " trophies in one step. Only the game owner or the tournament oracle can do this."
+                    ],
+                    "label": "submit_results",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 243
+                    },
+                    "selector": "0xcb7f8304"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "tournament_id",
+                            "type": {
+                                "displayName": [
+                                    "TournamentId"
+                                ],
+                                "type": 27
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Cancel a tournament that has no results yet, refunding every entrant.",
+                        " Only the game owner can do this."
+                    ],
+                    "label": "cancel_tournament",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 243
+                    },
+                    "selector": "0x2b32c1cc"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "achievement",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "name",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "description",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "points",
+                            "type": {
+                                "displayName": [
+                                    "u32"
+                                ],
+                                "type": 27
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Define an achievement players of the game can earn. Only the game owner can do this."
+                    ],
+                    "label": "define_achievement",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 243
+                    },
+                    "selector": "0xf44397e9"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "achievement",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Returns an achievement defined by a game"
+                    ],
+                    "label": "achievement",
+                    "mutates": false,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 307
+                    },
+                    "selector": "0x02253beb"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "achievement",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "account",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Grant an achievement to a player. Only the game's operators or, directly, its owner can",
+                        " do this; games with signers use a `GrantAchievement` proposal in place of the owner."
+                    ],
+                    "label": "grant_achievement",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 243
+                    },
+                    "selector": "0x66f81de2"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "account",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Returns the achievements a player earned across all games, as `(game, achievement)` pairs"
+                    ],
+                    "label": "achievements_of",
+                    "mutates": false,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 309
+                    },
+                    "selector": "0x5f766f93"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "account",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Returns a player's gamer score"
+                    ],
+                    "label": "gamer_score",
+                    "mutates": false,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 271
+                    },
+                    "selector": "0x75f1cbdc"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "account",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        },
+                        {
+                            "label": "reason_hash",
+                            "type": {
+                                "displayName": [
+                                    "Hash"
+                                ],
+                                "type": 28
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Flag a player for fraud. Only the game owner can do this."
+                    ],
+                    "label": "flag_player",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 243
+                    },
+                    "selector": "0x97f10838"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "appeal_hash",
+                            "type": {
+                                "displayName": [
+                                    "Hash"
+                                ],
+                                "type": 28
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Appeal a game's flag on the caller."
+                    ],
+                    "label": "appeal_flag",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 243
+                    },
+                    "selector": "0xc80e9607"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "account",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Clear a game's flag on a player. Only the game owner can do this."
+                    ],
+                    "label": "clear_flag",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 243
+                    },
+                    "selector": "0x8fdcacd6"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "account",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Returns a game's flag on a player"
+                    ],
+                    "label": "flag",
+                    "mutates": false,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 310
+                    },
+                    "selector": "0xe5fe2fee"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "account",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Returns whether any game flags a player"
+                    ],
+                    "label": "is_flagged",
+                    "mutates": false,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 297
+                    },
+                    "selector": "0x5e666940"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "refuse_flagged",
+                            "type": {
+                                "displayName": [
+                                    "bool"
+                                ],
+                                "type": 37
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Refuse or accept gifts and exchanges of the game's assets from players flagged",
+                        " by the game or a game it trusts. Only the game owner can do this."
+                    ],
+                    "label": "set_flag_policy",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 243
+                    },
+                    "selector": "0x77b46439"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Returns whether a game refuses transfers from flagged players"
+                    ],
+                    "label": "refuses_flagged",
+                    "mutates": false,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 297
+                    },
+                    "selector": "0xde6b3700"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "trusted_game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "trusted",
+                            "type": {
+                                "displayName": [
+                                    "bool"
+                                ],
+                                "type": 37
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Trust or stop trusting another game's flags and reputation.",
+                        " Only the game owner can do this."
+                    ],
+                    "label": "set_flag_trust",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 243
+                    },
+                    "selector": "0xf56e5ab8"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Returns the games whose flags and reputation a game trusts"
+                    ],
+                    "label": "trusted_games",
+                    "mutates": false,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 251
+                    },
+                    "selector": "0xb65bafdc"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "account",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        },
+                        {
+                            "label": "delta",
+                            "type": {
+                                "displayName": [
+                                    "i32"
+                                ],
+                                "type": 152
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Raise or lower a player's reputation with a game. Only the game's operators or,",
+                        " directly, its owner can do this; games with signers use an `AdjustReputation` proposal",
+                        " in place of the owner."
+                    ],
+                    "label": "adjust_reputation",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 243
+                    },
+                    "selector": "0x3cd65d95"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "account",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Returns a player's reputation with a game"
+                    ],
+                    "label": "reputation",
+                    "mutates": false,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 312
+                    },
+                    "selector": "0xfd7fcb15"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "counted",
+                            "type": {
+                                "displayName": [
+                                    "bool"
+                                ],
+                                "type": 37
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Count a game's reputation towards global reputation, or stop counting it.",
+                        " Only the contract admin can do this, so throwaway games cannot sway global scores."
+                    ],
+                    "label": "set_reputation_game",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 243
+                    },
+                    "selector": "0xa8d23861"
+                },
+                {
+                    "args": [],
+                    "default": false,
+                    "docs": [
+                        " Returns the games whose reputation counts towards global reputation"
+                    ],
+                    "label": "reputation_games",
+                    "mutates": false,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 251
+                    },
+                    "selector": "0xc6a5b724"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "account",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Returns a player's reputation summed across the games counted globally"
+                    ],
+                    "label": "global_reputation",
+                    "mutates": false,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 313
+                    },
+                    "selector": "0x89ddeae9"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "account",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Returns a player's reputation with a game plus the games it trusts"
+                    ],
+                    "label": "trusted_reputation",
+                    "mutates": false,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 313
+                    },
+                    "selector": "0xaf1eac0d"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "asset",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Returns whether the game can freeze and claw back an asset"
+                    ],
+                    "label": "is_recoverable",
+                    "mutates": false,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 297
+                    },
+                    "selector": "0xc27f818b"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "account",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        },
+                        {
+                            "label": "asset",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Returns whether a player's holding of an asset is frozen"
+                    ],
+                    "label": "is_frozen",
+                    "mutates": false,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 297
+                    },
+                    "selector": "0x0e6a1faf"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "account",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        },
+                        {
+                            "label": "asset",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Stop a player from moving a recoverable asset out of their holdings.",
+                        " Only the game owner can do this."
+                    ],
+                    "label": "freeze_holding",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 243
+                    },
+                    "selector": "0xd805efee"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "account",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        },
+                        {
+                            "label": "asset",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Lift a freeze on a player's holding. Only the game owner can do this."
+                    ],
+                    "label": "unfreeze_holding",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 243
+                    },
+                    "selector": "0xc4b8ccaa"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "account",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        },
+                        {
+                            "label": "asset",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "count",
+                            "type": {
+                                "displayName": [
+                                    "u64"
+                                ],
+                                "type": 30
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [
+                        " Burn units of a recoverable asset from a player's holdings, frozen or not.",
+                        " Only the game owner can do this."
+                    ],
+                    "label": "clawback",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 243
+                    },
+                    "selector": "0xf1779b32"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "player",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        },
+                        {
+                            "label": "asset",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "count",
+                            "type": {
+                                "displayName": [
+                                    "u64"
+                                ],
+                                "type": 30
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [],
+                    "label": "AssetVerse::award",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 243
+                    },
+                    "selector": "0x7a2caa34"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "player",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        },
+                        {
+                            "label": "asset",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "count",
+                            "type": {
+                                "displayName": [
+                                    "u64"
+                                ],
+                                "type": 30
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [],
+                    "label": "AssetVerse::consume",
+                    "mutates": true,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 243
+                    },
+                    "selector": "0x8eb243fd"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "account",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        },
+                        {
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [],
+                    "label": "AssetVerse::holdings_of",
+                    "mutates": false,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 315
+                    },
+                    "selector": "0xcee92f53"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "account",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        },
+                        {
+                            "label": "asset",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [],
+                    "label": "AssetVerse::balance_of",
+                    "mutates": false,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 271
+                    },
+                    "selector": "0x52ce4d00"
+                },
+                {
+                    "args": [
+                        {
+                            "label": "game",
+                            "type": {
+                                "displayName": [
+                                    "String"
+                                ],
+                                "type": 0
+                            }
+                        },
+                        {
+                            "label": "operator",
+                            "type": {
+                                "displayName": [
+                                    "AccountId"
+                                ],
+                                "type": 4
+                            }
+                        }
+                    ],
+                    "default": false,
+                    "docs": [],
+                    "label": "AssetVerse::is_game_operator",
+                    "mutates": false,
+                    "payable": false,
+                    "returnType": {
+                        "displayName": [
+                            "ink",
+                            "MessageResult"
+                        ],
+                        "type": 297
+                    },
+                    "selector": "0x890fe615"
+                }
+            ]
+        },
+        "storage": {
+            "root": {
+                "layout": {
+                    "struct": {
+                        "fields": [
+                            {
+                                "layout": {
+                                    "root": {
+                                        "layout": {
+                                            "struct": {
+                                                "fields": [
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0x17e8cef2",
+                                                                "ty": 0
+                                                            }
+                                                        },
+                                                        "name": "name"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0x17e8cef2",
+                                                                "ty": 0
+                                                            }
+                                                        },
+                                                        "name": "avatar_uri"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0x17e8cef2",
+                                                                "ty": 1
+                                                            }
+                                                        },
+                                                        "name": "balance"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0x17e8cef2",
+                                                                "ty": 2
+                                                            }
+                                                        },
+                                                        "name": "assets"
+                                                    }
+                                                ],
+                                                "name": "Player"
+                                            }
+                                        },
+                                        "root_key": "0x17e8cef2",
+                                        "ty": 3
+                                    }
+                                },
+                                "name": "players"
+                            },
+                            {
+                                "layout": {
+                                    "root": {
+                                        "layout": {
+                                            "leaf": {
+                                                "key": "0x040d27f2",
+                                                "ty": 12
+                                            }
+                                        },
+                                        "root_key": "0x040d27f2",
+                                        "ty": 14
+                                    }
+                                },
+                                "name": "assets"
+                            },
+                            {
+                                "layout": {
+                                    "leaf": {
+                                        "key": "0x00000000",
+                                        "ty": 2
+                                    }
+                                },
+                                "name": "games"
+                            },
+                            {
+                                "layout": {
+                                    "root": {
+                                        "layout": {
+                                            "leaf": {
+                                                "key": "0x18387801",
+                                                "ty": 4
+                                            }
+                                        },
+                                        "root_key": "0x18387801",
+                                        "ty": 17
+                                    }
+                                },
+                                "name": "game_owners"
+                            },
+                            {
+                                "layout": {
+                                    "root": {
+                                        "layout": {
+                                            "struct": {
+                                                "fields": [
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0xc751b535",
+                                                                "ty": 0
+                                                            }
+                                                        },
+                                                        "name": "description"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0xc751b535",
+                                                                "ty": 0
+                                                            }
+                                                        },
+                                                        "name": "uri"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "enum": {
+                                                                "dispatchKey": "0xc751b535",
+                                                                "name": "Rarity",
+                                                                "variants": {
+                                                                    "0": {
+                                                                        "fields": [],
+                                                                        "name": "Common"
+                                                                    },
+                                                                    "1": {
+                                                                        "fields": [],
+                                                                        "name": "Uncommon"
+                                                                    },
+                                                                    "2": {
+                                                                        "fields": [],
+                                                                        "name": "Rare"
+                                                                    },
+                                                                    "3": {
+                                                                        "fields": [],
+                                                                        "name": "Epic"
+                                                                    },
+                                                                    "4": {
+                                                                        "fields": [],
+                                                                        "name": "Legendary"
+                                                                    }
+                                                                }
+                                                            }
+                                                        },
+                                                        "name": "rarity"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0xc751b535",
+                                                                "ty": 0
+                                                            }
+                                                        },
+                                                        "name": "category"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0xc751b535",
+                                                                "ty": 20
+                                                            }
+                                                        },
+                                                        "name": "attributes"
+                                                    }
+                                                ],
+                                                "name": "AssetMetadata"
+                                            }
+                                        },
+                                        "root_key": "0xc751b535",
+                                        "ty": 22
+                                    }
+                                },
+                                "name": "metadata"
+                            },
+                            {
+                                "layout": {
+                                    "root": {
+                                        "layout": {
+                                            "struct": {
+                                                "fields": [
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0x8a7ea3d9",
+                                                                "ty": 0
+                                                            }
+                                                        },
+                                                        "name": "game"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0x8a7ea3d9",
+                                                                "ty": 0
+                                                            }
+                                                        },
+                                                        "name": "asset"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0x8a7ea3d9",
+                                                                "ty": 4
+                                                            }
+                                                        },
+                                                        "name": "owner"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0x8a7ea3d9",
+                                                                "ty": 20
+                                                            }
+                                                        },
+                                                        "name": "state"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0x8a7ea3d9",
+                                                                "ty": 27
+                                                            }
+                                                        },
+                                                        "name": "version"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0x8a7ea3d9",
+                                                                "ty": 28
+                                                            }
+                                                        },
+                                                        "name": "state_hash"
+                                                    }
+                                                ],
+                                                "name": "Item"
+                                            }
+                                        },
+                                        "root_key": "0x8a7ea3d9",
+                                        "ty": 29
+                                    }
+                                },
+                                "name": "items"
+                            },
+                            {
+                                "layout": {
+                                    "leaf": {
+                                        "key": "0x00000000",
+                                        "ty": 30
+                                    }
+                                },
+                                "name": "next_token_id"
+                            },
+                            {
+                                "layout": {
+                                    "root": {
+                                        "layout": {
+                                            "leaf": {
+                                                "key": "0x228a4d5d",
+                                                "ty": 2
+                                            }
+                                        },
+                                        "root_key": "0x228a4d5d",
+                                        "ty": 34
+                                    }
+                                },
+                                "name": "state_editors"
+                            },
+                            {
+                                "layout": {
+                                    "root": {
+                                        "layout": {
+                                            "struct": {
+                                                "fields": [
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0xfc298278",
+                                                                "ty": 30
+                                                            }
+                                                        },
+                                                        "name": "give_units"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0xfc298278",
+                                                                "ty": 30
+                                                            }
+                                                        },
+                                                        "name": "take_units"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0xfc298278",
+                                                                "ty": 30
+                                                            }
+                                                        },
+                                                        "name": "cap_per_period"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0xfc298278",
+                                                                "ty": 27
+                                                            }
+                                                        },
+                                                        "name": "period"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0xfc298278",
+                                                                "ty": 37
+                                                            }
+                                                        },
+                                                        "name": "from_approved"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0xfc298278",
+                                                                "ty": 37
+                                                            }
+                                                        },
+                                                        "name": "to_approved"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0xfc298278",
+                                                                "ty": 27
+                                                            }
+                                                        },
+                                                        "name": "period_start"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0xfc298278",
+                                                                "ty": 30
+                                                            }
+                                                        },
+                                                        "name": "converted"
+                                                    }
+                                                ],
+                                                "name": "ConversionRule"
+                                            }
+                                        },
+                                        "root_key": "0xfc298278",
+                                        "ty": 38
+                                    }
+                                },
+                                "name": "conversions"
+                            },
+                            {
+                                "layout": {
+                                    "root": {
+                                        "layout": {
+                                            "leaf": {
+                                                "key": "0x253ebffc",
+                                                "ty": 4
+                                            }
+                                        },
+                                        "root_key": "0x253ebffc",
+                                        "ty": 43
+                                    }
+                                },
+                                "name": "names"
+                            },
+                            {
+                                "layout": {
+                                    "root": {
+                                        "layout": {
+                                            "leaf": {
+                                                "key": "0x82f9a66f",
+                                                "ty": 11
+                                            }
+                                        },
+                                        "root_key": "0x82f9a66f",
+                                        "ty": 46
+                                    }
+                                },
+                                "name": "endowed"
+                            },
+                            {
+                                "layout": {
+                                    "root": {
+                                        "layout": {
+                                            "leaf": {
+                                                "key": "0x4394d66a",
+                                                "ty": 4
+                                            }
+                                        },
+                                        "root_key": "0x4394d66a",
+                                        "ty": 49
+                                    }
+                                },
+                                "name": "holders"
+                            },
+                            {
+                                "layout": {
+                                    "root": {
+                                        "layout": {
+                                            "leaf": {
+                                                "key": "0x9b2998d9",
+                                                "ty": 27
+                                            }
+                                        },
+                                        "root_key": "0x9b2998d9",
+                                        "ty": 53
+                                    }
+                                },
+                                "name": "holder_counts"
+                            },
+                            {
+                                "layout": {
+                                    "root": {
+                                        "layout": {
+                                            "leaf": {
+                                                "key": "0x413a93ab",
+                                                "ty": 27
+                                            }
+                                        },
+                                        "root_key": "0x413a93ab",
+                                        "ty": 56
+                                    }
+                                },
+                                "name": "holder_index"
+                            },
+                            {
+                                "layout": {
+                                    "root": {
+                                        "layout": {
+                                            "struct": {
+                                                "fields": [
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0x586a6d33",
+                                                                "ty": 0
+                                                            }
+                                                        },
+                                                        "name": "game"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0x586a6d33",
+                                                                "ty": 60
+                                                            }
+                                                        },
+                                                        "name": "contents"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0x586a6d33",
+                                                                "ty": 1
+                                                            }
+                                                        },
+                                                        "name": "price"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0x586a6d33",
+                                                                "ty": 30
+                                                            }
+                                                        },
+                                                        "name": "max_per_player"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0x586a6d33",
+                                                                "ty": 27
+                                                            }
+                                                        },
+                                                        "name": "available_from"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "enum": {
+                                                                "dispatchKey": "0x586a6d33",
+                                                                "name": "Option",
+                                                                "variants": {
+                                                                    "0": {
+                                                                        "fields": [],
+                                                                        "name": "None"
+                                                                    },
+                                                                    "1": {
+                                                                        "fields": [
+                                                                            {
+                                                                                "layout": {
+                                                                                    "leaf": {
+                                                                                        "key": "0x586a6d33",
+                                                                                        "ty": 27
+                                                                                    }
+                                                                                },
+                                                                                "name": "0"
+                                                                            }
+                                                                        ],
+                                                                        "name": "Some"
+                                                                    }
+                                                                }
+                                                            }
+                                                        },
+                                                        "name": "available_until"
+                                                    }
+                                                ],
+                                                "name": "Bundle"
+                                            }
+                                        },
+                                        "root_key": "0x586a6d33",
+                                        "ty": 62
+                                    }
+                                },
+                                "name": "bundles"
+                            },
+                            {
+                                "layout": {
+                                    "leaf": {
+                                        "key": "0x00000000",
+                                        "ty": 27
+                                    }
+                                },
+                                "name": "next_bundle_id"
+                            },
+                            {
+                                "layout": {
+                                    "root": {
+                                        "layout": {
+                                            "leaf": {
+                                                "key": "0x2ba80af2",
+                                                "ty": 30
+                                            }
+                                        },
+                                        "root_key": "0x2ba80af2",
+                                        "ty": 67
+                                    }
+                                },
+                                "name": "bundle_purchases"
+                            },
+                            {
+                                "layout": {
+                                    "root": {
+                                        "layout": {
+                                            "struct": {
+                                                "fields": [
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0x05251484",
+                                                                "ty": 0
+                                                            }
+                                                        },
+                                                        "name": "game"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0x05251484",
+                                                                "ty": 0
+                                                            }
+                                                        },
+                                                        "name": "asset"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "enum": {
+                                                                "dispatchKey": "0x05251484",
+                                                                "name": "Discount",
+                                                                "variants": {
+                                                                    "0": {
+                                                                        "fields": [
+                                                                            {
+                                                                                "layout": {
+                                                                                    "leaf": {
+                                                                                        "key": "0x05251484",
+                                                                                        "ty": 6
+                                                                                    }
+                                                                                },
+                                                                                "name": "0"
+                                                                            }
+                                                                        ],
+                                                                        "name": "Percent"
+                                                                    },
+                                                                    "1": {
+                                                                        "fields": [
+                                                                            {
+                                                                                "layout": {
+                                                                                    "leaf": {
+                                                                                        "key": "0x05251484",
+                                                                                        "ty": 1
+                                                                                    }
+                                                                                },
+                                                                                "name": "0"
+                                                                            }
+                                                                        ],
+                                                                        "name": "Absolute"
+                                                                    }
+                                                                }
+                                                            }
+                                                        },
+                                                        "name": "discount"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0x05251484",
+                                                                "ty": 27
+                                                            }
+                                                        },
+                                                        "name": "start"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0x05251484",
+                                                                "ty": 27
+                                                            }
+                                                        },
+                                                        "name": "end"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0x05251484",
+                                                                "ty": 30
+                                                            }
+                                                        },
+                                                        "name": "max_per_player"
+                                                    }
+                                                ],
+                                                "name": "Sale"
+                                            }
+                                        },
+                                        "root_key": "0x05251484",
+                                        "ty": 71
+                                    }
+                                },
+                                "name": "sales"
+                            },
+                            {
+                                "layout": {
+                                    "root": {
+                                        "layout": {
+                                            "leaf": {
+                                                "key": "0x2442e0e1",
+                                                "ty": 76
+                                            }
+                                        },
+                                        "root_key": "0x2442e0e1",
+                                        "ty": 77
+                                    }
+                                },
+                                "name": "asset_sales"
+                            },
+                            {
+                                "layout": {
+                                    "leaf": {
+                                        "key": "0x00000000",
+                                        "ty": 27
+                                    }
+                                },
+                                "name": "next_sale_id"
+                            },
+                            {
+                                "layout": {
+                                    "root": {
+                                        "layout": {
+                                            "leaf": {
+                                                "key": "0x8b01fa86",
+                                                "ty": 30
+                                            }
+                                        },
+                                        "root_key": "0x8b01fa86",
+                                        "ty": 80
+                                    }
+                                },
+                                "name": "sale_purchases"
+                            },
+                            {
+                                "layout": {
+                                    "root": {
+                                        "layout": {
+                                            "struct": {
+                                                "fields": [
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0x6b9a7698",
+                                                                "ty": 0
+                                                            }
+                                                        },
+                                                        "name": "game"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0x6b9a7698",
+                                                                "ty": 0
+                                                            }
+                                                        },
+                                                        "name": "asset"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "enum": {
+                                                                "dispatchKey": "0x6b9a7698",
+                                                                "name": "Discount",
+                                                                "variants": {
+                                                                    "0": {
+                                                                        "fields": [
+                                                                            {
+                                                                                "layout": {
+                                                                                    "leaf": {
+                                                                                        "key": "0x6b9a7698",
+                                                                                        "ty": 6
+                                                                                    }
+                                                                                },
+                                                                                "name": "0"
+                                                                            }
+                                                                        ],
+                                                                        "name": "Percent"
+                                                                    },
+                                                                    "1": {
+                                                                        "fields": [
+                                                                            {
+                                                                                "layout": {
+                                                                                    "leaf": {
+                                                                                        "key": "0x6b9a7698",
+                                                                                        "ty": 1
+                                                                                    }
+                                                                                },
+                                                                                "name": "0"
+                                                                            }
+                                                                        ],
+                                                                        "name": "Absolute"
+                                                                    }
+                                                                }
+                                                            }
+                                                        },
+                                                        "name": "discount"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0x6b9a7698",
+                                                                "ty": 27
+                                                            }
+                                                        },
+                                                        "name": "expires"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0x6b9a7698",
+                                                                "ty": 27
+                                                            }
+                                                        },
+                                                        "name": "remaining_uses"
+                                                    }
+                                                ],
+                                                "name": "Coupon"
+                                            }
+                                        },
+                                        "root_key": "0x6b9a7698",
+                                        "ty": 83
+                                    }
+                                },
+                                "name": "coupons"
+                            },
+                            {
+                                "layout": {
+                                    "root": {
+                                        "layout": {
+                                            "enum": {
+                                                "dispatchKey": "0xa1128801",
+                                                "name": "PriceCurve",
+                                                "variants": {
+                                                    "0": {
+                                                        "fields": [
+                                                            {
+                                                                "layout": {
+                                                                    "leaf": {
+                                                                        "key": "0xa1128801",
+                                                                        "ty": 1
+                                                                    }
+                                                                },
+                                                                "name": "base"
+                                                            },
+                                                            {
+                                                                "layout": {
+                                                                    "leaf": {
+                                                                        "key": "0xa1128801",
+                                                                        "ty": 1
+                                                                    }
+                                                                },
+                                                                "name": "slope"
+                                                            }
+                                                        ],
+                                                        "name": "Linear"
+                                                    },
+                                                    "1": {
+                                                        "fields": [
+                                                            {
+                                                                "layout": {
+                                                                    "leaf": {
+                                                                        "key": "0xa1128801",
+                                                                        "ty": 1
+                                                                    }
+                                                                },
+                                                                "name": "base"
+                                                            },
+                                                            {
+                                                                "layout": {
+                                                                    "leaf": {
+                                                                        "key": "0xa1128801",
+                                                                        "ty": 27
+                                                                    }
+                                                                },
+                                                                "name": "growth_bps"
+                                                            }
+                                                        ],
+                                                        "name": "Exponential"
+                                                    },
+                                                    "2": {
+                                                        "fields": [
+                                                            {
+                                                                "layout": {
+                                                                    "leaf": {
+                                                                        "key": "0xa1128801",
+                                                                        "ty": 1
+                                                                    }
+                                                                },
+                                                                "name": "base"
+                                                            },
+                                                            {
+                                                                "layout": {
+                                                                    "leaf": {
+                                                                        "key": "0xa1128801",
+                                                                        "ty": 30
+                                                                    }
+                                                                },
+                                                                "name": "step"
+                                                            },
+                                                            {
+                                                                "layout": {
+                                                                    "leaf": {
+                                                                        "key": "0xa1128801",
+                                                                        "ty": 1
+                                                                    }
+                                                                },
+                                                                "name": "increment"
+                                                            }
+                                                        ],
+                                                        "name": "Step"
+                                                    }
+                                                }
+                                            }
+                                        },
+                                        "root_key": "0xa1128801",
+                                        "ty": 87
+                                    }
+                                },
+                                "name": "curves"
+                            },
+                            {
+                                "layout": {
+                                    "root": {
+                                        "layout": {
+                                            "leaf": {
+                                                "key": "0x0c100280",
+                                                "ty": 30
+                                            }
+                                        },
+                                        "root_key": "0x0c100280",
+                                        "ty": 91
+                                    }
+                                },
+                                "name": "supply"
+                            },
+                            {
+                                "layout": {
+                                    "root": {
+                                        "layout": {
+                                            "leaf": {
+                                                "key": "0x96e62f80",
+                                                "ty": 1
+                                            }
+                                        },
+                                        "root_key": "0x96e62f80",
+                                        "ty": 94
+                                    }
+                                },
+                                "name": "curve_reserves"
+                            },
+                            {
+                                "layout": {
+                                    "root": {
+                                        "layout": {
+                                            "leaf": {
+                                                "key": "0xe19f3875",
+                                                "ty": 6
+                                            }
+                                        },
+                                        "root_key": "0xe19f3875",
+                                        "ty": 97
+                                    }
+                                },
+                                "name": "buyback_rates"
+                            },
+                            {
+                                "layout": {
+                                    "root": {
+                                        "layout": {
+                                            "leaf": {
+                                                "key": "0x2d028125",
+                                                "ty": 1
+                                            }
+                                        },
+                                        "root_key": "0x2d028125",
+                                        "ty": 100
+                                    }
+                                },
+                                "name": "game_reserves"
+                            },
+                            {
+                                "layout": {
+                                    "root": {
+                                        "layout": {
+                                            "struct": {
+                                                "fields": [
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0xac01f4e5",
+                                                                "ty": 0
+                                                            }
+                                                        },
+                                                        "name": "game"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0xac01f4e5",
+                                                                "ty": 0
+                                                            }
+                                                        },
+                                                        "name": "name"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0xac01f4e5",
+                                                                "ty": 0
+                                                            }
+                                                        },
+                                                        "name": "symbol"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0xac01f4e5",
+                                                                "ty": 6
+                                                            }
+                                                        },
+                                                        "name": "decimals"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0xac01f4e5",
+                                                                "ty": 1
+                                                            }
+                                                        },
+                                                        "name": "total_supply"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0xac01f4e5",
+                                                                "ty": 37
+                                                            }
+                                                        },
+                                                        "name": "prices_denominated"
+                                                    }
+                                                ],
+                                                "name": "Currency"
+                                            }
+                                        },
+                                        "root_key": "0xac01f4e5",
+                                        "ty": 103
+                                    }
+                                },
+                                "name": "currencies"
+                            },
+                            {
+                                "layout": {
+                                    "root": {
+                                        "layout": {
+                                            "leaf": {
+                                                "key": "0xa7cae35f",
+                                                "ty": 27
+                                            }
+                                        },
+                                        "root_key": "0xa7cae35f",
+                                        "ty": 107
+                                    }
+                                },
+                                "name": "game_currencies"
+                            },
+                            {
+                                "layout": {
+                                    "leaf": {
+                                        "key": "0x00000000",
+                                        "ty": 27
+                                    }
+                                },
+                                "name": "next_currency_id"
+                            },
+                            {
+                                "layout": {
+                                    "root": {
+                                        "layout": {
+                                            "leaf": {
+                                                "key": "0x81a10f4e",
+                                                "ty": 1
+                                            }
+                                        },
+                                        "root_key": "0x81a10f4e",
+                                        "ty": 110
+                                    }
+                                },
+                                "name": "currency_balances"
+                            },
+                            {
+                                "layout": {
+                                    "root": {
+                                        "layout": {
+                                            "leaf": {
+                                                "key": "0xd8bf38fd",
+                                                "ty": 1
+                                            }
+                                        },
+                                        "root_key": "0xd8bf38fd",
+                                        "ty": 113
+                                    }
+                                },
+                                "name": "currency_allowances"
+                            },
+                            {
+                                "layout": {
+                                    "root": {
+                                        "layout": {
+                                            "enum": {
+                                                "dispatchKey": "0x113d11d4",
+                                                "name": "PriceCurrency",
+                                                "variants": {
+                                                    "0": {
+                                                        "fields": [],
+                                                        "name": "Store"
+                                                    },
+                                                    "1": {
+                                                        "fields": [],
+                                                        "name": "Native"
+                                                    },
+                                                    "2": {
+                                                        "fields": [
+                                                            {
+                                                                "layout": {
+                                                                    "leaf": {
+                                                                        "key": "0x113d11d4",
+                                                                        "ty": 4
+                                                                    }
+                                                                },
+                                                                "name": "0"
+                                                            }
+                                                        ],
+                                                        "name": "Psp22"
+                                                    }
+                                                }
+                                            }
+                                        },
+                                        "root_key": "0x113d11d4",
+                                        "ty": 117
+                                    }
+                                },
+                                "name": "price_currencies"
+                            },
+                            {
+                                "layout": {
+                                    "root": {
+                                        "layout": {
+                                            "struct": {
+                                                "fields": [
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0x53c0edc7",
+                                                                "ty": 1
+                                                            }
+                                                        },
+                                                        "name": "rate"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0x53c0edc7",
+                                                                "ty": 1
+                                                            }
+                                                        },
+                                                        "name": "reward_per_unit"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0x53c0edc7",
+                                                                "ty": 27
+                                                            }
+                                                        },
+                                                        "name": "last_update"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0x53c0edc7",
+                                                                "ty": 30
+                                                            }
+                                                        },
+                                                        "name": "total_staked"
+                                                    }
+                                                ],
+                                                "name": "RewardPool"
+                                            }
+                                        },
+                                        "root_key": "0x53c0edc7",
+                                        "ty": 121
+                                    }
+                                },
+                                "name": "reward_pools"
+                            },
+                            {
+                                "layout": {
+                                    "root": {
+                                        "layout": {
+                                            "struct": {
+                                                "fields": [
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0x0b019e75",
+                                                                "ty": 30
+                                                            }
+                                                        },
+                                                        "name": "amount"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0x0b019e75",
+                                                                "ty": 1
+                                                            }
+                                                        },
+                                                        "name": "reward_debt"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0x0b019e75",
+                                                                "ty": 1
+                                                            }
+                                                        },
+                                                        "name": "pending"
+                                                    }
+                                                ],
+                                                "name": "Stake"
+                                            }
+                                        },
+                                        "root_key": "0x0b019e75",
+                                        "ty": 125
+                                    }
+                                },
+                                "name": "stakes"
+                            },
+                            {
+                                "layout": {
+                                    "root": {
+                                        "layout": {
+                                            "struct": {
+                                                "fields": [
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0x86bb31b8",
+                                                                "ty": 0
+                                                            }
+                                                        },
+                                                        "name": "name"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0x86bb31b8",
+                                                                "ty": 130
+                                                            }
+                                                        },
+                                                        "name": "members"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0x86bb31b8",
+                                                                "ty": 27
+                                                            }
+                                                        },
+                                                        "name": "quorum"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0x86bb31b8",
+                                                                "ty": 1
+                                                            }
+                                                        },
+                                                        "name": "balance"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0x86bb31b8",
+                                                                "ty": 60
+                                                            }
+                                                        },
+                                                        "name": "assets"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0x86bb31b8",
+                                                                "ty": 27
+                                                            }
+                                                        },
+                                                        "name": "next_proposal_id"
+                                                    }
+                                                ],
+                                                "name": "Guild"
+                                            }
+                                        },
+                                        "root_key": "0x86bb31b8",
+                                        "ty": 133
+                                    }
+                                },
+                                "name": "guilds"
+                            },
+                            {
+                                "layout": {
+                                    "leaf": {
+                                        "key": "0x00000000",
+                                        "ty": 27
+                                    }
+                                },
+                                "name": "next_guild_id"
+                            },
+                            {
+                                "layout": {
+                                    "root": {
+                                        "layout": {
+                                            "struct": {
+                                                "fields": [
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0x50cca83a",
+                                                                "ty": 4
+                                                            }
+                                                        },
+                                                        "name": "to"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "enum": {
+                                                                "dispatchKey": "0x50cca83a",
+                                                                "name": "VaultItem",
+                                                                "variants": {
+                                                                    "0": {
+                                                                        "fields": [
+                                                                            {
+                                                                                "layout": {
+                                                                                    "leaf": {
+                                                                                        "key": "0x50cca83a",
+                                                                                        "ty": 1
+                                                                                    }
+                                                                                },
+                                                                                "name": "0"
+                                                                            }
+                                                                        ],
+                                                                        "name": "Balance"
+                                                                    },
+                                                                    "1": {
+                                                                        "fields": [
+                                                                            {
+                                                                                "layout": {
+                                                                                    "leaf": {
+                                                                                        "key": "0x50cca83a",
+                                                                                        "ty": 0
+                                                                                    }
+                                                                                },
+                                                                                "name": "0"
+                                                                            },
+                                                                            {
+                                                                                "layout": {
+                                                                                    "leaf": {
+                                                                                        "key": "0x50cca83a",
+                                                                                        "ty": 30
+                                                                                    }
+                                                                                },
+                                                                                "name": "1"
+                                                                            }
+                                                                        ],
+                                                                        "name": "Asset"
+                                                                    }
+                                                                }
+                                                            }
+                                                        },
+                                                        "name": "item"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0x50cca83a",
+                                                                "ty": 137
+                                                            }
+                                                        },
+                                                        "name": "approvals"
+                                                    }
+                                                ],
+                                                "name": "Withdrawal"
+                                            }
+                                        },
+                                        "root_key": "0x50cca83a",
+                                        "ty": 138
+                                    }
+                                },
+                                "name": "withdrawals"
+                            },
+                            {
+                                "layout": {
+                                    "root": {
+                                        "layout": {
+                                            "struct": {
+                                                "fields": [
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0x349a31b2",
+                                                                "ty": 137
+                                                            }
+                                                        },
+                                                        "name": "signers"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0x349a31b2",
+                                                                "ty": 27
+                                                            }
+                                                        },
+                                                        "name": "threshold"
+                                                    }
+                                                ],
+                                                "name": "GameCouncil"
+                                            }
+                                        },
+                                        "root_key": "0x349a31b2",
+                                        "ty": 144
+                                    }
+                                },
+                                "name": "councils"
+                            },
+                            {
+                                "layout": {
+                                    "root": {
+                                        "layout": {
+                                            "struct": {
+                                                "fields": [
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0xd0b773ff",
+                                                                "ty": 0
+                                                            }
+                                                        },
+                                                        "name": "game"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "enum": {
+                                                                "dispatchKey": "0xd0b773ff",
+                                                                "name": "GameAction",
+                                                                "variants": {
+                                                                    "0": {
+                                                                        "fields": [
+                                                                            {
+                                                                                "layout": {
+                                                                                    "leaf": {
+                                                                                        "key": "0xd0b773ff",
+                                                                                        "ty": 0
+                                                                                    }
+                                                                                },
+                                                                                "name": "asset"
+                                                                            },
+                                                                            {
+                                                                                "layout": {
+                                                                                    "leaf": {
+                                                                                        "key": "0xd0b773ff",
+                                                                                        "ty": 1
+                                                                                    }
+                                                                                },
+                                                                                "name": "price"
+                                                                            }
+                                                                        ],
+                                                                        "name": "SetPrice"
+                                                                    },
+                                                                    "1": {
+                                                                        "fields": [
+                                                                            {
+                                                                                "layout": {
+                                                                                    "leaf": {
+                                                                                        "key": "0xd0b773ff",
+                                                                                        "ty": 0
+                                                                                    }
+                                                                                },
+                                                                                "name": "asset"
+                                                                            },
+                                                                            {
+                                                                                "layout": {
+                                                                                    "leaf": {
+                                                                                        "key": "0xd0b773ff",
+                                                                                        "ty": 4
+                                                                                    }
+                                                                                },
+                                                                                "name": "owner"
+                                                                            }
+                                                                        ],
+                                                                        "name": "MintItem"
+                                                                    },
+                                                                    "10": {
+                                                                        "fields": [
+                                                                            {
+                                                                                "layout": {
+                                                                                    "struct": {
+                                                                                        "fields": [
+                                                                                            {
+                                                                                                "layout": {
+                                                                                                    "enum": {
+                                                                                                        "dispatchKey": "0xd0b773ff",
+                                                                                                        "name": "VaultItem",
+                                                                                                        "variants": {
+                                                                                                            "0": {
+                                                                                                                "fields": [
+                                                                                                                    {
+                                                                                                                        "layout": {
+                                                                                                                            "leaf": {
+                                                                                                                                "key": "0xd0b773ff",
+                                                                                                                                "ty": 1
+                                                                                                                            }
+                                                                                                                        },
+                                                                                                                        "name": "0"
+                                                                                                                    }
+                                                                                                                ],
+                                                                                                                "name": "Balance"
+                                                                                                            },
+                                                                                                            "1": {
+                                                                                                                "fields": [
+                                                                                                                    {
+                                                                                                                        "layout": {
+                                                                                                                            "leaf": {
+                                                                                                                                "key": "0xd0b773ff",
+                                                                                                                                "ty": 0
+                                                                                                                            }
+                                                                                                                        },
+                                                                                                                        "name": "0"
+                                                                                                                    },
+                                                                                                                    {
+                                                                                                                        "layout": {
+                                                                                                                            "leaf": {
+                                                                                                                                "key": "0xd0b773ff",
+                                                                                                                                "ty": 30
+                                                                                                                            }
+                                                                                                                        },
+                                                                                                                        "name": "1"
+                                                                                                                    }
+                                                                                                                ],
+                                                                                                                "name": "Asset"
+                                                                                                            }
+                                                                                                        }
+                                                                                                    }
+                                                                                                },
+                                                                                                "name": "entry_fee"
+                                                                                            },
+                                                                                            {
+                                                                                                "layout": {
+                                                                                                    "leaf": {
+                                                                                                        "key": "0xd0b773ff",
+                                                                                                        "ty": 27
+                                                                                                    }
+                                                                                                },
+                                                                                                "name": "max_entrants"
+                                                                                            },
+                                                                                            {
+                                                                                                "layout": {
+                                                                                                    "leaf": {
+                                                                                                        "key": "0xd0b773ff",
+                                                                                                        "ty": 27
+                                                                                                    }
+                                                                                                },
+                                                                                                "name": "opens_at"
+                                                                                            },
+                                                                                            {
+                                                                                                "layout": {
+                                                                                                    "leaf": {
+                                                                                                        "key": "0xd0b773ff",
+                                                                                                        "ty": 27
+                                                                                                    }
+                                                                                                },
+                                                                                                "name": "closes_at"
+                                                                                            },
+                                                                                            {
+                                                                                                "layout": {
+                                                                                                    "leaf": {
+                                                                                                        "key": "0xd0b773ff",
+                                                                                                        "ty": 148
+                                                                                                    }
+                                                                                                },
+                                                                                                "name": "prizes"
+                                                                                            },
+                                                                                            {
+                                                                                                "layout": {
+                                                                                                    "enum": {
+                                                                                                        "dispatchKey": "0xd0b773ff",
+                                                                                                        "name": "Option",
+                                                                                                        "variants": {
+                                                                                                            "0": {
+                                                                                                                "fields": [],
+                                                                                                                "name": "None"
+                                                                                                            },
+                                                                                                            "1": {
+                                                                                                                "fields": [
+                                                                                                                    {
+                                                                                                                        "layout": {
+                                                                                                                            "leaf": {
+                                                                                                                                "key": "0xd0b773ff",
+                                                                                                                                "ty": 4
+                                                                                                                            }
+                                                                                                                        },
+                                                                                                                        "name": "0"
+                                                                                                                    }
+                                                                                                                ],
+                                                                                                                "name": "Some"
+                                                                                                            }
+                                                                                                        }
+                                                                                                    }
+                                                                                                },
+                                                                                                "name": "oracle"
+                                                                                            }
+                                                                                        ],
+                                                                                        "name": "TournamentTerms"
+                                                                                    }
+                                                                                },
+                                                                                "name": "terms"
+                                                                            }
+                                                                        ],
+                                                                        "name": "CreateTournament"
+                                                                    },
+                                                                    "11": {
+                                                                        "fields": [
+                                                                            {
+                                                                                "layout": {
+                                                                                    "leaf": {
+                                                                                        "key": "0xd0b773ff",
+                                                                                        "ty": 0
+                                                                                    }
+                                                                                },
+                                                                                "name": "asset"
+                                                                            },
+                                                                            {
+                                                                                "layout": {
+                                                                                    "leaf": {
+                                                                                        "key": "0xd0b773ff",
+                                                                                        "ty": 6
+                                                                                    }
+                                                                                },
+                                                                                "name": "percent"
+                                                                            }
+                                                                        ],
+                                                                        "name": "SetBuyback"
+                                                                    },
+                                                                    "12": {
+                                                                        "fields": [
+                                                                            {
+                                                                                "layout": {
+                                                                                    "leaf": {
+                                                                                        "key": "0xd0b773ff",
+                                                                                        "ty": 0
+                                                                                    }
+                                                                                },
+                                                                                "name": "asset"
+                                                                            },
+                                                                            {
+                                                                                "layout": {
+                                                                                    "enum": {
+                                                                                        "dispatchKey": "0xd0b773ff",
+                                                                                        "name": "PriceCurrency",
+                                                                                        "variants": {
+                                                                                            "0": {
+                                                                                                "fields": [],
+                                                                                                "name": "Store"
+                                                                                            },
+                                                                                            "1": {
+                                                                                                "fields": [],
+                                                                                                "name": "Native"
+                                                                                            },
+                                                                                            "2": {
+                                                                                                "fields": [
+                                                                                                    {
+                                                                                                        "layout": {
+                                                                                                            "leaf": {
+                                                                                                                "key": "0xd0b773ff",
+                                                                                                                "ty": 4
+                                                                                                            }
+                                                                                                        },
+                                                                                                        "name": "0"
+                                                                                                    }
+                                                                                                ],
+                                                                                                "name": "Psp22"
+                                                                                            }
+                                                                                        }
+                                                                                    }
+                                                                                },
+                                                                                "name": "currency"
+                                                                            }
+                                                                        ],
+                                                                        "name": "SetPriceCurrency"
+                                                                    },
+                                                                    "13": {
+                                                                        "fields": [
+                                                                            {
+                                                                                "layout": {
+                                                                                    "leaf": {
+                                                                                        "key": "0xd0b773ff",
+                                                                                        "ty": 4
+                                                                                    }
+                                                                                },
+                                                                                "name": "operator"
+                                                                            },
+                                                                            {
+                                                                                "layout": {
+                                                                                    "leaf": {
+                                                                                        "key": "0xd0b773ff",
+                                                                                        "ty": 37
+                                                                                    }
+                                                                                },
+                                                                                "name": "allowed"
+                                                                            }
+                                                                        ],
+                                                                        "name": "SetOperator"
+                                                                    },
+                                                                    "14": {
+                                                                        "fields": [
+                                                                            {
+                                                                                "layout": {
+                                                                                    "enum": {
+                                                                                        "dispatchKey": "0xd0b773ff",
+                                                                                        "name": "Option",
+                                                                                        "variants": {
+                                                                                            "0": {
+                                                                                                "fields": [],
+                                                                                                "name": "None"
+                                                                                            },
+                                                                                            "1": {
+                                                                                                "fields": [
+                                                                                                    {
+                                                                                                        "layout": {
+                                                                                                            "enum": {
+                                                                                                                "dispatchKey": "0xd0b773ff",
+                                                                                                                "name": "SignerKey",
+                                                                                                                "variants": {
+                                                                                                                    "0": {
+                                                                                                                        "fields": [
+                                                                                                                            {
+                                                                                                                                "layout": {
+                                                                                                                                    "array": {
+                                                                                                                                        "layout": {
+                                                                                                                                            "leaf": {
+                                                                                                                                                "key": "0xd0b773ff",
+                                                                                                                                                "ty": 6
+                                                                                                                                            }
+                                                                                                                                        },
+                                                                                                                                        "len": 32,
+                                                                                                                                        "offset": "0xd0b773ff"
+                                                                                                                                    }
+                                                                                                                                },
+                                                                                                                                "name": "0"
+                                                                                                                            }
+                                                                                                                        ],
+                                                                                                                        "name": "Sr25519"
+                                                                                                                    },
+                                                                                                                    "1": {
+                                                                                                                        "fields": [
+                                                                                                                            {
+                                                                                                                                "layout": {
+                                                                                                                                    "array": {
+                                                                                                                                        "layout": {
+                                                                                                                                            "leaf": {
+                                                                                                                                                "key": "0xd0b773ff",
+                                                                                                                                                "ty": 6
+                                                                                                                                            }
+                                                                                                                                        },
+                                                                                                                                        "len": 33,
+                                                                                                                                        "offset": "0xd0b773ff"
+                                                                                                                                    }
+                                                                                                                                },
+                                                                                                                                "name": "0"
+                                                                                                                            }
+                                                                                                                        ],
+                                                                                                                        "name": "Ecdsa"
+                                                                                                                    }
+                                                                                                                }
+                                                                                                            }
+                                                                                                        },
+                                                                                                        "name": "0"
+                                                                                                    }
+                                                                                                ],
+                                                                                                "name": "Some"
+                                                                                            }
+                                                                                        }
+                                                                                    }
+                                                                                },
+                                                                                "name": "signer"
+                                                                            }
+                                                                        ],
+                                                                        "name": "SetVoucherSigner"
+                                                                    },
+                                                                    "15": {
+                                                                        "fields": [
+                                                                            {
+                                                                                "layout": {
+                                                                                    "leaf": {
+                                                                                        "key": "0xd0b773ff",
+                                                                                        "ty": 0
+                                                                                    }
+                                                                                },
+                                                                                "name": "achievement"
+                                                                            },
+                                                                            {
+                                                                                "layout": {
+                                                                                    "leaf": {
+                                                                                        "key": "0xd0b773ff",
+                                                                                        "ty": 4
+                                                                                    }
+                                                                                },
+                                                                                "name": "account"
+                                                                            }
+                                                                        ],
+                                                                        "name": "GrantAchievement"
+                                                                    },
+                                                                    "16": {
+                                                                        "fields": [
+                                                                            {
+                                                                                "layout": {
+                                                                                    "leaf": {
+                                                                                        "key": "0xd0b773ff",
+                                                                                        "ty": 4
+                                                                                    }
+                                                                                },
+                                                                                "name": "account"
+                                                                            },
+                                                                            {
+                                                                                "layout": {
+                                                                                    "leaf": {
+                                                                                        "key": "0xd0b773ff",
+                                                                                        "ty": 152
+                                                                                    }
+                                                                                },
+                                                                                "name": "delta"
+                                                                            }
+                                                                        ],
+                                                                        "name": "AdjustReputation"
+                                                                    },
+                                                                    "17": {
+                                                                        "fields": [
+                                                                            {
+                                                                                "layout": {
+                                                                                    "struct": {
+                                                                                        "fields": [
+                                                                                            {
+                                                                                                "layout": {
+                                                                                                    "leaf": {
+                                                                                                        "key": "0xd0b773ff",
+                                                                                                        "ty": 0
+                                                                                                    }
+                                                                                                },
+                                                                                                "name": "0"
+                                                                                            },
+                                                                                            {
+                                                                                                "layout": {
+                                                                                                    "leaf": {
+                                                                                                        "key": "0xd0b773ff",
+                                                                                                        "ty": 0
+                                                                                                    }
+                                                                                                },
+                                                                                                "name": "1"
+                                                                                            },
+                                                                                            {
+                                                                                                "layout": {
+                                                                                                    "leaf": {
+                                                                                                        "key": "0xd0b773ff",
+                                                                                                        "ty": 0
+                                                                                                    }
+                                                                                                },
+                                                                                                "name": "2"
+                                                                                            },
+                                                                                            {
+                                                                                                "layout": {
+                                                                                                    "leaf": {
+                                                                                                        "key": "0xd0b773ff",
+                                                                                                        "ty": 0
+                                                                                                    }
+                                                                                                },
+                                                                                                "name": "3"
+                                                                                            }
+                                                                                        ],
+                                                                                        "name": "(A, B, C, D)"
+                                                                                    }
+                                                                                },
+                                                                                "name": "route"
+                                                                            },
+                                                                            {
+                                                                                "layout": {
+                                                                                    "leaf": {
+                                                                                        "key": "0xd0b773ff",
+                                                                                        "ty": 30
+                                                                                    }
+                                                                                },
+                                                                                "name": "give_units"
+                                                                            },
+                                                                            {
+                                                                                "layout": {
+                                                                                    "leaf": {
+                                                                                        "key": "0xd0b773ff",
+                                                                                        "ty": 30
+                                                                                    }
+                                                                                },
+                                                                                "name": "take_units"
+                                                                            },
+                                                                            {
+                                                                                "layout": {
+                                                                                    "leaf": {
+                                                                                        "key": "0xd0b773ff",
+                                                                                        "ty": 30
+                                                                                    }
+                                                                                },
+                                                                                "name": "cap_per_period"
+                                                                            },
+                                                                            {
+                                                                                "layout": {
+                                                                                    "leaf": {
+                                                                                        "key": "0xd0b773ff",
+                                                                                        "ty": 27
+                                                                                    }
+                                                                                },
+                                                                                "name": "period"
+                                                                            }
+                                                                        ],
+                                                                        "name": "ProposeConversion"
+                                                                    },
+                                                                    "18": {
+                                                                        "fields": [
+                                                                            {
+                                                                                "layout": {
+                                                                                    "leaf": {
+                                                                                        "key": "0xd0b773ff",
+                                                                                        "ty": 0
+                                                                                    }
+                                                                                },
+                                                                                "name": "name"
+                                                                            },
+                                                                            {
+                                                                                "layout": {
+                                                                                    "leaf": {
+                                                                                        "key": "0xd0b773ff",
+                                                                                        "ty": 0
+                                                                                    }
+                                                                                },
+                                                                                "name": "symbol"
+                                                                            },
+                                                                            {
+                                                                                "layout": {
+                                                                                    "leaf": {
+                                                                                        "key": "0xd0b773ff",
+                                                                                        "ty": 6
+                                                                                    }
+                                                                                },
+                                                                                "name": "decimals"
+                                                                            },
+                                                                            {
+                                                                                "layout": {
+                                                                                    "leaf": {
+                                                                                        "key": "0xd0b773ff",
+                                                                                        "ty": 37
+                                                                                    }
+                                                                                },
+                                                                                "name": "denominate_prices"
+                                                                            }
+                                                                        ],
+                                                                        "name": "CreateCurrency"
+                                                                    },
+                                                                    "19": {
+                                                                        "fields": [
+                                                                            {
+                                                                                "layout": {
+                                                                                    "leaf": {
+                                                                                        "key": "0xd0b773ff",
+                                                                                        "ty": 0
+                                                                                    }
+                                                                                },
+                                                                                "name": "name"
+                                                                            },
+                                                                            {
+                                                                                "layout": {
+                                                                                    "leaf": {
+                                                                                        "key": "0xd0b773ff",
+                                                                                        "ty": 1
+                                                                                    }
+                                                                                },
+                                                                                "name": "price"
+                                                                            },
+                                                                            {
+                                                                                "layout": {
+                                                                                    "leaf": {
+                                                                                        "key": "0xd0b773ff",
+                                                                                        "ty": 37
+                                                                                    }
+                                                                                },
+                                                                                "name": "recoverable"
+                                                                            }
+                                                                        ],
+                                                                        "name": "RegisterAsset"
+                                                                    },
+                                                                    "2": {
+                                                                        "fields": [
+                                                                            {
+                                                                                "layout": {
+                                                                                    "leaf": {
+                                                                                        "key": "0xd0b773ff",
+                                                                                        "ty": 4
+                                                                                    }
+                                                                                },
+                                                                                "name": "to"
+                                                                            },
+                                                                            {
+                                                                                "layout": {
+                                                                                    "leaf": {
+                                                                                        "key": "0xd0b773ff",
+                                                                                        "ty": 1
+                                                                                    }
+                                                                                },
+                                                                                "name": "value"
+                                                                            }
+                                                                        ],
+                                                                        "name": "MintCurrency"
+                                                                    },
+                                                                    "3": {
+                                                                        "fields": [
+                                                                            {
+                                                                                "layout": {
+                                                                                    "leaf": {
+                                                                                        "key": "0xd0b773ff",
+                                                                                        "ty": 4
+                                                                                    }
+                                                                                },
+                                                                                "name": "to"
+                                                                            },
+                                                                            {
+                                                                                "layout": {
+                                                                                    "leaf": {
+                                                                                        "key": "0xd0b773ff",
+                                                                                        "ty": 1
+                                                                                    }
+                                                                                },
+                                                                                "name": "amount"
+                                                                            }
+                                                                        ],
+                                                                        "name": "WithdrawReserve"
+                                                                    },
+                                                                    "4": {
+                                                                        "fields": [
+                                                                            {
+                                                                                "layout": {
+                                                                                    "leaf": {
+                                                                                        "key": "0xd0b773ff",
+                                                                                        "ty": 137
+                                                                                    }
+                                                                                },
+                                                                                "name": "signers"
+                                                                            },
+                                                                            {
+                                                                                "layout": {
+                                                                                    "leaf": {
+                                                                                        "key": "0xd0b773ff",
+                                                                                        "ty": 27
+                                                                                    }
+                                                                                },
+                                                                                "name": "threshold"
+                                                                            }
+                                                                        ],
+                                                                        "name": "SetSigners"
+                                                                    },
+                                                                    "5": {
+                                                                        "fields": [
+                                                                            {
+                                                                                "layout": {
+                                                                                    "leaf": {
+                                                                                        "key": "0xd0b773ff",
+                                                                                        "ty": 60
+                                                                                    }
+                                                                                },
+                                                                                "name": "contents"
+                                                                            },
+                                                                            {
+                                                                                "layout": {
+                                                                                    "leaf": {
+                                                                                        "key": "0xd0b773ff",
+                                                                                        "ty": 1
+                                                                                    }
+                                                                                },
+                                                                                "name": "price"
+                                                                            },
+                                                                            {
+                                                                                "layout": {
+                                                                                    "leaf": {
+                                                                                        "key": "0xd0b773ff",
+                                                                                        "ty": 30
+                                                                                    }
+                                                                                },
+                                                                                "name": "max_per_player"
+                                                                            },
+                                                                            {
+                                                                                "layout": {
+                                                                                    "leaf": {
+                                                                                        "key": "0xd0b773ff",
+                                                                                        "ty": 27
+                                                                                    }
+                                                                                },
+                                                                                "name": "available_from"
+                                                                            },
+                                                                            {
+                                                                                "layout": {
+                                                                                    "enum": {
+                                                                                        "dispatchKey": "0xd0b773ff",
+                                                                                        "name": "Option",
+                                                                                        "variants": {
+                                                                                            "0": {
+                                                                                                "fields": [],
+                                                                                                "name": "None"
+                                                                                            },
+                                                                                            "1": {
+                                                                                                "fields": [
+                                                                                                    {
+                                                                                                        "layout": {
+                                                                                                            "leaf": {
+                                                                                                                "key": "0xd0b773ff",
+                                                                                                                "ty": 27
+                                                                                                            }
+                                                                                                        },
+                                                                                                        "name": "0"
+                                                                                                    }
+                                                                                                ],
+                                                                                                "name": "Some"
+                                                                                            }
+                                                                                        }
+                                                                                    }
+                                                                                },
+                                                                                "name": "available_until"
+                                                                            }
+                                                                        ],
+                                                                        "name": "CreateBundle"
+                                                                    },
+                                                                    "6": {
+                                                                        "fields": [
+                                                                            {
+                                                                                "layout": {
+                                                                                    "leaf": {
+                                                                                        "key": "0xd0b773ff",
+                                                                                        "ty": 0
+                                                                                    }
+                                                                                },
+                                                                                "name": "asset"
+                                                                            },
+                                                                            {
+                                                                                "layout": {
+                                                                                    "leaf": {
+                                                                                        "key": "0xd0b773ff",
+                                                                                        "ty": 28
+                                                                                    }
+                                                                                },
+                                                                                "name": "code_hash"
+                                                                            },
+                                                                            {
+                                                                                "layout": {
+                                                                                    "enum": {
+                                                                                        "dispatchKey": "0xd0b773ff",
+                                                                                        "name": "Discount",
+                                                                                        "variants": {
+                                                                                            "0": {
+                                                                                                "fields": [
+                                                                                                    {
+                                                                                                        "layout": {
+                                                                                                            "leaf": {
+                                                                                                                "key": "0xd0b773ff",
+                                                                                                                "ty": 6
+                                                                                                            }
+                                                                                                        },
+                                                                                                        "name": "0"
+                                                                                                    }
+                                                                                                ],
+                                                                                                "name": "Percent"
+                                                                                            },
+                                                                                            "1": {
+                                                                                                "fields": [
+                                                                                                    {
+                                                                                                        "layout": {
+                                                                                                            "leaf": {
+                                                                                                                "key": "0xd0b773ff",
+                                                                                                                "ty": 1
+                                                                                                            }
+                                                                                                        },
+                                                                                                        "name": "0"
+                                                                                                    }
+                                                                                                ],
+                                                                                                "name": "Absolute"
+                                                                                            }
+                                                                                        }
+                                                                                    }
+                                                                                },
+                                                                                "name": "discount"
+                                                                            },
+                                                                            {
+                                                                                "layout": {
+                                                                                    "leaf": {
+                                                                                        "key": "0xd0b773ff",
+                                                                                        "ty": 27
+                                                                                    }
+                                                                                },
+                                                                                "name": "expires"
+                                                                            },
+                                                                            {
+                                                                                "layout": {
+                                                                                    "leaf": {
+                                                                                        "key": "0xd0b773ff",
+                                                                                        "ty": 27
+                                                                                    }
+                                                                                },
+                                                                                "name": "uses"
+                                                                            }
+                                                                        ],
+                                                                        "name": "AddCoupon"
+                                                                    },
+                                                                    "7": {
+                                                                        "fields": [
+                                                                            {
+                                                                                "layout": {
+                                                                                    "leaf": {
+                                                                                        "key": "0xd0b773ff",
+                                                                                        "ty": 0
+                                                                                    }
+                                                                                },
+                                                                                "name": "asset"
+                                                                            },
+                                                                            {
+                                                                                "layout": {
+                                                                                    "enum": {
+                                                                                        "dispatchKey": "0xd0b773ff",
+                                                                                        "name": "Discount",
+                                                                                        "variants": {
+                                                                                            "0": {
+                                                                                                "fields": [
+                                                                                                    {
+                                                                                                        "layout": {
+                                                                                                            "leaf": {
+                                                                                                                "key": "0xd0b773ff",
+                                                                                                                "ty": 6
+                                                                                                            }
+                                                                                                        },
+                                                                                                        "name": "0"
+                                                                                                    }
+                                                                                                ],
+                                                                                                "name": "Percent"
+                                                                                            },
+                                                                                            "1": {
+                                                                                                "fields": [
+                                                                                                    {
+                                                                                                        "layout": {
+                                                                                                            "leaf": {
+                                                                                                                "key": "0xd0b773ff",
+                                                                                                                "ty": 1
+                                                                                                            }
+                                                                                                        },
+                                                                                                        "name": "0"
+                                                                                                    }
+                                                                                                ],
+                                                                                                "name": "Absolute"
+                                                                                            }
+                                                                                        }
+                                                                                    }
+                                                                                },
+                                                                                "name": "discount"
+                                                                            },
+                                                                            {
+                                                                                "layout": {
+                                                                                    "leaf": {
+                                                                                        "key": "0xd0b773ff",
+                                                                                        "ty": 27
+                                                                                    }
+                                                                                },
+                                                                                "name": "start"
+                                                                            },
+                                                                            {
+                                                                                "layout": {
+                                                                                    "leaf": {
+                                                                                        "key": "0xd0b773ff",
+                                                                                        "ty": 27
+                                                                                    }
+                                                                                },
+                                                                                "name": "end"
+                                                                            },
+                                                                            {
+                                                                                "layout": {
+                                                                                    "leaf": {
+                                                                                        "key": "0xd0b773ff",
+                                                                                        "ty": 30
+                                                                                    }
+                                                                                },
+                                                                                "name": "max_per_player"
+                                                                            }
+                                                                        ],
+                                                                        "name": "ScheduleSale"
+                                                                    },
+                                                                    "8": {
+                                                                        "fields": [
+                                                                            {
+                                                                                "layout": {
+                                                                                    "leaf": {
+                                                                                        "key": "0xd0b773ff",
+                                                                                        "ty": 0
+                                                                                    }
+                                                                                },
+                                                                                "name": "asset"
+                                                                            },
+                                                                            {
+                                                                                "layout": {
+                                                                                    "enum": {
+                                                                                        "dispatchKey": "0xd0b773ff",
+                                                                                        "name": "Option",
+                                                                                        "variants": {
+                                                                                            "0": {
+                                                                                                "fields": [],
+                                                                                                "name": "None"
+                                                                                            },
+                                                                                            "1": {
+                                                                                                "fields": [
+                                                                                                    {
+                                                                                                        "layout": {
+                                                                                                            "enum": {
+                                                                                                                "dispatchKey": "0xd0b773ff",
+                                                                                                                "name": "PriceCurve",
+                                                                                                                "variants": {
+                                                                                                                    "0": {
+                                                                                                                        "fields": [
+                                                                                                                            {
+                                                                                                                                "layout": {
+                                                                                                                                    "leaf": {
+                                                                                                                                        "key": "0xd0b773ff",
+                                                                                                                                        "ty": 1
+                                                                                                                                    }
+                                                                                                                                },
+                                                                                                                                "name": "base"
+                                                                                                                            },
+                                                                                                                            {
+                                                                                                                                "layout": {
+                                                                                                                                    "leaf": {
+                                                                                                                                        "key": "0xd0b773ff",
+                                                                                                                                        "ty": 1
+                                                                                                                                    }
+                                                                                                                                },
+                                                                                                                                "name": "slope"
+                                                                                                                            }
+                                                                                                                        ],
+                                                                                                                        "name": "Linear"
+                                                                                                                    },
+                                                                                                                    "1": {
+                                                                                                                        "fields": [
+                                                                                                                            {
+                                                                                                                                "layout": {
+                                                                                                                                    "leaf": {
+                                                                                                                                        "key": "0xd0b773ff",
+                                                                                                                                        "ty": 1
+                                                                                                                                    }
+                                                                                                                                },
+                                                                                                                                "name": "base"
+                                                                                                                            },
+                                                                                                                            {
+                                                                                                                                "layout": {
+                                                                                                                                    "leaf": {
+                                                                                                                                        "key": "0xd0b773ff",
+                                                                                                                                        "ty": 27
+                                                                                                                                    }
+                                                                                                                                },
+                                                                                                                                "name": "growth_bps"
+                                                                                                                            }
+                                                                                                                        ],
+                                                                                                                        "name": "Exponential"
+                                                                                                                    },
+                                                                                                                    "2": {
+                                                                                                                        "fields": [
+                                                                                                                            {
+                                                                                                                                "layout": {
+                                                                                                                                    "leaf": {
+                                                                                                                                        "key": "0xd0b773ff",
+                                                                                                                                        "ty": 1
+                                                                                                                                    }
+                                                                                                                                },
+                                                                                                                                "name": "base"
+                                                                                                                            },
+                                                                                                                            {
+                                                                                                                                "layout": {
+                                                                                                                                    "leaf": {
+                                                                                                                                        "key": "0xd0b773ff",
+                                                                                                                                        "ty": 30
+                                                                                                                                    }
+                                                                                                                                },
+                                                                                                                                "name": "step"
+                                                                                                                            },
+                                                                                                                            {
+                                                                                                                                "layout": {
+                                                                                                                                    "leaf": {
+                                                                                                                                        "key": "0xd0b773ff",
+                                                                                                                                        "ty": 1
+                                                                                                                                    }
+                                                                                                                                },
+                                                                                                                                "name": "increment"
+                                                                                                                            }
+                                                                                                                        ],
+                                                                                                                        "name": "Step"
+                                                                                                                    }
+                                                                                                                }
+                                                                                                            }
+                                                                                                        },
+                                                                                                        "name": "0"
+                                                                                                    }
+                                                                                                ],
+                                                                                                "name": "Some"
+                                                                                            }
+                                                                                        }
+                                                                                    }
+                                                                                },
+                                                                                "name": "curve"
+                                                                            }
+                                                                        ],
+                                                                        "name": "SetPriceCurve"
+                                                                    },
+                                                                    "9": {
+                                                                        "fields": [
+                                                                            {
+                                                                                "layout": {
+                                                                                    "leaf": {
+                                                                                        "key": "0xd0b773ff",
+                                                                                        "ty": 0
+                                                                                    }
+                                                                                },
+                                                                                "name": "asset"
+                                                                            },
+                                                                            {
+                                                                                "layout": {
+                                                                                    "leaf": {
+                                                                                        "key": "0xd0b773ff",
+                                                                                        "ty": 1
+                                                                                    }
+                                                                                },
+                                                                                "name": "rate"
+                                                                            }
+                                                                        ],
+                                                                        "name": "SetRewardRate"
+                                                                    }
+                                                                }
+                                                            }
+                                                        },
+                                                        "name": "action"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0xd0b773ff",
+                                                                "ty": 137
+                                                            }
+                                                        },
+                                                        "name": "approvals"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0xd0b773ff",
+                                                                "ty": 27
+                                                            }
+                                                        },
+                                                        "name": "expires_at"
+                                                    }
+                                                ],
+                                                "name": "GameProposal"
+                                            }
+                                        },
+                                        "root_key": "0xd0b773ff",
+                                        "ty": 153
+                                    }
+                                },
+                                "name": "game_proposals"
+                            },
+                            {
+                                "layout": {
+                                    "leaf": {
+                                        "key": "0x00000000",
+                                        "ty": 27
+                                    }
+                                },
+                                "name": "next_game_proposal_id"
+                            },
+                            {
+                                "layout": {
+                                    "root": {
+                                        "layout": {
+                                            "enum": {
+                                                "dispatchKey": "0xc0c19754",
+                                                "name": "SignerKey",
+                                                "variants": {
+                                                    "0": {
+                                                        "fields": [
+                                                            {
+                                                                "layout": {
+                                                                    "array": {
+                                                                        "layout": {
+                                                                            "leaf": {
+                                                                                "key": "0xc0c19754",
+                                                                                "ty": 6
+                                                                            }
+                                                                        },
+                                                                        "len": 32,
+                                                                        "offset": "0xc0c19754"
+                                                                    }
+                                                                },
+                                                                "name": "0"
+                                                            }
+                                                        ],
+                                                        "name": "Sr25519"
+                                                    },
+                                                    "1": {
+                                                        "fields": [
+                                                            {
+                                                                "layout": {
+                                                                    "array": {
+                                                                        "layout": {
+                                                                            "leaf": {
+                                                                                "key": "0xc0c19754",
+                                                                                "ty": 6
+                                                                            }
+                                                                        },
+                                                                        "len": 33,
+                                                                        "offset": "0xc0c19754"
+                                                                    }
+                                                                },
+                                                                "name": "0"
+                                                            }
+                                                        ],
+                                                        "name": "Ecdsa"
+                                                    }
+                                                }
+                                            }
+                                        },
+                                        "root_key": "0xc0c19754",
+                                        "ty": 164
+                                    }
+                                },
+                                "name": "voucher_signers"
+                            },
+                            {
+                                "layout": {
+                                    "root": {
+                                        "layout": {
+                                            "leaf": {
+                                                "key": "0x841a1116",
+                                                "ty": 11
+                                            }
+                                        },
+                                        "root_key": "0x841a1116",
+                                        "ty": 167
+                                    }
+                                },
+                                "name": "redeemed_vouchers"
+                            },
+                            {
+                                "layout": {
+                                    "root": {
+                                        "layout": {
+                                            "leaf": {
+                                                "key": "0x994216e6",
+                                                "ty": 30
+                                            }
+                                        },
+                                        "root_key": "0x994216e6",
+                                        "ty": 170
+                                    }
+                                },
+                                "name": "player_nonces"
+                            },
+                            {
+                                "layout": {
+                                    "root": {
+                                        "layout": {
+                                            "struct": {
+                                                "fields": [
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0x2bba2b9b",
+                                                                "ty": 0
+                                                            }
+                                                        },
+                                                        "name": "game"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0x2bba2b9b",
+                                                                "ty": 0
+                                                            }
+                                                        },
+                                                        "name": "asset"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0x2bba2b9b",
+                                                                "ty": 28
+                                                            }
+                                                        },
+                                                        "name": "merkle_root"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0x2bba2b9b",
+                                                                "ty": 30
+                                                            }
+                                                        },
+                                                        "name": "remaining"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0x2bba2b9b",
+                                                                "ty": 27
+                                                            }
+                                                        },
+                                                        "name": "expires_at"
+                                                    }
+                                                ],
+                                                "name": "Airdrop"
+                                            }
+                                        },
+                                        "root_key": "0x2bba2b9b",
+                                        "ty": 173
+                                    }
+                                },
+                                "name": "airdrops"
+                            },
+                            {
+                                "layout": {
+                                    "root": {
+                                        "layout": {
+                                            "leaf": {
+                                                "key": "0x0127bb41",
+                                                "ty": 1
+                                            }
+                                        },
+                                        "root_key": "0x0127bb41",
+                                        "ty": 177
+                                    }
+                                },
+                                "name": "airdrop_claims"
+                            },
+                            {
+                                "layout": {
+                                    "leaf": {
+                                        "key": "0x00000000",
+                                        "ty": 27
+                                    }
+                                },
+                                "name": "next_airdrop_id"
+                            },
+                            {
+                                "layout": {
+                                    "root": {
+                                        "layout": {
+                                            "leaf": {
+                                                "key": "0xd4e49b96",
+                                                "ty": 11
+                                            }
+                                        },
+                                        "root_key": "0xd4e49b96",
+                                        "ty": 180
+                                    }
+                                },
+                                "name": "game_operators"
+                            },
+                            {
+                                "layout": {
+                                    "root": {
+                                        "layout": {
+                                            "struct": {
+                                                "fields": [
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0x52777418",
+                                                                "ty": 4
+                                                            }
+                                                        },
+                                                        "name": "challenger"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0x52777418",
+                                                                "ty": 4
+                                                            }
+                                                        },
+                                                        "name": "opponent"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "enum": {
+                                                                "dispatchKey": "0x52777418",
+                                                                "name": "VaultItem",
+                                                                "variants": {
+                                                                    "0": {
+                                                                        "fields": [
+                                                                            {
+                                                                                "layout": {
+                                                                                    "leaf": {
+                                                                                        "key": "0x52777418",
+                                                                                        "ty": 1
+                                                                                    }
+                                                                                },
+                                                                                "name": "0"
+                                                                            }
+                                                                        ],
+                                                                        "name": "Balance"
+                                                                    },
+                                                                    "1": {
+                                                                        "fields": [
+                                                                            {
+                                                                                "layout": {
+                                                                                    "leaf": {
+                                                                                        "key": "0x52777418",
+                                                                                        "ty": 0
+                                                                                    }
+                                                                                },
+                                                                                "name": "0"
+                                                                            },
+                                                                            {
+                                                                                "layout": {
+                                                                                    "leaf": {
+                                                                                        "key": "0x52777418",
+                                                                                        "ty": 30
+                                                                                    }
+                                                                                },
+                                                                                "name": "1"
+                                                                            }
+                                                                        ],
+                                                                        "name": "Asset"
+                                                                    }
+                                                                }
+                                                            }
+                                                        },
+                                                        "name": "stake"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0x52777418",
+                                                                "ty": 27
+                                                            }
+                                                        },
+                                                        "name": "timeout"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0x52777418",
+                                                                "ty": 27
+                                                            }
+                                                        },
+                                                        "name": "deadline"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0x52777418",
+                                                                "ty": 28
+                                                            }
+                                                        },
+                                                        "name": "challenger_commit"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "enum": {
+                                                                "dispatchKey": "0x52777418",
+                                                                "name": "Option",
+                                                                "variants": {
+                                                                    "0": {
+                                                                        "fields": [],
+                                                                        "name": "None"
+                                                                    },
+                                                                    "1": {
+                                                                        "fields": [
+                                                                            {
+                                                                                "layout": {
+                                                                                    "leaf": {
+                                                                                        "key": "0x52777418",
+                                                                                        "ty": 28
+                                                                                    }
+                                                                                },
+                                                                                "name": "0"
+                                                                            }
+                                                                        ],
+                                                                        "name": "Some"
+                                                                    }
+                                                                }
+                                                            }
+                                                        },
+                                                        "name": "opponent_commit"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "enum": {
+                                                                "dispatchKey": "0x52777418",
+                                                                "name": "Option",
+                                                                "variants": {
+                                                                    "0": {
+                                                                        "fields": [],
+                                                                        "name": "None"
+                                                                    },
+                                                                    "1": {
+                                                                        "fields": [
+                                                                            {
+                                                                                "layout": {
+                                                                                    "enum": {
+                                                                                        "dispatchKey": "0x52777418",
+                                                                                        "name": "Hand",
+                                                                                        "variants": {
+                                                                                            "0": {
+                                                                                                "fields": [],
+                                                                                                "name": "Rock"
+                                                                                            },
+                                                                                            "1": {
+                                                                                                "fields": [],
+                                                                                                "name": "Paper"
+                                                                                            },
+                                                                                            "2": {
+                                                                                                "fields": [],
+                                                                                                "name": "Scissors"
+                                                                                            }
+                                                                                        }
+                                                                                    }
+                                                                                },
+                                                                                "name": "0"
+                                                                            }
+                                                                        ],
+                                                                        "name": "Some"
+                                                                    }
+                                                                }
+                                                            }
+                                                        },
+                                                        "name": "challenger_hand"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "enum": {
+                                                                "dispatchKey": "0x52777418",
+                                                                "name": "Option",
+                                                                "variants": {
+                                                                    "0": {
+                                                                        "fields": [],
+                                                                        "name": "None"
+                                                                    },
+                                                                    "1": {
+                                                                        "fields": [
+                                                                            {
+                                                                                "layout": {
+                                                                                    "enum": {
+                                                                                        "dispatchKey": "0x52777418",
+                                                                                        "name": "Hand",
+                                                                                        "variants": {
+                                                                                            "0": {
+                                                                                                "fields": [],
+                                                                                                "name": "Rock"
+                                                                                            },
+                                                                                            "1": {
+                                                                                                "fields": [],
+                                                                                                "name": "Paper"
+                                                                                            },
+                                                                                            "2": {
+                                                                                                "fields": [],
+                                                                                                "name": "Scissors"
+                                                                                            }
+                                                                                        }
+                                                                                    }
+                                                                                },
+                                                                                "name": "0"
+                                                                            }
+                                                                        ],
+                                                                        "name": "Some"
+                                                                    }
+                                                                }
+                                                            }
+                                                        },
+                                                        "name": "opponent_hand"
+                                                    }
+                                                ],
+                                                "name": "Match"
+                                            }
+                                        },
+                                        "root_key": "0x52777418",
+                                        "ty": 183
+                                    }
+                                },
+                                "name": "matches"
+                            },
+                            {
+                                "layout": {
+                                    "leaf": {
+                                        "key": "0x00000000",
+                                        "ty": 27
+                                    }
+                                },
+                                "name": "next_match_id"
+                            },
+                            {
+                                "layout": {
+                                    "root": {
+                                        "layout": {
+                                            "struct": {
+                                                "fields": [
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0xcbd33e34",
+                                                                "ty": 0
+                                                            }
+                                                        },
+                                                        "name": "game"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "struct": {
+                                                                "fields": [
+                                                                    {
+                                                                        "layout": {
+                                                                            "enum": {
+                                                                                "dispatchKey": "0xcbd33e34",
+                                                                                "name": "VaultItem",
+                                                                                "variants": {
+                                                                                    "0": {
+                                                                                        "fields": [
+                                                                                            {
+                                                                                                "layout": {
+                                                                                                    "leaf": {
+                                                                                                        "key": "0xcbd33e34",
+                                                                                                        "ty": 1
+                                                                                                    }
+                                                                                                },
+                                                                                                "name": "0"
+                                                                                            }
+                                                                                        ],
+                                                                                        "name": "Balance"
+                                                                                    },
+                                                                                    "1": {
+                                                                                        "fields": [
+                                                                                            {
+                                                                                                "layout": {
+                                                                                                    "leaf": {
+                                                                                                        "key": "0xcbd33e34",
+                                                                                                        "ty": 0
+                                                                                                    }
+                                                                                                },
+                                                                                                "name": "0"
+                                                                                            },
+                                                                                            {
+                                                                                                "layout": {
+                                                                                                    "leaf": {
+                                                                                                        "key": "0xcbd33e34",
+                                                                                                        "ty": 30
+                                                                                                    }
+                                                                                                },
+                                                                                                "name": "1"
+                                                                                            }
+                                                                                        ],
+                                                                                        "name": "Asset"
+                                                                                    }
+                                                                                }
+                                                                            }
+                                                                        },
+                                                                        "name": "entry_fee"
+                                                                    },
+                                                                    {
+                                                                        "layout": {
+                                                                            "leaf": {
+                                                                                "key": "0xcbd33e34",
+                                                                                "ty": 27
+                                                                            }
+                                                                        },
+                                                                        "name": "max_entrants"
+                                                                    },
+                                                                    {
+                                                                        "layout": {
+                                                                            "leaf": {
+                                                                                "key": "0xcbd33e34",
+                                                                                "ty": 27
+                                                                            }
+                                                                        },
+                                                                        "name": "opens_at"
+                                                                    },
+                                                                    {
+                                                                        "layout": {
+                                                                            "leaf": {
+                                                                                "key": "0xcbd33e34",
+                                                                                "ty": 27
+                                                                            }
+                                                                        },
+                                                                        "name": "closes_at"
+                                                                    },
+                                                                    {
+                                                                        "layout": {
+                                                                            "leaf": {
+                                                                                "key": "0xcbd33e34",
+                                                                                "ty": 148
+                                                                            }
+                                                                        },
+                                                                        "name": "prizes"
+                                                                    },
+                                                                    {
+                                                                        "layout": {
+                                                                            "enum": {
+                                                                                "dispatchKey": "0xcbd33e34",
+                                                                                "name": "Option",
+                                                                                "variants": {
+                                                                                    "0": {
+                                                                                        "fields": [],
+                                                                                        "name": "None"
+                                                                                    },
+                                                                                    "1": {
+                                                                                        "fields": [
+                                                                                            {
+                                                                                                "layout": {
+                                                                                                    "leaf": {
+                                                                                                        "key": "0xcbd33e34",
+                                                                                                        "ty": 4
+                                                                                                    }
+                                                                                                },
+                                                                                                "name": "0"
+                                                                                            }
+                                                                                        ],
+                                                                                        "name": "Some"
+                                                                                    }
+                                                                                }
+                                                                            }
+                                                                        },
+                                                                        "name": "oracle"
+                                                                    }
+                                                                ],
+                                                                "name": "TournamentTerms"
+                                                            }
+                                                        },
+                                                        "name": "terms"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0xcbd33e34",
+                                                                "ty": 137
+                                                            }
+                                                        },
+                                                        "name": "entrants"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "enum": {
+                                                                "dispatchKey": "0xcbd33e34",
+                                                                "name": "TournamentStatus",
+                                                                "variants": {
+                                                                    "0": {
+                                                                        "fields": [],
+                                                                        "name": "Open"
+                                                                    },
+                                                                    "1": {
+                                                                        "fields": [],
+                                                                        "name": "Completed"
+                                                                    },
+                                                                    "2": {
+                                                                        "fields": [],
+                                                                        "name": "Cancelled"
+                                                                    }
+                                                                }
+                                                            }
+                                                        },
+                                                        "name": "status"
+                                                    }
+                                                ],
+                                                "name": "Tournament"
+                                            }
+                                        },
+                                        "root_key": "0xcbd33e34",
+                                        "ty": 190
+                                    }
+                                },
+                                "name": "tournaments"
+                            },
+                            {
+                                "layout": {
+                                    "leaf": {
+                                        "key": "0x00000000",
+                                        "ty": 27
+                                    }
+                                },
+                                "name": "next_tournament_id"
+                            },
+                            {
+                                "layout": {
+                                    "root": {
+                                        "layout": {
+                                            "struct": {
+                                                "fields": [
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0xc6b68c92",
+                                                                "ty": 0
+                                                            }
+                                                        },
+                                                        "name": "name"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0xc6b68c92",
+                                                                "ty": 0
+                                                            }
+                                                        },
+                                                        "name": "description"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0xc6b68c92",
+                                                                "ty": 27
+                                                            }
+                                                        },
+                                                        "name": "points"
+                                                    }
+                                                ],
+                                                "name": "Achievement"
+                                            }
+                                        },
+                                        "root_key": "0xc6b68c92",
+                                        "ty": 195
+                                    }
+                                },
+                                "name": "achievements"
+                            },
+                            {
+                                "layout": {
+                                    "root": {
+                                        "layout": {
+                                            "leaf": {
+                                                "key": "0xaff0c07f",
+                                                "ty": 20
+                                            }
+                                        },
+                                        "root_key": "0xaff0c07f",
+                                        "ty": 199
+                                    }
+                                },
+                                "name": "earned_achievements"
+                            },
+                            {
+                                "layout": {
+                                    "root": {
+                                        "layout": {
+                                            "leaf": {
+                                                "key": "0x3dfda12b",
+                                                "ty": 30
+                                            }
+                                        },
+                                        "root_key": "0x3dfda12b",
+                                        "ty": 202
+                                    }
+                                },
+                                "name": "gamer_scores"
+                            },
+                            {
+                                "layout": {
+                                    "root": {
+                                        "layout": {
+                                            "leaf": {
+                                                "key": "0x7466d177",
+                                                "ty": 0
+                                            }
+                                        },
+                                        "root_key": "0x7466d177",
+                                        "ty": 205
+                                    }
+                                },
+                                "name": "asset_games"
+                            },
+                            {
+                                "layout": {
+                                    "root": {
+                                        "layout": {
+                                            "struct": {
+                                                "fields": [
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0x0d15d576",
+                                                                "ty": 28
+                                                            }
+                                                        },
+                                                        "name": "reason_hash"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0x0d15d576",
+                                                                "ty": 27
+                                                            }
+                                                        },
+                                                        "name": "flagged_at"
+                                                    },
+                                                    {
+                                                        "layout": {
+                                                            "enum": {
+                                                                "dispatchKey": "0x0d15d576",
+                                                                "name": "Option",
+                                                                "variants": {
+                                                                    "0": {
+                                                                        "fields": [],
+                                                                        "name": "None"
+                                                                    },
+                                                                    "1": {
+                                                                        "fields": [
+                                                                            {
+                                                                                "layout": {
+                                                                                    "leaf": {
+                                                                                        "key": "0x0d15d576",
+                                                                                        "ty": 28
+                                                                                    }
+                                                                                },
+                                                                                "name": "0"
+                                                                            }
+                                                                        ],
+                                                                        "name": "Some"
+                                                                    }
+                                                                }
+                                                            }
+                                                        },
+                                                        "name": "appeal_hash"
+                                                    }
+                                                ],
+                                                "name": "Flag"
+                                            }
+                                        },
+                                        "root_key": "0x0d15d576",
+                                        "ty": 208
+                                    }
+                                },
+                                "name": "flags"
+                            },
+                            {
+                                "layout": {
+                                    "root": {
+                                        "layout": {
+                                            "leaf": {
+                                                "key": "0xe4a58f9e",
+                                                "ty": 27
+                                            }
+                                        },
+                                        "root_key": "0xe4a58f9e",
+                                        "ty": 212
+                                    }
+                                },
+                                "name": "flag_counts"
+                            },
+                            {
+                                "layout": {
+                                    "root": {
+                                        "layout": {
+                                            "leaf": {
+                                                "key": "0x721cf155",
+                                                "ty": 11
+                                            }
+                                        },
+                                        "root_key": "0x721cf155",
+                                        "ty": 215
+                                    }
+                                },
+                                "name": "refuse_flagged"
+                            },
+                            {
+                                "layout": {
+                                    "root": {
+                                        "layout": {
+                                            "leaf": {
+                                                "key": "0xe31db3ab",
+                                                "ty": 152
+                                            }
+                                        },
+                                        "root_key": "0xe31db3ab",
+                                        "ty": 218
+                                    }
+                                },
+                                "name": "reputation"
+                            },
+                            {
+                                "layout": {
+                                    "root": {
+                                        "layout": {
+                                            "leaf": {
+                                                "key": "0x2db32ae7",
+                                                "ty": 2
+                                            }
+                                        },
+                                        "root_key": "0x2db32ae7",
+                                        "ty": 221
+                                    }
+                                },
+                                "name": "flag_trust"
+                            },
+                            {
+                                "layout": {
+                                    "root": {
+                                        "layout": {
+                                            "leaf": {
+                                                "key": "0x05150339",
+                                                "ty": 11
+                                            }
+                                        },
+                                        "root_key": "0x05150339",
+                                        "ty": 224
+                                    }
+                                },
+                                "name": "recoverable"
+                            },
+                            {
+                                "layout": {
+                                    "root": {
+                                        "layout": {
+                                            "leaf": {
+                                                "key": "0x18610542",
+                                                "ty": 11
+                                            }
+                                        },
+                                        "root_key": "0x18610542",
+                                        "ty": 227
+                                    }
+                                },
+                                "name": "frozen"
+                            },
+                            {
+                                "layout": {
+                                    "root": {
+                                        "layout": {
+                                            "leaf": {
+                                                "key": "0x63056d47",
+                                                "ty": 27
+                                            }
+                                        },
+                                        "root_key": "0x63056d47",
+                                        "ty": 231
+                                    }
+                                },
+                                "name": "open_escrows"
+                            },
+                            {
+                                "layout": {
+                                    "root": {
+                                        "layout": {
+                                            "leaf": {
+                                                "key": "0xb6757971",
+                                                "ty": 30
+                                            }
+                                        },
+                                        "root_key": "0xb6757971",
+                                        "ty": 234
+                                    }
+                                },
+                                "name": "curve_holdings"
+                            },
+                            {
+                                "layout": {
+                                    "enum": {
+                                        "dispatchKey": "0x00000000",
+                                        "name": "Option",
+                                        "variants": {
+                                            "0": {
+                                                "fields": [],
+                                                "name": "None"
+                                            },
+                                            "1": {
+                                                "fields": [
+                                                    {
+                                                        "layout": {
+                                                            "leaf": {
+                                                                "key": "0x00000000",
+                                                                "ty": 4
+                                                            }
+                                                        },
+                                                        "name": "0"
+                                                    }
+                                                ],
+                                                "name": "Some"
+                                            }
+                                        }
+                                    }
+                                },
+                                "name": "admin"
+                            },
+                            {
+                                "layout": {
+                                    "leaf": {
+                                        "key": "0x00000000",
+                                        "ty": 2
+                                    }
+                                },
+                                "name": "reputation_games"
+                            },
+                            {
+                                "layout": {
+                                    "root": {
+                                        "layout": {
+                                            "leaf": {
+                                                "key": "0x0e0159bb",
+                                                "ty": 27
+                                            }
+                                        },
+                                        "root_key": "0x0e0159bb",
+                                        "ty": 237
+                                    }
+                                },
+                                "name": "open_positions"
+                            }
+                        ],
+                        "name": "Assets"
+                    }
+                },
+                "root_key": "0x00000000",
+                "ty": 240
+            }
+        },
+        "types": [
+            {
+                "id": 0,
+                "type": {
+                    "def": {
+                        "primitive": "str"
+                    }
+                }
+            },
+            {
+                "id": 1,
+                "type": {
+                    "def": {
+                        "primitive": "u128"
+                    }
+                }
+            },
+            {
+                "id": 2,
+                "type": {
+                    "def": {
+                        "sequence": {
+                            "type": 0
+                        }
+                    }
+                }
+            },
+            {
+                "id": 3,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "K",
+                            "type": 4
+                        },
+                        {
+                            "name": "V",
+                            "type": 7
+                        },
+                        {
+                            "name": "KeyType",
+                            "type": 8
+                        }
+                    ],
+                    "path": [
+                        "ink_storage",
+                        "lazy",
+                        "mapping",
+                        "Mapping"
+                    ]
+                }
+            },
+            {
+                "id": 4,
+                "type": {
+                    "def": {
+                        "composite": {
+                            "fields": [
+                                {
+                                    "type": 5,
+                                    "typeName": "[u8; 32]"
+                                }
+                            ]
+                        }
+                    },
+                    "path": [
+                        "ink_primitives",
+                        "types",
+                        "AccountId"
+                    ]
+                }
+            },
+            {
+                "id": 5,
+                "type": {
+                    "def": {
+                        "array": {
+                            "len": 32,
+                            "type": 6
+                        }
+                    }
+                }
+            },
+            {
+                "id": 6,
+                "type": {
+                    "def": {
+                        "primitive": "u8"
+                    }
+                }
+            },
+            {
+                "id": 7,
+                "type": {
+                    "def": {
+                        "composite": {
+                            "fields": [
+                                {
+                                    "name": "name",
+                                    "type": 0,
+                                    "typeName": "String"
+                                },
+                                {
+                                    "name": "avatar_uri",
+                                    "type": 0,
+                                    "typeName": "String"
+                                },
+                                {
+                                    "name": "balance",
+                                    "type": 1,
+                                    "typeName": "Balance"
+                                },
+                                {
+                                    "name": "assets",
+                                    "type": 2,
+                                    "typeName": "Vec<String>"
+                                }
+                            ]
+                        }
+                    },
+                    "path": [
+                        "assets",
+                        "assets",
+                        "Player"
+                    ]
+                }
+            },
+            {
+                "id": 8,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "L",
+                            "type": 9
+                        },
+                        {
+                            "name": "R",
+                            "type": 10
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ResolverKey"
+                    ]
+                }
+            },
+            {
+                "id": 9,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "AutoKey"
+                    ]
+                }
+            },
+            {
+                "id": 10,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "ParentKey",
+                            "type": 11
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ManualKey"
+                    ]
+                }
+            },
+            {
+                "id": 11,
+                "type": {
+                    "def": {
+                        "tuple": []
+                    }
+                }
+            },
+            {
+                "id": 12,
+                "type": {
+                    "def": {
+                        "sequence": {
+                            "type": 13
+                        }
+                    }
+                }
+            },
+            {
+                "id": 13,
+                "type": {
+                    "def": {
+                        "tuple": [
+                            0,
+                            1
+                        ]
+                    }
+                }
+            },
+            {
+                "id": 14,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "K",
+                            "type": 0
+                        },
+                        {
+                            "name": "V",
+                            "type": 12
+                        },
+                        {
+                            "name": "KeyType",
+                            "type": 15
+                        }
+                    ],
+                    "path": [
+                        "ink_storage",
+                        "lazy",
+                        "mapping",
+                        "Mapping"
+                    ]
+                }
+            },
+            {
+                "id": 15,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "L",
+                            "type": 9
+                        },
+                        {
+                            "name": "R",
+                            "type": 16
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ResolverKey"
+                    ]
+                }
+            },
+            {
+                "id": 16,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "ParentKey",
+                            "type": 11
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ManualKey"
+                    ]
+                }
+            },
+            {
+                "id": 17,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "K",
+                            "type": 0
+                        },
+                        {
+                            "name": "V",
+                            "type": 4
+                        },
+                        {
+                            "name": "KeyType",
+                            "type": 18
+                        }
+                    ],
+                    "path": [
+                        "ink_storage",
+                        "lazy",
+                        "mapping",
+                        "Mapping"
+                    ]
+                }
+            },
+            {
+                "id": 18,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "L",
+                            "type": 9
+                        },
+                        {
+                            "name": "R",
+                            "type": 19
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ResolverKey"
+                    ]
+                }
+            },
+            {
+                "id": 19,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "ParentKey",
+                            "type": 11
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ManualKey"
+                    ]
+                }
+            },
+            {
+                "id": 20,
+                "type": {
+                    "def": {
+                        "sequence": {
+                            "type": 21
+                        }
+                    }
+                }
+            },
+            {
+                "id": 21,
+                "type": {
+                    "def": {
+                        "tuple": [
+                            0,
+                            0
+                        ]
+                    }
+                }
+            },
+            {
+                "id": 22,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "K",
+                            "type": 21
+                        },
+                        {
+                            "name": "V",
+                            "type": 23
+                        },
+                        {
+                            "name": "KeyType",
+                            "type": 25
+                        }
+                    ],
+                    "path": [
+                        "ink_storage",
+                        "lazy",
+                        "mapping",
+                        "Mapping"
+                    ]
+                }
+            },
+            {
+                "id": 23,
+                "type": {
+                    "def": {
+                        "composite": {
+                            "fields": [
+                                {
+                                    "name": "description",
+                                    "type": 0,
+                                    "typeName": "String"
+                                },
+                                {
+                                    "name": "uri",
+                                    "type": 0,
+                                    "typeName": "String"
+                                },
+                                {
+                                    "name": "rarity",
+                                    "type": 24,
+                                    "typeName": "Rarity"
+                                },
+                                {
+                                    "name": "category",
+                                    "type": 0,
+                                    "typeName": "String"
+                                },
+                                {
+                                    "name": "attributes",
+                                    "type": 20,
+                                    "typeName": "Vec<(String, String)>"
+                                }
+                            ]
+                        }
+                    },
+                    "path": [
+                        "assets",
+                        "assets",
+                        "AssetMetadata"
+                    ]
+                }
+            },
+            {
+                "id": 24,
+                "type": {
+                    "def": {
+                        "variant": {
+                            "variants": [
+                                {
+                                    "index": 0,
+                                    "name": "Common"
+                                },
+                                {
+                                    "index": 1,
+                                    "name": "Uncommon"
+                                },
+                                {
+                                    "index": 2,
+                                    "name": "Rare"
+                                },
+                                {
+                                    "index": 3,
+                                    "name": "Epic"
+                                },
+                                {
+                                    "index": 4,
+                                    "name": "Legendary"
+                                }
+                            ]
+                        }
+                    },
+                    "path": [
+                        "assets",
+                        "assets",
+                        "Rarity"
+                    ]
+                }
+            },
+            {
+                "id": 25,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "L",
+                            "type": 9
+                        },
+                        {
+                            "name": "R",
+                            "type": 26
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ResolverKey"
+                    ]
+                }
+            },
+            {
+                "id": 26,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "ParentKey",
+                            "type": 11
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ManualKey"
+                    ]
+                }
+            },
+            {
+                "id": 27,
+                "type": {
+                    "def": {
+                        "primitive": "u32"
+                    }
+                }
+            },
+            {
+                "id": 28,
+                "type": {
+                    "def": {
+                        "composite": {
+                            "fields": [
+                                {
+                                    "type": 5,
+                                    "typeName": "[u8; 32]"
+                                }
+                            ]
+                        }
+                    },
+                    "path": [
+                        "ink_primitives",
+                        "types",
+                        "Hash"
+                    ]
+                }
+            },
+            {
+                "id": 29,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "K",
+                            "type": 30
+                        },
+                        {
+                            "name": "V",
+                            "type": 31
+                        },
+                        {
+                            "name": "KeyType",
+                            "type": 32
+                        }
+                    ],
+                    "path": [
+                        "ink_storage",
+                        "lazy",
+                        "mapping",
+                        "Mapping"
+                    ]
+                }
+            },
+            {
+                "id": 30,
+                "type": {
+                    "def": {
+                        "primitive": "u64"
+                    }
+                }
+            },
+            {
+                "id": 31,
+                "type": {
+                    "def": {
+                        "composite": {
+                            "fields": [
+                                {
+                                    "name": "game",
+                                    "type": 0,
+                                    "typeName": "String"
+                                },
+                                {
+                                    "name": "asset",
+                                    "type": 0,
+                                    "typeName": "String"
+                                },
+                                {
+                                    "name": "owner",
+                                    "type": 4,
+                                    "typeName": "AccountId"
+                                },
+                                {
+                                    "name": "state",
+                                    "type": 20,
+                                    "typeName": "Vec<(String, String)>"
+                                },
+                                {
+                                    "name": "version",
+                                    "type": 27,
+                                    "typeName": "u32"
+                                },
+                                {
+                                    "name": "state_hash",
+                                    "type": 28,
+                                    "typeName": "Hash"
+                                }
+                            ]
+                        }
+                    },
+                    "path": [
+                        "assets",
+                        "assets",
+                        "Item"
+                    ]
+                }
+            },
+            {
+                "id": 32,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "L",
+                            "type": 9
+                        },
+                        {
+                            "name": "R",
+                            "type": 33
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ResolverKey"
+                    ]
+                }
+            },
+            {
+                "id": 33,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "ParentKey",
+                            "type": 11
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ManualKey"
+                    ]
+                }
+            },
+            {
+                "id": 34,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "K",
+                            "type": 0
+                        },
+                        {
+                            "name": "V",
+                            "type": 2
+                        },
+                        {
+                            "name": "KeyType",
+                            "type": 35
+                        }
+                    ],
+                    "path": [
+                        "ink_storage",
+                        "lazy",
+                        "mapping",
+                        "Mapping"
+                    ]
+                }
+            },
+            {
+                "id": 35,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "L",
+                            "type": 9
+                        },
+                        {
+                            "name": "R",
+                            "type": 36
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ResolverKey"
+                    ]
+                }
+            },
+            {
+                "id": 36,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "ParentKey",
+                            "type": 11
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ManualKey"
+                    ]
+                }
+            },
+            {
+                "id": 37,
+                "type": {
+                    "def": {
+                        "primitive": "bool"
+                    }
+                }
+            },
+            {
+                "id": 38,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "K",
+                            "type": 39
+                        },
+                        {
+                            "name": "V",
+                            "type": 40
+                        },
+                        {
+                            "name": "KeyType",
+                            "type": 41
+                        }
+                    ],
+                    "path": [
+                        "ink_storage",
+                        "lazy",
+                        "mapping",
+                        "Mapping"
+                    ]
+                }
+            },
+            {
+                "id": 39,
+                "type": {
+                    "def": {
+                        "tuple": [
+                            0,
+                            0,
+                            0,
+                            0
+                        ]
+                    }
+                }
+            },
+            {
+                "id": 40,
+                "type": {
+                    "def": {
+                        "composite": {
+                            "fields": [
+                                {
+                                    "name": "give_units",
+                                    "type": 30,
+                                    "typeName": "u64"
+                                },
+                                {
+                                    "name": "take_units",
+                                    "type": 30,
+                                    "typeName": "u64"
+                                },
+                                {
+                                    "name": "cap_per_period",
+                                    "type": 30,
+                                    "typeName": "u64"
+                                },
+                                {
+                                    "name": "period",
+                                    "type": 27,
+                                    "typeName": "BlockNumber"
+                                },
+                                {
+                                    "name": "from_approved",
+                                    "type": 37,
+                                    "typeName": "bool"
+                                },
+                                {
+                                    "name": "to_approved",
+                                    "type": 37,
+                                    "typeName": "bool"
+                                },
+                                {
+                                    "name": "period_start",
+                                    "type": 27,
+                                    "typeName": "BlockNumber"
+                                },
+                                {
+                                    "name": "converted",
+                                    "type": 30,
+                                    "typeName": "u64"
+                                }
+                            ]
+                        }
+                    },
+                    "path": [
+                        "assets",
+                        "assets",
+                        "ConversionRule"
+                    ]
+                }
+            },
+            {
+                "id": 41,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "L",
+                            "type": 9
+                        },
+                        {
+                            "name": "R",
+                            "type": 42
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ResolverKey"
+                    ]
+                }
+            },
+            {
+                "id": 42,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "ParentKey",
+                            "type": 11
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ManualKey"
+                    ]
+                }
+            },
+            {
+                "id": 43,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "K",
+                            "type": 0
+                        },
+                        {
+                            "name": "V",
+                            "type": 4
+                        },
+                        {
+                            "name": "KeyType",
+                            "type": 44
+                        }
+                    ],
+                    "path": [
+                        "ink_storage",
+                        "lazy",
+                        "mapping",
+                        "Mapping"
+                    ]
+                }
+            },
+            {
+                "id": 44,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "L",
+                            "type": 9
+                        },
+                        {
+                            "name": "R",
+                            "type": 45
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ResolverKey"
+                    ]
+                }
+            },
+            {
+                "id": 45,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "ParentKey",
+                            "type": 11
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ManualKey"
+                    ]
+                }
+            },
+            {
+                "id": 46,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "K",
+                            "type": 4
+                        },
+                        {
+                            "name": "V",
+                            "type": 11
+                        },
+                        {
+                            "name": "KeyType",
+                            "type": 47
+                        }
+                    ],
+                    "path": [
+                        "ink_storage",
+                        "lazy",
+                        "mapping",
+                        "Mapping"
+                    ]
+                }
+            },
+            {
+                "id": 47,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "L",
+                            "type": 9
+                        },
+                        {
+                            "name": "R",
+                            "type": 48
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ResolverKey"
+                    ]
+                }
+            },
+            {
+                "id": 48,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "ParentKey",
+                            "type": 11
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ManualKey"
+                    ]
+                }
+            },
+            {
+                "id": 49,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "K",
+                            "type": 50
+                        },
+                        {
+                            "name": "V",
+                            "type": 4
+                        },
+                        {
+                            "name": "KeyType",
+                            "type": 51
+                        }
+                    ],
+                    "path": [
+                        "ink_storage",
+                        "lazy",
+                        "mapping",
+                        "Mapping"
+                    ]
+                }
+            },
+            {
+                "id": 50,
+                "type": {
+                    "def": {
+                        "tuple": [
+                            0,
+                            27
+                        ]
+                    }
+                }
+            },
+            {
+                "id": 51,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "L",
+                            "type": 9
+                        },
+                        {
+                            "name": "R",
+                            "type": 52
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ResolverKey"
+                    ]
+                }
+            },
+            {
+                "id": 52,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "ParentKey",
+                            "type": 11
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ManualKey"
+                    ]
+                }
+            },
+            {
+                "id": 53,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "K",
+                            "type": 0
+                        },
+                        {
+                            "name": "V",
+                            "type": 27
+                        },
+                        {
+                            "name": "KeyType",
+                            "type": 54
+                        }
+                    ],
+                    "path": [
+                        "ink_storage",
+                        "lazy",
+                        "mapping",
+                        "Mapping"
+                    ]
+                }
+            },
+            {
+                "id": 54,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "L",
+                            "type": 9
+                        },
+                        {
+                            "name": "R",
+                            "type": 55
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ResolverKey"
+                    ]
+                }
+            },
+            {
+                "id": 55,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "ParentKey",
+                            "type": 11
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ManualKey"
+                    ]
+                }
+            },
+            {
+                "id": 56,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "K",
+                            "type": 57
+                        },
+                        {
+                            "name": "V",
+                            "type": 27
+                        },
+                        {
+                            "name": "KeyType",
+                            "type": 58
+                        }
+                    ],
+                    "path": [
+                        "ink_storage",
+                        "lazy",
+                        "mapping",
+                        "Mapping"
+                    ]
+                }
+            },
+            {
+                "id": 57,
+                "type": {
+                    "def": {
+                        "tuple": [
+                            0,
+                            4
+                        ]
+                    }
+                }
+            },
+            {
+                "id": 58,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "L",
+                            "type": 9
+                        },
+                        {
+                            "name": "R",
+                            "type": 59
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ResolverKey"
+                    ]
+                }
+            },
+            {
+                "id": 59,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "ParentKey",
+                            "type": 11
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ManualKey"
+                    ]
+                }
+            },
+            {
+                "id": 60,
+                "type": {
+                    "def": {
+                        "sequence": {
+                            "type": 61
+                        }
+                    }
+                }
+            },
+            {
+                "id": 61,
+                "type": {
+                    "def": {
+                        "tuple": [
+                            0,
+                            30
+                        ]
+                    }
+                }
+            },
+            {
+                "id": 62,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "K",
+                            "type": 27
+                        },
+                        {
+                            "name": "V",
+                            "type": 63
+                        },
+                        {
+                            "name": "KeyType",
+                            "type": 65
+                        }
+                    ],
+                    "path": [
+                        "ink_storage",
+                        "lazy",
+                        "mapping",
+                        "Mapping"
+                    ]
+                }
+            },
+            {
+                "id": 63,
+                "type": {
+                    "def": {
+                        "composite": {
+                            "fields": [
+                                {
+                                    "name": "game",
+                                    "type": 0,
+                                    "typeName": "String"
+                                },
+                                {
+                                    "name": "contents",
+                                    "type": 60,
+                                    "typeName": "Vec<(String, u64)>"
+                                },
+                                {
+                                    "name": "price",
+                                    "type": 1,
+                                    "typeName": "Balance"
+                                },
+                                {
+                                    "name": "max_per_player",
+                                    "type": 30,
+                                    "typeName": "u64"
+                                },
+                                {
+                                    "name": "available_from",
+                                    "type": 27,
+                                    "typeName": "BlockNumber"
+                                },
+                                {
+                                    "name": "available_until",
+                                    "type": 64,
+                                    "typeName": "Option<BlockNumber>"
+                                }
+                            ]
+                        }
+                    },
+                    "path": [
+                        "assets",
+                        "assets",
+                        "Bundle"
+                    ]
+                }
+            },
+            {
+                "id": 64,
+                "type": {
+                    "def": {
+                        "variant": {
+                            "variants": [
+                                {
+                                    "index": 0,
+                                    "name": "None"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 27
+                                        }
+                                    ],
+                                    "index": 1,
+                                    "name": "Some"
+                                }
+                            ]
+                        }
+                    },
+                    "params": [
+                        {
+                            "name": "T",
+                            "type": 27
+                        }
+                    ],
+                    "path": [
+                        "Option"
+                    ]
+                }
+            },
+            {
+                "id": 65,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "L",
+                            "type": 9
+                        },
+                        {
+                            "name": "R",
+                            "type": 66
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ResolverKey"
+                    ]
+                }
+            },
+            {
+                "id": 66,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "ParentKey",
+                            "type": 11
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ManualKey"
+                    ]
+                }
+            },
+            {
+                "id": 67,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "K",
+                            "type": 68
+                        },
+                        {
+                            "name": "V",
+                            "type": 30
+                        },
+                        {
+                            "name": "KeyType",
+                            "type": 69
+                        }
+                    ],
+                    "path": [
+                        "ink_storage",
+                        "lazy",
+                        "mapping",
+                        "Mapping"
+                    ]
+                }
+            },
+            {
+                "id": 68,
+                "type": {
+                    "def": {
+                        "tuple": [
+                            27,
+                            4
+                        ]
+                    }
+                }
+            },
+            {
+                "id": 69,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "L",
+                            "type": 9
+                        },
+                        {
+                            "name": "R",
+                            "type": 70
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ResolverKey"
+                    ]
+                }
+            },
+            {
+                "id": 70,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "ParentKey",
+                            "type": 11
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ManualKey"
+                    ]
+                }
+            },
+            {
+                "id": 71,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "K",
+                            "type": 27
+                        },
+                        {
+                            "name": "V",
+                            "type": 72
+                        },
+                        {
+                            "name": "KeyType",
+                            "type": 74
+                        }
+                    ],
+                    "path": [
+                        "ink_storage",
+                        "lazy",
+                        "mapping",
+                        "Mapping"
+                    ]
+                }
+            },
+            {
+                "id": 72,
+                "type": {
+                    "def": {
+                        "composite": {
+                            "fields": [
+                                {
+                                    "name": "game",
+                                    "type": 0,
+                                    "typeName": "String"
+                                },
+                                {
+                                    "name": "asset",
+                                    "type": 0,
+                                    "typeName": "String"
+                                },
+                                {
+                                    "name": "discount",
+                                    "type": 73,
+                                    "typeName": "Discount"
+                                },
+                                {
+                                    "name": "start",
+                                    "type": 27,
+                                    "typeName": "BlockNumber"
+                                },
+                                {
+                                    "name": "end",
+                                    "type": 27,
+                                    "typeName": "BlockNumber"
+                                },
+                                {
+                                    "name": "max_per_player",
+                                    "type": 30,
+                                    "typeName": "u64"
+                                }
+                            ]
+                        }
+                    },
+                    "path": [
+                        "assets",
+                        "assets",
+                        "Sale"
+                    ]
+                }
+            },
+            {
+                "id": 73,
+                "type": {
+                    "def": {
+                        "variant": {
+                            "variants": [
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 6,
+                                            "typeName": "u8"
+                                        }
+                                    ],
+                                    "index": 0,
+                                    "name": "Percent"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 1,
+                                            "typeName": "Balance"
+                                        }
+                                    ],
+                                    "index": 1,
+                                    "name": "Absolute"
+                                }
+                            ]
+                        }
+                    },
+                    "path": [
+                        "assets",
+                        "assets",
+                        "Discount"
+                    ]
+                }
+            },
+            {
+                "id": 74,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "L",
+                            "type": 9
+                        },
+                        {
+                            "name": "R",
+                            "type": 75
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ResolverKey"
+                    ]
+                }
+            },
+            {
+                "id": 75,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "ParentKey",
+                            "type": 11
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ManualKey"
+                    ]
+                }
+            },
+            {
+                "id": 76,
+                "type": {
+                    "def": {
+                        "sequence": {
+                            "type": 27
+                        }
+                    }
+                }
+            },
+            {
+                "id": 77,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "K",
+                            "type": 21
+                        },
+                        {
+                            "name": "V",
+                            "type": 76
+                        },
+                        {
+                            "name": "KeyType",
+                            "type": 78
+                        }
+                    ],
+                    "path": [
+                        "ink_storage",
+                        "lazy",
+                        "mapping",
+                        "Mapping"
+                    ]
+                }
+            },
+            {
+                "id": 78,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "L",
+                            "type": 9
+                        },
+                        {
+                            "name": "R",
+                            "type": 79
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ResolverKey"
+                    ]
+                }
+            },
+            {
+                "id": 79,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "ParentKey",
+                            "type": 11
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ManualKey"
+                    ]
+                }
+            },
+            {
+                "id": 80,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "K",
+                            "type": 68
+                        },
+                        {
+                            "name": "V",
+                            "type": 30
+                        },
+                        {
+                            "name": "KeyType",
+                            "type": 81
+                        }
+                    ],
+                    "path": [
+                        "ink_storage",
+                        "lazy",
+                        "mapping",
+                        "Mapping"
+                    ]
+                }
+            },
+            {
+                "id": 81,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "L",
+                            "type": 9
+                        },
+                        {
+                            "name": "R",
+                            "type": 82
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ResolverKey"
+                    ]
+                }
+            },
+            {
+                "id": 82,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "ParentKey",
+                            "type": 11
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ManualKey"
+                    ]
+                }
+            },
+            {
+                "id": 83,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "K",
+                            "type": 28
+                        },
+                        {
+                            "name": "V",
+                            "type": 84
+                        },
+                        {
+                            "name": "KeyType",
+                            "type": 85
+                        }
+                    ],
+                    "path": [
+                        "ink_storage",
+                        "lazy",
+                        "mapping",
+                        "Mapping"
+                    ]
+                }
+            },
+            {
+                "id": 84,
+                "type": {
+                    "def": {
+                        "composite": {
+                            "fields": [
+                                {
+                                    "name": "game",
+                                    "type": 0,
+                                    "typeName": "String"
+                                },
+                                {
+                                    "name": "asset",
+                                    "type": 0,
+                                    "typeName": "String"
+                                },
+                                {
+                                    "name": "discount",
+                                    "type": 73,
+                                    "typeName": "Discount"
+                                },
+                                {
+                                    "name": "expires",
+                                    "type": 27,
+                                    "typeName": "BlockNumber"
+                                },
+                                {
+                                    "name": "remaining_uses",
+                                    "type": 27,
+                                    "typeName": "u32"
+                                }
+                            ]
+                        }
+                    },
+                    "path": [
+                        "assets",
+                        "assets",
+                        "Coupon"
+                    ]
+                }
+            },
+            {
+                "id": 85,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "L",
+                            "type": 9
+                        },
+                        {
+                            "name": "R",
+                            "type": 86
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ResolverKey"
+                    ]
+                }
+            },
+            {
+                "id": 86,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "ParentKey",
+                            "type": 11
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ManualKey"
+                    ]
+                }
+            },
+            {
+                "id": 87,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "K",
+                            "type": 21
+                        },
+                        {
+                            "name": "V",
+                            "type": 88
+                        },
+                        {
+                            "name": "KeyType",
+                            "type": 89
+                        }
+                    ],
+                    "path": [
+                        "ink_storage",
+                        "lazy",
+                        "mapping",
+                        "Mapping"
+                    ]
+                }
+            },
+            {
+                "id": 88,
+                "type": {
+                    "def": {
+                        "variant": {
+                            "variants": [
+                                {
+                                    "fields": [
+                                        {
+                                            "name": "base",
+                                            "type": 1,
+                                            "typeName": "Balance"
+                                        },
+                                        {
+                                            "name": "slope",
+                                            "type": 1,
+                                            "typeName": "Balance"
+                                        }
+                                    ],
+                                    "index": 0,
+                                    "name": "Linear"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "name": "base",
+                                            "type": 1,
+                                            "typeName": "Balance"
+                                        },
+                                        {
+                                            "name": "growth_bps",
+                                            "type": 27,
+                                            "typeName": "u32"
+                                        }
+                                    ],
+                                    "index": 1,
+                                    "name": "Exponential"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "name": "base",
+                                            "type": 1,
+                                            "typeName": "Balance"
+                                        },
+                                        {
+                                            "name": "step",
+                                            "type": 30,
+                                            "typeName": "u64"
+                                        },
+                                        {
+                                            "name": "increment",
+                                            "type": 1,
+                                            "typeName": "Balance"
+                                        }
+                                    ],
+                                    "index": 2,
+                                    "name": "Step"
+                                }
+                            ]
+                        }
+                    },
+                    "path": [
+                        "assets",
+                        "assets",
+                        "PriceCurve"
+                    ]
+                }
+            },
+            {
+                "id": 89,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "L",
+                            "type": 9
+                        },
+                        {
+                            "name": "R",
+                            "type": 90
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ResolverKey"
+                    ]
+                }
+            },
+            {
+                "id": 90,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "ParentKey",
+                            "type": 11
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ManualKey"
+                    ]
+                }
+            },
+            {
+                "id": 91,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "K",
+                            "type": 21
+                        },
+                        {
+                            "name": "V",
+                            "type": 30
+                        },
+                        {
+                            "name": "KeyType",
+                            "type": 92
+                        }
+                    ],
+                    "path": [
+                        "ink_storage",
+                        "lazy",
+                        "mapping",
+                        "Mapping"
+                    ]
+                }
+            },
+            {
+                "id": 92,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "L",
+                            "type": 9
+                        },
+                        {
+                            "name": "R",
+                            "type": 93
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ResolverKey"
+                    ]
+                }
+            },
+            {
+                "id": 93,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "ParentKey",
+                            "type": 11
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ManualKey"
+                    ]
+                }
+            },
+            {
+                "id": 94,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "K",
+                            "type": 21
+                        },
+                        {
+                            "name": "V",
+                            "type": 1
+                        },
+                        {
+                            "name": "KeyType",
+                            "type": 95
+                        }
+                    ],
+                    "path": [
+                        "ink_storage",
+                        "lazy",
+                        "mapping",
+                        "Mapping"
+                    ]
+                }
+            },
+            {
+                "id": 95,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "L",
+                            "type": 9
+                        },
+                        {
+                            "name": "R",
+                            "type": 96
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ResolverKey"
+                    ]
+                }
+            },
+            {
+                "id": 96,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "ParentKey",
+                            "type": 11
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ManualKey"
+                    ]
+                }
+            },
+            {
+                "id": 97,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "K",
+                            "type": 21
+                        },
+                        {
+                            "name": "V",
+                            "type": 6
+                        },
+                        {
+                            "name": "KeyType",
+                            "type": 98
+                        }
+                    ],
+                    "path": [
+                        "ink_storage",
+                        "lazy",
+                        "mapping",
+                        "Mapping"
+                    ]
+                }
+            },
+            {
+                "id": 98,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "L",
+                            "type": 9
+                        },
+                        {
+                            "name": "R",
+                            "type": 99
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ResolverKey"
+                    ]
+                }
+            },
+            {
+                "id": 99,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "ParentKey",
+                            "type": 11
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ManualKey"
+                    ]
+                }
+            },
+            {
+                "id": 100,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "K",
+                            "type": 0
+                        },
+                        {
+                            "name": "V",
+                            "type": 1
+                        },
+                        {
+                            "name": "KeyType",
+                            "type": 101
+                        }
+                    ],
+                    "path": [
+                        "ink_storage",
+                        "lazy",
+                        "mapping",
+                        "Mapping"
+                    ]
+                }
+            },
+            {
+                "id": 101,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "L",
+                            "type": 9
+                        },
+                        {
+                            "name": "R",
+                            "type": 102
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ResolverKey"
+                    ]
+                }
+            },
+            {
+                "id": 102,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "ParentKey",
+                            "type": 11
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ManualKey"
+                    ]
+                }
+            },
+            {
+                "id": 103,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "K",
+                            "type": 27
+                        },
+                        {
+                            "name": "V",
+                            "type": 104
+                        },
+                        {
+                            "name": "KeyType",
+                            "type": 105
+                        }
+                    ],
+                    "path": [
+                        "ink_storage",
+                        "lazy",
+                        "mapping",
+                        "Mapping"
+                    ]
+                }
+            },
+            {
+                "id": 104,
+                "type": {
+                    "def": {
+                        "composite": {
+                            "fields": [
+                                {
+                                    "name": "game",
+                                    "type": 0,
+                                    "typeName": "String"
+                                },
+                                {
+                                    "name": "name",
+                                    "type": 0,
+                                    "typeName": "String"
+                                },
+                                {
+                                    "name": "symbol",
+                                    "type": 0,
+                                    "typeName": "String"
+                                },
+                                {
+                                    "name": "decimals",
+                                    "type": 6,
+                                    "typeName": "u8"
+                                },
+                                {
+                                    "name": "total_supply",
+                                    "type": 1,
+                                    "typeName": "Balance"
+                                },
+                                {
+                                    "name": "prices_denominated",
+                                    "type": 37,
+                                    "typeName": "bool"
+                                }
+                            ]
+                        }
+                    },
+                    "path": [
+                        "assets",
+                        "assets",
+                        "Currency"
+                    ]
+                }
+            },
+            {
+                "id": 105,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "L",
+                            "type": 9
+                        },
+                        {
+                            "name": "R",
+                            "type": 106
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ResolverKey"
+                    ]
+                }
+            },
+            {
+                "id": 106,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "ParentKey",
+                            "type": 11
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ManualKey"
+                    ]
+                }
+            },
+            {
+                "id": 107,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "K",
+                            "type": 0
+                        },
+                        {
+                            "name": "V",
+                            "type": 27
+                        },
+                        {
+                            "name": "KeyType",
+                            "type": 108
+                        }
+                    ],
+                    "path": [
+                        "ink_storage",
+                        "lazy",
+                        "mapping",
+                        "Mapping"
+                    ]
+                }
+            },
+            {
+                "id": 108,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "L",
+                            "type": 9
+                        },
+                        {
+                            "name": "R",
+                            "type": 109
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ResolverKey"
+                    ]
+                }
+            },
+            {
+                "id": 109,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "ParentKey",
+                            "type": 11
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ManualKey"
+                    ]
+                }
+            },
+            {
+                "id": 110,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "K",
+                            "type": 68
+                        },
+                        {
+                            "name": "V",
+                            "type": 1
+                        },
+                        {
+                            "name": "KeyType",
+                            "type": 111
+                        }
+                    ],
+                    "path": [
+                        "ink_storage",
+                        "lazy",
+                        "mapping",
+                        "Mapping"
+                    ]
+                }
+            },
+            {
+                "id": 111,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "L",
+                            "type": 9
+                        },
+                        {
+                            "name": "R",
+                            "type": 112
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ResolverKey"
+                    ]
+                }
+            },
+            {
+                "id": 112,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "ParentKey",
+                            "type": 11
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ManualKey"
+                    ]
+                }
+            },
+            {
+                "id": 113,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "K",
+                            "type": 114
+                        },
+                        {
+                            "name": "V",
+                            "type": 1
+                        },
+                        {
+                            "name": "KeyType",
+                            "type": 115
+                        }
+                    ],
+                    "path": [
+                        "ink_storage",
+                        "lazy",
+                        "mapping",
+                        "Mapping"
+                    ]
+                }
+            },
+            {
+                "id": 114,
+                "type": {
+                    "def": {
+                        "tuple": [
+                            27,
+                            4,
+                            4
+                        ]
+                    }
+                }
+            },
+            {
+                "id": 115,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "L",
+                            "type": 9
+                        },
+                        {
+                            "name": "R",
+                            "type": 116
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ResolverKey"
+                    ]
+                }
+            },
+            {
+                "id": 116,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "ParentKey",
+                            "type": 11
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ManualKey"
+                    ]
+                }
+            },
+            {
+                "id": 117,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "K",
+                            "type": 21
+                        },
+                        {
+                            "name": "V",
+                            "type": 118
+                        },
+                        {
+                            "name": "KeyType",
+                            "type": 119
+                        }
+                    ],
+                    "path": [
+                        "ink_storage",
+                        "lazy",
+                        "mapping",
+                        "Mapping"
+                    ]
+                }
+            },
+            {
+                "id": 118,
+                "type": {
+                    "def": {
+                        "variant": {
+                            "variants": [
+                                {
+                                    "index": 0,
+                                    "name": "Store"
+                                },
+                                {
+                                    "index": 1,
+                                    "name": "Native"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 4,
+                                            "typeName": "AccountId"
+                                        }
+                                    ],
+                                    "index": 2,
+                                    "name": "Psp22"
+                                }
+                            ]
+                        }
+                    },
+                    "path": [
+                        "assets",
+                        "assets",
+                        "PriceCurrency"
+                    ]
+                }
+            },
+            {
+                "id": 119,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "L",
+                            "type": 9
+                        },
+                        {
+                            "name": "R",
+                            "type": 120
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ResolverKey"
+                    ]
+                }
+            },
+            {
+                "id": 120,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "ParentKey",
+                            "type": 11
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ManualKey"
+                    ]
+                }
+            },
+            {
+                "id": 121,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "K",
+                            "type": 21
+                        },
+                        {
+                            "name": "V",
+                            "type": 122
+                        },
+                        {
+                            "name": "KeyType",
+                            "type": 123
+                        }
+                    ],
+                    "path": [
+                        "ink_storage",
+                        "lazy",
+                        "mapping",
+                        "Mapping"
+                    ]
+                }
+            },
+            {
+                "id": 122,
+                "type": {
+                    "def": {
+                        "composite": {
+                            "fields": [
+                                {
+                                    "name": "rate",
+                                    "type": 1,
+                                    "typeName": "Balance"
+                                },
+                                {
+                                    "name": "reward_per_unit",
+                                    "type": 1,
+                                    "typeName": "Balance"
+                                },
+                                {
+                                    "name": "last_update",
+                                    "type": 27,
+                                    "typeName": "BlockNumber"
+                                },
+                                {
+                                    "name": "total_staked",
+                                    "type": 30,
+                                    "typeName": "u64"
+                                }
+                            ]
+                        }
+                    },
+                    "path": [
+                        "assets",
+                        "assets",
+                        "RewardPool"
+                    ]
+                }
+            },
+            {
+                "id": 123,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "L",
+                            "type": 9
+                        },
+                        {
+                            "name": "R",
+                            "type": 124
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ResolverKey"
+                    ]
+                }
+            },
+            {
+                "id": 124,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "ParentKey",
+                            "type": 11
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ManualKey"
+                    ]
+                }
+            },
+            {
+                "id": 125,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "K",
+                            "type": 126
+                        },
+                        {
+                            "name": "V",
+                            "type": 127
+                        },
+                        {
+                            "name": "KeyType",
+                            "type": 128
+                        }
+                    ],
+                    "path": [
+                        "ink_storage",
+                        "lazy",
+                        "mapping",
+                        "Mapping"
+                    ]
+                }
+            },
+            {
+                "id": 126,
+                "type": {
+                    "def": {
+                        "tuple": [
+                            4,
+                            0,
+                            0
+                        ]
+                    }
+                }
+            },
+            {
+                "id": 127,
+                "type": {
+                    "def": {
+                        "composite": {
+                            "fields": [
+                                {
+                                    "name": "amount",
+                                    "type": 30,
+                                    "typeName": "u64"
+                                },
+                                {
+                                    "name": "reward_debt",
+                                    "type": 1,
+                                    "typeName": "Balance"
+                                },
+                                {
+                                    "name": "pending",
+                                    "type": 1,
+                                    "typeName": "Balance"
+                                }
+                            ]
+                        }
+                    },
+                    "path": [
+                        "assets",
+                        "assets",
+                        "Stake"
+                    ]
+                }
+            },
+            {
+                "id": 128,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "L",
+                            "type": 9
+                        },
+                        {
+                            "name": "R",
+                            "type": 129
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ResolverKey"
+                    ]
+                }
+            },
+            {
+                "id": 129,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "ParentKey",
+                            "type": 11
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ManualKey"
+                    ]
+                }
+            },
+            {
+                "id": 130,
+                "type": {
+                    "def": {
+                        "sequence": {
+                            "type": 131
+                        }
+                    }
+                }
+            },
+            {
+                "id": 131,
+                "type": {
+                    "def": {
+                        "tuple": [
+                            4,
+                            132
+                        ]
+                    }
+                }
+            },
+            {
+                "id": 132,
+                "type": {
+                    "def": {
+                        "variant": {
+                            "variants": [
+                                {
+                                    "index": 0,
+                                    "name": "Leader"
+                                },
+                                {
+                                    "index": 1,
+                                    "name": "Officer"
+                                },
+                                {
+                                    "index": 2,
+                                    "name": "Member"
+                                }
+                            ]
+                        }
+                    },
+                    "path": [
+                        "assets",
+                        "assets",
+                        "GuildRole"
+                    ]
+                }
+            },
+            {
+                "id": 133,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "K",
+                            "type": 27
+                        },
+                        {
+                            "name": "V",
+                            "type": 134
+                        },
+                        {
+                            "name": "KeyType",
+                            "type": 135
+                        }
+                    ],
+                    "path": [
+                        "ink_storage",
+                        "lazy",
+                        "mapping",
+                        "Mapping"
+                    ]
+                }
+            },
+            {
+                "id": 134,
+                "type": {
+                    "def": {
+                        "composite": {
+                            "fields": [
+                                {
+                                    "name": "name",
+                                    "type": 0,
+                                    "typeName": "String"
+                                },
+                                {
+                                    "name": "members",
+                                    "type": 130,
+                                    "typeName": "Vec<(AccountId, GuildRole)>"
+                                },
+                                {
+                                    "name": "quorum",
+                                    "type": 27,
+                                    "typeName": "u32"
+                                },
+                                {
+                                    "name": "balance",
+                                    "type": 1,
+                                    "typeName": "Balance"
+                                },
+                                {
+                                    "name": "assets",
+                                    "type": 60,
+                                    "typeName": "Vec<(String, u64)>"
+                                },
+                                {
+                                    "name": "next_proposal_id",
+                                    "type": 27,
+                                    "typeName": "u32"
+                                }
+                            ]
+                        }
+                    },
+                    "path": [
+                        "assets",
+                        "assets",
+                        "Guild"
+                    ]
+                }
+            },
+            {
+                "id": 135,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "L",
+                            "type": 9
+                        },
+                        {
+                            "name": "R",
+                            "type": 136
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ResolverKey"
+                    ]
+                }
+            },
+            {
+                "id": 136,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "ParentKey",
+                            "type": 11
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ManualKey"
+                    ]
+                }
+            },
+            {
+                "id": 137,
+                "type": {
+                    "def": {
+                        "sequence": {
+                            "type": 4
+                        }
+                    }
+                }
+            },
+            {
+                "id": 138,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "K",
+                            "type": 139
+                        },
+                        {
+                            "name": "V",
+                            "type": 140
+                        },
+                        {
+                            "name": "KeyType",
+                            "type": 142
+                        }
+                    ],
+                    "path": [
+                        "ink_storage",
+                        "lazy",
+                        "mapping",
+                        "Mapping"
+                    ]
+                }
+            },
+            {
+                "id": 139,
+                "type": {
+                    "def": {
+                        "tuple": [
+                            27,
+                            27
+                        ]
+                    }
+                }
+            },
+            {
+                "id": 140,
+                "type": {
+                    "def": {
+                        "composite": {
+                            "fields": [
+                                {
+                                    "name": "to",
+                                    "type": 4,
+                                    "typeName": "AccountId"
+                                },
+                                {
+                                    "name": "item",
+                                    "type": 141,
+                                    "typeName": "VaultItem"
+                                },
+                                {
+                                    "name": "approvals",
+                                    "type": 137,
+                                    "typeName": "Vec<AccountId>"
+                                }
+                            ]
+                        }
+                    },
+                    "path": [
+                        "assets",
+                        "assets",
+                        "Withdrawal"
+                    ]
+                }
+            },
+            {
+                "id": 141,
+                "type": {
+                    "def": {
+                        "variant": {
+                            "variants": [
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 1,
+                                            "typeName": "Balance"
+                                        }
+                                    ],
+                                    "index": 0,
+                                    "name": "Balance"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 0,
+                                            "typeName": "String"
+                                        },
+                                        {
+                                            "type": 30,
+                                            "typeName": "u64"
+                                        }
+                                    ],
+                                    "index": 1,
+                                    "name": "Asset"
+                                }
+                            ]
+                        }
+                    },
+                    "path": [
+                        "assets",
+                        "assets",
+                        "VaultItem"
+                    ]
+                }
+            },
+            {
+                "id": 142,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "L",
+                            "type": 9
+                        },
+                        {
+                            "name": "R",
+                            "type": 143
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ResolverKey"
+                    ]
+                }
+            },
+            {
+                "id": 143,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "ParentKey",
+                            "type": 11
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ManualKey"
+                    ]
+                }
+            },
+            {
+                "id": 144,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "K",
+                            "type": 0
+                        },
+                        {
+                            "name": "V",
+                            "type": 145
+                        },
+                        {
+                            "name": "KeyType",
+                            "type": 146
+                        }
+                    ],
+                    "path": [
+                        "ink_storage",
+                        "lazy",
+                        "mapping",
+                        "Mapping"
+                    ]
+                }
+            },
+            {
+                "id": 145,
+                "type": {
+                    "def": {
+                        "composite": {
+                            "fields": [
+                                {
+                                    "name": "signers",
+                                    "type": 137,
+                                    "typeName": "Vec<AccountId>"
+                                },
+                                {
+                                    "name": "threshold",
+                                    "type": 27,
+                                    "typeName": "u32"
+                                }
+                            ]
+                        }
+                    },
+                    "path": [
+                        "assets",
+                        "assets",
+                        "GameCouncil"
+                    ]
+                }
+            },
+            {
+                "id": 146,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "L",
+                            "type": 9
+                        },
+                        {
+                            "name": "R",
+                            "type": 147
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ResolverKey"
+                    ]
+                }
+            },
+            {
+                "id": 147,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "ParentKey",
+                            "type": 11
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ManualKey"
+                    ]
+                }
+            },
+            {
+                "id": 148,
+                "type": {
+                    "def": {
+                        "sequence": {
+                            "type": 149
+                        }
+                    }
+                }
+            },
+            {
+                "id": 149,
+                "type": {
+                    "def": {
+                        "composite": {
+                            "fields": [
+                                {
+                                    "name": "share_bps",
+                                    "type": 150,
+                                    "typeName": "u16"
+                                },
+                                {
+                                    "name": "trophy",
+                                    "type": 151,
+                                    "typeName": "Option<(String, u64)>"
+                                }
+                            ]
+                        }
+                    },
+                    "path": [
+                        "assets",
+                        "assets",
+                        "Prize"
+                    ]
+                }
+            },
+            {
+                "id": 150,
+                "type": {
+                    "def": {
+                        "primitive": "u16"
+                    }
+                }
+            },
+            {
+                "id": 151,
+                "type": {
+                    "def": {
+                        "variant": {
+                            "variants": [
+                                {
+                                    "index": 0,
+                                    "name": "None"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 61
+                                        }
+                                    ],
+                                    "index": 1,
+                                    "name": "Some"
+                                }
+                            ]
+                        }
+                    },
+                    "params": [
+                        {
+                            "name": "T",
+                            "type": 61
+                        }
+                    ],
+                    "path": [
+                        "Option"
+                    ]
+                }
+            },
+            {
+                "id": 152,
+                "type": {
+                    "def": {
+                        "primitive": "i32"
+                    }
+                }
+            },
+            {
+                "id": 153,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "K",
+                            "type": 27
+                        },
+                        {
+                            "name": "V",
+                            "type": 154
+                        },
+                        {
+                            "name": "KeyType",
+                            "type": 162
+                        }
+                    ],
+                    "path": [
+                        "ink_storage",
+                        "lazy",
+                        "mapping",
+                        "Mapping"
+                    ]
+                }
+            },
+            {
+                "id": 154,
+                "type": {
+                    "def": {
+                        "composite": {
+                            "fields": [
+                                {
+                                    "name": "game",
+                                    "type": 0,
+                                    "typeName": "String"
+                                },
+                                {
+                                    "name": "action",
+                                    "type": 155,
+                                    "typeName": "GameAction"
+                                },
+                                {
+                                    "name": "approvals",
+                                    "type": 137,
+                                    "typeName": "Vec<AccountId>"
+                                },
+                                {
+                                    "name": "expires_at",
+                                    "type": 27,
+                                    "typeName": "BlockNumber"
+                                }
+                            ]
+                        }
+                    },
+                    "path": [
+                        "assets",
+                        "assets",
+                        "GameProposal"
+                    ]
+                }
+            },
+            {
+                "id": 155,
+                "type": {
+                    "def": {
+                        "variant": {
+                            "variants": [
+                                {
+                                    "fields": [
+                                        {
+                                            "name": "asset",
+                                            "type": 0,
+                                            "typeName": "String"
+                                        },
+                                        {
+                                            "name": "price",
+                                            "type": 1,
+                                            "typeName": "Balance"
+                                        }
+                                    ],
+                                    "index": 0,
+                                    "name": "SetPrice"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "name": "asset",
+                                            "type": 0,
+                                            "typeName": "String"
+                                        },
+                                        {
+                                            "name": "owner",
+                                            "type": 4,
+                                            "typeName": "AccountId"
+                                        }
+                                    ],
+                                    "index": 1,
+                                    "name": "MintItem"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "name": "to",
+                                            "type": 4,
+                                            "typeName": "AccountId"
+                                        },
+                                        {
+                                            "name": "value",
+                                            "type": 1,
+                                            "typeName": "Balance"
+                                        }
+                                    ],
+                                    "index": 2,
+                                    "name": "MintCurrency"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "name": "to",
+                                            "type": 4,
+                                            "typeName": "AccountId"
+                                        },
+                                        {
+                                            "name": "amount",
+                                            "type": 1,
+                                            "typeName": "Balance"
+                                        }
+                                    ],
+                                    "index": 3,
+                                    "name": "WithdrawReserve"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "name": "signers",
+                                            "type": 137,
+                                            "typeName": "Vec<AccountId>"
+                                        },
+                                        {
+                                            "name": "threshold",
+                                            "type": 27,
+                                            "typeName": "u32"
+                                        }
+                                    ],
+                                    "index": 4,
+                                    "name": "SetSigners"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "name": "contents",
+                                            "type": 60,
+                                            "typeName": "Vec<(String, u64)>"
+                                        },
+                                        {
+                                            "name": "price",
+                                            "type": 1,
+                                            "typeName": "Balance"
+                                        },
+                                        {
+                                            "name": "max_per_player",
+                                            "type": 30,
+                                            "typeName": "u64"
+                                        },
+                                        {
+                                            "name": "available_from",
+                                            "type": 27,
+                                            "typeName": "BlockNumber"
+                                        },
+                                        {
+                                            "name": "available_until",
+                                            "type": 64,
+                                            "typeName": "Option<BlockNumber>"
+                                        }
+                                    ],
+                                    "index": 5,
+                                    "name": "CreateBundle"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "name": "asset",
+                                            "type": 0,
+                                            "typeName": "String"
+                                        },
+                                        {
+                                            "name": "code_hash",
+                                            "type": 28,
+                                            "typeName": "Hash"
+                                        },
+                                        {
+                                            "name": "discount",
+                                            "type": 73,
+                                            "typeName": "Discount"
+                                        },
+                                        {
+                                            "name": "expires",
+                                            "type": 27,
+                                            "typeName": "BlockNumber"
+                                        },
+                                        {
+                                            "name": "uses",
+                                            "type": 27,
+                                            "typeName": "u32"
+                                        }
+                                    ],
+                                    "index": 6,
+                                    "name": "AddCoupon"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "name": "asset",
+                                            "type": 0,
+                                            "typeName": "String"
+                                        },
+                                        {
+                                            "name": "discount",
+                                            "type": 73,
+                                            "typeName": "Discount"
+                                        },
+                                        {
+                                            "name": "start",
+                                            "type": 27,
+                                            "typeName": "BlockNumber"
+                                        },
+                                        {
+                                            "name": "end",
+                                            "type": 27,
+                                            "typeName": "BlockNumber"
+                                        },
+                                        {
+                                            "name": "max_per_player",
+                                            "type": 30,
+                                            "typeName": "u64"
+                                        }
+                                    ],
+                                    "index": 7,
+                                    "name": "ScheduleSale"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "name": "asset",
+                                            "type": 0,
+                                            "typeName": "String"
+                                        },
+                                        {
+                                            "name": "curve",
+                                            "type": 156,
+                                            "typeName": "Option<PriceCurve>"
+                                        }
+                                    ],
+                                    "index": 8,
+                                    "name": "SetPriceCurve"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "name": "asset",
+                                            "type": 0,
+                                            "typeName": "String"
+                                        },
+                                        {
+                                            "name": "rate",
+                                            "type": 1,
+                                            "typeName": "Balance"
+                                        }
+                                    ],
+                                    "index": 9,
+                                    "name": "SetRewardRate"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "name": "terms",
+                                            "type": 157,
+                                            "typeName": "TournamentTerms"
+                                        }
+                                    ],
+                                    "index": 10,
+                                    "name": "CreateTournament"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "name": "asset",
+                                            "type": 0,
+                                            "typeName": "String"
+                                        },
+                                        {
+                                            "name": "percent",
+                                            "type": 6,
+                                            "typeName": "u8"
+                                        }
+                                    ],
+                                    "index": 11,
+                                    "name": "SetBuyback"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "name": "asset",
+                                            "type": 0,
+                                            "typeName": "String"
+                                        },
+                                        {
+                                            "name": "currency",
+                                            "type": 118,
+                                            "typeName": "PriceCurrency"
+                                        }
+                                    ],
+                                    "index": 12,
+                                    "name": "SetPriceCurrency"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "name": "operator",
+                                            "type": 4,
+                                            "typeName": "AccountId"
+                                        },
+                                        {
+                                            "name": "allowed",
+                                            "type": 37,
+                                            "typeName": "bool"
+                                        }
+                                    ],
+                                    "index": 13,
+                                    "name": "SetOperator"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "name": "signer",
+                                            "type": 159,
+                                            "typeName": "Option<SignerKey>"
+                                        }
+                                    ],
+                                    "index": 14,
+                                    "name": "SetVoucherSigner"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "name": "achievement",
+                                            "type": 0,
+                                            "typeName": "String"
+                                        },
+                                        {
+                                            "name": "account",
+                                            "type": 4,
+                                            "typeName": "AccountId"
+                                        }
+                                    ],
+                                    "index": 15,
+                                    "name": "GrantAchievement"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "name": "account",
+                                            "type": 4,
+                                            "typeName": "AccountId"
+                                        },
+                                        {
+                                            "name": "delta",
+                                            "type": 152,
+                                            "typeName": "i32"
+                                        }
+                                    ],
+                                    "index": 16,
+                                    "name": "AdjustReputation"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "name": "route",
+                                            "type": 39,
+                                            "typeName": "ConversionRoute"
+                                        },
+                                        {
+                                            "name": "give_units",
+                                            "type": 30,
+                                            "typeName": "u64"
+                                        },
+                                        {
+                                            "name": "take_units",
+                                            "type": 30,
+                                            "typeName": "u64"
+                                        },
+                                        {
+                                            "name": "cap_per_period",
+                                            "type": 30,
+                                            "typeName": "u64"
+                                        },
+                                        {
+                                            "name": "period",
+                                            "type": 27,
+                                            "typeName": "BlockNumber"
+                                        }
+                                    ],
+                                    "index": 17,
+                                    "name": "ProposeConversion"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "name": "name",
+                                            "type": 0,
+                                            "typeName": "String"
+                                        },
+                                        {
+                                            "name": "symbol",
+                                            "type": 0,
+                                            "typeName": "String"
+                                        },
+                                        {
+                                            "name": "decimals",
+                                            "type": 6,
+                                            "typeName": "u8"
+                                        },
+                                        {
+                                            "name": "denominate_prices",
+                                            "type": 37,
+                                            "typeName": "bool"
+                                        }
+                                    ],
+                                    "index": 18,
+                                    "name": "CreateCurrency"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "name": "name",
+                                            "type": 0,
+                                            "typeName": "String"
+                                        },
+                                        {
+                                            "name": "price",
+                                            "type": 1,
+                                            "typeName": "Balance"
+                                        },
+                                        {
+                                            "name": "recoverable",
+                                            "type": 37,
+                                            "typeName": "bool"
+                                        }
+                                    ],
+                                    "index": 19,
+                                    "name": "RegisterAsset"
+                                }
+                            ]
+                        }
+                    },
+                    "path": [
+                        "assets",
+                        "assets",
+                        "GameAction"
+                    ]
+                }
+            },
+            {
+                "id": 156,
+                "type": {
+                    "def": {
+                        "variant": {
+                            "variants": [
+                                {
+                                    "index": 0,
+                                    "name": "None"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 88
+                                        }
+                                    ],
+                                    "index": 1,
+                                    "name": "Some"
+                                }
+                            ]
+                        }
+                    },
+                    "params": [
+                        {
+                            "name": "T",
+                            "type": 88
+                        }
+                    ],
+                    "path": [
+                        "Option"
+                    ]
+                }
+            },
+            {
+                "id": 157,
+                "type": {
+                    "def": {
+                        "composite": {
+                            "fields": [
+                                {
+                                    "name": "entry_fee",
+                                    "type": 141,
+                                    "typeName": "VaultItem"
+                                },
+                                {
+                                    "name": "max_entrants",
+                                    "type": 27,
+                                    "typeName": "u32"
+                                },
+                                {
+                                    "name": "opens_at",
+                                    "type": 27,
+                                    "typeName": "BlockNumber"
+                                },
+                                {
+                                    "name": "closes_at",
+                                    "type": 27,
+                                    "typeName": "BlockNumber"
+                                },
+                                {
+                                    "name": "prizes",
+                                    "type": 148,
+                                    "typeName": "Vec<Prize>"
+                                },
+                                {
+                                    "name": "oracle",
+                                    "type": 158,
+                                    "typeName": "Option<AccountId>"
+                                }
+                            ]
+                        }
+                    },
+                    "path": [
+                        "assets",
+                        "assets",
+                        "TournamentTerms"
+                    ]
+                }
+            },
+            {
+                "id": 158,
+                "type": {
+                    "def": {
+                        "variant": {
+                            "variants": [
+                                {
+                                    "index": 0,
+                                    "name": "None"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 4
+                                        }
+                                    ],
+                                    "index": 1,
+                                    "name": "Some"
+                                }
+                            ]
+                        }
+                    },
+                    "params": [
+                        {
+                            "name": "T",
+                            "type": 4
+                        }
+                    ],
+                    "path": [
+                        "Option"
+                    ]
+                }
+            },
+            {
+                "id": 159,
+                "type": {
+                    "def": {
+                        "variant": {
+                            "variants": [
+                                {
+                                    "index": 0,
+                                    "name": "None"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 160
+                                        }
+                                    ],
+                                    "index": 1,
+                                    "name": "Some"
+                                }
+                            ]
+                        }
+                    },
+                    "params": [
+                        {
+                            "name": "T",
+                            "type": 160
+                        }
+                    ],
+                    "path": [
+                        "Option"
+                    ]
+                }
+            },
+            {
+                "id": 160,
+                "type": {
+                    "def": {
+                        "variant": {
+                            "variants": [
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 5,
+                                            "typeName": "[u8; 32]"
+                                        }
+                                    ],
+                                    "index": 0,
+                                    "name": "Sr25519"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 161,
+                                            "typeName": "[u8; 33]"
+                                        }
+                                    ],
+                                    "index": 1,
+                                    "name": "Ecdsa"
+                                }
+                            ]
+                        }
+                    },
+                    "path": [
+                        "assets",
+                        "assets",
+                        "SignerKey"
+                    ]
+                }
+            },
+            {
+                "id": 161,
+                "type": {
+                    "def": {
+                        "array": {
+                            "len": 33,
+                            "type": 6
+                        }
+                    }
+                }
+            },
+            {
+                "id": 162,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "L",
+                            "type": 9
+                        },
+                        {
+                            "name": "R",
+                            "type": 163
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ResolverKey"
+                    ]
+                }
+            },
+            {
+                "id": 163,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "ParentKey",
+                            "type": 11
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ManualKey"
+                    ]
+                }
+            },
+            {
+                "id": 164,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "K",
+                            "type": 0
+                        },
+                        {
+                            "name": "V",
+                            "type": 160
+                        },
+                        {
+                            "name": "KeyType",
+                            "type": 165
+                        }
+                    ],
+                    "path": [
+                        "ink_storage",
+                        "lazy",
+                        "mapping",
+                        "Mapping"
+                    ]
+                }
+            },
+            {
+                "id": 165,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "L",
+                            "type": 9
+                        },
+                        {
+                            "name": "R",
+                            "type": 166
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ResolverKey"
+                    ]
+                }
+            },
+            {
+                "id": 166,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "ParentKey",
+                            "type": 11
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ManualKey"
+                    ]
+                }
+            },
+            {
+                "id": 167,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "K",
+                            "type": 61
+                        },
+                        {
+                            "name": "V",
+                            "type": 11
+                        },
+                        {
+                            "name": "KeyType",
+                            "type": 168
+                        }
+                    ],
+                    "path": [
+                        "ink_storage",
+                        "lazy",
+                        "mapping",
+                        "Mapping"
+                    ]
+                }
+            },
+            {
+                "id": 168,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "L",
+                            "type": 9
+                        },
+                        {
+                            "name": "R",
+                            "type": 169
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ResolverKey"
+                    ]
+                }
+            },
+            {
+                "id": 169,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "ParentKey",
+                            "type": 11
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ManualKey"
+                    ]
+                }
+            },
+            {
+                "id": 170,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "K",
+                            "type": 4
+                        },
+                        {
+                            "name": "V",
+                            "type": 30
+                        },
+                        {
+                            "name": "KeyType",
+                            "type": 171
+                        }
+                    ],
+                    "path": [
+                        "ink_storage",
+                        "lazy",
+                        "mapping",
+                        "Mapping"
+                    ]
+                }
+            },
+            {
+                "id": 171,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "L",
+                            "type": 9
+                        },
+                        {
+                            "name": "R",
+                            "type": 172
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ResolverKey"
+                    ]
+                }
+            },
+            {
+                "id": 172,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "ParentKey",
+                            "type": 11
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ManualKey"
+                    ]
+                }
+            },
+            {
+                "id": 173,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "K",
+                            "type": 27
+                        },
+                        {
+                            "name": "V",
+                            "type": 174
+                        },
+                        {
+                            "name": "KeyType",
+                            "type": 175
+                        }
+                    ],
+                    "path": [
+                        "ink_storage",
+                        "lazy",
+                        "mapping",
+                        "Mapping"
+                    ]
+                }
+            },
+            {
+                "id": 174,
+                "type": {
+                    "def": {
+                        "composite": {
+                            "fields": [
+                                {
+                                    "name": "game",
+                                    "type": 0,
+                                    "typeName": "String"
+                                },
+                                {
+                                    "name": "asset",
+                                    "type": 0,
+                                    "typeName": "String"
+                                },
+                                {
+                                    "name": "merkle_root",
+                                    "type": 28,
+                                    "typeName": "Hash"
+                                },
+                                {
+                                    "name": "remaining",
+                                    "type": 30,
+                                    "typeName": "u64"
+                                },
+                                {
+                                    "name": "expires_at",
+                                    "type": 27,
+                                    "typeName": "BlockNumber"
+                                }
+                            ]
+                        }
+                    },
+                    "path": [
+                        "assets",
+                        "assets",
+                        "Airdrop"
+                    ]
+                }
+            },
+            {
+                "id": 175,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "L",
+                            "type": 9
+                        },
+                        {
+                            "name": "R",
+                            "type": 176
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ResolverKey"
+                    ]
+                }
+            },
+            {
+                "id": 176,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "ParentKey",
+                            "type": 11
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ManualKey"
+                    ]
+                }
+            },
+            {
+                "id": 177,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "K",
+                            "type": 139
+                        },
+                        {
+                            "name": "V",
+                            "type": 1
+                        },
+                        {
+                            "name": "KeyType",
+                            "type": 178
+                        }
+                    ],
+                    "path": [
+                        "ink_storage",
+                        "lazy",
+                        "mapping",
+                        "Mapping"
+                    ]
+                }
+            },
+            {
+                "id": 178,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "L",
+                            "type": 9
+                        },
+                        {
+                            "name": "R",
+                            "type": 179
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ResolverKey"
+                    ]
+                }
+            },
+            {
+                "id": 179,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "ParentKey",
+                            "type": 11
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ManualKey"
+                    ]
+                }
+            },
+            {
+                "id": 180,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "K",
+                            "type": 57
+                        },
+                        {
+                            "name": "V",
+                            "type": 11
+                        },
+                        {
+                            "name": "KeyType",
+                            "type": 181
+                        }
+                    ],
+                    "path": [
+                        "ink_storage",
+                        "lazy",
+                        "mapping",
+                        "Mapping"
+                    ]
+                }
+            },
+            {
+                "id": 181,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "L",
+                            "type": 9
+                        },
+                        {
+                            "name": "R",
+                            "type": 182
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ResolverKey"
+                    ]
+                }
+            },
+            {
+                "id": 182,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "ParentKey",
+                            "type": 11
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ManualKey"
+                    ]
+                }
+            },
+            {
+                "id": 183,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "K",
+                            "type": 27
+                        },
+                        {
+                            "name": "V",
+                            "type": 184
+                        },
+                        {
+                            "name": "KeyType",
+                            "type": 188
+                        }
+                    ],
+                    "path": [
+                        "ink_storage",
+                        "lazy",
+                        "mapping",
+                        "Mapping"
+                    ]
+                }
+            },
+            {
+                "id": 184,
+                "type": {
+                    "def": {
+                        "composite": {
+                            "fields": [
+                                {
+                                    "name": "challenger",
+                                    "type": 4,
+                                    "typeName": "AccountId"
+                                },
+                                {
+                                    "name": "opponent",
+                                    "type": 4,
+                                    "typeName": "AccountId"
+                                },
+                                {
+                                    "name": "stake",
+                                    "type": 141,
+                                    "typeName": "VaultItem"
+                                },
+                                {
+                                    "name": "timeout",
+                                    "type": 27,
+                                    "typeName": "BlockNumber"
+                                },
+                                {
+                                    "name": "deadline",
+                                    "type": 27,
+                                    "typeName": "BlockNumber"
+                                },
+                                {
+                                    "name": "challenger_commit",
+                                    "type": 28,
+                                    "typeName": "Hash"
+                                },
+                                {
+                                    "name": "opponent_commit",
+                                    "type": 185,
+                                    "typeName": "Option<Hash>"
+                                },
+                                {
+                                    "name": "challenger_hand",
+                                    "type": 186,
+                                    "typeName": "Option<Hand>"
+                                },
+                                {
+                                    "name": "opponent_hand",
+                                    "type": 186,
+                                    "typeName": "Option<Hand>"
+                                }
+                            ]
+                        }
+                    },
+                    "path": [
+                        "assets",
+                        "assets",
+                        "Match"
+                    ]
+                }
+            },
+            {
+                "id": 185,
+                "type": {
+                    "def": {
+                        "variant": {
+                            "variants": [
+                                {
+                                    "index": 0,
+                                    "name": "None"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 28
+                                        }
+                                    ],
+                                    "index": 1,
+                                    "name": "Some"
+                                }
+                            ]
+                        }
+                    },
+                    "params": [
+                        {
+                            "name": "T",
+                            "type": 28
+                        }
+                    ],
+                    "path": [
+                        "Option"
+                    ]
+                }
+            },
+            {
+                "id": 186,
+                "type": {
+                    "def": {
+                        "variant": {
+                            "variants": [
+                                {
+                                    "index": 0,
+                                    "name": "None"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 187
+                                        }
+                                    ],
+                                    "index": 1,
+                                    "name": "Some"
+                                }
+                            ]
+                        }
+                    },
+                    "params": [
+                        {
+                            "name": "T",
+                            "type": 187
+                        }
+                    ],
+                    "path": [
+                        "Option"
+                    ]
+                }
+            },
+            {
+                "id": 187,
+                "type": {
+                    "def": {
+                        "variant": {
+                            "variants": [
+                                {
+                                    "index": 0,
+                                    "name": "Rock"
+                                },
+                                {
+                                    "index": 1,
+                                    "name": "Paper"
+                                },
+                                {
+                                    "index": 2,
+                                    "name": "Scissors"
+                                }
+                            ]
+                        }
+                    },
+                    "path": [
+                        "assets",
+                        "assets",
+                        "Hand"
+                    ]
+                }
+            },
+            {
+                "id": 188,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "L",
+                            "type": 9
+                        },
+                        {
+                            "name": "R",
+                            "type": 189
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ResolverKey"
+                    ]
+                }
+            },
+            {
+                "id": 189,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "ParentKey",
+                            "type": 11
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ManualKey"
+                    ]
+                }
+            },
+            {
+                "id": 190,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "K",
+                            "type": 27
+                        },
+                        {
+                            "name": "V",
+                            "type": 191
+                        },
+                        {
+                            "name": "KeyType",
+                            "type": 193
+                        }
+                    ],
+                    "path": [
+                        "ink_storage",
+                        "lazy",
+                        "mapping",
+                        "Mapping"
+                    ]
+                }
+            },
+            {
+                "id": 191,
+                "type": {
+                    "def": {
+                        "composite": {
+                            "fields": [
+                                {
+                                    "name": "game",
+                                    "type": 0,
+                                    "typeName": "String"
+                                },
+                                {
+                                    "name": "terms",
+                                    "type": 157,
+                                    "typeName": "TournamentTerms"
+                                },
+                                {
+                                    "name": "entrants",
+                                    "type": 137,
+                                    "typeName": "Vec<AccountId>"
+                                },
+                                {
+                                    "name": "status",
+                                    "type": 192,
+                                    "typeName": "TournamentStatus"
+                                }
+                            ]
+                        }
+                    },
+                    "path": [
+                        "assets",
+                        "assets",
+                        "Tournament"
+                    ]
+                }
+            },
+            {
+                "id": 192,
+                "type": {
+                    "def": {
+                        "variant": {
+                            "variants": [
+                                {
+                                    "index": 0,
+                                    "name": "Open"
+                                },
+                                {
+                                    "index": 1,
+                                    "name": "Completed"
+                                },
+                                {
+                                    "index": 2,
+                                    "name": "Cancelled"
+                                }
+                            ]
+                        }
+                    },
+                    "path": [
+                        "assets",
+                        "assets",
+                        "TournamentStatus"
+                    ]
+                }
+            },
+            {
+                "id": 193,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "L",
+                            "type": 9
+                        },
+                        {
+                            "name": "R",
+                            "type": 194
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ResolverKey"
+                    ]
+                }
+            },
+            {
+                "id": 194,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "ParentKey",
+                            "type": 11
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ManualKey"
+                    ]
+                }
+            },
+            {
+                "id": 195,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "K",
+                            "type": 21
+                        },
+                        {
+                            "name": "V",
+                            "type": 196
+                        },
+                        {
+                            "name": "KeyType",
+                            "type": 197
+                        }
+                    ],
+                    "path": [
+                        "ink_storage",
+                        "lazy",
+                        "mapping",
+                        "Mapping"
+                    ]
+                }
+            },
+            {
+                "id": 196,
+                "type": {
+                    "def": {
+                        "composite": {
+                            "fields": [
+                                {
+                                    "name": "name",
+                                    "type": 0,
+                                    "typeName": "String"
+                                },
+                                {
+                                    "name": "description",
+                                    "type": 0,
+                                    "typeName": "String"
+                                },
+                                {
+                                    "name": "points",
+                                    "type": 27,
+                                    "typeName": "u32"
+                                }
+                            ]
+                        }
+                    },
+                    "path": [
+                        "assets",
+                        "assets",
+                        "Achievement"
+                    ]
+                }
+            },
+            {
+                "id": 197,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "L",
+                            "type": 9
+                        },
+                        {
+                            "name": "R",
+                            "type": 198
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ResolverKey"
+                    ]
+                }
+            },
+            {
+                "id": 198,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "ParentKey",
+                            "type": 11
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ManualKey"
+                    ]
+                }
+            },
+            {
+                "id": 199,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "K",
+                            "type": 4
+                        },
+                        {
+                            "name": "V",
+                            "type": 20
+                        },
+                        {
+                            "name": "KeyType",
+                            "type": 200
+                        }
+                    ],
+                    "path": [
+                        "ink_storage",
+                        "lazy",
+                        "mapping",
+                        "Mapping"
+                    ]
+                }
+            },
+            {
+                "id": 200,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "L",
+                            "type": 9
+                        },
+                        {
+                            "name": "R",
+                            "type": 201
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ResolverKey"
+                    ]
+                }
+            },
+            {
+                "id": 201,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "ParentKey",
+                            "type": 11
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ManualKey"
+                    ]
+                }
+            },
+            {
+                "id": 202,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "K",
+                            "type": 4
+                        },
+                        {
+                            "name": "V",
+                            "type": 30
+                        },
+                        {
+                            "name": "KeyType",
+                            "type": 203
+                        }
+                    ],
+                    "path": [
+                        "ink_storage",
+                        "lazy",
+                        "mapping",
+                        "Mapping"
+                    ]
+                }
+            },
+            {
+                "id": 203,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "L",
+                            "type": 9
+                        },
+                        {
+                            "name": "R",
+                            "type": 204
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ResolverKey"
+                    ]
+                }
+            },
+            {
+                "id": 204,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "ParentKey",
+                            "type": 11
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ManualKey"
+                    ]
+                }
+            },
+            {
+                "id": 205,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "K",
+                            "type": 0
+                        },
+                        {
+                            "name": "V",
+                            "type": 0
+                        },
+                        {
+                            "name": "KeyType",
+                            "type": 206
+                        }
+                    ],
+                    "path": [
+                        "ink_storage",
+                        "lazy",
+                        "mapping",
+                        "Mapping"
+                    ]
+                }
+            },
+            {
+                "id": 206,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "L",
+                            "type": 9
+                        },
+                        {
+                            "name": "R",
+                            "type": 207
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ResolverKey"
+                    ]
+                }
+            },
+            {
+                "id": 207,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "ParentKey",
+                            "type": 11
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ManualKey"
+                    ]
+                }
+            },
+            {
+                "id": 208,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "K",
+                            "type": 57
+                        },
+                        {
+                            "name": "V",
+                            "type": 209
+                        },
+                        {
+                            "name": "KeyType",
+                            "type": 210
+                        }
+                    ],
+                    "path": [
+                        "ink_storage",
+                        "lazy",
+                        "mapping",
+                        "Mapping"
+                    ]
+                }
+            },
+            {
+                "id": 209,
+                "type": {
+                    "def": {
+                        "composite": {
+                            "fields": [
+                                {
+                                    "name": "reason_hash",
+                                    "type": 28,
+                                    "typeName": "Hash"
+                                },
+                                {
+                                    "name": "flagged_at",
+                                    "type": 27,
+                                    "typeName": "BlockNumber"
+                                },
+                                {
+                                    "name": "appeal_hash",
+                                    "type": 185,
+                                    "typeName": "Option<Hash>"
+                                }
+                            ]
+                        }
+                    },
+                    "path": [
+                        "assets",
+                        "assets",
+                        "Flag"
+                    ]
+                }
+            },
+            {
+                "id": 210,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "L",
+                            "type": 9
+                        },
+                        {
+                            "name": "R",
+                            "type": 211
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ResolverKey"
+                    ]
+                }
+            },
+            {
+                "id": 211,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "ParentKey",
+                            "type": 11
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ManualKey"
+                    ]
+                }
+            },
+            {
+                "id": 212,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "K",
+                            "type": 4
+                        },
+                        {
+                            "name": "V",
+                            "type": 27
+                        },
+                        {
+                            "name": "KeyType",
+                            "type": 213
+                        }
+                    ],
+                    "path": [
+                        "ink_storage",
+                        "lazy",
+                        "mapping",
+                        "Mapping"
+                    ]
+                }
+            },
+            {
+                "id": 213,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "L",
+                            "type": 9
+                        },
+                        {
+                            "name": "R",
+                            "type": 214
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ResolverKey"
+                    ]
+                }
+            },
+            {
+                "id": 214,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "ParentKey",
+                            "type": 11
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ManualKey"
+                    ]
+                }
+            },
+            {
+                "id": 215,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "K",
+                            "type": 0
+                        },
+                        {
+                            "name": "V",
+                            "type": 11
+                        },
+                        {
+                            "name": "KeyType",
+                            "type": 216
+                        }
+                    ],
+                    "path": [
+                        "ink_storage",
+                        "lazy",
+                        "mapping",
+                        "Mapping"
+                    ]
+                }
+            },
+            {
+                "id": 216,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "L",
+                            "type": 9
+                        },
+                        {
+                            "name": "R",
+                            "type": 217
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ResolverKey"
+                    ]
+                }
+            },
+            {
+                "id": 217,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "ParentKey",
+                            "type": 11
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ManualKey"
+                    ]
+                }
+            },
+            {
+                "id": 218,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "K",
+                            "type": 57
+                        },
+                        {
+                            "name": "V",
+                            "type": 152
+                        },
+                        {
+                            "name": "KeyType",
+                            "type": 219
+                        }
+                    ],
+                    "path": [
+                        "ink_storage",
+                        "lazy",
+                        "mapping",
+                        "Mapping"
+                    ]
+                }
+            },
+            {
+                "id": 219,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "L",
+                            "type": 9
+                        },
+                        {
+                            "name": "R",
+                            "type": 220
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ResolverKey"
+                    ]
+                }
+            },
+            {
+                "id": 220,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "ParentKey",
+                            "type": 11
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ManualKey"
+                    ]
+                }
+            },
+            {
+                "id": 221,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "K",
+                            "type": 0
+                        },
+                        {
+                            "name": "V",
+                            "type": 2
+                        },
+                        {
+                            "name": "KeyType",
+                            "type": 222
+                        }
+                    ],
+                    "path": [
+                        "ink_storage",
+                        "lazy",
+                        "mapping",
+                        "Mapping"
+                    ]
+                }
+            },
+            {
+                "id": 222,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "L",
+                            "type": 9
+                        },
+                        {
+                            "name": "R",
+                            "type": 223
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ResolverKey"
+                    ]
+                }
+            },
+            {
+                "id": 223,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "ParentKey",
+                            "type": 11
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ManualKey"
+                    ]
+                }
+            },
+            {
+                "id": 224,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "K",
+                            "type": 21
+                        },
+                        {
+                            "name": "V",
+                            "type": 11
+                        },
+                        {
+                            "name": "KeyType",
+                            "type": 225
+                        }
+                    ],
+                    "path": [
+                        "ink_storage",
+                        "lazy",
+                        "mapping",
+                        "Mapping"
+                    ]
+                }
+            },
+            {
+                "id": 225,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "L",
+                            "type": 9
+                        },
+                        {
+                            "name": "R",
+                            "type": 226
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ResolverKey"
+                    ]
+                }
+            },
+            {
+                "id": 226,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "ParentKey",
+                            "type": 11
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ManualKey"
+                    ]
+                }
+            },
+            {
+                "id": 227,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "K",
+                            "type": 228
+                        },
+                        {
+                            "name": "V",
+                            "type": 11
+                        },
+                        {
+                            "name": "KeyType",
+                            "type": 229
+                        }
+                    ],
+                    "path": [
+                        "ink_storage",
+                        "lazy",
+                        "mapping",
+                        "Mapping"
+                    ]
+                }
+            },
+            {
+                "id": 228,
+                "type": {
+                    "def": {
+                        "tuple": [
+                            4,
+                            0
+                        ]
+                    }
+                }
+            },
+            {
+                "id": 229,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "L",
+                            "type": 9
+                        },
+                        {
+                            "name": "R",
+                            "type": 230
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ResolverKey"
+                    ]
+                }
+            },
+            {
+                "id": 230,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "ParentKey",
+                            "type": 11
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ManualKey"
+                    ]
+                }
+            },
+            {
+                "id": 231,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "K",
+                            "type": 4
+                        },
+                        {
+                            "name": "V",
+                            "type": 27
+                        },
+                        {
+                            "name": "KeyType",
+                            "type": 232
+                        }
+                    ],
+                    "path": [
+                        "ink_storage",
+                        "lazy",
+                        "mapping",
+                        "Mapping"
+                    ]
+                }
+            },
+            {
+                "id": 232,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "L",
+                            "type": 9
+                        },
+                        {
+                            "name": "R",
+                            "type": 233
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ResolverKey"
+                    ]
+                }
+            },
+            {
+                "id": 233,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "ParentKey",
+                            "type": 11
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ManualKey"
+                    ]
+                }
+            },
+            {
+                "id": 234,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "K",
+                            "type": 228
+                        },
+                        {
+                            "name": "V",
+                            "type": 30
+                        },
+                        {
+                            "name": "KeyType",
+                            "type": 235
+                        }
+                    ],
+                    "path": [
+                        "ink_storage",
+                        "lazy",
+                        "mapping",
+                        "Mapping"
+                    ]
+                }
+            },
+            {
+                "id": 235,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "L",
+                            "type": 9
+                        },
+                        {
+                            "name": "R",
+                            "type": 236
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ResolverKey"
+                    ]
+                }
+            },
+            {
+                "id": 236,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "ParentKey",
+                            "type": 11
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ManualKey"
+                    ]
+                }
+            },
+            {
+                "id": 237,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "K",
+                            "type": 4
+                        },
+                        {
+                            "name": "V",
+                            "type": 27
+                        },
+                        {
+                            "name": "KeyType",
+                            "type": 238
+                        }
+                    ],
+                    "path": [
+                        "ink_storage",
+                        "lazy",
+                        "mapping",
+                        "Mapping"
+                    ]
+                }
+            },
+            {
+                "id": 238,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "L",
+                            "type": 9
+                        },
+                        {
+                            "name": "R",
+                            "type": 239
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ResolverKey"
+                    ]
+                }
+            },
+            {
+                "id": 239,
+                "type": {
+                    "def": {
+                        "composite": {}
+                    },
+                    "params": [
+                        {
+                            "name": "ParentKey",
+                            "type": 11
+                        }
+                    ],
+                    "path": [
+                        "ink_storage_traits",
+                        "impls",
+                        "ManualKey"
+                    ]
+                }
+            },
+            {
+                "id": 240,
+                "type": {
+                    "def": {
+                        "composite": {
+                            "fields": [
+                                {
+                                    "name": "players",
+                                    "type": 3,
+                                    "typeName": "<Mapping<AccountId, Player> as::ink::storage::traits::\nAutoStorableHint<::ink::storage::traits::ManualKey<4073646103u32,\n()>,>>::Type"
+                                },
+                                {
+                                    "name": "assets",
+                                    "type": 14,
+                                    "typeName": "<Mapping<String, AssetList> as::ink::storage::traits::\nAutoStorableHint<::ink::storage::traits::ManualKey<4062645508u32,\n()>,>>::Type"
+                                },
+                                {
+                                    "name": "games",
+                                    "type": 2,
+                                    "typeName": "<Vec<String> as::ink::storage::traits::AutoStorableHint<::ink\n::storage::traits::ManualKey<1824059528u32, ()>,>>::Type"
+                                },
+                                {
+                                    "name": "game_owners",
+                                    "type": 17,
+                                    "typeName": "<Mapping<String, AccountId> as::ink::storage::traits::\nAutoStorableHint<::ink::storage::traits::ManualKey<24655896u32, ()\n>,>>::Type"
+                                },
+                                {
+                                    "name": "metadata",
+                                    "type": 22,
+                                    "typeName": "<Mapping<AssetKey, AssetMetadata> as::ink::storage::traits::\nAutoStorableHint<::ink::storage::traits::ManualKey<901075399u32, ()\n>,>>::Type"
+                                },
+                                {
+                                    "name": "items",
+                                    "type": 29,
+                                    "typeName": "<Mapping<TokenId, Item> as::ink::storage::traits::AutoStorableHint\n<::ink::storage::traits::ManualKey<3651370634u32, ()>,>>::Type"
+                                },
+                                {
+                                    "name": "next_token_id",
+                                    "type": 30,
+                                    "typeName": "<TokenId as::ink::storage::traits::AutoStorableHint<::ink::\nstorage::traits::ManualKey<234067237u32, ()>,>>::Type"
+                                },
+                                {
+                                    "name": "state_editors",
+                                    "type": 34,
+                                    "typeName": "<Mapping<String, Vec<String>> as::ink::storage::traits::\nAutoStorableHint<::ink::storage::traits::ManualKey<1565362722u32,\n()>,>>::Type"
+                                },
+                                {
+                                    "name": "conversions",
+                                    "type": 38,
+                                    "typeName": "<Mapping<ConversionRoute, ConversionRule> as::ink::storage::traits\n::AutoStorableHint<::ink::storage::traits::ManualKey<\n2021796348u32, ()>,>>::Type"
+                                },
+                                {
+                                    "name": "names",
+                                    "type": 43,
+                                    "typeName": "<Mapping<String, AccountId> as::ink::storage::traits::\nAutoStorableHint<::ink::storage::traits::ManualKey<4240391717u32,\n()>,>>::Type"
+                                },
+                                {
+                                    "name": "endowed",
+                                    "type": 46,
+                                    "typeName": "<Mapping<AccountId, ()> as::ink::storage::traits::AutoStorableHint\n<::ink::storage::traits::ManualKey<1873213826u32, ()>,>>::Type"
+                                },
+                                {
+                                    "name": "holders",
+                                    "type": 49,
+                                    "typeName": "<Mapping<(String, u32), AccountId> as::ink::storage::traits::\nAutoStorableHint<::ink::storage::traits::ManualKey<1792447555u32,\n()>,>>::Type"
+                                },
+                                {
+                                    "name": "holder_counts",
+                                    "type": 53,
+                                    "typeName": "<Mapping<String, u32> as::ink::storage::traits::AutoStorableHint<\n::ink::storage::traits::ManualKey<3650627995u32, ()>,>>::Type"
+                                },
+                                {
+                                    "name": "holder_index",
+                                    "type": 56,
+                                    "typeName": "<Mapping<(String, AccountId), u32> as::ink::storage::traits::\nAutoStorableHint<::ink::storage::traits::ManualKey<2878552641u32,\n()>,>>::Type"
+                                },
+                                {
+                                    "name": "bundles",
+                                    "type": 62,
+                                    "typeName": "<Mapping<BundleId, Bundle> as::ink::storage::traits::\nAutoStorableHint<::ink::storage::traits::ManualKey<862808664u32, ()\n>,>>::Type"
+                                },
+                                {
+                                    "name": "next_bundle_id",
+                                    "type": 27,
+                                    "typeName": "<BundleId as::ink::storage::traits::AutoStorableHint<::ink::\nstorage::traits::ManualKey<4047439247u32, ()>,>>::Type"
+                                },
+                                {
+                                    "name": "bundle_purchases",
+                                    "type": 67,
+                                    "typeName": "<Mapping<(BundleId, AccountId), u64> as::ink::storage::traits::\nAutoStorableHint<::ink::storage::traits::ManualKey<4060784683u32,\n()>,>>::Type"
+                                },
+                                {
+                                    "name": "sales",
+                                    "type": 71,
+                                    "typeName": "<Mapping<SaleId, Sale> as::ink::storage::traits::AutoStorableHint\n<::ink::storage::traits::ManualKey<2215912709u32, ()>,>>::Type"
+                                },
+                                {
+                                    "name": "asset_sales",
+                                    "type": 77,
+                                    "typeName": "<Mapping<AssetKey, Vec<SaleId>> as::ink::storage::traits::\nAutoStorableHint<::ink::storage::traits::ManualKey<3789570596u32,\n()>,>>::Type"
+                                },
+                                {
+                                    "name": "next_sale_id",
+                                    "type": 27,
+                                    "typeName": "<SaleId as::ink::storage::traits::AutoStorableHint<::ink::\nstorage::traits::ManualKey<1706253462u32, ()>,>>::Type"
+                                },
+                                {
+                                    "name": "sale_purchases",
+                                    "type": 80,
+                                    "typeName": "<Mapping<(SaleId, AccountId), u64> as::ink::storage::traits::\nAutoStorableHint<::ink::storage::traits::ManualKey<2264531339u32,\n()>,>>::Type"
+                                },
+                                {
+                                    "name": "coupons",
+                                    "type": 83,
+                                    "typeName": "<Mapping<Hash, Coupon> as::ink::storage::traits::AutoStorableHint\n<::ink::storage::traits::ManualKey<2557909611u32, ()>,>>::Type"
+                                },
+                                {
+                                    "name": "curves",
+                                    "type": 87,
+                                    "typeName": "<Mapping<AssetKey, PriceCurve> as::ink::storage::traits::\nAutoStorableHint<::ink::storage::traits::ManualKey<25694881u32, ()\n>,>>::Type"
+                                },
+                                {
+                                    "name": "supply",
+                                    "type": 91,
+                                    "typeName": "<Mapping<AssetKey, u64> as::ink::storage::traits::AutoStorableHint\n<::ink::storage::traits::ManualKey<2147618828u32, ()>,>>::Type"
+                                },
+                                {
+                                    "name": "curve_reserves",
+                                    "type": 94,
+                                    "typeName": "<Mapping<AssetKey, Balance> as::ink::storage::traits::\nAutoStorableHint<::ink::storage::traits::ManualKey<2150622870u32,\n()>,>>::Type"
+                                },
+                                {
+                                    "name": "buyback_rates",
+                                    "type": 97,
+                                    "typeName": "<Mapping<AssetKey, u8> as::ink::storage::traits::AutoStorableHint\n<::ink::storage::traits::ManualKey<1966645217u32, ()>,>>::Type"
+                                },
+                                {
+                                    "name": "game_reserves",
+                                    "type": 100,
+                                    "typeName": "<Mapping<String, Balance> as::ink::storage::traits::\nAutoStorableHint<::ink::storage::traits::ManualKey<629211693u32, ()\n>,>>::Type"
+                                },
+                                {
+                                    "name": "currencies",
+                                    "type": 103,
+                                    "typeName": "<Mapping<CurrencyId, Currency> as::ink::storage::traits::\nAutoStorableHint<::ink::storage::traits::ManualKey<3857973676u32,\n()>,>>::Type"
+                                },
+                                {
+                                    "name": "game_currencies",
+                                    "type": 107,
+                                    "typeName": "<Mapping<String, CurrencyId> as::ink::storage::traits::\nAutoStorableHint<::ink::storage::traits::ManualKey<1608764071u32,\n()>,>>::Type"
+                                },
+                                {
+                                    "name": "next_currency_id",
+                                    "type": 27,
+                                    "typeName": "<CurrencyId as::ink::storage::traits::AutoStorableHint<::ink::\nstorage::traits::ManualKey<216362149u32, ()>,>>::Type"
+                                },
+                                {
+                                    "name": "currency_balances",
+                                    "type": 110,
+                                    "typeName": "<Mapping<(CurrencyId, AccountId), Balance> as::ink::storage::traits\n::AutoStorableHint<::ink::storage::traits::ManualKey<\n1309647233u32, ()>,>>::Type"
+                                },
+                                {
+                                    "name": "currency_allowances",
+                                    "type": 113,
+                                    "typeName": "<Mapping<AllowanceKey, Balance> as::ink::storage::traits::\nAutoStorableHint<::ink::storage::traits::ManualKey<4248354776u32,\n()>,>>::Type"
+                                },
+                                {
+                                    "name": "price_currencies",
+                                    "type": 117,
+                                    "typeName": "<Mapping<AssetKey, PriceCurrency> as::ink::storage::traits::\nAutoStorableHint<::ink::storage::traits::ManualKey<3557899537u32,\n()>,>>::Type"
+                                },
+                                {
+                                    "name": "reward_pools",
+                                    "type": 121,
+                                    "typeName": "<Mapping<AssetKey, RewardPool> as::ink::storage::traits::\nAutoStorableHint<::ink::storage::traits::ManualKey<3354247251u32,\n()>,>>::Type"
+                                },
+                                {
+                                    "name": "stakes",
+                                    "type": 125,
+                                    "typeName": "<Mapping<StakeKey, Stake> as::ink::storage::traits::\nAutoStorableHint<::ink::storage::traits::ManualKey<1973289227u32,\n()>,>>::Type"
+                                },
+                                {
+                                    "name": "guilds",
+                                    "type": 133,
+                                    "typeName": "<Mapping<GuildId, Guild> as::ink::storage::traits::\nAutoStorableHint<::ink::storage::traits::ManualKey<3090267014u32,\n()>,>>::Type"
+                                },
+                                {
+                                    "name": "next_guild_id",
+                                    "type": 27,
+                                    "typeName": "<GuildId as::ink::storage::traits::AutoStorableHint<::ink::\nstorage::traits::ManualKey<2068852788u32, ()>,>>::Type"
+                                },
+                                {
+                                    "name": "withdrawals",
+                                    "type": 138,
+                                    "typeName": "<Mapping<(GuildId, u32), Withdrawal> as::ink::storage::traits::\nAutoStorableHint<::ink::storage::traits::ManualKey<984140880u32, ()\n>,>>::Type"
+                                },
+                                {
+                                    "name": "councils",
+                                    "type": 144,
+                                    "typeName": "<Mapping<String, GameCouncil> as::ink::storage::traits::\nAutoStorableHint<::ink::storage::traits::ManualKey<2989595188u32,\n()>,>>::Type"
+                                },
+                                {
+                                    "name": "game_proposals",
+                                    "type": 153,
+                                    "typeName": "<Mapping<u32, GameProposal> as::ink::storage::traits::\nAutoStorableHint<::ink::storage::traits::ManualKey<4285773776u32,\n()>,>>::Type"
+                                },
+                                {
+                                    "name": "next_game_proposal_id",
+                                    "type": 27,
+                                    "typeName": "<u32 as::ink::storage::traits::AutoStorableHint<::ink::storage\n::traits::ManualKey<3307519685u32, ()>,>>::Type"
+                                },
+                                {
+                                    "name": "voucher_signers",
+                                    "type": 164,
+                                    "typeName": "<Mapping<String, SignerKey> as::ink::storage::traits::\nAutoStorableHint<::ink::storage::traits::ManualKey<1419231680u32,\n()>,>>::Type"
+                                },
+                                {
+                                    "name": "redeemed_vouchers",
+                                    "type": 167,
+                                    "typeName": "<Mapping<(String, u64), ()> as::ink::storage::traits::\nAutoStorableHint<::ink::storage::traits::ManualKey<370219652u32, ()\n>,>>::Type"
+                                },
+                                {
+                                    "name": "player_nonces",
+                                    "type": 170,
+                                    "typeName": "<Mapping<AccountId, u64> as::ink::storage::traits::\nAutoStorableHint<::ink::storage::traits::ManualKey<3860218521u32,\n()>,>>::Type"
+                                },
+                                {
+                                    "name": "airdrops",
+                                    "type": 173,
+                                    "typeName": "<Mapping<AirdropId, Airdrop> as::ink::storage::traits::\nAutoStorableHint<::ink::storage::traits::ManualKey<2603334187u32,\n()>,>>::Type"
+                                },
+                                {
+                                    "name": "airdrop_claims",
+                                    "type": 177,
+                                    "typeName": "<Mapping<(AirdropId, u32), u128> as::ink::storage::traits::\nAutoStorableHint<::ink::storage::traits::ManualKey<1102784257u32,\n()>,>>::Type"
+                                },
+                                {
+                                    "name": "next_airdrop_id",
+                                    "type": 27,
+                                    "typeName": "<AirdropId as::ink::storage::traits::AutoStorableHint<::ink::\nstorage::traits::ManualKey<590461944u32, ()>,>>::Type"
+                                },
+                                {
+                                    "name": "game_operators",
+                                    "type": 180,
+                                    "typeName": "<Mapping<(String, AccountId), ()> as::ink::storage::traits::\nAutoStorableHint<::ink::storage::traits::ManualKey<2526799060u32,\n()>,>>::Type"
+                                },
+                                {
+                                    "name": "matches",
+                                    "type": 183,
+                                    "typeName": "<Mapping<MatchId, Match> as::ink::storage::traits::\nAutoStorableHint<::ink::storage::traits::ManualKey<410285906u32, ()\n>,>>::Type"
+                                },
+                                {
+                                    "name": "next_match_id",
+                                    "type": 27,
+                                    "typeName": "<MatchId as::ink::storage::traits::AutoStorableHint<::ink::\nstorage::traits::ManualKey<3834869281u32, ()>,>>::Type"
+                                },
+                                {
+                                    "name": "tournaments",
+                                    "type": 190,
+                                    "typeName": "<Mapping<TournamentId, Tournament> as::ink::storage::traits::\nAutoStorableHint<::ink::storage::traits::ManualKey<876532683u32, ()\n>,>>::Type"
+                                },
+                                {
+                                    "name": "next_tournament_id",
+                                    "type": 27,
+                                    "typeName": "<TournamentId as::ink::storage::traits::AutoStorableHint<::ink::\nstorage::traits::ManualKey<481978260u32, ()>,>>::Type"
+                                },
+                                {
+                                    "name": "achievements",
+                                    "type": 195,
+                                    "typeName": "<Mapping<AchievementKey, Achievement> as::ink::storage::traits::\nAutoStorableHint<::ink::storage::traits::ManualKey<2458695366u32,\n()>,>>::Type"
+                                },
+                                {
+                                    "name": "earned_achievements",
+                                    "type": 199,
+                                    "typeName": "<Mapping<AccountId, Vec<AchievementKey>> as::ink::storage::traits\n::AutoStorableHint<::ink::storage::traits::ManualKey<\n2143350959u32, ()>,>>::Type"
+                                },
+                                {
+                                    "name": "gamer_scores",
+                                    "type": 202,
+                                    "typeName": "<Mapping<AccountId, u64> as::ink::storage::traits::\nAutoStorableHint<::ink::storage::traits::ManualKey<732036413u32, ()\n>,>>::Type"
+                                },
+                                {
+                                    "name": "asset_games",
+                                    "type": 205,
+                                    "typeName": "<Mapping<String, String> as::ink::storage::traits::\nAutoStorableHint<::ink::storage::traits::ManualKey<2010211956u32,\n()>,>>::Type"
+                                },
+                                {
+                                    "name": "flags",
+                                    "type": 208,
+                                    "typeName": "<Mapping<(String, AccountId), Flag> as::ink::storage::traits::\nAutoStorableHint<::ink::storage::traits::ManualKey<1993676045u32,\n()>,>>::Type"
+                                },
+                                {
+                                    "name": "flag_counts",
+                                    "type": 212,
+                                    "typeName": "<Mapping<AccountId, u32> as::ink::storage::traits::\nAutoStorableHint<::ink::storage::traits::ManualKey<2660214244u32,\n()>,>>::Type"
+                                },
+                                {
+                                    "name": "refuse_flagged",
+                                    "type": 215,
+                                    "typeName": "<Mapping<String, ()> as::ink::storage::traits::AutoStorableHint<\n::ink::storage::traits::ManualKey<1441864818u32, ()>,>>::Type"
+                                },
+                                {
+                                    "name": "reputation",
+                                    "type": 218,
+                                    "typeName": "<Mapping<(String, AccountId), i32> as::ink::storage::traits::\nAutoStorableHint<::ink::storage::traits::ManualKey<2880642531u32,\n()>,>>::Type"
+                                },
+                                {
+                                    "name": "flag_trust",
+                                    "type": 221,
+                                    "typeName": "<Mapping<String, Vec<String>> as::ink::storage::traits::\nAutoStorableHint<::ink::storage::traits::ManualKey<3878335277u32,\n()>,>>::Type"
+                                },
+                                {
+                                    "name": "recoverable",
+                                    "type": 224,
+                                    "typeName": "<Mapping<AssetKey, ()> as::ink::storage::traits::AutoStorableHint\n<::ink::storage::traits::ManualKey<956503301u32, ()>,>>::Type"
+                                },
+                                {
+                                    "name": "frozen",
+                                    "type": 227,
+                                    "typeName": "<Mapping<(AccountId, String), ()> as::ink::storage::traits::\nAutoStorableHint<::ink::storage::traits::ManualKey<1107648792u32,\n()>,>>::Type"
+                                },
+                                {
+                                    "name": "open_escrows",
+                                    "type": 231,
+                                    "typeName": "<Mapping<AccountId, u32> as::ink::storage::traits::\nAutoStorableHint<::ink::storage::traits::ManualKey<1198327139u32,\n()>,>>::Type"
+                                },
+                                {
+                                    "name": "curve_holdings",
+                                    "type": 234,
+                                    "typeName": "<Mapping<(AccountId, String), u64> as::ink::storage::traits::\nAutoStorableHint<::ink::storage::traits::ManualKey<1903785398u32,\n()>,>>::Type"
+                                },
+                                {
+                                    "name": "admin",
+                                    "type": 158,
+                                    "typeName": "<Option<AccountId> as::ink::storage::traits::AutoStorableHint<::\nink::storage::traits::ManualKey<1504352097u32, ()>,>>::Type"
+                                },
+                                {
+                                    "name": "reputation_games",
+                                    "type": 2,
+                                    "typeName": "<Vec<String> as::ink::storage::traits::AutoStorableHint<::ink\n::storage::traits::ManualKey<3108593855u32, ()>,>>::Type"
+                                },
+                                {
+                                    "name": "open_positions",
+                                    "type": 237,
+                                    "typeName": "<Mapping<AccountId, u32> as::ink::storage::traits::\nAutoStorableHint<::ink::storage::traits::ManualKey<3143172366u32,\n()>,>>::Type"
+                                }
+                            ]
+                        }
+                    },
+                    "path": [
+                        "assets",
+                        "assets",
+                        "Assets"
+                    ]
+                }
+            },
+            {
+                "id": 241,
+                "type": {
+                    "def": {
+                        "variant": {
+                            "variants": [
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 11
+                                        }
+                                    ],
+                                    "index": 0,
+                                    "name": "Ok"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 242
+                                        }
+                                    ],
+                                    "index": 1,
+                                    "name": "Err"
+                                }
+                            ]
+                        }
+                    },
+                    "params": [
+                        {
+                            "name": "T",
+                            "type": 11
+                        },
+                        {
+                            "name": "E",
+                            "type": 242
+                        }
+                    ],
+                    "path": [
+                        "Result"
+                    ]
+                }
+            },
+            {
+                "id": 242,
+                "type": {
+                    "def": {
+                        "variant": {
+                            "variants": [
+                                {
+                                    "index": 1,
+                                    "name": "CouldNotReadInput"
+                                }
+                            ]
+                        }
+                    },
+                    "path": [
+                        "ink_primitives",
+                        "LangError"
+                    ]
+                }
+            },
+            {
+                "id": 243,
+                "type": {
+                    "def": {
+                        "variant": {
+                            "variants": [
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 244
+                                        }
+                                    ],
+                                    "index": 0,
+                                    "name": "Ok"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 242
+                                        }
+                                    ],
+                                    "index": 1,
+                                    "name": "Err"
+                                }
+                            ]
+                        }
+                    },
+                    "params": [
+                        {
+                            "name": "T",
+                            "type": 244
+                        },
+                        {
+                            "name": "E",
+                            "type": 242
+                        }
+                    ],
+                    "path": [
+                        "Result"
+                    ]
+                }
+            },
+            {
+                "id": 244,
+                "type": {
+                    "def": {
+                        "variant": {
+                            "variants": [
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 11
+                                        }
+                                    ],
+                                    "index": 0,
+                                    "name": "Ok"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 245
+                                        }
+                                    ],
+                                    "index": 1,
+                                    "name": "Err"
+                                }
+                            ]
+                        }
+                    },
+                    "params": [
+                        {
+                            "name": "T",
+                            "type": 11
+                        },
+                        {
+                            "name": "E",
+                            "type": 245
+                        }
+                    ],
+                    "path": [
+                        "Result"
+                    ]
+                }
+            },
+            {
+                "id": 245,
+                "type": {
+                    "def": {
+                        "variant": {
+                            "variants": [
+                                {
+                                    "index": 0,
+                                    "name": "AssetNotFound"
+                                },
+                                {
+                                    "index": 1,
+                                    "name": "GameWithoutAssets"
+                                },
+                                {
+                                    "index": 2,
+                                    "name": "InsufficientBalance"
+                                },
+                                {
+                                    "index": 3,
+                                    "name": "PlayerNotFound"
+                                },
+                                {
+                                    "index": 4,
+                                    "name": "InsufficientAssetCount"
+                                },
+                                {
+                                    "index": 5,
+                                    "name": "NotGameOwner"
+                                },
+                                {
+                                    "index": 6,
+                                    "name": "ItemNotFound"
+                                },
+                                {
+                                    "index": 7,
+                                    "name": "ConversionNotApproved"
+                                },
+                                {
+                                    "index": 8,
+                                    "name": "InvalidConversionAmount"
+                                },
+                                {
+                                    "index": 9,
+                                    "name": "ConversionCapExceeded"
+                                },
+                                {
+                                    "index": 10,
+                                    "name": "Overflow"
+                                },
+                                {
+                                    "index": 11,
+                                    "name": "PlayerAlreadyExists"
+                                },
+                                {
+                                    "index": 12,
+                                    "name": "NameTaken"
+                                },
+                                {
+                                    "index": 13,
+                                    "name": "HoldingsNotEmpty"
+                                },
+                                {
+                                    "index": 14,
+                                    "name": "BundleNotFound"
+                                },
+                                {
+                                    "index": 15,
+                                    "name": "BundleUnavailable"
+                                },
+                                {
+                                    "index": 16,
+                                    "name": "PurchaseLimitReached"
+                                },
+                                {
+                                    "index": 17,
+                                    "name": "SaleNotFound"
+                                },
+                                {
+                                    "index": 18,
+                                    "name": "InvalidDiscount"
+                                },
+                                {
+                                    "index": 19,
+                                    "name": "InvalidCoupon"
+                                },
+                                {
+                                    "index": 20,
+                                    "name": "NoPriceCurve"
+                                },
+                                {
+                                    "index": 21,
+                                    "name": "PriceCurveInUse"
+                                },
+                                {
+                                    "index": 22,
+                                    "name": "InvalidPriceCurve"
+                                },
+                                {
+                                    "index": 23,
+                                    "name": "InsufficientReserve"
+                                },
+                                {
+                                    "index": 24,
+                                    "name": "NoBuyback"
+                                },
+                                {
+                                    "index": 25,
+                                    "name": "InvalidBuybackRate"
+                                },
+                                {
+                                    "index": 26,
+                                    "name": "CurrencyNotFound"
+                                },
+                                {
+                                    "index": 27,
+                                    "name": "CurrencyAlreadyExists"
+                                },
+                                {
+                                    "index": 28,
+                                    "name": "InsufficientAllowance"
+                                },
+                                {
+                                    "index": 29,
+                                    "name": "UnsupportedCurrency"
+                                },
+                                {
+                                    "index": 30,
+                                    "name": "IncorrectPayment"
+                                },
+                                {
+                                    "index": 31,
+                                    "name": "PaymentFailed"
+                                },
+                                {
+                                    "index": 32,
+                                    "name": "StakingDisabled"
+                                },
+                                {
+                                    "index": 33,
+                                    "name": "InsufficientStake"
+                                },
+                                {
+                                    "index": 34,
+                                    "name": "GuildNotFound"
+                                },
+                                {
+                                    "index": 35,
+                                    "name": "NotGuildMember"
+                                },
+                                {
+                                    "index": 36,
+                                    "name": "NotGuildOfficer"
+                                },
+                                {
+                                    "index": 37,
+                                    "name": "AlreadyGuildMember"
+                                },
+                                {
+                                    "index": 38,
+                                    "name": "ProposalNotFound"
+                                },
+                                {
+                                    "index": 39,
+                                    "name": "AlreadyApproved"
+                                },
+                                {
+                                    "index": 40,
+                                    "name": "MultisigRequired"
+                                },
+                                {
+                                    "index": 41,
+                                    "name": "NotSigner"
+                                },
+                                {
+                                    "index": 42,
+                                    "name": "InvalidThreshold"
+                                },
+                                {
+                                    "index": 43,
+                                    "name": "ProposalExpired"
+                                },
+                                {
+                                    "index": 44,
+                                    "name": "ThresholdNotReached"
+                                },
+                                {
+                                    "index": 45,
+                                    "name": "NoVoucherSigner"
+                                },
+                                {
+                                    "index": 46,
+                                    "name": "InvalidSignature"
+                                },
+                                {
+                                    "index": 47,
+                                    "name": "VoucherExpired"
+                                },
+                                {
+                                    "index": 48,
+                                    "name": "VoucherAlreadyRedeemed"
+                                },
+                                {
+                                    "index": 49,
+                                    "name": "InvalidNonce"
+                                },
+                                {
+                                    "index": 50,
+                                    "name": "AirdropNotFound"
+                                },
+                                {
+                                    "index": 51,
+                                    "name": "AirdropExpired"
+                                },
+                                {
+                                    "index": 52,
+                                    "name": "AirdropNotExpired"
+                                },
+                                {
+                                    "index": 53,
+                                    "name": "AlreadyClaimed"
+                                },
+                                {
+                                    "index": 54,
+                                    "name": "InvalidProof"
+                                },
+                                {
+                                    "index": 55,
+                                    "name": "TransferRejected"
+                                },
+                                {
+                                    "index": 56,
+                                    "name": "MatchNotFound"
+                                },
+                                {
+                                    "index": 57,
+                                    "name": "NotMatchPlayer"
+                                },
+                                {
+                                    "index": 58,
+                                    "name": "InvalidMatchPhase"
+                                },
+                                {
+                                    "index": 59,
+                                    "name": "InvalidReveal"
+                                },
+                                {
+                                    "index": 60,
+                                    "name": "DeadlinePassed"
+                                },
+                                {
+                                    "index": 61,
+                                    "name": "DeadlineNotReached"
+                                },
+                                {
+                                    "index": 62,
+                                    "name": "TournamentNotFound"
+                                },
+                                {
+                                    "index": 63,
+                                    "name": "TournamentClosed"
+                                },
+                                {
+                                    "index": 64,
+                                    "name": "TournamentFull"
+                                },
+                                {
+                                    "index": 65,
+                                    "name": "AlreadyEntered"
+                                },
+                                {
+                                    "index": 66,
+                                    "name": "InvalidPrizeSplit"
+                                },
+                                {
+                                    "index": 67,
+                                    "name": "InvalidRanking"
+                                },
+                                {
+                                    "index": 68,
+                                    "name": "AchievementExists"
+                                },
+                                {
+                                    "index": 69,
+                                    "name": "AchievementNotFound"
+                                },
+                                {
+                                    "index": 70,
+                                    "name": "AlreadyGranted"
+                                },
+                                {
+                                    "index": 71,
+                                    "name": "AlreadyFlagged"
+                                },
+                                {
+                                    "index": 72,
+                                    "name": "NotFlagged"
+                                },
+                                {
+                                    "index": 73,
+                                    "name": "SenderFlagged"
+                                },
+                                {
+                                    "index": 74,
+                                    "name": "NotRecoverable"
+                                },
+                                {
+                                    "index": 75,
+                                    "name": "HoldingFrozen"
+                                },
+                                {
+                                    "index": 76,
+                                    "name": "AssetExists"
+                                },
+                                {
+                                    "index": 77,
+                                    "name": "CurveNotDiscountable"
+                                },
+                                {
+                                    "index": 78,
+                                    "name": "EscrowOutstanding"
+                                },
+                                {
+                                    "index": 79,
+                                    "name": "StorePricingInUse"
+                                },
+                                {
+                                    "index": 80,
+                                    "name": "NotBoughtOnCurve"
+                                },
+                                {
+                                    "index": 81,
+                                    "name": "NotAdmin"
+                                },
+                                {
+                                    "index": 82,
+                                    "name": "NotItemOwner"
+                                },
+                                {
+                                    "index": 83,
+                                    "name": "AccountInUse"
+                                },
+                                {
+                                    "index": 84,
+                                    "name": "InvalidMatchTerms"
+                                },
+                                {
+                                    "index": 85,
+                                    "name": "QuorumTooHigh"
+                                }
+                            ]
+                        }
+                    },
+                    "path": [
+                        "assets",
+                        "assets",
+                        "ContractError"
+                    ]
+                }
+            },
+            {
+                "id": 246,
+                "type": {
+                    "def": {
+                        "variant": {
+                            "variants": [
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 158
+                                        }
+                                    ],
+                                    "index": 0,
+                                    "name": "Ok"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 242
+                                        }
+                                    ],
+                                    "index": 1,
+                                    "name": "Err"
+                                }
+                            ]
+                        }
+                    },
+                    "params": [
+                        {
+                            "name": "T",
+                            "type": 158
+                        },
+                        {
+                            "name": "E",
+                            "type": 242
+                        }
+                    ],
+                    "path": [
+                        "Result"
+                    ]
+                }
+            },
+            {
+                "id": 247,
+                "type": {
+                    "def": {
+                        "variant": {
+                            "variants": [
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 248
+                                        }
+                                    ],
+                                    "index": 0,
+                                    "name": "Ok"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 242
+                                        }
+                                    ],
+                                    "index": 1,
+                                    "name": "Err"
+                                }
+                            ]
+                        }
+                    },
+                    "params": [
+                        {
+                            "name": "T",
+                            "type": 248
+                        },
+                        {
+                            "name": "E",
+                            "type": 242
+                        }
+                    ],
+                    "path": [
+                        "Result"
+                    ]
+                }
+            },
+            {
+                "id": 248,
+                "type": {
+                    "def": {
+                        "variant": {
+                            "variants": [
+                                {
+                                    "index": 0,
+                                    "name": "None"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 7
+                                        }
+                                    ],
+                                    "index": 1,
+                                    "name": "Some"
+                                }
+                            ]
+                        }
+                    },
+                    "params": [
+                        {
+                            "name": "T",
+                            "type": 7
+                        }
+                    ],
+                    "path": [
+                        "Option"
+                    ]
+                }
+            },
+            {
+                "id": 249,
+                "type": {
+                    "def": {
+                        "variant": {
+                            "variants": [
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 137
+                                        }
+                                    ],
+                                    "index": 0,
+                                    "name": "Ok"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 242
+                                        }
+                                    ],
+                                    "index": 1,
+                                    "name": "Err"
+                                }
+                            ]
+                        }
+                    },
+                    "params": [
+                        {
+                            "name": "T",
+                            "type": 137
+                        },
+                        {
+                            "name": "E",
+                            "type": 242
+                        }
+                    ],
+                    "path": [
+                        "Result"
+                    ]
+                }
+            },
+            {
+                "id": 250,
+                "type": {
+                    "def": {
+                        "variant": {
+                            "variants": [
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 27
+                                        }
+                                    ],
+                                    "index": 0,
+                                    "name": "Ok"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 242
+                                        }
+                                    ],
+                                    "index": 1,
+                                    "name": "Err"
+                                }
+                            ]
+                        }
+                    },
+                    "params": [
+                        {
+                            "name": "T",
+                            "type": 27
+                        },
+                        {
+                            "name": "E",
+                            "type": 242
+                        }
+                    ],
+                    "path": [
+                        "Result"
+                    ]
+                }
+            },
+            {
+                "id": 251,
+                "type": {
+                    "def": {
+                        "variant": {
+                            "variants": [
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 2
+                                        }
+                                    ],
+                                    "index": 0,
+                                    "name": "Ok"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 242
+                                        }
+                                    ],
+                                    "index": 1,
+                                    "name": "Err"
+                                }
+                            ]
+                        }
+                    },
+                    "params": [
+                        {
+                            "name": "T",
+                            "type": 2
+                        },
+                        {
+                            "name": "E",
+                            "type": 242
+                        }
+                    ],
+                    "path": [
+                        "Result"
+                    ]
+                }
+            },
+            {
+                "id": 252,
+                "type": {
+                    "def": {
+                        "variant": {
+                            "variants": [
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 253
+                                        }
+                                    ],
+                                    "index": 0,
+                                    "name": "Ok"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 242
+                                        }
+                                    ],
+                                    "index": 1,
+                                    "name": "Err"
+                                }
+                            ]
+                        }
+                    },
+                    "params": [
+                        {
+                            "name": "T",
+                            "type": 253
+                        },
+                        {
+                            "name": "E",
+                            "type": 242
+                        }
+                    ],
+                    "path": [
+                        "Result"
+                    ]
+                }
+            },
+            {
+                "id": 253,
+                "type": {
+                    "def": {
+                        "variant": {
+                            "variants": [
+                                {
+                                    "index": 0,
+                                    "name": "None"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 12
+                                        }
+                                    ],
+                                    "index": 1,
+                                    "name": "Some"
+                                }
+                            ]
+                        }
+                    },
+                    "params": [
+                        {
+                            "name": "T",
+                            "type": 12
+                        }
+                    ],
+                    "path": [
+                        "Option"
+                    ]
+                }
+            },
+            {
+                "id": 254,
+                "type": {
+                    "def": {
+                        "variant": {
+                            "variants": [
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 255
+                                        }
+                                    ],
+                                    "index": 0,
+                                    "name": "Ok"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 242
+                                        }
+                                    ],
+                                    "index": 1,
+                                    "name": "Err"
+                                }
+                            ]
+                        }
+                    },
+                    "params": [
+                        {
+                            "name": "T",
+                            "type": 255
+                        },
+                        {
+                            "name": "E",
+                            "type": 242
+                        }
+                    ],
+                    "path": [
+                        "Result"
+                    ]
+                }
+            },
+            {
+                "id": 255,
+                "type": {
+                    "def": {
+                        "variant": {
+                            "variants": [
+                                {
+                                    "index": 0,
+                                    "name": "None"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 23
+                                        }
+                                    ],
+                                    "index": 1,
+                                    "name": "Some"
+                                }
+                            ]
+                        }
+                    },
+                    "params": [
+                        {
+                            "name": "T",
+                            "type": 23
+                        }
+                    ],
+                    "path": [
+                        "Option"
+                    ]
+                }
+            },
+            {
+                "id": 256,
+                "type": {
+                    "def": {
+                        "variant": {
+                            "variants": [
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 257
+                                        }
+                                    ],
+                                    "index": 0,
+                                    "name": "Ok"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 242
+                                        }
+                                    ],
+                                    "index": 1,
+                                    "name": "Err"
+                                }
+                            ]
+                        }
+                    },
+                    "params": [
+                        {
+                            "name": "T",
+                            "type": 257
+                        },
+                        {
+                            "name": "E",
+                            "type": 242
+                        }
+                    ],
+                    "path": [
+                        "Result"
+                    ]
+                }
+            },
+            {
+                "id": 257,
+                "type": {
+                    "def": {
+                        "variant": {
+                            "variants": [
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 30
+                                        }
+                                    ],
+                                    "index": 0,
+                                    "name": "Ok"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 245
+                                        }
+                                    ],
+                                    "index": 1,
+                                    "name": "Err"
+                                }
+                            ]
+                        }
+                    },
+                    "params": [
+                        {
+                            "name": "T",
+                            "type": 30
+                        },
+                        {
+                            "name": "E",
+                            "type": 245
+                        }
+                    ],
+                    "path": [
+                        "Result"
+                    ]
+                }
+            },
+            {
+                "id": 258,
+                "type": {
+                    "def": {
+                        "variant": {
+                            "variants": [
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 259
+                                        }
+                                    ],
+                                    "index": 0,
+                                    "name": "Ok"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 242
+                                        }
+                                    ],
+                                    "index": 1,
+                                    "name": "Err"
+                                }
+                            ]
+                        }
+                    },
+                    "params": [
+                        {
+                            "name": "T",
+                            "type": 259
+                        },
+                        {
+                            "name": "E",
+                            "type": 242
+                        }
+                    ],
+                    "path": [
+                        "Result"
+                    ]
+                }
+            },
+            {
+                "id": 259,
+                "type": {
+                    "def": {
+                        "variant": {
+                            "variants": [
+                                {
+                                    "index": 0,
+                                    "name": "None"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 31
+                                        }
+                                    ],
+                                    "index": 1,
+                                    "name": "Some"
+                                }
+                            ]
+                        }
+                    },
+                    "params": [
+                        {
+                            "name": "T",
+                            "type": 31
+                        }
+                    ],
+                    "path": [
+                        "Option"
+                    ]
+                }
+            },
+            {
+                "id": 260,
+                "type": {
+                    "def": {
+                        "variant": {
+                            "variants": [
+                                {
+                                    "index": 0,
+                                    "name": "None"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 0
+                                        }
+                                    ],
+                                    "index": 1,
+                                    "name": "Some"
+                                }
+                            ]
+                        }
+                    },
+                    "params": [
+                        {
+                            "name": "T",
+                            "type": 0
+                        }
+                    ],
+                    "path": [
+                        "Option"
+                    ]
+                }
+            },
+            {
+                "id": 261,
+                "type": {
+                    "def": {
+                        "variant": {
+                            "variants": [
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 262
+                                        }
+                                    ],
+                                    "index": 0,
+                                    "name": "Ok"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 242
+                                        }
+                                    ],
+                                    "index": 1,
+                                    "name": "Err"
+                                }
+                            ]
+                        }
+                    },
+                    "params": [
+                        {
+                            "name": "T",
+                            "type": 262
+                        },
+                        {
+                            "name": "E",
+                            "type": 242
+                        }
+                    ],
+                    "path": [
+                        "Result"
+                    ]
+                }
+            },
+            {
+                "id": 262,
+                "type": {
+                    "def": {
+                        "variant": {
+                            "variants": [
+                                {
+                                    "index": 0,
+                                    "name": "None"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 40
+                                        }
+                                    ],
+                                    "index": 1,
+                                    "name": "Some"
+                                }
+                            ]
+                        }
+                    },
+                    "params": [
+                        {
+                            "name": "T",
+                            "type": 40
+                        }
+                    ],
+                    "path": [
+                        "Option"
+                    ]
+                }
+            },
+            {
+                "id": 263,
+                "type": {
+                    "def": {
+                        "variant": {
+                            "variants": [
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 264
+                                        }
+                                    ],
+                                    "index": 0,
+                                    "name": "Ok"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 242
+                                        }
+                                    ],
+                                    "index": 1,
+                                    "name": "Err"
+                                }
+                            ]
+                        }
+                    },
+                    "params": [
+                        {
+                            "name": "T",
+                            "type": 264
+                        },
+                        {
+                            "name": "E",
+                            "type": 242
+                        }
+                    ],
+                    "path": [
+                        "Result"
+                    ]
+                }
+            },
+            {
+                "id": 264,
+                "type": {
+                    "def": {
+                        "variant": {
+                            "variants": [
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 27
+                                        }
+                                    ],
+                                    "index": 0,
+                                    "name": "Ok"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 245
+                                        }
+                                    ],
+                                    "index": 1,
+                                    "name": "Err"
+                                }
+                            ]
+                        }
+                    },
+                    "params": [
+                        {
+                            "name": "T",
+                            "type": 27
+                        },
+                        {
+                            "name": "E",
+                            "type": 245
+                        }
+                    ],
+                    "path": [
+                        "Result"
+                    ]
+                }
+            },
+            {
+                "id": 265,
+                "type": {
+                    "def": {
+                        "variant": {
+                            "variants": [
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 266
+                                        }
+                                    ],
+                                    "index": 0,
+                                    "name": "Ok"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 242
+                                        }
+                                    ],
+                                    "index": 1,
+                                    "name": "Err"
+                                }
+                            ]
+                        }
+                    },
+                    "params": [
+                        {
+                            "name": "T",
+                            "type": 266
+                        },
+                        {
+                            "name": "E",
+                            "type": 242
+                        }
+                    ],
+                    "path": [
+                        "Result"
+                    ]
+                }
+            },
+            {
+                "id": 266,
+                "type": {
+                    "def": {
+                        "variant": {
+                            "variants": [
+                                {
+                                    "index": 0,
+                                    "name": "None"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 63
+                                        }
+                                    ],
+                                    "index": 1,
+                                    "name": "Some"
+                                }
+                            ]
+                        }
+                    },
+                    "params": [
+                        {
+                            "name": "T",
+                            "type": 63
+                        }
+                    ],
+                    "path": [
+                        "Option"
+                    ]
+                }
+            },
+            {
+                "id": 267,
+                "type": {
+                    "def": {
+                        "variant": {
+                            "variants": [
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 268
+                                        }
+                                    ],
+                                    "index": 0,
+                                    "name": "Ok"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 242
+                                        }
+                                    ],
+                                    "index": 1,
+                                    "name": "Err"
+                                }
+                            ]
+                        }
+                    },
+                    "params": [
+                        {
+                            "name": "T",
+                            "type": 268
+                        },
+                        {
+                            "name": "E",
+                            "type": 242
+                        }
+                    ],
+                    "path": [
+                        "Result"
+                    ]
+                }
+            },
+            {
+                "id": 268,
+                "type": {
+                    "def": {
+                        "sequence": {
+                            "type": 269
+                        }
+                    }
+                }
+            },
+            {
+                "id": 269,
+                "type": {
+                    "def": {
+                        "tuple": [
+                            27,
+                            72
+                        ]
+                    }
+                }
+            },
+            {
+                "id": 270,
+                "type": {
+                    "def": {
+                        "variant": {
+                            "variants": [
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 156
+                                        }
+                                    ],
+                                    "index": 0,
+                                    "name": "Ok"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 242
+                                        }
+                                    ],
+                                    "index": 1,
+                                    "name": "Err"
+                                }
+                            ]
+                        }
+                    },
+                    "params": [
+                        {
+                            "name": "T",
+                            "type": 156
+                        },
+                        {
+                            "name": "E",
+                            "type": 242
+                        }
+                    ],
+                    "path": [
+                        "Result"
+                    ]
+                }
+            },
+            {
+                "id": 271,
+                "type": {
+                    "def": {
+                        "variant": {
+                            "variants": [
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 30
+                                        }
+                                    ],
+                                    "index": 0,
+                                    "name": "Ok"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 242
+                                        }
+                                    ],
+                                    "index": 1,
+                                    "name": "Err"
+                                }
+                            ]
+                        }
+                    },
+                    "params": [
+                        {
+                            "name": "T",
+                            "type": 30
+                        },
+                        {
+                            "name": "E",
+                            "type": 242
+                        }
+                    ],
+                    "path": [
+                        "Result"
+                    ]
+                }
+            },
+            {
+                "id": 272,
+                "type": {
+                    "def": {
+                        "variant": {
+                            "variants": [
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 273
+                                        }
+                                    ],
+                                    "index": 0,
+                                    "name": "Ok"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 242
+                                        }
+                                    ],
+                                    "index": 1,
+                                    "name": "Err"
+                                }
+                            ]
+                        }
+                    },
+                    "params": [
+                        {
+                            "name": "T",
+                            "type": 273
+                        },
+                        {
+                            "name": "E",
+                            "type": 242
+                        }
+                    ],
+                    "path": [
+                        "Result"
+                    ]
+                }
+            },
+            {
+                "id": 273,
+                "type": {
+                    "def": {
+                        "variant": {
+                            "variants": [
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 1
+                                        }
+                                    ],
+                                    "index": 0,
+                                    "name": "Ok"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 245
+                                        }
+                                    ],
+                                    "index": 1,
+                                    "name": "Err"
+                                }
+                            ]
+                        }
+                    },
+                    "params": [
+                        {
+                            "name": "T",
+                            "type": 1
+                        },
+                        {
+                            "name": "E",
+                            "type": 245
+                        }
+                    ],
+                    "path": [
+                        "Result"
+                    ]
+                }
+            },
+            {
+                "id": 274,
+                "type": {
+                    "def": {
+                        "variant": {
+                            "variants": [
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 275
+                                        }
+                                    ],
+                                    "index": 0,
+                                    "name": "Ok"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 242
+                                        }
+                                    ],
+                                    "index": 1,
+                                    "name": "Err"
+                                }
+                            ]
+                        }
+                    },
+                    "params": [
+                        {
+                            "name": "T",
+                            "type": 275
+                        },
+                        {
+                            "name": "E",
+                            "type": 242
+                        }
+                    ],
+                    "path": [
+                        "Result"
+                    ]
+                }
+            },
+            {
+                "id": 275,
+                "type": {
+                    "def": {
+                        "variant": {
+                            "variants": [
+                                {
+                                    "index": 0,
+                                    "name": "None"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 6
+                                        }
+                                    ],
+                                    "index": 1,
+                                    "name": "Some"
+                                }
+                            ]
+                        }
+                    },
+                    "params": [
+                        {
+                            "name": "T",
+                            "type": 6
+                        }
+                    ],
+                    "path": [
+                        "Option"
+                    ]
+                }
+            },
+            {
+                "id": 276,
+                "type": {
+                    "def": {
+                        "variant": {
+                            "variants": [
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 1
+                                        }
+                                    ],
+                                    "index": 0,
+                                    "name": "Ok"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 242
+                                        }
+                                    ],
+                                    "index": 1,
+                                    "name": "Err"
+                                }
+                            ]
+                        }
+                    },
+                    "params": [
+                        {
+                            "name": "T",
+                            "type": 1
+                        },
+                        {
+                            "name": "E",
+                            "type": 242
+                        }
+                    ],
+                    "path": [
+                        "Result"
+                    ]
+                }
+            },
+            {
+                "id": 277,
+                "type": {
+                    "def": {
+                        "variant": {
+                            "variants": [
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 64
+                                        }
+                                    ],
+                                    "index": 0,
+                                    "name": "Ok"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 242
+                                        }
+                                    ],
+                                    "index": 1,
+                                    "name": "Err"
+                                }
+                            ]
+                        }
+                    },
+                    "params": [
+                        {
+                            "name": "T",
+                            "type": 64
+                        },
+                        {
+                            "name": "E",
+                            "type": 242
+                        }
+                    ],
+                    "path": [
+                        "Result"
+                    ]
+                }
+            },
+            {
+                "id": 278,
+                "type": {
+                    "def": {
+                        "variant": {
+                            "variants": [
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 279
+                                        }
+                                    ],
+                                    "index": 0,
+                                    "name": "Ok"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 242
+                                        }
+                                    ],
+                                    "index": 1,
+                                    "name": "Err"
+                                }
+                            ]
+                        }
+                    },
+                    "params": [
+                        {
+                            "name": "T",
+                            "type": 279
+                        },
+                        {
+                            "name": "E",
+                            "type": 242
+                        }
+                    ],
+                    "path": [
+                        "Result"
+                    ]
+                }
+            },
+            {
+                "id": 279,
+                "type": {
+                    "def": {
+                        "variant": {
+                            "variants": [
+                                {
+                                    "index": 0,
+                                    "name": "None"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 104
+                                        }
+                                    ],
+                                    "index": 1,
+                                    "name": "Some"
+                                }
+                            ]
+                        }
+                    },
+                    "params": [
+                        {
+                            "name": "T",
+                            "type": 104
+                        }
+                    ],
+                    "path": [
+                        "Option"
+                    ]
+                }
+            },
+            {
+                "id": 280,
+                "type": {
+                    "def": {
+                        "variant": {
+                            "variants": [
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 260
+                                        }
+                                    ],
+                                    "index": 0,
+                                    "name": "Ok"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 242
+                                        }
+                                    ],
+                                    "index": 1,
+                                    "name": "Err"
+                                }
+                            ]
+                        }
+                    },
+                    "params": [
+                        {
+                            "name": "T",
+                            "type": 260
+                        },
+                        {
+                            "name": "E",
+                            "type": 242
+                        }
+                    ],
+                    "path": [
+                        "Result"
+                    ]
+                }
+            },
+            {
+                "id": 281,
+                "type": {
+                    "def": {
+                        "variant": {
+                            "variants": [
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 6
+                                        }
+                                    ],
+                                    "index": 0,
+                                    "name": "Ok"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 242
+                                        }
+                                    ],
+                                    "index": 1,
+                                    "name": "Err"
+                                }
+                            ]
+                        }
+                    },
+                    "params": [
+                        {
+                            "name": "T",
+                            "type": 6
+                        },
+                        {
+                            "name": "E",
+                            "type": 242
+                        }
+                    ],
+                    "path": [
+                        "Result"
+                    ]
+                }
+            },
+            {
+                "id": 282,
+                "type": {
+                    "def": {
+                        "sequence": {
+                            "type": 6
+                        }
+                    }
+                }
+            },
+            {
+                "id": 283,
+                "type": {
+                    "def": {
+                        "variant": {
+                            "variants": [
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 118
+                                        }
+                                    ],
+                                    "index": 0,
+                                    "name": "Ok"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 242
+                                        }
+                                    ],
+                                    "index": 1,
+                                    "name": "Err"
+                                }
+                            ]
+                        }
+                    },
+                    "params": [
+                        {
+                            "name": "T",
+                            "type": 118
+                        },
+                        {
+                            "name": "E",
+                            "type": 242
+                        }
+                    ],
+                    "path": [
+                        "Result"
+                    ]
+                }
+            },
+            {
+                "id": 284,
+                "type": {
+                    "def": {
+                        "variant": {
+                            "variants": [
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 285
+                                        }
+                                    ],
+                                    "index": 0,
+                                    "name": "Ok"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 242
+                                        }
+                                    ],
+                                    "index": 1,
+                                    "name": "Err"
+                                }
+                            ]
+                        }
+                    },
+                    "params": [
+                        {
+                            "name": "T",
+                            "type": 285
+                        },
+                        {
+                            "name": "E",
+                            "type": 242
+                        }
+                    ],
+                    "path": [
+                        "Result"
+                    ]
+                }
+            },
+            {
+                "id": 285,
+                "type": {
+                    "def": {
+                        "variant": {
+                            "variants": [
+                                {
+                                    "index": 0,
+                                    "name": "None"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 122
+                                        }
+                                    ],
+                                    "index": 1,
+                                    "name": "Some"
+                                }
+                            ]
+                        }
+                    },
+                    "params": [
+                        {
+                            "name": "T",
+                            "type": 122
+                        }
+                    ],
+                    "path": [
+                        "Option"
+                    ]
+                }
+            },
+            {
+                "id": 286,
+                "type": {
+                    "def": {
+                        "variant": {
+                            "variants": [
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 287
+                                        }
+                                    ],
+                                    "index": 0,
+                                    "name": "Ok"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 242
+                                        }
+                                    ],
+                                    "index": 1,
+                                    "name": "Err"
+                                }
+                            ]
+                        }
+                    },
+                    "params": [
+                        {
+                            "name": "T",
+                            "type": 287
+                        },
+                        {
+                            "name": "E",
+                            "type": 242
+                        }
+                    ],
+                    "path": [
+                        "Result"
+                    ]
+                }
+            },
+            {
+                "id": 287,
+                "type": {
+                    "def": {
+                        "variant": {
+                            "variants": [
+                                {
+                                    "index": 0,
+                                    "name": "None"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 127
+                                        }
+                                    ],
+                                    "index": 1,
+                                    "name": "Some"
+                                }
+                            ]
+                        }
+                    },
+                    "params": [
+                        {
+                            "name": "T",
+                            "type": 127
+                        }
+                    ],
+                    "path": [
+                        "Option"
+                    ]
+                }
+            },
+            {
+                "id": 288,
+                "type": {
+                    "def": {
+                        "variant": {
+                            "variants": [
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 289
+                                        }
+                                    ],
+                                    "index": 0,
+                                    "name": "Ok"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 242
+                                        }
+                                    ],
+                                    "index": 1,
+                                    "name": "Err"
+                                }
+                            ]
+                        }
+                    },
+                    "params": [
+                        {
+                            "name": "T",
+                            "type": 289
+                        },
+                        {
+                            "name": "E",
+                            "type": 242
+                        }
+                    ],
+                    "path": [
+                        "Result"
+                    ]
+                }
+            },
+            {
+                "id": 289,
+                "type": {
+                    "def": {
+                        "variant": {
+                            "variants": [
+                                {
+                                    "index": 0,
+                                    "name": "None"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 134
+                                        }
+                                    ],
+                                    "index": 1,
+                                    "name": "Some"
+                                }
+                            ]
+                        }
+                    },
+                    "params": [
+                        {
+                            "name": "T",
+                            "type": 134
                         }
                     ],
-                    "default": false,
-                    "docs": [],
-                    "label": "exchange_asset",
-                    "mutates": true,
-                    "payable": false,
-                    "returnType": {
-                        "displayName": [
-                            "ink",
-                            "MessageResult"
-                        ],
-                        "type": 26
-                    },
-                    "selector": "0xe9af9c8a"
+                    "path": [
+                        "Option"
+                    ]
                 }
-            ]
-        },
-        "storage": {
-            "root": {
-                "layout": {
-                    "struct": {
-                        "fields": [
-                            {
-                                "layout": {
-                                    "root": {
-                                        "layout": {
-                                            "struct": {
-                                                "fields": [
-                                                    {
-                                                        "layout": {
-                                                            "leaf": {
-                                                                "key": "0x17e8cef2",
-                                                                "ty": 0
-                                                            }
-                                                        },
-                                                        "name": "name"
-                                                    },
-                                                    {
-                                                        "layout": {
-                                                            "leaf": {
-                                                                "key": "0x17e8cef2",
-                                                                "ty": 1
-                                                            }
-                                                        },
-                                                        "name": "balance"
-                                                    },
-                                                    {
-                                                        "layout": {
-                                                            "leaf": {
-                                                                "key": "0x17e8cef2",
-                                                                "ty": 2
-                                                            }
-                                                        },
-                                                        "name": "assets"
-                                                    }
-                                                ],
-                                                "name": "Player"
-                                            }
-                                        },
-                                        "root_key": "0x17e8cef2",
-                                        "ty": 3
-                                    }
-                                },
-                                "name": "players"
-                            },
-                            {
-                                "layout": {
-                                    "root": {
-                                        "layout": {
-                                            "leaf": {
-                                                "key": "0x040d27f2",
-                                                "ty": 12
-                                            }
-                                        },
-                                        "root_key": "0x040d27f2",
-                                        "ty": 14
-                                    }
-                                },
-                                "name": "assets"
-                            },
-                            {
-                                "layout": {
-                                    "leaf": {
-                                        "key": "0x00000000",
-                                        "ty": 2
-                                    }
+            },
+            {
+                "id": 290,
+                "type": {
+                    "def": {
+                        "variant": {
+                            "variants": [
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 291
+                                        }
+                                    ],
+                                    "index": 0,
+                                    "name": "Ok"
                                 },
-                                "name": "games"
-                            }
-                        ],
-                        "name": "Assets"
-                    }
-                },
-                "root_key": "0x00000000",
-                "ty": 17
-            }
-        },
-        "types": [
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 242
+                                        }
+                                    ],
+                                    "index": 1,
+                                    "name": "Err"
+                                }
+                            ]
+                        }
+                    },
+                    "params": [
+                        {
+                            "name": "T",
+                            "type": 291
+                        },
+                        {
+                            "name": "E",
+                            "type": 242
+                        }
+                    ],
+                    "path": [
+                        "Result"
+                    ]
+                }
+            },
             {
-                "id": 0,
+                "id": 291,
                 "type": {
                     "def": {
-                        "primitive": "str"
-                    }
+                        "variant": {
+                            "variants": [
+                                {
+                                    "index": 0,
+                                    "name": "None"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 140
+                                        }
+                                    ],
+                                    "index": 1,
+                                    "name": "Some"
+                                }
+                            ]
+                        }
+                    },
+                    "params": [
+                        {
+                            "name": "T",
+                            "type": 140
+                        }
+                    ],
+                    "path": [
+                        "Option"
+                    ]
                 }
             },
             {
-                "id": 1,
+                "id": 292,
                 "type": {
                     "def": {
-                        "primitive": "u128"
-                    }
+                        "variant": {
+                            "variants": [
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 293
+                                        }
+                                    ],
+                                    "index": 0,
+                                    "name": "Ok"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 242
+                                        }
+                                    ],
+                                    "index": 1,
+                                    "name": "Err"
+                                }
+                            ]
+                        }
+                    },
+                    "params": [
+                        {
+                            "name": "T",
+                            "type": 293
+                        },
+                        {
+                            "name": "E",
+                            "type": 242
+                        }
+                    ],
+                    "path": [
+                        "Result"
+                    ]
                 }
             },
             {
-                "id": 2,
+                "id": 293,
                 "type": {
                     "def": {
-                        "sequence": {
-                            "type": 0
+                        "variant": {
+                            "variants": [
+                                {
+                                    "index": 0,
+                                    "name": "None"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 145
+                                        }
+                                    ],
+                                    "index": 1,
+                                    "name": "Some"
+                                }
+                            ]
                         }
-                    }
+                    },
+                    "params": [
+                        {
+                            "name": "T",
+                            "type": 145
+                        }
+                    ],
+                    "path": [
+                        "Option"
+                    ]
                 }
             },
             {
-                "id": 3,
+                "id": 294,
                 "type": {
                     "def": {
-                        "composite": {}
+                        "variant": {
+                            "variants": [
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 295
+                                        }
+                                    ],
+                                    "index": 0,
+                                    "name": "Ok"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 242
+                                        }
+                                    ],
+                                    "index": 1,
+                                    "name": "Err"
+                                }
+                            ]
+                        }
                     },
                     "params": [
                         {
-                            "name": "K",
-                            "type": 4
-                        },
-                        {
-                            "name": "V",
-                            "type": 7
+                            "name": "T",
+                            "type": 295
                         },
                         {
-                            "name": "KeyType",
-                            "type": 8
+                            "name": "E",
+                            "type": 242
                         }
                     ],
                     "path": [
-                        "ink_storage",
-                        "lazy",
-                        "mapping",
-                        "Mapping"
+                        "Result"
                     ]
                 }
             },
             {
-                "id": 4,
+                "id": 295,
                 "type": {
                     "def": {
-                        "composite": {
-                            "fields": [
+                        "variant": {
+                            "variants": [
                                 {
-                                    "type": 5,
-                                    "typeName": "[u8; 32]"
+                                    "index": 0,
+                                    "name": "None"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 154
+                                        }
+                                    ],
+                                    "index": 1,
+                                    "name": "Some"
                                 }
                             ]
                         }
                     },
+                    "params": [
+                        {
+                            "name": "T",
+                            "type": 154
+                        }
+                    ],
                     "path": [
-                        "ink_primitives",
-                        "types",
-                        "AccountId"
+                        "Option"
                     ]
                 }
             },
             {
-                "id": 5,
-                "type": {
-                    "def": {
-                        "array": {
-                            "len": 32,
-                            "type": 6
-                        }
-                    }
-                }
-            },
-            {
-                "id": 6,
-                "type": {
-                    "def": {
-                        "primitive": "u8"
-                    }
-                }
-            },
-            {
-                "id": 7,
+                "id": 296,
                 "type": {
                     "def": {
-                        "composite": {
-                            "fields": [
-                                {
-                                    "name": "name",
-                                    "type": 0,
-                                    "typeName": "String"
-                                },
+                        "variant": {
+                            "variants": [
                                 {
-                                    "name": "balance",
-                                    "type": 1,
-                                    "typeName": "Balance"
+                                    "fields": [
+                                        {
+                                            "type": 159
+                                        }
+                                    ],
+                                    "index": 0,
+                                    "name": "Ok"
                                 },
                                 {
-                                    "name": "assets",
-                                    "type": 2,
-                                    "typeName": "Vec<String>"
+                                    "fields": [
+                                        {
+                                            "type": 242
+                                        }
+                                    ],
+                                    "index": 1,
+                                    "name": "Err"
                                 }
                             ]
                         }
                     },
+                    "params": [
+                        {
+                            "name": "T",
+                            "type": 159
+                        },
+                        {
+                            "name": "E",
+                            "type": 242
+                        }
+                    ],
                     "path": [
-                        "assets",
-                        "assets",
-                        "Player"
+                        "Result"
                     ]
                 }
             },
             {
-                "id": 8,
+                "id": 297,
                 "type": {
                     "def": {
-                        "composite": {}
+                        "variant": {
+                            "variants": [
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 37
+                                        }
+                                    ],
+                                    "index": 0,
+                                    "name": "Ok"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 242
+                                        }
+                                    ],
+                                    "index": 1,
+                                    "name": "Err"
+                                }
+                            ]
+                        }
                     },
                     "params": [
                         {
-                            "name": "L",
-                            "type": 9
+                            "name": "T",
+                            "type": 37
                         },
                         {
-                            "name": "R",
-                            "type": 10
+                            "name": "E",
+                            "type": 242
                         }
                     ],
                     "path": [
-                        "ink_storage_traits",
-                        "impls",
-                        "ResolverKey"
+                        "Result"
                     ]
                 }
             },
             {
-                "id": 9,
+                "id": 298,
                 "type": {
                     "def": {
-                        "composite": {}
+                        "composite": {
+                            "fields": [
+                                {
+                                    "name": "game",
+                                    "type": 0,
+                                    "typeName": "String"
+                                },
+                                {
+                                    "name": "player",
+                                    "type": 4,
+                                    "typeName": "AccountId"
+                                },
+                                {
+                                    "name": "asset",
+                                    "type": 0,
+                                    "typeName": "String"
+                                },
+                                {
+                                    "name": "count",
+                                    "type": 30,
+                                    "typeName": "u64"
+                                },
+                                {
+                                    "name": "nonce",
+                                    "type": 30,
+                                    "typeName": "u64"
+                                },
+                                {
+                                    "name": "expires_at",
+                                    "type": 27,
+                                    "typeName": "BlockNumber"
+                                }
+                            ]
+                        }
                     },
                     "path": [
-                        "ink_storage_traits",
-                        "impls",
-                        "AutoKey"
+                        "assets",
+                        "assets",
+                        "Voucher"
                     ]
                 }
             },
             {
-                "id": 10,
+                "id": 299,
                 "type": {
                     "def": {
-                        "composite": {}
-                    },
-                    "params": [
-                        {
-                            "name": "ParentKey",
-                            "type": 11
+                        "variant": {
+                            "variants": [
+                                {
+                                    "fields": [
+                                        {
+                                            "name": "receiver",
+                                            "type": 4,
+                                            "typeName": "AccountId"
+                                        },
+                                        {
+                                            "name": "asset",
+                                            "type": 0,
+                                            "typeName": "String"
+                                        },
+                                        {
+                                            "name": "amount",
+                                            "type": 30,
+                                            "typeName": "u64"
+                                        }
+                                    ],
+                                    "index": 0,
+                                    "name": "GiftAsset"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "name": "from_game",
+                                            "type": 0,
+                                            "typeName": "String"
+                                        },
+                                        {
+                                            "name": "asset_give",
+                                            "type": 0,
+                                            "typeName": "String"
+                                        },
+                                        {
+                                            "name": "units_give",
+                                            "type": 30,
+                                            "typeName": "u64"
+                                        },
+                                        {
+                                            "name": "to_game",
+                                            "type": 0,
+                                            "typeName": "String"
+                                        },
+                                        {
+                                            "name": "asset_take",
+                                            "type": 0,
+                                            "typeName": "String"
+                                        }
+                                    ],
+                                    "index": 1,
+                                    "name": "ExchangeAsset"
+                                }
+                            ]
                         }
-                    ],
+                    },
                     "path": [
-                        "ink_storage_traits",
-                        "impls",
-                        "ManualKey"
+                        "assets",
+                        "assets",
+                        "SignedCall"
                     ]
                 }
             },
             {
-                "id": 11,
-                "type": {
-                    "def": {
-                        "tuple": []
-                    }
-                }
-            },
-            {
-                "id": 12,
+                "id": 300,
                 "type": {
                     "def": {
-                        "sequence": {
-                            "type": 13
+                        "variant": {
+                            "variants": [
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 301
+                                        }
+                                    ],
+                                    "index": 0,
+                                    "name": "Ok"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 242
+                                        }
+                                    ],
+                                    "index": 1,
+                                    "name": "Err"
+                                }
+                            ]
                         }
-                    }
-                }
-            },
-            {
-                "id": 13,
-                "type": {
-                    "def": {
-                        "tuple": [
-                            0,
-                            1
-                        ]
-                    }
-                }
-            },
-            {
-                "id": 14,
-                "type": {
-                    "def": {
-                        "composite": {}
                     },
-                    "params": [
-                        {
-                            "name": "K",
-                            "type": 0
-                        },
+                    "params": [
                         {
-                            "name": "V",
-                            "type": 12
+                            "name": "T",
+                            "type": 301
                         },
                         {
-                            "name": "KeyType",
-                            "type": 15
+                            "name": "E",
+                            "type": 242
                         }
                     ],
                     "path": [
-                        "ink_storage",
-                        "lazy",
-                        "mapping",
-                        "Mapping"
+                        "Result"
                     ]
                 }
             },
             {
-                "id": 15,
+                "id": 301,
                 "type": {
                     "def": {
-                        "composite": {}
+                        "variant": {
+                            "variants": [
+                                {
+                                    "index": 0,
+                                    "name": "None"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 174
+                                        }
+                                    ],
+                                    "index": 1,
+                                    "name": "Some"
+                                }
+                            ]
+                        }
                     },
                     "params": [
                         {
-                            "name": "L",
-                            "type": 9
-                        },
-                        {
-                            "name": "R",
-                            "type": 16
+                            "name": "T",
+                            "type": 174
                         }
                     ],
                     "path": [
-                        "ink_storage_traits",
-                        "impls",
-                        "ResolverKey"
+                        "Option"
                     ]
                 }
             },
             {
-                "id": 16,
+                "id": 302,
                 "type": {
                     "def": {
-                        "composite": {}
+                        "sequence": {
+                            "type": 28
+                        }
+                    }
+                }
+            },
+            {
+                "id": 303,
+                "type": {
+                    "def": {
+                        "variant": {
+                            "variants": [
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 304
+                                        }
+                                    ],
+                                    "index": 0,
+                                    "name": "Ok"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 242
+                                        }
+                                    ],
+                                    "index": 1,
+                                    "name": "Err"
+                                }
+                            ]
+                        }
                     },
                     "params": [
                         {
-                            "name": "ParentKey",
-                            "type": 11
+                            "name": "T",
+                            "type": 304
+                        },
+                        {
+                            "name": "E",
+                            "type": 242
                         }
                     ],
                     "path": [
-                        "ink_storage_traits",
-                        "impls",
-                        "ManualKey"
+                        "Result"
                     ]
                 }
             },
             {
-                "id": 17,
+                "id": 304,
                 "type": {
                     "def": {
-                        "composite": {
-                            "fields": [
-                                {
-                                    "name": "players",
-                                    "type": 3,
-                                    "typeName": "<Mapping<AccountId, Player> as::ink::storage::traits::\nAutoStorableHint<::ink::storage::traits::ManualKey<4073646103u32,\n()>,>>::Type"
-                                },
+                        "variant": {
+                            "variants": [
                                 {
-                                    "name": "assets",
-                                    "type": 14,
-                                    "typeName": "<Mapping<String, Vec<(String, Balance)>> as::ink::storage::traits\n::AutoStorableHint<::ink::storage::traits::ManualKey<\n4062645508u32, ()>,>>::Type"
+                                    "index": 0,
+                                    "name": "None"
                                 },
                                 {
-                                    "name": "games",
-                                    "type": 2,
-                                    "typeName": "<Vec<String> as::ink::storage::traits::AutoStorableHint<::ink\n::storage::traits::ManualKey<1824059528u32, ()>,>>::Type"
+                                    "fields": [
+                                        {
+                                            "type": 184
+                                        }
+                                    ],
+                                    "index": 1,
+                                    "name": "Some"
                                 }
                             ]
                         }
                     },
+                    "params": [
+                        {
+                            "name": "T",
+                            "type": 184
+                        }
+                    ],
                     "path": [
-                        "assets",
-                        "assets",
-                        "Assets"
+                        "Option"
                     ]
                 }
             },
             {
-                "id": 18,
+                "id": 305,
                 "type": {
                     "def": {
                         "variant": {
@@ -862,7 +21783,7 @@ export function metadata() {
                                 {
                                     "fields": [
                                         {
-                                            "type": 11
+                                            "type": 306
                                         }
                                     ],
                                     "index": 0,
@@ -871,7 +21792,7 @@ export function metadata() {
                                 {
                                     "fields": [
                                         {
-                                            "type": 19
+                                            "type": 242
                                         }
                                     ],
                                     "index": 1,
@@ -883,11 +21804,11 @@ export function metadata() {
                     "params": [
                         {
                             "name": "T",
-                            "type": 11
+                            "type": 306
                         },
                         {
                             "name": "E",
-                            "type": 19
+                            "type": 242
                         }
                     ],
                     "path": [
@@ -896,26 +21817,40 @@ export function metadata() {
                 }
             },
             {
-                "id": 19,
+                "id": 306,
                 "type": {
                     "def": {
                         "variant": {
                             "variants": [
                                 {
+                                    "index": 0,
+                                    "name": "None"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 191
+                                        }
+                                    ],
                                     "index": 1,
-                                    "name": "CouldNotReadInput"
+                                    "name": "Some"
                                 }
                             ]
                         }
                     },
+                    "params": [
+                        {
+                            "name": "T",
+                            "type": 191
+                        }
+                    ],
                     "path": [
-                        "ink_primitives",
-                        "LangError"
+                        "Option"
                     ]
                 }
             },
             {
-                "id": 20,
+                "id": 307,
                 "type": {
                     "def": {
                         "variant": {
@@ -923,7 +21858,7 @@ export function metadata() {
                                 {
                                     "fields": [
                                         {
-                                            "type": 21
+                                            "type": 308
                                         }
                                     ],
                                     "index": 0,
@@ -932,7 +21867,7 @@ export function metadata() {
                                 {
                                     "fields": [
                                         {
-                                            "type": 19
+                                            "type": 242
                                         }
                                     ],
                                     "index": 1,
@@ -944,11 +21879,11 @@ export function metadata() {
                     "params": [
                         {
                             "name": "T",
-                            "type": 21
+                            "type": 308
                         },
                         {
                             "name": "E",
-                            "type": 19
+                            "type": 242
                         }
                     ],
                     "path": [
@@ -957,7 +21892,7 @@ export function metadata() {
                 }
             },
             {
-                "id": 21,
+                "id": 308,
                 "type": {
                     "def": {
                         "variant": {
@@ -969,7 +21904,7 @@ export function metadata() {
                                 {
                                     "fields": [
                                         {
-                                            "type": 7
+                                            "type": 196
                                         }
                                     ],
                                     "index": 1,
@@ -981,7 +21916,7 @@ export function metadata() {
                     "params": [
                         {
                             "name": "T",
-                            "type": 7
+                            "type": 196
                         }
                     ],
                     "path": [
@@ -990,7 +21925,7 @@ export function metadata() {
                 }
             },
             {
-                "id": 22,
+                "id": 309,
                 "type": {
                     "def": {
                         "variant": {
@@ -998,7 +21933,7 @@ export function metadata() {
                                 {
                                     "fields": [
                                         {
-                                            "type": 2
+                                            "type": 20
                                         }
                                     ],
                                     "index": 0,
@@ -1007,7 +21942,7 @@ export function metadata() {
                                 {
                                     "fields": [
                                         {
-                                            "type": 19
+                                            "type": 242
                                         }
                                     ],
                                     "index": 1,
@@ -1019,11 +21954,11 @@ export function metadata() {
                     "params": [
                         {
                             "name": "T",
-                            "type": 2
+                            "type": 20
                         },
                         {
                             "name": "E",
-                            "type": 19
+                            "type": 242
                         }
                     ],
                     "path": [
@@ -1032,7 +21967,7 @@ export function metadata() {
                 }
             },
             {
-                "id": 23,
+                "id": 310,
                 "type": {
                     "def": {
                         "variant": {
@@ -1040,7 +21975,7 @@ export function metadata() {
                                 {
                                     "fields": [
                                         {
-                                            "type": 24
+                                            "type": 311
                                         }
                                     ],
                                     "index": 0,
@@ -1049,7 +21984,7 @@ export function metadata() {
                                 {
                                     "fields": [
                                         {
-                                            "type": 19
+                                            "type": 242
                                         }
                                     ],
                                     "index": 1,
@@ -1061,11 +21996,11 @@ export function metadata() {
                     "params": [
                         {
                             "name": "T",
-                            "type": 24
+                            "type": 311
                         },
                         {
                             "name": "E",
-                            "type": 19
+                            "type": 242
                         }
                     ],
                     "path": [
@@ -1074,7 +22009,7 @@ export function metadata() {
                 }
             },
             {
-                "id": 24,
+                "id": 311,
                 "type": {
                     "def": {
                         "variant": {
@@ -1086,7 +22021,7 @@ export function metadata() {
                                 {
                                     "fields": [
                                         {
-                                            "type": 12
+                                            "type": 209
                                         }
                                     ],
                                     "index": 1,
@@ -1098,7 +22033,7 @@ export function metadata() {
                     "params": [
                         {
                             "name": "T",
-                            "type": 12
+                            "type": 209
                         }
                     ],
                     "path": [
@@ -1107,15 +22042,7 @@ export function metadata() {
                 }
             },
             {
-                "id": 25,
-                "type": {
-                    "def": {
-                        "primitive": "u64"
-                    }
-                }
-            },
-            {
-                "id": 26,
+                "id": 312,
                 "type": {
                     "def": {
                         "variant": {
@@ -1123,7 +22050,7 @@ export function metadata() {
                                 {
                                     "fields": [
                                         {
-                                            "type": 27
+                                            "type": 152
                                         }
                                     ],
                                     "index": 0,
@@ -1132,7 +22059,7 @@ export function metadata() {
                                 {
                                     "fields": [
                                         {
-                                            "type": 19
+                                            "type": 242
                                         }
                                     ],
                                     "index": 1,
@@ -1144,11 +22071,11 @@ export function metadata() {
                     "params": [
                         {
                             "name": "T",
-                            "type": 27
+                            "type": 152
                         },
                         {
                             "name": "E",
-                            "type": 19
+                            "type": 242
                         }
                     ],
                     "path": [
@@ -1157,7 +22084,7 @@ export function metadata() {
                 }
             },
             {
-                "id": 27,
+                "id": 313,
                 "type": {
                     "def": {
                         "variant": {
@@ -1165,7 +22092,7 @@ export function metadata() {
                                 {
                                     "fields": [
                                         {
-                                            "type": 11
+                                            "type": 314
                                         }
                                     ],
                                     "index": 0,
@@ -1174,7 +22101,7 @@ export function metadata() {
                                 {
                                     "fields": [
                                         {
-                                            "type": 28
+                                            "type": 242
                                         }
                                     ],
                                     "index": 1,
@@ -1186,11 +22113,11 @@ export function metadata() {
                     "params": [
                         {
                             "name": "T",
-                            "type": 11
+                            "type": 314
                         },
                         {
                             "name": "E",
-                            "type": 28
+                            "type": 242
                         }
                     ],
                     "path": [
@@ -1199,79 +22126,90 @@ export function metadata() {
                 }
             },
             {
-                "id": 28,
+                "id": 314,
+                "type": {
+                    "def": {
+                        "primitive": "i64"
+                    }
+                }
+            },
+            {
+                "id": 315,
                 "type": {
                     "def": {
                         "variant": {
                             "variants": [
                                 {
+                                    "fields": [
+                                        {
+                                            "type": 60
+                                        }
+                                    ],
                                     "index": 0,
-                                    "name": "AssetNotFound"
+                                    "name": "Ok"
                                 },
                                 {
+                                    "fields": [
+                                        {
+                                            "type": 242
+                                        }
+                                    ],
                                     "index": 1,
-                                    "name": "GameWithoutAssets"
-                                },
-                                {
-                                    "index": 2,
-                                    "name": "InsufficientBalance"
-                                },
-                                {
-                                    "index": 3,
-                                    "name": "PlayerNotFound"
-                                },
-                                {
-                                    "index": 4,
-                                    "name": "InsufficientAssetCount"
+                                    "name": "Err"
                                 }
                             ]
                         }
                     },
+                    "params": [
+                        {
+                            "name": "T",
+                            "type": 60
+                        },
+                        {
+                            "name": "E",
+                            "type": 242
+                        }
+                    ],
                     "path": [
-                        "assets",
-                        "assets",
-                        "ContractError"
+                        "Result"
                     ]
                 }
             },
             {
-                "id": 29,
-                "type": {
-                    "def": {
-                        "primitive": "i64"
-                    }
-                }
-            },
-            {
-                "id": 30,
+                "id": 316,
                 "type": {
                     "def": {
-                        "composite": {
-                            "fields": [
+                        "variant": {
+                            "variants": [
                                 {
-                                    "type": 5,
-                                    "typeName": "[u8; 32]"
+                                    "index": 0,
+                                    "name": "None"
+                                },
+                                {
+                                    "fields": [
+                                        {
+                                            "type": 132
+                                        }
+                                    ],
+                                    "index": 1,
+                                    "name": "Some"
                                 }
                             ]
                         }
                     },
+                    "params": [
+                        {
+                            "name": "T",
+                            "type": 132
+                        }
+                    ],
                     "path": [
-                        "ink_primitives",
-                        "types",
-                        "Hash"
+                        "Option"
                     ]
                 }
             },
             {
-                "id": 31,
-                "type": {
-                    "def": {
-                        "primitive": "u32"
-                    }
-                }
-            },
-            {
-                "id": 32,
+                "id": 317,
                 "type": {
                     "def": {
                         "variant": {}